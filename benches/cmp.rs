@@ -0,0 +1,77 @@
+//! Compares `==` and `cmp` on the string types against the same operations through [`str`].
+
+use divan::{Bencher, black_box};
+use microstring::*;
+
+fn main() {
+    divan::main();
+}
+
+const WORDS: [&str; 8] = ["GBP", "USD", "EUR", "JPY", "GBX", "US", "", "€"];
+
+fn strings<T>(new: fn(&str) -> Option<T>) -> Vec<T> {
+    WORDS.iter().map(|it| new(it).unwrap()).collect()
+}
+
+macro_rules! benches {
+    ($($module:ident: $ty:ty),* $(,)?) => {$(
+        mod $module {
+            use super::*;
+
+            #[divan::bench]
+            fn eq(bencher: Bencher) {
+                let strings = strings(<$ty>::new);
+                bencher.bench_local(|| {
+                    for l in &strings {
+                        for r in &strings {
+                            black_box(black_box(l) == black_box(r));
+                        }
+                    }
+                });
+            }
+
+            #[divan::bench]
+            fn eq_str(bencher: Bencher) {
+                let strings = strings(<$ty>::new);
+                bencher.bench_local(|| {
+                    for l in &strings {
+                        for r in &strings {
+                            black_box(black_box(l).as_str() == black_box(r).as_str());
+                        }
+                    }
+                });
+            }
+
+            #[divan::bench]
+            fn cmp(bencher: Bencher) {
+                let strings = strings(<$ty>::new);
+                bencher.bench_local(|| {
+                    for l in &strings {
+                        for r in &strings {
+                            black_box(black_box(l).cmp(black_box(r)));
+                        }
+                    }
+                });
+            }
+
+            #[divan::bench]
+            fn cmp_str(bencher: Bencher) {
+                let strings = strings(<$ty>::new);
+                bencher.bench_local(|| {
+                    for l in &strings {
+                        for r in &strings {
+                            black_box(black_box(l).as_str().cmp(black_box(r).as_str()));
+                        }
+                    }
+                });
+            }
+        }
+    )*};
+}
+
+benches! {
+    nano: NanoString,
+    micro: MicroString,
+    milli: MilliString,
+    hecto: HectoString,
+}
@@ -0,0 +1,66 @@
+//! Compares lookups in the word-keyed maps against a [`HashMap`].
+
+use std::collections::HashMap;
+
+use divan::{Bencher, black_box};
+use microstring::*;
+
+fn main() {
+    divan::main();
+}
+
+const KEYS: [&str; 8] = ["GBP", "USD", "EUR", "JPY", "GBX", "US", "", "€"];
+
+#[divan::bench]
+fn hash_map(bencher: Bencher) {
+    let map = KEYS
+        .iter()
+        .map(|it| (NanoString::new(it).unwrap(), ()))
+        .collect::<HashMap<_, _>>();
+    let keys = KEYS.map(|it| NanoString::new(it).unwrap());
+    bencher.bench_local(|| {
+        for key in &keys {
+            black_box(map.get(black_box(key)));
+        }
+    });
+}
+
+#[divan::bench]
+fn nano_map(bencher: Bencher) {
+    let map = KEYS
+        .iter()
+        .map(|it| (NanoString::new(it).unwrap(), ()))
+        .collect::<NanoMap<_>>();
+    let keys = KEYS.map(|it| NanoString::new(it).unwrap());
+    bencher.bench_local(|| {
+        for key in &keys {
+            black_box(map.get(black_box(key)));
+        }
+    });
+}
+
+#[divan::bench]
+fn hash_map_str(bencher: Bencher) {
+    let map = KEYS
+        .iter()
+        .map(|it| (NanoString::new(it).unwrap(), ()))
+        .collect::<HashMap<_, _>>();
+    bencher.bench_local(|| {
+        for key in KEYS {
+            black_box(map.get(black_box(key)));
+        }
+    });
+}
+
+#[divan::bench]
+fn nano_map_str(bencher: Bencher) {
+    let map = KEYS
+        .iter()
+        .map(|it| (NanoString::new(it).unwrap(), ()))
+        .collect::<NanoMap<_>>();
+    bencher.bench_local(|| {
+        for key in KEYS {
+            black_box(map.get(black_box(key)));
+        }
+    });
+}
@@ -0,0 +1,377 @@
+//! Procedural macros for [`microstring`](https://docs.rs/microstring).
+//!
+//! Use them through `microstring`, with its `macros` feature enabled.
+
+use proc_macro2::{Ident, Span};
+use quote::{ToTokens as _, format_ident, quote, quote_spanned};
+use syn::{
+    Arm, DeriveInput, Expr, ExprLit, Lit, LitInt, LitStr, Pat, Token, Type, Visibility, braced,
+    parse::{Parse, ParseStream},
+    parse_macro_input, parse_quote,
+};
+
+mod newtype;
+#[allow(dead_code, reason = "only the generator test expands into microstring")]
+mod string;
+
+/// Defines a string type with the given capacity, in bytes.
+///
+/// The type has the same methods and trait impls as the built-in strings,
+/// including an [`InlineStr`](https://docs.rs/microstring/latest/microstring/trait.InlineStr.html) impl,
+/// a niche, and an error, map and (where the size allows) atomic type,
+/// all named after the string type.
+/// ```
+/// microstring::define!(pub struct Label, capacity = 23);
+///
+/// const LABEL: Label = Label::new("hello").unwrap();
+/// assert_eq!(LABEL, "hello");
+/// assert_eq!(size_of::<Label>(), 24);
+/// assert_eq!(size_of::<Option<Label>>(), 24);
+/// assert!(Label::new("this is longer than 23 bytes").is_none());
+/// ```
+///
+/// Each type is defined in a module named after it,
+/// so types whose names differ only in case may be defined alongside each other.
+/// ```
+/// microstring::define!(pub struct Label, capacity = 11);
+/// microstring::define!(pub struct LABEL, capacity = 23);
+/// microstring::define!(pub struct r#Tag, capacity = 5);
+///
+/// assert_eq!(Label::CAPACITY, 11);
+/// assert_eq!(LABEL::CAPACITY, 23);
+/// assert_eq!(Tag::new("hello").unwrap(), "hello");
+/// ```
+///
+/// The capacity must be between 1 and 255.
+/// ```compile_fail
+/// microstring::define!(pub struct Label, capacity = 256);
+/// ```
+///
+/// # Features
+/// Impls for other crates are gated on features of the crate calling this macro,
+/// with the same names as `microstring`'s,
+/// and each must enable the corresponding `microstring` feature.
+/// ```toml
+/// [features]
+/// alloc = ["microstring/alloc"]
+/// std = ["microstring/std"]
+/// serde = ["microstring/serde"]
+/// ```
+/// Features which the calling crate does not declare are off,
+/// and do not cause `unexpected_cfgs` warnings.
+#[proc_macro]
+pub fn define(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let Define {
+        vis,
+        ident,
+        capacity,
+    } = parse_macro_input!(input);
+    // see `string::Host::Downstream`
+    let module = format_ident!("__microstring_{}", ident);
+    let string = string::string(
+        &string::Host::Downstream,
+        &inner_vis(&vis),
+        &ident,
+        capacity,
+    );
+    quote! {
+        #[allow(unexpected_cfgs, non_snake_case)]
+        mod #module {
+            #string
+        }
+        #vis use #module::*;
+    }
+    .into()
+}
+
+/// The visibility inside a child module which is equivalent to `vis` outside of it.
+fn inner_vis(vis: &Visibility) -> proc_macro2::TokenStream {
+    match vis {
+        Visibility::Inherited => quote!(pub(super)),
+        Visibility::Restricted(restricted) => {
+            let path = &restricted.path;
+            let first = path.segments.first().map(|it| it.ident.to_string());
+            match (path.leading_colon, first.as_deref()) {
+                (None, Some("self")) => {
+                    let rest = path.segments.iter().skip(1);
+                    quote!(pub(in super #(::#rest)*))
+                }
+                (None, Some("super")) => quote!(pub(in super::#path)),
+                _ => vis.into_token_stream(),
+            }
+        }
+        Visibility::Public(_) => vis.into_token_stream(),
+    }
+}
+
+/// Creates a [`NanoString`](https://docs.rs/microstring/latest/microstring/struct.NanoString.html)
+/// from a string literal, checking its length at compile time.
+///
+/// This may be used in `const` and `static` positions.
+/// The string types compare as whole words rather than deriving [`PartialEq`],
+/// so they cannot be used as patterns: use [`match_micro!`] instead.
+/// ```
+/// use microstring::{NanoString, nano};
+///
+/// const GBP: NanoString = nano!("GBP");
+/// static USD: NanoString = nano!("USD");
+/// assert_eq!(GBP, "GBP");
+/// assert_eq!(USD, "USD");
+/// ```
+/// Literals which are too long are rejected with a message like
+/// ``"`GEEBEEPEE` is 9 bytes, NanoString holds at most 3"``.
+/// ```compile_fail
+/// microstring::nano!("GEEBEEPEE");
+/// ```
+#[proc_macro]
+pub fn nano(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    literal(parse_macro_input!(input), "NanoString", 3)
+}
+
+/// Creates a [`MicroString`](https://docs.rs/microstring/latest/microstring/struct.MicroString.html)
+/// from a string literal, checking its length at compile time.
+///
+/// See [`nano!`] for details.
+/// ```
+/// const EXCHANGE: microstring::MicroString = microstring::micro!("XLON");
+/// ```
+#[proc_macro]
+pub fn micro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    literal(parse_macro_input!(input), "MicroString", 7)
+}
+
+/// Creates a [`MilliString`](https://docs.rs/microstring/latest/microstring/struct.MilliString.html)
+/// from a string literal, checking its length at compile time.
+///
+/// See [`nano!`] for details.
+/// ```
+/// const GREETING: microstring::MilliString = microstring::milli!("hello world :)");
+/// ```
+#[proc_macro]
+pub fn milli(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    literal(parse_macro_input!(input), "MilliString", 15)
+}
+
+/// Expands to an inline `const` which constructs the string,
+/// after checking that it fits.
+fn literal(lit: LitStr, ty: &str, capacity: usize) -> proc_macro::TokenStream {
+    let s = lit.value();
+    if s.len() > capacity {
+        let msg = format!("`{s}` is {} bytes, {ty} holds at most {capacity}", s.len());
+        return syn::Error::new(lit.span(), msg).into_compile_error().into();
+    }
+    let ty = Ident::new(ty, Span::call_site());
+    quote! {
+        const {
+            match ::microstring::#ty::new(#lit) {
+                ::core::option::Option::Some(it) => it,
+                ::core::option::Option::None => ::core::unreachable!(),
+            }
+        }
+    }
+    .into()
+}
+
+/// Matches a string against string literals, by switching on its packed
+/// [`to_bits`](https://docs.rs/microstring/latest/microstring/struct.NanoString.html#method.to_bits)
+/// representation.
+///
+/// The string's type follows the scrutinee, and may be any
+/// [`InlineStr`](https://docs.rs/microstring/latest/microstring/trait.InlineStr.html),
+/// including those created with [`define!`].
+/// Patterns are string literals, alternatives of them, or `_`,
+/// and arms may have guards.
+/// ```
+/// use microstring::{NanoString, match_micro};
+///
+/// fn symbol(code: NanoString) -> &'static str {
+///     match_micro!(code: NanoString {
+///         "GBP" => "£",
+///         "USD" | "US" => "$",
+///         _ => "¤",
+///     })
+/// }
+/// assert_eq!(symbol(NanoString::new("GBP").unwrap()), "£");
+/// assert_eq!(symbol(NanoString::new("US").unwrap()), "$");
+/// assert_eq!(symbol(NanoString::new("JPY").unwrap()), "¤");
+/// ```
+/// Literals which are too long fail to compile.
+/// ```compile_fail
+/// # use microstring::{NanoString, match_micro};
+/// match_micro!(NanoString::EMPTY: NanoString {
+///     "GEEBEEPEE" => {}
+///     _ => {}
+/// })
+/// ```
+#[proc_macro]
+pub fn match_micro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let MatchMicro {
+        scrutinee,
+        ty,
+        mut arms,
+    } = parse_macro_input!(input);
+    let mut lits = vec![];
+    for arm in &mut arms {
+        if let Err(e) = const_pat(&mut arm.pat, &mut lits) {
+            return e.into_compile_error().into();
+        }
+    }
+    let ty_name = ty.to_token_stream().to_string().replace(' ', "");
+    let consts = lits.iter().enumerate().map(|(ix, lit)| {
+        let ident = format_ident!("__MATCH_MICRO_{ix}");
+        let s = lit.value();
+        let msg = format!(
+            "`{s}` is {} bytes, which does not fit in {ty_name}",
+            s.len()
+        );
+        quote_spanned! {lit.span()=>
+            const #ident: <#ty as ::microstring::InlineStr>::Bits = match <#ty>::new(#lit) {
+                ::core::option::Option::Some(it) => it.to_bits(),
+                ::core::option::Option::None => ::core::panic!(#msg),
+            };
+        }
+    });
+    quote! {
+        {
+            #(#consts)*
+            match <#ty>::to_bits(#scrutinee) {
+                #(#arms)*
+            }
+        }
+    }
+    .into()
+}
+
+/// Replaces each string literal in `pat` with a const, which is defined for the literal in `lits`.
+fn const_pat(pat: &mut Pat, lits: &mut Vec<LitStr>) -> syn::Result<()> {
+    match pat {
+        Pat::Lit(ExprLit {
+            lit: Lit::Str(lit), ..
+        }) => {
+            let ident = format_ident!("__MATCH_MICRO_{}", lits.len());
+            lits.push(lit.clone());
+            *pat = parse_quote!(#ident);
+            Ok(())
+        }
+        Pat::Or(or) => or.cases.iter_mut().try_for_each(|pat| const_pat(pat, lits)),
+        Pat::Paren(paren) => const_pat(&mut paren.pat, lits),
+        Pat::Wild(_) => Ok(()),
+        pat => Err(syn::Error::new_spanned(
+            pat,
+            "expected a string literal or `_`",
+        )),
+    }
+}
+
+/// Implements the same traits as the wrapped string for a newtype, such as `struct Ticker(MicroString)`.
+///
+/// Strings are constructed with [`FromStr`](core::str::FromStr) or [`TryFrom`],
+/// and read through [`Deref<Target = str>`](core::ops::Deref) or [`IntoIterator`].
+/// Comparisons, hashing, formatting, serde, schemars and arbitrary impls
+/// all forward to the wrapped string,
+/// and are gated on features as described for [`define!`].
+///
+/// `#[micro(validate = path)]` names a `fn(&str) -> bool`,
+/// which every constructor calls.
+/// Rejected strings return an error with
+/// [`ErrorKind::Invalid`](https://docs.rs/microstring/latest/microstring/enum.ErrorKind.html#variant.Invalid).
+/// Validated newtypes do not implement [`Default`] or any trait which allows mutation,
+/// such as [`DerefMut`](core::ops::DerefMut).
+///
+/// Some of the wrapped string's API is deliberately not forwarded:
+/// - Comparisons and conversions between sizes, such as `PartialEq<MilliString>`,
+///   which would need an impl for each size.
+///   Use [`AsRef`] or [`From`] to get at the wrapped string.
+/// - [`InlineStr`](https://docs.rs/microstring/latest/microstring/trait.InlineStr.html), which is sealed.
+/// - The map and atomic types.
+///   Newtypes implement [`Lookup`](https://docs.rs/microstring/latest/microstring/trait.Lookup.html)
+///   for the wrapped string, so may be used to look up entries in its map.
+/// - Inherent methods, such as `to_bits` and the `const` methods.
+/// ```
+/// use microstring::{ErrorKind, MicroNewtype, MicroString};
+///
+/// #[derive(Clone, Copy, MicroNewtype)]
+/// #[micro(validate = is_ticker)]
+/// struct Ticker(MicroString);
+///
+/// fn is_ticker(s: &str) -> bool {
+///     !s.is_empty() && s.bytes().all(|b| b.is_ascii_uppercase())
+/// }
+///
+/// let ticker = "AAPL".parse::<Ticker>().unwrap();
+/// assert_eq!(ticker, "AAPL");
+/// assert_eq!(ticker.len(), 4);
+/// assert_eq!("aapl".parse::<Ticker>().unwrap_err().kind(), ErrorKind::Invalid);
+/// assert_eq!("TOOLONGTICKER".parse::<Ticker>().unwrap_err().kind(), ErrorKind::TooLong);
+/// assert!(Ticker::try_from(MicroString::EMPTY).is_err());
+/// assert_eq!(Ticker::try_from(*b"MSFT").unwrap(), "MSFT");
+/// ```
+#[proc_macro_derive(MicroNewtype, attributes(micro))]
+pub fn micro_newtype(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    newtype::newtype(parse_macro_input!(input as DeriveInput))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// `$scrutinee: $ty { $($arm)* }`
+struct MatchMicro {
+    scrutinee: Expr,
+    ty: Type,
+    arms: Vec<Arm>,
+}
+
+impl Parse for MatchMicro {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let scrutinee = Expr::parse_without_eager_brace(input)?;
+        input.parse::<Token![:]>()?;
+        let ty = input.parse()?;
+        let content;
+        braced!(content in input);
+        let mut arms = vec![];
+        while !content.is_empty() {
+            arms.push(content.parse()?);
+        }
+        Ok(Self {
+            scrutinee,
+            ty,
+            arms,
+        })
+    }
+}
+
+/// `$vis struct $ident, capacity = $capacity $(,)?`
+struct Define {
+    vis: Visibility,
+    ident: Ident,
+    capacity: u8,
+}
+
+impl Parse for Define {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let vis = input.parse()?;
+        input.parse::<Token![struct]>()?;
+        let ident = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let key = input.parse::<Ident>()?;
+        if key != "capacity" {
+            return Err(syn::Error::new(key.span(), "expected `capacity`"));
+        }
+        input.parse::<Token![=]>()?;
+        let lit = input.parse::<LitInt>()?;
+        let capacity = match lit.base10_parse::<u8>() {
+            Ok(0) | Err(_) => {
+                return Err(syn::Error::new(
+                    lit.span(),
+                    "capacity must be between 1 and 255",
+                ));
+            }
+            Ok(it) => it,
+        };
+        input.parse::<Option<Token![,]>>()?;
+        Ok(Self {
+            vis,
+            ident,
+            capacity,
+        })
+    }
+}
@@ -0,0 +1,400 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, Path};
+
+use crate::string::Host;
+
+pub fn newtype(input: DeriveInput) -> syn::Result<TokenStream> {
+    let host = Host::Downstream;
+    let krate = host.krate();
+    let [alloc, std, serde, schemars, arbitrary, const_default] = [
+        "alloc",
+        "std",
+        "serde",
+        "schemars",
+        "arbitrary",
+        "const_default",
+    ]
+    .map(|name| host.dep(name));
+    let DeriveInput {
+        attrs,
+        ident,
+        generics,
+        data,
+        ..
+    } = input;
+    if !generics.params.is_empty() || generics.where_clause.is_some() {
+        return Err(syn::Error::new_spanned(
+            generics,
+            "MicroNewtype cannot be derived for generic types",
+        ));
+    }
+    let inner = match data {
+        Data::Struct(data) => match data.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => fields.unnamed[0].ty.clone(),
+            fields => {
+                return Err(syn::Error::new_spanned(
+                    fields,
+                    "MicroNewtype can only be derived for a tuple struct with one field",
+                ));
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                ident,
+                "MicroNewtype can only be derived for a tuple struct with one field",
+            ));
+        }
+    };
+    let mut validate = None::<Path>;
+    for attr in &attrs {
+        if attr.path().is_ident("micro") {
+            attr.parse_nested_meta(|meta| match meta.path.is_ident("validate") {
+                true => {
+                    validate = Some(meta.value()?.parse()?);
+                    Ok(())
+                }
+                false => Err(meta.error("expected `validate`")),
+            })?;
+        }
+    }
+    let inline_str = quote!(<#inner as #krate::InlineStr>);
+    let error = quote!(#inline_str::Error);
+    let (mut owned_cfgs, mut owneds) = host.owneds();
+    // the generator implements `Cow<'static, str>` separately, as it cannot be created from a `&str`
+    owned_cfgs.push(quote!(#[cfg(feature = "alloc")]));
+    owneds.push(quote!(#alloc::borrow::Cow<'static, str>));
+    let (borrowed_cfgs, borrowed) = [
+        (
+            quote!(#[cfg(feature = "alloc")]),
+            quote!(#alloc::boxed::Box<str>),
+        ),
+        (
+            quote!(#[cfg(feature = "alloc")]),
+            quote!(#alloc::borrow::Cow<'_, str>),
+        ),
+        (quote!(#[cfg(feature = "std")]), quote!(&#std::ffi::OsStr)),
+        (quote!(#[cfg(feature = "std")]), quote!(&#std::path::Path)),
+        (quote!(), quote!(&::core::ffi::CStr)),
+    ]
+    .into_iter()
+    .unzip::<_, _, Vec<_>, Vec<_>>();
+    let (str_cfgs, str_likes) = host.str_likes();
+    // a validated string may only be changed by constructing a new one
+    let (check, unchecked) = match validate {
+        Some(validate) => (
+            quote! {
+                let validate: fn(&str) -> bool = #validate;
+                if !validate(&inner) {
+                    return Err(#inline_str::__error(#krate::ErrorKind::Invalid, inner.len()));
+                }
+            },
+            quote!(),
+        ),
+        None => (
+            quote!(),
+            quote! {
+                impl ::core::default::Default for #ident {
+                    fn default() -> Self {
+                        Self(#inline_str::EMPTY)
+                    }
+                }
+                #[cfg(feature = "const-default")]
+                impl #const_default::ConstDefault for #ident {
+                    const DEFAULT: Self = Self(#inline_str::EMPTY);
+                }
+                impl ::core::convert::AsMut<str> for #ident {
+                    fn as_mut(&mut self) -> &mut str {
+                        ::core::convert::AsMut::<str>::as_mut(&mut self.0)
+                    }
+                }
+                impl ::core::borrow::BorrowMut<str> for #ident {
+                    fn borrow_mut(&mut self) -> &mut str {
+                        ::core::borrow::BorrowMut::<str>::borrow_mut(&mut self.0)
+                    }
+                }
+                impl ::core::ops::DerefMut for #ident {
+                    fn deref_mut(&mut self) -> &mut str {
+                        &mut self.0
+                    }
+                }
+                impl ::core::fmt::Write for #ident {
+                    fn write_str(&mut self, s: &str) -> ::core::fmt::Result {
+                        ::core::fmt::Write::write_str(&mut self.0, s)
+                    }
+                    fn write_char(&mut self, c: char) -> ::core::fmt::Result {
+                        ::core::fmt::Write::write_char(&mut self.0, c)
+                    }
+                    fn write_fmt(&mut self, args: ::core::fmt::Arguments<'_>) -> ::core::fmt::Result {
+                        ::core::fmt::Write::write_fmt(&mut self.0, args)
+                    }
+                }
+                impl ::core::iter::Extend<char> for #ident {
+                    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+                        ::core::iter::Extend::<char>::extend(&mut self.0, iter)
+                    }
+                }
+                impl<'a> ::core::iter::Extend<&'a str> for #ident {
+                    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+                        ::core::iter::Extend::<&'a str>::extend(&mut self.0, iter)
+                    }
+                }
+            },
+        ),
+    };
+    let impls = quote! {
+        // construct
+        // ---------
+
+        /// Every other constructor goes through here.
+        impl ::core::convert::TryFrom<#inner> for #ident {
+            type Error = #error;
+            fn try_from(inner: #inner) -> Result<Self, #error> {
+                #check
+                Ok(Self(inner))
+            }
+        }
+        impl ::core::str::FromStr for #ident {
+            type Err = #error;
+            fn from_str(s: &str) -> Result<Self, #error> {
+                s.parse::<#inner>().and_then(Self::try_from)
+            }
+        }
+        impl ::core::convert::TryFrom<&str> for #ident {
+            type Error = #error;
+            fn try_from(value: &str) -> Result<Self, #error> {
+                value.parse()
+            }
+        }
+        impl ::core::convert::TryFrom<&[u8]> for #ident {
+            type Error = #error;
+            fn try_from(value: &[u8]) -> Result<Self, #error> {
+                #inline_str::from_utf8(value).and_then(Self::try_from)
+            }
+        }
+        impl<const N: usize> ::core::convert::TryFrom<[u8; N]> for #ident {
+            type Error = #error;
+            fn try_from(value: [u8; N]) -> Result<Self, #error> {
+                #inline_str::from_utf8(&value).and_then(Self::try_from)
+            }
+        }
+        #[cfg(feature = "alloc")]
+        impl ::core::convert::TryFrom<#alloc::string::String> for #ident {
+            type Error = #error;
+            fn try_from(value: #alloc::string::String) -> Result<Self, #error> {
+                value.parse()
+            }
+        }
+        #(
+            #borrowed_cfgs
+            impl ::core::convert::TryFrom<#borrowed> for #ident {
+                type Error = #error;
+                fn try_from(value: #borrowed) -> Result<Self, #error> {
+                    <#inner as ::core::convert::TryFrom<#borrowed>>::try_from(value).and_then(Self::try_from)
+                }
+            }
+        )*
+
+        // convert
+        // -------
+
+        impl ::core::convert::From<#ident> for #inner {
+            fn from(value: #ident) -> Self {
+                value.0
+            }
+        }
+        #(
+            #owned_cfgs
+            impl ::core::convert::From<#ident> for #owneds {
+                fn from(value: #ident) -> Self {
+                    Self::from(value.0)
+                }
+            }
+        )*
+        impl ::core::convert::AsRef<#inner> for #ident {
+            fn as_ref(&self) -> &#inner {
+                &self.0
+            }
+        }
+        impl ::core::convert::AsRef<str> for #ident {
+            fn as_ref(&self) -> &str {
+                ::core::convert::AsRef::<str>::as_ref(&self.0)
+            }
+        }
+        impl ::core::convert::AsRef<[u8]> for #ident {
+            fn as_ref(&self) -> &[u8] {
+                ::core::convert::AsRef::<[u8]>::as_ref(&self.0)
+            }
+        }
+        #[cfg(feature = "std")]
+        impl ::core::convert::AsRef<#std::ffi::OsStr> for #ident {
+            fn as_ref(&self) -> &#std::ffi::OsStr {
+                ::core::convert::AsRef::<#std::ffi::OsStr>::as_ref(&self.0)
+            }
+        }
+        #[cfg(feature = "std")]
+        impl ::core::convert::AsRef<#std::path::Path> for #ident {
+            fn as_ref(&self) -> &#std::path::Path {
+                ::core::convert::AsRef::<#std::path::Path>::as_ref(&self.0)
+            }
+        }
+        impl ::core::borrow::Borrow<str> for #ident {
+            fn borrow(&self) -> &str {
+                ::core::borrow::Borrow::<str>::borrow(&self.0)
+            }
+        }
+        impl ::core::ops::Deref for #ident {
+            type Target = str;
+            fn deref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl ::core::iter::IntoIterator for #ident {
+            type Item = char;
+            type IntoIter = #krate::IntoChars<#inner>;
+            fn into_iter(self) -> #krate::IntoChars<#inner> {
+                ::core::iter::IntoIterator::into_iter(self.0)
+            }
+        }
+
+        // cmp
+        // ---
+
+        impl ::core::cmp::PartialEq for #ident {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+        impl ::core::cmp::Eq for #ident {}
+        impl ::core::cmp::PartialOrd for #ident {
+            fn partial_cmp(&self, other: &Self) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for #ident {
+            fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+        /// Hashes the same as the wrapped string.
+        impl ::core::hash::Hash for #ident {
+            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                ::core::hash::Hash::hash(&self.0, state)
+            }
+        }
+        #(
+            #str_cfgs
+            impl ::core::cmp::PartialEq<#str_likes> for #ident {
+                fn eq(&self, other: &#str_likes) -> bool {
+                    self.0.eq(other)
+                }
+            }
+            #str_cfgs
+            impl ::core::cmp::PartialOrd<#str_likes> for #ident {
+                fn partial_cmp(&self, other: &#str_likes) -> Option<::core::cmp::Ordering> {
+                    self.0.partial_cmp(other)
+                }
+            }
+            #str_cfgs
+            impl ::core::cmp::PartialEq<#ident> for #str_likes {
+                fn eq(&self, other: &#ident) -> bool {
+                    other.0.eq(self)
+                }
+            }
+            #str_cfgs
+            impl ::core::cmp::PartialOrd<#ident> for #str_likes {
+                fn partial_cmp(&self, other: &#ident) -> Option<::core::cmp::Ordering> {
+                    other.0.partial_cmp(self).map(::core::cmp::Ordering::reverse)
+                }
+            }
+        )*
+
+        // map
+        // ---
+
+        /// Looks up entries in the map for the wrapped string, such as `MicroMap`.
+        impl #krate::Lookup<#inner> for #ident {
+            fn lookup(&self) -> Option<#inner> {
+                Some(self.0)
+            }
+        }
+
+        // fmt
+        // ---
+
+        impl ::core::fmt::Debug for #ident {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Debug::fmt(&self.0, f)
+            }
+        }
+        impl ::core::fmt::Display for #ident {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        // serde
+        // -----
+
+        #[cfg(feature = "serde")]
+        impl #serde::Serialize for #ident {
+            fn serialize<S: #serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+                #serde::Serialize::serialize(&self.0, s)
+            }
+        }
+        #[cfg(feature = "serde")]
+        impl<'de> #serde::Deserialize<'de> for #ident {
+            fn deserialize<D: #serde::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+                let inner = <#inner as #serde::Deserialize<'de>>::deserialize(d)?;
+                Self::try_from(inner).map_err(#serde::de::Error::custom)
+            }
+        }
+
+        // schemars
+        // --------
+
+        #[cfg(feature = "schemars")]
+        impl #schemars::JsonSchema for #ident {
+            fn schema_name() -> #std::borrow::Cow<'static, str> {
+                #std::borrow::Cow::Borrowed(::core::stringify!(#ident))
+            }
+            fn json_schema(generator: &mut #schemars::SchemaGenerator) -> #schemars::Schema {
+                <#inner as #schemars::JsonSchema>::json_schema(generator)
+            }
+            fn schema_id() -> #std::borrow::Cow<'static, str> {
+                #std::borrow::Cow::Borrowed(::core::concat!(
+                    ::core::module_path!(),
+                    "::",
+                    ::core::stringify!(#ident)
+                ))
+            }
+        }
+
+        // arbitrary
+        // ---------
+
+        #[cfg(feature = "arbitrary")]
+        impl<'a> #arbitrary::Arbitrary<'a> for #ident {
+            fn arbitrary(u: &mut #arbitrary::Unstructured<'a>) -> #arbitrary::Result<Self> {
+                let inner = <#inner as #arbitrary::Arbitrary<'a>>::arbitrary(u)?;
+                Self::try_from(inner).map_err(|_| #arbitrary::Error::IncorrectFormat)
+            }
+            fn size_hint(depth: usize) -> (usize, Option<usize>) {
+                <#inner as #arbitrary::Arbitrary<'a>>::size_hint(depth)
+            }
+        }
+
+        // unchecked
+        // ---------
+
+        #unchecked
+    };
+    // see `Host::Downstream`
+    Ok(quote! {
+        #[allow(unexpected_cfgs)]
+        const _: () = {
+            #impls
+        };
+    })
+}
@@ -0,0 +1,2262 @@
+use proc_macro2::{Ident, Literal, Span, TokenStream};
+use quote::quote;
+
+/// Where the generated code is expanded.
+pub enum Host<'a> {
+    /// In this crate, with a short and a too-long string for the examples.
+    Microstring { small: &'a str, big: &'a str },
+    /// In a downstream crate, through `microstring::define!` or `#[derive(MicroNewtype)]`.
+    ///
+    /// The generated items are gated on features which the downstream crate may not declare,
+    /// and `unexpected_cfgs` can only be allowed on a scope enclosing them.
+    Downstream,
+}
+
+impl Host<'_> {
+    /// The path to the `microstring` crate.
+    pub fn krate(&self) -> TokenStream {
+        match self {
+            Self::Microstring { .. } => quote!(crate),
+            Self::Downstream => quote!(::microstring),
+        }
+    }
+    /// The path to the given dependency, which downstream crates may not depend on directly.
+    pub fn dep(&self, name: &str) -> TokenStream {
+        let ident = Ident::new(name, Span::call_site());
+        match self {
+            Self::Microstring { .. } if matches!(name, "alloc" | "std") => quote!(::#ident),
+            Self::Microstring { .. } => quote!(#ident),
+            Self::Downstream => quote!(::microstring::__private::#ident),
+        }
+    }
+    /// Owned strings which can be created from a `&str`, and the `cfg` each is gated on.
+    pub fn owneds(&self) -> (Vec<TokenStream>, Vec<TokenStream>) {
+        let [alloc, std] = ["alloc", "std"].map(|name| self.dep(name));
+        [
+            (
+                quote!(#[cfg(feature = "alloc")]),
+                quote!(#alloc::string::String),
+            ),
+            (
+                quote!(#[cfg(feature = "alloc")]),
+                quote!(#alloc::boxed::Box<str>),
+            ),
+            (
+                quote!(#[cfg(feature = "alloc")]),
+                quote!(#alloc::rc::Rc<str>),
+            ),
+            (
+                quote!(#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]),
+                quote!(#alloc::sync::Arc<str>),
+            ),
+            (quote!(#[cfg(feature = "std")]), quote!(#std::ffi::OsString)),
+            (quote!(#[cfg(feature = "std")]), quote!(#std::path::PathBuf)),
+        ]
+        .into_iter()
+        .unzip()
+    }
+    /// [`str`] and the strings which compare as it, and the `cfg` each is gated on.
+    pub fn str_likes(&self) -> (Vec<TokenStream>, Vec<TokenStream>) {
+        let alloc = self.dep("alloc");
+        [
+            (quote!(), quote!(str)),
+            (quote!(), quote!(&str)),
+            (
+                quote!(#[cfg(feature = "alloc")]),
+                quote!(#alloc::string::String),
+            ),
+            (
+                quote!(#[cfg(feature = "alloc")]),
+                quote!(#alloc::borrow::Cow<'_, str>),
+            ),
+            (
+                quote!(#[cfg(feature = "alloc")]),
+                quote!(#alloc::boxed::Box<str>),
+            ),
+        ]
+        .into_iter()
+        .unzip()
+    }
+    /// `item`, deriving the zerocopy traits when the `zerocopy` feature is enabled.
+    ///
+    /// Downstream, `item` is written out twice,
+    /// because `#[allow(unexpected_cfgs)]` has no effect on a `cfg_attr`.
+    fn derive_zerocopy(&self, attrs: TokenStream, item: TokenStream) -> TokenStream {
+        let zerocopy = self.dep("zerocopy");
+        let derive = quote!(derive(#zerocopy::IntoBytes, #zerocopy::Immutable, #zerocopy::KnownLayout, #zerocopy::Unaligned));
+        match self {
+            Self::Microstring { .. } => quote! {
+                #attrs
+                #[cfg_attr(feature = "zerocopy", #derive)]
+                #item
+            },
+            Self::Downstream => quote! {
+                #[cfg(feature = "zerocopy")]
+                #attrs
+                #[#derive]
+                #[zerocopy(crate = "::microstring::__private::zerocopy")]
+                #item
+                #[cfg(not(feature = "zerocopy"))]
+                #attrs
+                #item
+            },
+        }
+    }
+    /// Documentation examples, which are only written for this crate's own types.
+    fn example(&self, f: impl FnOnce(&str, &str) -> String) -> String {
+        match self {
+            Self::Microstring { small, big } => f(small, big),
+            Self::Downstream => String::new(),
+        }
+    }
+}
+
+/// The unsigned integer with the same size as a string of capacity `n`, if there is one.
+fn prim(n: u8) -> Option<&'static str> {
+    match n {
+        1 => Some("u16"),
+        3 => Some("u32"),
+        7 => Some("u64"),
+        15 => Some("u128"),
+        _ => None,
+    }
+}
+
+pub fn string(host: &Host, vis: &TokenStream, ident: &Ident, n: u8) -> TokenStream {
+    let krate = host.krate();
+    let private = quote!(#krate::__private);
+    let [
+        alloc,
+        std,
+        serde,
+        schemars,
+        arbitrary,
+        const_default,
+        unicode_segmentation,
+    ] = [
+        "alloc",
+        "std",
+        "serde",
+        "schemars",
+        "arbitrary",
+        "const_default",
+        "unicode_segmentation",
+    ]
+    .map(|name| host.dep(name));
+    // without the `r#` of a raw identifier, which cannot be suffixed
+    let name = ident.to_string().trim_start_matches("r#").to_owned();
+    let err_ident = Ident::new(&format!("{name}Error"), Span::call_site());
+    let len_ident = Ident::new(&format!("{name}Len"), Span::call_site());
+    let len = len(host, &len_ident, n);
+    let prim = prim(n);
+    let (bits, packed) = match prim {
+        Some(prim) => {
+            let prim = Ident::new(prim, Span::call_site());
+            (quote!(#prim), "a single integer")
+        }
+        None => (quote!([u8; #n as usize + 1]), "a byte array"),
+    };
+    // every length byte is valid at the largest capacity, leaving no niche
+    let (niche_doc, option_size) = match n {
+        u8::MAX => (
+            "\nEvery length is valid, so there is no niche for [`Option`] to use.\n",
+            " + 1",
+        ),
+        _ => ("", ""),
+    };
+    let struct_doc = indoc::formatdoc! {"
+        A stack-allocated string which can hold up to {n} UTF-8 encoded bytes.
+    "} + niche_doc
+        + &indoc::formatdoc! {"
+
+            With the `zerocopy` feature, this implements `IntoBytes`, `Immutable`,
+            `KnownLayout` and `Unaligned`.
+            `TryFromBytes` is deliberately not implemented, because its derive cannot check
+            that the bytes are UTF-8 followed by zero padding,
+            and neither is `FromZeros`, which requires it.
+            Use [`try_ref_from_bytes`]({ident}::try_ref_from_bytes)
+            or [`try_slice_from_bytes`]({ident}::try_slice_from_bytes) instead.
+        "}
+        + &host.example(|_, _| {
+            let size = match prim {
+                Some(prim) => format!("size_of::<{prim}>()"),
+                None => format!("{n} + 1"),
+            };
+            indoc::formatdoc! {"
+                ```
+                # use core::mem::size_of;
+                # use microstring::*;
+                assert_eq! {{
+                    size_of::<{ident}>(),
+                    {size},
+                }}
+                assert_eq! {{
+                    size_of::<{ident}>(){option_size},
+                    size_of::<Option<{ident}>>(),
+                }}
+                ```
+            "}
+        });
+    let new_doc = indoc::formatdoc! {"
+        Returns [`None`] if the given <code>[str::len()] > {n}</code>.
+    "} + &host.example(|small, big| {
+        indoc::formatdoc! {"
+            ```
+            # use microstring::*;
+            const STRING: {ident} = {ident}::new(\"{small}\").unwrap();
+            ```
+            ```compile_fail
+            # use microstring::*;
+            const TOO_BIG: {ident} = {ident}::new(\"{big}\").unwrap();
+            ```
+        "}
+    });
+    let from_utf8_doc = indoc::formatdoc! {"
+        Returns an error if the given bytes are not UTF-8, or if <code>[slice::len()] > {n}</code>.
+    "} + &host.example(|small, big| {
+        indoc::formatdoc! {"
+            ```
+            # use microstring::*;
+            const STRING: {ident} = match {ident}::from_utf8(b\"{small}\") {{
+                Ok(it) => it,
+                Err(_) => panic!(),
+            }};
+            assert_eq!({ident}::from_utf8(b\"\\xFF\").unwrap_err().kind(), ErrorKind::InvalidUtf8);
+            assert_eq!({ident}::from_utf8(b\"{big}\").unwrap_err().kind(), ErrorKind::TooLong);
+            ```
+        "}
+    });
+    let from_utf8_unchecked_doc = indoc::formatdoc! {"
+        Returns [`None`] if <code>[slice::len()] > {n}</code>.
+
+        # Safety
+        - The given bytes must be valid UTF-8.
+    "};
+    let try_concat_doc = indoc::formatdoc! {"
+        Concatenates the given strings.
+
+        Returns an error if the result would be longer than {n} bytes.
+    "} + &host.example(|small, big| {
+        indoc::formatdoc! {"
+            ```
+            # use microstring::*;
+            assert_eq!({ident}::try_concat(\"{small}\", \"\").unwrap(), \"{small}\");
+            assert!({ident}::try_concat(\"{small}\", \"{big}\").is_err());
+            ```
+        "}
+    });
+    let new_truncating_doc = indoc::formatdoc! {"
+        Truncates the given string to at most {n} bytes, on a [`char`] boundary.
+    "} + &host.example(|_, big| {
+        let truncated = &big[..n as usize];
+        indoc::formatdoc! {"
+            ```
+            # use microstring::*;
+            const STRING: {ident} = {ident}::new_truncating(\"{big}\");
+            assert_eq!(STRING, \"{truncated}\");
+            ```
+        "}
+    });
+    let new_truncating_with_marker_doc = indoc::formatdoc! {"
+        If the given string is longer than {n} bytes, truncates it on a [`char`] boundary,
+        leaving room to append the given `marker`.
+
+        # Panics
+        - If the string needs truncating, and <code>marker.[len()](str::len) > {n}</code>.
+    "} + &host.example(|_, big| {
+        let marked = format!("{}…", &big[..n as usize - '…'.len_utf8()]);
+        indoc::formatdoc! {"
+            ```
+            # use microstring::*;
+            const STRING: {ident} = {ident}::new_truncating_with_marker(\"{big}\", \"…\");
+            assert_eq!(STRING, \"{marked}\");
+            ```
+        "}
+    });
+    let to_bits_doc = match prim {
+        Some(prim) => format!("Packs this string into a [`{prim}`].\n"),
+        None => "Packs this string into a byte array.\n".into(),
+    } + &indoc::formatdoc! {"
+
+        The string's bytes, zero padded to {n} bytes, come first,
+        and its length is the last byte.
+        This is stable, so may be persisted.
+        Comparing the packed representations orders them the same as comparing the strings.
+    "} + &host.example(|small, _| {
+        let small_len = small.len();
+        let small_be = format!(
+            "{small}{}\\x{small_len:02x}",
+            "\\0".repeat(n as usize - small_len)
+        );
+        let (bits, expected) = match prim {
+            Some(prim) => (
+                prim.into(),
+                format!("{prim}::from_be_bytes(*b\"{small_be}\")"),
+            ),
+            None => (format!("[u8; {n} + 1]"), format!("*b\"{small_be}\"")),
+        };
+        indoc::formatdoc! {"
+            ```
+            # use microstring::*;
+            const BITS: {bits} = {ident}::new(\"{small}\").unwrap().to_bits();
+            assert_eq!(BITS, {expected});
+            assert_eq!({ident}::from_bits(BITS), {ident}::new(\"{small}\"));
+            ```
+        "}
+    });
+    let try_ref_from_bytes_doc = indoc::formatdoc! {"
+        Interprets the given bytes as a string, without copying.
+
+        The bytes must be laid out as produced by `zerocopy::IntoBytes`:
+        a length byte, followed by that many bytes of UTF-8, followed by zero padding.
+        Returns [`None`] otherwise.
+
+        This is the checked alternative to `zerocopy::TryFromBytes`,
+        which is not implemented for this type because it cannot check the contents.
+    "} + &host.example(|small, _| {
+        let small_len = small.len();
+        indoc::formatdoc! {"
+            ```
+            # use microstring::*;
+            let mut bytes = [0; {n} + 1];
+            bytes[0] = {small_len};
+            bytes[1..][..{small_len}].copy_from_slice(b\"{small}\");
+            assert_eq!({ident}::try_ref_from_bytes(&bytes).unwrap().as_str(), \"{small}\");
+            bytes[1] = 0xFF;
+            assert_eq!({ident}::try_ref_from_bytes(&bytes), None);
+            ```
+        "}
+    });
+    let map_ident = Ident::new(
+        &format!("{}Map", name.trim_end_matches("String")),
+        Span::call_site(),
+    );
+    let krate_doc = krate.to_string().replace(' ', "");
+    let map_doc = indoc::formatdoc! {"
+        A hash map keyed by [`{ident}`].
+
+        Keys are stored and hashed as their packed [`to_bits`]({ident}::to_bits) representation,
+        using [`BuildWordHasher`]({krate_doc}::BuildWordHasher).
+        Entries may also be looked up by [`str`] and [`String`](std::string::String).
+    "} + &host.example(|small, big| {
+        indoc::formatdoc! {"
+            ```
+            # use microstring::*;
+            let mut map = {map_ident}::new();
+            map.insert({ident}::new(\"{small}\").unwrap(), 1);
+            assert_eq!(map.get(\"{small}\"), Some(&1));
+            assert_eq!(map.get(&{ident}::new(\"{small}\").unwrap()), Some(&1));
+            assert_eq!(map.get(&String::from(\"{small}\")), Some(&1));
+            assert_eq!(map.get(\"{big}\"), None);
+
+            *map.entry({ident}::new(\"{small}\").unwrap()).or_default() += 1;
+            assert_eq!(map.into_iter().collect::<Vec<_>>(), [({ident}::new(\"{small}\").unwrap(), 2)]);
+            ```
+        "}
+    });
+    let arbitrary_doc = indoc::formatdoc! {"
+        Generates strings of every length up to {n} bytes, including multi-byte [`char`]s.
+    "} + &host.example(|_, _| {
+        indoc::formatdoc! {"
+            ```
+            # use microstring::*;
+            use arbitrary::{{Arbitrary, Unstructured}};
+
+            // an empty input consumes nothing
+            assert_eq!({ident}::arbitrary(&mut Unstructured::new(&[])).unwrap(), \"\");
+            let (min, max) = {ident}::size_hint(0);
+            assert_eq!(min, 0);
+            let max = max.unwrap();
+
+            let mut lens = [false; {n} + 1];
+            let mut multi_byte = false;
+            for seed in 0..=u8::MAX {{
+                // the first byte chooses the length
+                let mut state = u32::from(seed) << 8 | 1;
+                let data = [seed].into_iter().chain((1..max).map(|_| {{
+                    state ^= state << 13;
+                    state ^= state >> 17;
+                    state ^= state << 5;
+                    state as u8
+                }})).collect::<Vec<_>>();
+                let mut u = Unstructured::new(&data);
+                let s = {ident}::arbitrary(&mut u).unwrap();
+                assert!(min <= data.len() - u.len() && data.len() - u.len() <= max);
+                assert!(str::from_utf8(s.as_bytes()).is_ok());
+                assert_eq!(s.to_bits(), {ident}::new(&s).unwrap().to_bits());
+                lens[s.len()] = true;
+                multi_byte |= s.chars().any(|c| c.len_utf8() > 1);
+            }}
+            assert!(lens.iter().all(|it| *it) && multi_byte);
+            ```
+        "}
+    });
+    let push_str_doc = indoc::formatdoc! {"
+        Appends the given string to the end of this one.
+
+        Returns an error, leaving this string unchanged, if the result would be
+        longer than {n} bytes.
+    "} + &host.example(|small, big| {
+        indoc::formatdoc! {"
+            ```
+            # use microstring::*;
+            let mut string = {ident}::new(\"{small}\").unwrap();
+            assert!(string.push_str(\"{big}\").is_err());
+            assert_eq!(string, \"{small}\");
+            ```
+        "}
+    });
+    let pop_doc = indoc::formatdoc! {"
+        Removes the last [`char`] from this string and returns it,
+        or [`None`] if this string is empty.
+    "} + &host.example(|_, _| {
+        indoc::formatdoc! {"
+            ```
+            # use microstring::*;
+            let mut string = {ident}::new(\"aé\").unwrap();
+            assert_eq!(string.pop(), Some('é'));
+            assert_eq!(string.to_bits(), {ident}::new(\"a\").unwrap().to_bits());
+            assert_eq!(string.pop(), Some('a'));
+            assert_eq!(string.pop(), None);
+            assert_eq!(string.to_bits(), {ident}::EMPTY.to_bits());
+            ```
+        "}
+    });
+    let truncate_doc = indoc::formatdoc! {"
+        Shortens this string to `new_len` bytes.
+
+        Has no effect if `new_len` is greater than the string's current length.
+
+        # Panics
+        - If `new_len` does not lie on a [`char`] boundary.
+    "} + &host.example(|_, _| {
+        indoc::formatdoc! {"
+            ```
+            # use microstring::*;
+            let mut string = {ident}::new(\"aé\").unwrap();
+            string.truncate(3);
+            assert_eq!(string, \"aé\");
+            string.truncate(1);
+            assert_eq!(string.to_bits(), {ident}::new(\"a\").unwrap().to_bits());
+            ```
+            ```should_panic
+            # use microstring::*;
+            {ident}::new(\"é\").unwrap().truncate(1);
+            ```
+        "}
+    });
+    let clear_doc = indoc::formatdoc! {"
+        Truncates this string to the empty string.
+    "} + &host.example(|small, _| {
+        indoc::formatdoc! {"
+            ```
+            # use microstring::*;
+            let mut string = {ident}::new(\"{small}\").unwrap();
+            string.clear();
+            assert_eq!(string.to_bits(), {ident}::EMPTY.to_bits());
+            ```
+        "}
+    });
+    let insert_str_doc = indoc::formatdoc! {"
+        Inserts the given string at byte position `idx`.
+
+        Returns an error, leaving this string unchanged, if there is not enough capacity.
+
+        # Panics
+        - If `idx` is out of bounds, or does not lie on a [`char`] boundary.
+    "} + &host.example(|_, big| {
+        indoc::formatdoc! {"
+            ```
+            # use microstring::*;
+            let mut string = {ident}::new(\"a\").unwrap();
+            string.insert_str(0, \"é\").unwrap();
+            assert_eq!(string, \"éa\");
+            assert!(string.insert_str(2, \"{big}\").is_err());
+            assert_eq!(string.to_bits(), {ident}::new(\"éa\").unwrap().to_bits());
+            ```
+            ```should_panic
+            # use microstring::*;
+            let _ = {ident}::new(\"é\").unwrap().insert_str(1, \"a\");
+            ```
+        "}
+    });
+    let remove_doc = indoc::formatdoc! {"
+        Removes the [`char`] at byte position `idx` and returns it.
+
+        # Panics
+        - If `idx` is out of bounds, or does not lie on a [`char`] boundary.
+    "} + &host.example(|_, _| {
+        indoc::formatdoc! {"
+            ```
+            # use microstring::*;
+            let mut string = {ident}::new(\"éa\").unwrap();
+            assert_eq!(string.remove(0), 'é');
+            assert_eq!(string.to_bits(), {ident}::new(\"a\").unwrap().to_bits());
+            ```
+            ```should_panic
+            # use microstring::*;
+            {ident}::new(\"é\").unwrap().remove(1);
+            ```
+        "}
+    });
+    let retain_doc = indoc::formatdoc! {"
+        Retains only the [`char`]s for which `f` returns `true`.
+    "} + &host.example(|_, _| {
+        indoc::formatdoc! {"
+            ```
+            # use microstring::*;
+            let mut string = {ident}::new(\"éa\").unwrap();
+            string.retain(|c| c.is_ascii());
+            assert_eq!(string.to_bits(), {ident}::new(\"a\").unwrap().to_bits());
+            ```
+        "}
+    });
+    // the letters at either end of each case, the bytes either side of them, and a non-ASCII char,
+    // split into as many strings as it takes to fit
+    let case_edges = {
+        let mut chunks = vec![String::new()];
+        for c in "@AZ[`az{é".chars() {
+            if chunks.last().unwrap().len() + c.len_utf8() > n as usize {
+                chunks.push(String::new())
+            }
+            chunks.last_mut().unwrap().push(c)
+        }
+        format!("{chunks:?}")
+    };
+    let to_ascii_uppercase_doc = indoc::formatdoc! {"
+        Returns a copy of this string, with ASCII letters converted to upper case.
+
+        Other bytes are unchanged.
+    "} + &host.example(|small, _| {
+        indoc::formatdoc! {"
+            ```
+            # use microstring::*;
+            const UPPER: {ident} = {ident}::new(\"{small}\").unwrap().to_ascii_uppercase();
+            assert_eq!(UPPER, \"{upper}\");
+            for s in {case_edges} {{
+                assert_eq!({ident}::new(s).unwrap().to_ascii_uppercase(), s.to_ascii_uppercase().as_str());
+            }}
+            ```
+        ", upper = small.to_ascii_uppercase()}
+    });
+    let to_ascii_lowercase_doc = indoc::formatdoc! {"
+        Returns a copy of this string, with ASCII letters converted to lower case.
+
+        Other bytes are unchanged.
+    "} + &host.example(|small, _| {
+        indoc::formatdoc! {"
+            ```
+            # use microstring::*;
+            const LOWER: {ident} = {ident}::new(\"{upper}\").unwrap().to_ascii_lowercase();
+            assert_eq!(LOWER, \"{lower}\");
+            for s in {case_edges} {{
+                assert_eq!({ident}::new(s).unwrap().to_ascii_lowercase(), s.to_ascii_lowercase().as_str());
+            }}
+            ```
+        ", upper = small.to_ascii_uppercase(), lower = small.to_ascii_lowercase()}
+    });
+    let try_from_iter_doc = indoc::formatdoc! {"
+        Collects the given [`char`]s into a string.
+
+        Returns an error if there are more than {n} bytes of them.
+        See also [`Collected`]({krate_doc}::Collected).
+    "} + &host.example(|small, big| {
+        indoc::formatdoc! {"
+            ```
+            # use microstring::*;
+            assert_eq!({ident}::try_from_iter(\"{small}\".chars()).unwrap(), \"{small}\");
+            assert!({ident}::try_from_iter(\"{big}\".chars()).is_err());
+            ```
+        "}
+    });
+    let into_bytes_doc = indoc::formatdoc! {"
+        Returns an owning iterator over the bytes of this string.
+    "} + &host.example(|small, _| {
+        indoc::formatdoc! {"
+            ```
+            # use microstring::*;
+            let string = {ident}::new(\"{small}\").unwrap();
+            assert!(string.into_bytes().eq(\"{small}\".bytes()));
+            assert_eq!(string.into_bytes().len(), {len});
+            ```
+        ", len = small.len()}
+    });
+    let into_iter_doc = indoc::formatdoc! {"
+        An owning iterator over the [`char`]s of this string.
+    "} + &host.example(|small, _| {
+        indoc::formatdoc! {"
+            ```
+            # use microstring::*;
+            let string = {ident}::new(\"{small}\").unwrap();
+            assert!(string.into_iter().rev().eq(\"{small}\".chars().rev()));
+            ```
+        "}
+    });
+    let write_doc = indoc::formatdoc! {"
+        Writes which would overflow the capacity return [`fmt::Error`](::core::fmt::Error).
+
+        On error, the string is restored to its state before the call to
+        [`write!`](::core::write).
+    "} + &host.example(|small, big| {
+        indoc::formatdoc! {"
+            ```
+            # use microstring::*;
+            use core::fmt::Write as _;
+            let mut string = {ident}::EMPTY;
+            assert!(write!(string, \"{{}}{{}}\", \"{small}\", \"{big}\").is_err());
+            assert_eq!(string, \"\");
+            ```
+        "}
+    });
+    // `#[macro_export]` macros can't be named by path from the crate that expands them,
+    // so downstream crates just get `try_from_fmt`.
+    let (try_from_fmt_doc, format_macro) = match host {
+        Host::Microstring { small, big } => {
+            let format_ident = Ident::new(
+                &format!(
+                    "{}_format",
+                    ident.to_string().trim_end_matches("String").to_lowercase()
+                ),
+                Span::call_site(),
+            );
+            let format_doc = indoc::formatdoc! {"
+                Creates a [`{ident}`] using interpolation of runtime expressions,
+                like [`format!`](https://doc.rust-lang.org/std/macro.format.html).
+
+                Returns an error if the result would be longer than {n} bytes.
+                ```
+                # use microstring::*;
+                assert_eq!({format_ident}!(\"{{}}\", \"{small}\").unwrap(), \"{small}\");
+                assert!({format_ident}!(\"{{}}\", \"{big}\").is_err());
+                ```
+            "};
+            let try_from_fmt_doc = indoc::formatdoc! {"
+                Creates a string from the given [`Arguments`](::core::fmt::Arguments).
+
+                See also [`{format_ident}!`](crate::{format_ident}).
+            "};
+            let format_macro = quote! {
+                #[doc = #format_doc]
+                #[macro_export]
+                macro_rules! #format_ident {
+                    ($($arg:tt)*) => {
+                        $crate::#ident::try_from_fmt(::core::format_args!($($arg)*))
+                    };
+                }
+            };
+            (try_from_fmt_doc, format_macro)
+        }
+        Host::Downstream => (
+            "Creates a string from the given [`Arguments`](::core::fmt::Arguments).\n".into(),
+            quote!(),
+        ),
+    };
+    let eq_doc = format!(" Compares the whole string as {packed}.");
+    // the padding is always zero, so the bytes past the length need not be skipped
+    let eq_body = match prim {
+        Some(_) => quote!(self.to_ne_bits() == other.to_ne_bits()),
+        None => quote!(self.len as u8 == other.len as u8 && self.bytes == other.bytes),
+    };
+    // a big-endian compare of a `u128` is slower than comparing the bytes in place,
+    // and `to_bits` would copy a byte array
+    let (ord_how, ord_body) = match prim {
+        Some("u128") | None => (
+            "the zero-padded bytes in place, then the length".to_owned(),
+            quote!(self.bytes.cmp(&other.bytes).then(self.len.cmp(&other.len))),
+        ),
+        _ => (
+            format!("the packed representation from [`to_bits`]({ident}::to_bits) as {packed}"),
+            quote!(self.to_bits().cmp(&other.to_bits())),
+        ),
+    };
+    let ord_doc = indoc::formatdoc! {"
+        Orders strings the same way as [`str`].
+
+        This compares {ord_how}.
+    "} + &host.example(|_, _| {
+        indoc::formatdoc! {"
+            ```
+            # use microstring::*;
+            let mut strings = [\"b\", \"a\\0\", \"\", \"a\"].map(|it| {ident}::new(it).unwrap());
+            strings.sort();
+            assert_eq!(strings, [\"\", \"a\", \"a\\0\", \"b\"]);
+            ```
+        "}
+    });
+    let (owned_cfgs, owneds) = host.owneds();
+    let alloc_strs = [
+        quote!(#alloc::boxed::Box<str>),
+        quote!(#alloc::borrow::Cow<'_, str>),
+    ];
+    let (str_cfgs, str_likes) = host.str_likes();
+    // `str` itself is implemented separately, and unconditionally
+    let (lookup_cfgs, lookups) = (&str_cfgs[1..], &str_likes[1..]);
+    let err_doc = indoc::formatdoc! {"
+        The error returned when a [`{ident}`] cannot be constructed.
+    "} + &host.example(|_, big| {
+        let big_len = big.len();
+        indoc::formatdoc! {"
+            ```
+            # use microstring::*;
+            let e = \"{big}\".parse::<{ident}>().unwrap_err();
+            assert_eq!(e.kind(), ErrorKind::TooLong);
+            assert_eq!(e.input_len(), {big_len});
+            assert_eq!(e.to_string(), \"expected a string of at most {n} bytes, but got {big_len} bytes\");
+            ```
+        "}
+    });
+    let too_long_msg = format!("expected a string of at most {n} bytes, but got {{len}} bytes");
+    let invalid_utf8_msg = format!(
+        "expected a UTF-8 string of at most {n} bytes, but got {{len}} bytes of invalid UTF-8"
+    );
+    let invalid_msg = "a string of {len} bytes failed validation";
+    let deser_err_msg = format!("a string of at most {n} bytes");
+    // `ErrorKind` is non-exhaustive outside of this crate
+    let error_kind_fallback = match host {
+        Host::Microstring { .. } => quote!(),
+        Host::Downstream => quote!(_ => ::core::fmt::Debug::fmt(&kind, f),),
+    };
+    let bits_fns = match prim {
+        Some(_) => quote! {
+            #[doc = #to_bits_doc]
+            pub const fn to_bits(self) -> #bits {
+                // in memory, the length comes first
+                #bits::from_be(self.to_ne_bits()).rotate_left(8)
+            }
+            /// Like [`to_bits`](Self::to_bits), but in native byte order,
+            /// so only suitable for equality.
+            const fn to_ne_bits(self) -> #bits {
+                unsafe { ::core::mem::transmute::<Self, #bits>(self) }
+            }
+            /// The inverse of [`to_ne_bits`](Self::to_ne_bits).
+            ///
+            /// # Safety
+            /// - `bits` must have been returned by [`to_ne_bits`](Self::to_ne_bits).
+            const unsafe fn from_ne_bits_unchecked(bits: #bits) -> Self {
+                unsafe { ::core::mem::transmute::<#bits, Self>(bits) }
+            }
+            /// The inverse of [`to_bits`](Self::to_bits).
+            ///
+            /// Returns [`None`] if the length is out of range,
+            /// the string is not UTF-8, or the padding is not zero.
+            pub const fn from_bits(bits: #bits) -> Option<Self> {
+                let [bytes @ .., len] = bits.to_be_bytes();
+                match Self::is_valid_parts(len, &bytes) {
+                    true => Some(unsafe { Self::from_bits_unchecked(bits) }),
+                    false => None,
+                }
+            }
+            /// The inverse of [`to_bits`](Self::to_bits), without any checks.
+            ///
+            /// # Safety
+            /// - `bits` must have been returned by [`to_bits`](Self::to_bits),
+            ///   or be accepted by [`from_bits`](Self::from_bits).
+            pub const unsafe fn from_bits_unchecked(bits: #bits) -> Self {
+                unsafe { ::core::mem::transmute::<#bits, Self>(bits.rotate_right(8).to_be()) }
+            }
+        },
+        None => quote! {
+            #[doc = #to_bits_doc]
+            pub const fn to_bits(self) -> #bits {
+                let mut bits = [0; #n as usize + 1];
+                let (bytes, len) = bits.split_at_mut(#n as usize);
+                bytes.copy_from_slice(&self.bytes);
+                len[0] = self.len as u8;
+                bits
+            }
+            /// The inverse of [`to_bits`](Self::to_bits).
+            ///
+            /// Returns [`None`] if the length is out of range,
+            /// the string is not UTF-8, or the padding is not zero.
+            pub const fn from_bits(bits: #bits) -> Option<Self> {
+                let [bytes @ .., len] = bits;
+                match Self::is_valid_parts(len, &bytes) {
+                    true => Some(unsafe { Self::from_bits_unchecked(bits) }),
+                    false => None,
+                }
+            }
+            /// The inverse of [`to_bits`](Self::to_bits), without any checks.
+            ///
+            /// # Safety
+            /// - `bits` must have been returned by [`to_bits`](Self::to_bits),
+            ///   or be accepted by [`from_bits`](Self::from_bits).
+            pub const unsafe fn from_bits_unchecked(bits: #bits) -> Self {
+                let [bytes @ .., len] = bits;
+                Self {
+                    len: unsafe { ::core::mem::transmute::<u8, #len_ident>(len) },
+                    bytes,
+                }
+            }
+        },
+    };
+    let ascii_case_fns = match prim {
+        Some(_) => quote! {
+            #[doc = #to_ascii_uppercase_doc]
+            pub const fn to_ascii_uppercase(self) -> Self {
+                let bits = self.to_ne_bits();
+                // the length is at most 15 and the padding is zero, so neither are letters
+                unsafe { Self::from_ne_bits_unchecked(bits ^ Self::ascii_case_mask(bits, b'a', b'z')) }
+            }
+            #[doc = #to_ascii_lowercase_doc]
+            pub const fn to_ascii_lowercase(self) -> Self {
+                let bits = self.to_ne_bits();
+                unsafe { Self::from_ne_bits_unchecked(bits ^ Self::ascii_case_mask(bits, b'A', b'Z')) }
+            }
+            /// Returns `0x20` in each byte of `bits` which is in `lo..=hi`, and zero elsewhere,
+            /// so that XOR-ing with it switches the case of those ASCII letters.
+            const fn ascii_case_mask(bits: #bits, lo: u8, hi: u8) -> #bits {
+                const ONES: #bits = #bits::MAX / 0xFF;
+                const HIGH: #bits = ONES * 0x80;
+                // without the high bits, the additions cannot carry into the next byte
+                let low = bits & !HIGH;
+                let ge_lo = low + ONES * (0x80 - lo as #bits);
+                let gt_hi = low + ONES * (0x7F - hi as #bits);
+                // the high bit of each byte is set if it was ASCII and in range
+                ((ge_lo ^ gt_hi) & !bits & HIGH) >> 2
+            }
+        },
+        None => quote! {
+            #[doc = #to_ascii_uppercase_doc]
+            pub const fn to_ascii_uppercase(mut self) -> Self {
+                self.make_ascii_uppercase();
+                self
+            }
+            #[doc = #to_ascii_lowercase_doc]
+            pub const fn to_ascii_lowercase(mut self) -> Self {
+                self.make_ascii_lowercase();
+                self
+            }
+        },
+    };
+    let definition = host.derive_zerocopy(
+        quote! {
+            #[doc = #struct_doc]
+            #[derive(Clone, Copy, Eq)]
+            #[repr(C)]
+        },
+        quote! {
+            #vis struct #ident {
+                len: #len_ident,
+                bytes: [u8; #n as _]
+            }
+        },
+    );
+    let atomic = match prim {
+        Some(prim) => atomic(host, vis, ident, prim),
+        None => quote!(),
+    };
+    quote! {
+        #definition
+        impl #ident {
+            pub const EMPTY: Self = Self::new("").unwrap();
+
+            #[doc = #new_doc]
+            pub const fn new(s: &str) -> Option<Self> {
+                match #len_ident::from_usize(s.len()) {
+                    Some(len) => {
+                        let mut bytes = [0; #n as _];
+                        unsafe {
+                            ::core::ptr::copy_nonoverlapping(s.as_ptr(), bytes.as_mut_ptr(), s.len())
+                        }
+                        Some(Self { len, bytes })
+                    },
+                    None => None
+                }
+            }
+            pub const fn as_str(&self) -> &str {
+                unsafe {
+                    str::from_utf8_unchecked(
+                        ::core::slice::from_raw_parts(
+                            self.bytes.as_ptr(),
+                            self.len as u8 as usize
+                        )
+                    )
+                }
+            }
+            pub const fn as_mut_str(&mut self) -> &mut str {
+                unsafe {
+                    str::from_utf8_unchecked_mut(
+                        ::core::slice::from_raw_parts_mut(
+                            self.bytes.as_mut_ptr(),
+                            self.len as u8 as usize
+                        )
+                    )
+                }
+            }
+            #[doc = #from_utf8_doc]
+            pub const fn from_utf8(bytes: &[u8]) -> Result<Self, #err_ident> {
+                if bytes.len() > #n as usize {
+                    return Err(#err_ident::new(#krate::ErrorKind::TooLong, bytes.len()));
+                }
+                match ::core::str::from_utf8(bytes) {
+                    Ok(s) => match Self::new(s) {
+                        Some(it) => Ok(it),
+                        None => Err(#err_ident::new(#krate::ErrorKind::TooLong, bytes.len())),
+                    },
+                    Err(_) => Err(#err_ident::new(#krate::ErrorKind::InvalidUtf8, bytes.len())),
+                }
+            }
+            #[doc = #from_utf8_unchecked_doc]
+            pub const unsafe fn from_utf8_unchecked(bytes: &[u8]) -> Option<Self> {
+                Self::new(unsafe { str::from_utf8_unchecked(bytes) })
+            }
+            #[doc = #new_truncating_doc]
+            pub const fn new_truncating(s: &str) -> Self {
+                let mut end = if s.len() < #n as usize { s.len() } else { #n as usize };
+                while !s.is_char_boundary(end) {
+                    end -= 1;
+                }
+                match Self::new(s.split_at(end).0) {
+                    Some(it) => it,
+                    None => unreachable!(),
+                }
+            }
+            #[doc = #new_truncating_with_marker_doc]
+            pub const fn new_truncating_with_marker(s: &str, marker: &str) -> Self {
+                if let Some(it) = Self::new(s) {
+                    return it;
+                }
+                assert!(marker.len() <= #n as usize, "marker is longer than the capacity");
+                let mut end = #n as usize - marker.len();
+                while !s.is_char_boundary(end) {
+                    end -= 1;
+                }
+                let mut it = Self::new_truncating(s.split_at(end).0);
+                match it.push_str(marker) {
+                    Ok(()) => it,
+                    Err(_) => unreachable!(),
+                }
+            }
+            /// Like [`new_truncating`](Self::new_truncating),
+            /// but never splits a grapheme cluster.
+            #[cfg(feature = "unicode-segmentation")]
+            pub fn new_truncating_graphemes(s: &str) -> Self {
+                use #unicode_segmentation::UnicodeSegmentation as _;
+                let end = s
+                    .grapheme_indices(true)
+                    .map(|(ix, grapheme)| ix + grapheme.len())
+                    .take_while(|end| *end <= #n as usize)
+                    .last()
+                    .unwrap_or(0);
+                Self::new_truncating(&s[..end])
+            }
+        }
+
+        #[doc = #err_doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #vis struct #err_ident {
+            kind: #krate::ErrorKind,
+            len: usize,
+        }
+
+        impl #err_ident {
+            const fn new(kind: #krate::ErrorKind, len: usize) -> Self {
+                Self { kind, len }
+            }
+            /// Why the input was rejected.
+            pub const fn kind(&self) -> #krate::ErrorKind {
+                self.kind
+            }
+            /// The length of the rejected input, in bytes.
+            ///
+            /// For operations which append to an existing string,
+            /// this is the length the string would have had.
+            pub const fn input_len(&self) -> usize {
+                self.len
+            }
+            /// The maximum length of the string being constructed, in bytes.
+            pub const fn capacity(&self) -> usize {
+                #n as usize
+            }
+        }
+
+        impl ::core::fmt::Display for #err_ident {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                let Self { kind, len } = *self;
+                match kind {
+                    #krate::ErrorKind::TooLong => write!(f, #too_long_msg),
+                    #krate::ErrorKind::InvalidUtf8 => write!(f, #invalid_utf8_msg),
+                    #krate::ErrorKind::Invalid => write!(f, #invalid_msg),
+                    #error_kind_fallback
+                }
+            }
+        }
+
+        impl ::core::error::Error for #err_ident {}
+
+        // const
+        // -----
+
+        // `const` versions of `str` methods,
+        // prefixed with `const_` where shadowing would change the signature or the result
+        impl #ident {
+            /// The maximum length, in bytes.
+            pub const CAPACITY: usize = #n as usize;
+            /// The length of this string, in bytes.
+            pub const fn len(&self) -> usize {
+                self.len as u8 as usize
+            }
+            /// Returns `true` if this string has a length of zero.
+            pub const fn is_empty(&self) -> bool {
+                self.len() == 0
+            }
+            /// The number of bytes which may be pushed before this string is full.
+            pub const fn remaining_capacity(&self) -> usize {
+                Self::CAPACITY - self.len()
+            }
+            /// Equivalent to [`str::as_bytes`], but usable in `const`.
+            ///
+            /// Unlike `as_bytes`, this does not shadow
+            /// [`zerocopy::IntoBytes::as_bytes`](https://docs.rs/zerocopy/latest/zerocopy/trait.IntoBytes.html#method.as_bytes),
+            /// which returns the length as well.
+            pub const fn const_as_bytes(&self) -> &[u8] {
+                self.as_str().as_bytes()
+            }
+            /// Equivalent to `==`, but usable in `const`.
+            pub const fn const_eq(&self, other: &str) -> bool {
+                self.len() == other.len() && self.const_cmp(other).is_eq()
+            }
+            /// Equivalent to [`Ord::cmp`], but usable in `const`.
+            pub const fn const_cmp(&self, other: &str) -> ::core::cmp::Ordering {
+                #private::cmp_bytes(self.const_as_bytes(), other.as_bytes())
+            }
+            /// Equivalent to [`str::starts_with`] with a [`str`] pattern, but usable in `const`.
+            pub const fn const_starts_with(&self, prefix: &str) -> bool {
+                match prefix.len() <= self.len() {
+                    true => {
+                        let (head, _) = self.const_as_bytes().split_at(prefix.len());
+                        #private::cmp_bytes(head, prefix.as_bytes()).is_eq()
+                    }
+                    false => false,
+                }
+            }
+            /// Equivalent to [`str::ends_with`] with a [`str`] pattern, but usable in `const`.
+            pub const fn const_ends_with(&self, suffix: &str) -> bool {
+                match self.len().checked_sub(suffix.len()) {
+                    Some(mid) => {
+                        let (_, tail) = self.const_as_bytes().split_at(mid);
+                        #private::cmp_bytes(tail, suffix.as_bytes()).is_eq()
+                    }
+                    None => false,
+                }
+            }
+            /// Converts this string to its ASCII upper case equivalent in-place.
+            pub const fn make_ascii_uppercase(&mut self) {
+                self.as_mut_str().make_ascii_uppercase()
+            }
+            /// Converts this string to its ASCII lower case equivalent in-place.
+            pub const fn make_ascii_lowercase(&mut self) {
+                self.as_mut_str().make_ascii_lowercase()
+            }
+            #ascii_case_fns
+        }
+
+        // mutate
+        // ------
+
+        impl #ident {
+            /// Appends the given [`char`] to the end of this string.
+            ///
+            /// Returns an error, leaving this string unchanged, if there is not enough capacity.
+            pub const fn push(&mut self, c: char) -> Result<(), #err_ident> {
+                self.push_str(c.encode_utf8(&mut [0; 4]))
+            }
+            #[doc = #push_str_doc]
+            pub const fn push_str(&mut self, s: &str) -> Result<(), #err_ident> {
+                let len = self.len as u8 as usize;
+                match #len_ident::from_usize(len + s.len()) {
+                    Some(new_len) => {
+                        unsafe {
+                            ::core::ptr::copy_nonoverlapping(s.as_ptr(), self.bytes.as_mut_ptr().add(len), s.len())
+                        }
+                        self.len = new_len;
+                        Ok(())
+                    },
+                    None => Err(#err_ident::new(#krate::ErrorKind::TooLong, len + s.len()))
+                }
+            }
+            #[doc = #try_concat_doc]
+            pub const fn try_concat(left: &str, right: &str) -> Result<Self, #err_ident> {
+                match Self::new(left) {
+                    Some(mut it) => match it.push_str(right) {
+                        Ok(()) => Ok(it),
+                        Err(e) => Err(e),
+                    },
+                    None => Err(#err_ident::new(#krate::ErrorKind::TooLong, left.len() + right.len())),
+                }
+            }
+            #[doc = #pop_doc]
+            pub fn pop(&mut self) -> Option<char> {
+                let c = self.as_str().chars().next_back()?;
+                unsafe { self.set_len(self.len as u8 as usize - c.len_utf8()) }
+                Some(c)
+            }
+            #[doc = #truncate_doc]
+            pub const fn truncate(&mut self, new_len: usize) {
+                if new_len < self.len as u8 as usize {
+                    assert!(self.as_str().is_char_boundary(new_len), "new_len does not lie on a char boundary");
+                    unsafe { self.set_len(new_len) }
+                }
+            }
+            #[doc = #clear_doc]
+            pub const fn clear(&mut self) {
+                unsafe { self.set_len(0) }
+            }
+            /// Inserts the given [`char`] at byte position `idx`.
+            ///
+            /// Returns an error, leaving this string unchanged, if there is not enough capacity.
+            ///
+            /// # Panics
+            /// - If `idx` is out of bounds, or does not lie on a [`char`] boundary.
+            pub const fn insert(&mut self, idx: usize, c: char) -> Result<(), #err_ident> {
+                self.insert_str(idx, c.encode_utf8(&mut [0; 4]))
+            }
+            #[doc = #insert_str_doc]
+            pub const fn insert_str(&mut self, idx: usize, s: &str) -> Result<(), #err_ident> {
+                assert!(self.as_str().is_char_boundary(idx), "idx does not lie on a char boundary");
+                let len = self.len as u8 as usize;
+                match #len_ident::from_usize(len + s.len()) {
+                    Some(new_len) => {
+                        unsafe {
+                            let p = self.bytes.as_mut_ptr();
+                            ::core::ptr::copy(p.add(idx), p.add(idx + s.len()), len - idx);
+                            ::core::ptr::copy_nonoverlapping(s.as_ptr(), p.add(idx), s.len());
+                        }
+                        self.len = new_len;
+                        Ok(())
+                    },
+                    None => Err(#err_ident::new(#krate::ErrorKind::TooLong, len + s.len()))
+                }
+            }
+            #[doc = #remove_doc]
+            pub fn remove(&mut self, idx: usize) -> char {
+                let Some(c) = self.as_str()[idx..].chars().next() else {
+                    panic!("cannot remove a char from the end of a string")
+                };
+                let len = self.len as u8 as usize;
+                let next = idx + c.len_utf8();
+                unsafe {
+                    let p = self.bytes.as_mut_ptr();
+                    ::core::ptr::copy(p.add(next), p.add(idx), len - next);
+                    self.set_len(len - c.len_utf8());
+                }
+                c
+            }
+            #[doc = #retain_doc]
+            pub fn retain(&mut self, mut f: impl FnMut(char) -> bool) {
+                let this = *self;
+                self.clear();
+                for c in this.as_str().chars() {
+                    if f(c) {
+                        // cannot fail: we never grow past the original length
+                        let _ = self.push(c);
+                    }
+                }
+            }
+            /// Sets the length, zeroing any bytes past it.
+            ///
+            /// # Safety
+            /// - `new_len` must be at most the current length.
+            /// - `new_len` must lie on a [`char`] boundary.
+            const unsafe fn set_len(&mut self, new_len: usize) {
+                let len = self.len as u8 as usize;
+                unsafe {
+                    ::core::ptr::write_bytes(self.bytes.as_mut_ptr().add(new_len), 0, len - new_len)
+                }
+                self.len = match #len_ident::from_usize(new_len) {
+                    Some(it) => it,
+                    None => unreachable!(),
+                };
+            }
+        }
+
+        // iter
+        // ----
+
+        impl #ident {
+            #[doc = #try_from_iter_doc]
+            pub fn try_from_iter<I: IntoIterator<Item = char>>(iter: I) -> Result<Self, #err_ident> {
+                let mut it = Self::EMPTY;
+                for c in iter {
+                    it.push(c)?;
+                }
+                Ok(it)
+            }
+            #[doc = #into_bytes_doc]
+            pub fn into_bytes(self) -> #krate::IntoBytes<Self> {
+                #krate::IntoBytes::new(self)
+            }
+        }
+
+        #[doc = #into_iter_doc]
+        impl ::core::iter::IntoIterator for #ident {
+            type Item = char;
+            type IntoIter = #krate::IntoChars<Self>;
+            fn into_iter(self) -> #krate::IntoChars<Self> {
+                #krate::IntoChars::new(self)
+            }
+        }
+
+        /// # Panics
+        /// - If there is not enough capacity.
+        ///   See [`push`](Self::push) for a fallible alternative.
+        impl ::core::iter::Extend<char> for #ident {
+            fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+                for c in iter {
+                    if let Err(e) = self.push(c) {
+                        panic!("{e}")
+                    }
+                }
+            }
+        }
+        /// # Panics
+        /// - If there is not enough capacity.
+        ///   See [`push_str`](Self::push_str) for a fallible alternative.
+        impl<'a> ::core::iter::Extend<&'a str> for #ident {
+            fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+                for s in iter {
+                    if let Err(e) = self.push_str(s) {
+                        panic!("{e}")
+                    }
+                }
+            }
+        }
+
+        // zero-copy
+        // ---------
+
+        impl #ident {
+            #[doc = #try_ref_from_bytes_doc]
+            pub const fn try_ref_from_bytes(bytes: &[u8]) -> Option<&Self> {
+                const { assert!(::core::mem::align_of::<Self>() == 1) }
+                match Self::is_valid(bytes) {
+                    true => Some(unsafe { &*bytes.as_ptr().cast::<Self>() }),
+                    false => None,
+                }
+            }
+            /// Like [`try_ref_from_bytes`](Self::try_ref_from_bytes),
+            /// but for a contiguous run of strings.
+            pub const fn try_slice_from_bytes(bytes: &[u8]) -> Option<&[Self]> {
+                const { assert!(::core::mem::align_of::<Self>() == 1) }
+                let size = ::core::mem::size_of::<Self>();
+                if !bytes.len().is_multiple_of(size) {
+                    return None;
+                }
+                let mut rest = bytes;
+                while let Some((chunk, tail)) = rest.split_at_checked(size) {
+                    if !Self::is_valid(chunk) {
+                        return None;
+                    }
+                    rest = tail;
+                }
+                Some(unsafe { ::core::slice::from_raw_parts(bytes.as_ptr().cast::<Self>(), bytes.len() / size) })
+            }
+            /// Whether the given bytes are a length byte, that many bytes of UTF-8, and zero padding.
+            const fn is_valid(bytes: &[u8]) -> bool {
+                match bytes {
+                    [len, rest @ ..] if rest.len() == #n as usize => Self::is_valid_parts(*len, rest),
+                    _ => false,
+                }
+            }
+            /// Whether `len` is in range, and `bytes` are that many bytes of UTF-8, followed by zero padding.
+            const fn is_valid_parts(len: u8, bytes: &[u8]) -> bool {
+                let Some(len) = #len_ident::from_u8(len) else {
+                    return false;
+                };
+                let (string, mut padding) = bytes.split_at(len as u8 as usize);
+                if ::core::str::from_utf8(string).is_err() {
+                    return false;
+                }
+                while let [0, tail @ ..] = padding {
+                    padding = tail;
+                }
+                padding.is_empty()
+            }
+        }
+
+        // bits
+        // ----
+
+        impl #ident {
+            #bits_fns
+        }
+
+        // inline_str
+        // ----------
+
+        impl #private::Sealed for #ident {}
+        impl #krate::InlineStr for #ident {
+            const CAPACITY: usize = #n as usize;
+            const EMPTY: Self = Self::EMPTY;
+            type Error = #err_ident;
+            type Bits = #bits;
+            fn new(s: &str) -> Option<Self> {
+                Self::new(s)
+            }
+            fn as_str(&self) -> &str {
+                self.as_str()
+            }
+            fn as_mut_str(&mut self) -> &mut str {
+                self.as_mut_str()
+            }
+            fn from_utf8(bytes: &[u8]) -> Result<Self, #err_ident> {
+                Self::from_utf8(bytes)
+            }
+            fn to_bits(self) -> #bits {
+                self.to_bits()
+            }
+            fn from_bits(bits: #bits) -> Option<Self> {
+                Self::from_bits(bits)
+            }
+            fn push_str(&mut self, s: &str) -> Result<(), #err_ident> {
+                self.push_str(s)
+            }
+            fn try_from_iter<I: IntoIterator<Item = char>>(iter: I) -> Result<Self, #err_ident> {
+                Self::try_from_iter(iter)
+            }
+            fn __error(kind: #krate::ErrorKind, len: usize) -> #err_ident {
+                #err_ident::new(kind, len)
+            }
+        }
+
+        // default
+        // -------
+
+        impl ::core::default::Default for #ident {
+            fn default() -> Self {
+                Self::EMPTY
+            }
+        }
+        impl ::core::default::Default for &#ident {
+            fn default() -> Self {
+                &#ident::EMPTY
+            }
+        }
+
+        // hash
+        // ----
+
+        /// Hashes the same as [`str`], as required by [`Borrow<str>`](::core::borrow::Borrow).
+        impl ::core::hash::Hash for #ident {
+            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                self.as_str().hash(state)
+            }
+        }
+
+        // convert
+        // -------
+
+        impl ::core::convert::AsRef<Self> for #ident {
+            fn as_ref(&self) -> &Self {
+                self
+            }
+        }
+        impl ::core::convert::AsMut<Self> for #ident {
+            fn as_mut(&mut self) -> &mut Self {
+                self
+            }
+        }
+
+        impl ::core::convert::AsRef<str> for #ident {
+            fn as_ref(&self) -> &str {
+                self.as_str()
+            }
+        }
+        impl ::core::convert::AsMut<str> for #ident {
+            fn as_mut(&mut self) -> &mut str {
+                self.as_mut_str()
+            }
+        }
+
+        impl ::core::convert::AsRef<[u8]> for #ident {
+            fn as_ref(&self) -> &[u8] {
+                self.as_str().as_bytes()
+            }
+        }
+
+        impl TryFrom<&str> for #ident {
+            type Error = #err_ident;
+            fn try_from(value: &str) -> Result<Self, #err_ident> {
+                Self::new(value).ok_or(#err_ident::new(#krate::ErrorKind::TooLong, value.len()))
+            }
+        }
+
+        impl TryFrom<&[u8]> for #ident {
+            type Error = #err_ident;
+            fn try_from(value: &[u8]) -> Result<Self, #err_ident> {
+                Self::from_utf8(value)
+            }
+        }
+
+        impl<const N: usize> TryFrom<[u8; N]> for #ident {
+            type Error = #err_ident;
+            fn try_from(value: [u8; N]) -> Result<Self, #err_ident> {
+                Self::from_utf8(&value)
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl ::core::convert::AsRef<#std::ffi::OsStr> for #ident {
+            fn as_ref(&self) -> &#std::ffi::OsStr {
+                self.as_str().as_ref()
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl ::core::convert::AsRef<#std::path::Path> for #ident {
+            fn as_ref(&self) -> &#std::path::Path {
+                self.as_str().as_ref()
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl ::core::convert::TryFrom<#alloc::string::String> for #ident {
+            type Error = #err_ident;
+            fn try_from(value: #alloc::string::String) -> Result<Self, #err_ident> {
+                Self::new(&value).ok_or(#err_ident::new(#krate::ErrorKind::TooLong, value.len()))
+            }
+        }
+
+        #(
+            #owned_cfgs
+            impl ::core::convert::From<#ident> for #owneds {
+                fn from(val: #ident) -> Self {
+                    Self::from(val.as_str())
+                }
+            }
+        )*
+
+        #[cfg(feature = "alloc")]
+        impl ::core::convert::From<#ident> for #alloc::borrow::Cow<'static, str> {
+            fn from(val: #ident) -> Self {
+                Self::Owned(#alloc::string::String::from(val))
+            }
+        }
+
+        #(
+            #[cfg(feature = "alloc")]
+            impl ::core::convert::TryFrom<#alloc_strs> for #ident {
+                type Error = #err_ident;
+                fn try_from(value: #alloc_strs) -> Result<Self, #err_ident> {
+                    Self::try_from(&*value)
+                }
+            }
+        )*
+
+        #[cfg(feature = "std")]
+        impl ::core::convert::TryFrom<&#std::ffi::OsStr> for #ident {
+            type Error = #err_ident;
+            fn try_from(value: &#std::ffi::OsStr) -> Result<Self, #err_ident> {
+                match value.to_str() {
+                    Some(s) => Self::try_from(s),
+                    None => Err(#err_ident::new(#krate::ErrorKind::InvalidUtf8, value.len())),
+                }
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl ::core::convert::TryFrom<&#std::path::Path> for #ident {
+            type Error = #err_ident;
+            fn try_from(value: &#std::path::Path) -> Result<Self, #err_ident> {
+                Self::try_from(value.as_os_str())
+            }
+        }
+
+        impl ::core::convert::TryFrom<&::core::ffi::CStr> for #ident {
+            type Error = #err_ident;
+            fn try_from(value: &::core::ffi::CStr) -> Result<Self, #err_ident> {
+                Self::from_utf8(value.to_bytes())
+            }
+        }
+
+        // borrow
+        // ------
+
+        impl ::core::borrow::Borrow<str> for #ident {
+            fn borrow(&self) -> &str {
+                self.as_str()
+            }
+        }
+        impl ::core::borrow::BorrowMut<str> for #ident {
+            fn borrow_mut(&mut self) -> &mut str {
+                self.as_mut_str()
+            }
+        }
+
+        // ops
+        // ---
+
+        impl ::core::ops::Deref for #ident {
+            type Target = str;
+            fn deref(&self) -> &str {
+                self.as_str()
+            }
+        }
+        impl ::core::ops::DerefMut for #ident {
+            fn deref_mut(&mut self) -> &mut str {
+                self.as_mut_str()
+            }
+        }
+
+        // cmp
+        // ---
+
+        #[doc = #eq_doc]
+        impl ::core::cmp::PartialEq for #ident {
+            fn eq(&self, other: &Self) -> bool {
+                #eq_body
+            }
+        }
+        impl ::core::cmp::PartialOrd for #ident {
+            fn partial_cmp(&self, other: &Self) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        #[doc = #ord_doc]
+        impl ::core::cmp::Ord for #ident {
+            fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                #ord_body
+            }
+        }
+        #(
+            #str_cfgs
+            impl ::core::cmp::PartialEq<#str_likes> for #ident {
+                fn eq(&self, other: &#str_likes) -> bool {
+                    self.as_str().eq(::core::convert::AsRef::<str>::as_ref(other))
+                }
+            }
+            #str_cfgs
+            impl ::core::cmp::PartialOrd<#str_likes> for #ident {
+                fn partial_cmp(&self, other: &#str_likes) -> Option<::core::cmp::Ordering> {
+                    self.as_str().partial_cmp(::core::convert::AsRef::<str>::as_ref(other))
+                }
+            }
+            #str_cfgs
+            impl ::core::cmp::PartialEq<#ident> for #str_likes {
+                fn eq(&self, other: &#ident) -> bool {
+                    ::core::convert::AsRef::<str>::as_ref(self).eq(other.as_str())
+                }
+            }
+            #str_cfgs
+            impl ::core::cmp::PartialOrd<#ident> for #str_likes {
+                fn partial_cmp(&self, other: &#ident) -> Option<::core::cmp::Ordering> {
+                    ::core::convert::AsRef::<str>::as_ref(self).partial_cmp(other.as_str())
+                }
+            }
+        )*
+
+        // fmt
+        // ---
+
+        impl ::core::fmt::Debug for #ident {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                self.as_str().fmt(f)
+            }
+        }
+        impl ::core::fmt::Display for #ident {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                self.as_str().fmt(f)
+            }
+        }
+
+        #[doc = #write_doc]
+        impl ::core::fmt::Write for #ident {
+            fn write_str(&mut self, s: &str) -> ::core::fmt::Result {
+                self.push_str(s).map_err(|_| ::core::fmt::Error)
+            }
+            fn write_char(&mut self, c: char) -> ::core::fmt::Result {
+                self.push(c).map_err(|_| ::core::fmt::Error)
+            }
+            fn write_fmt(&mut self, args: ::core::fmt::Arguments<'_>) -> ::core::fmt::Result {
+                let before = *self;
+                ::core::fmt::write(self, args).inspect_err(|_| *self = before)
+            }
+        }
+
+        impl #ident {
+            #[doc = #try_from_fmt_doc]
+            pub fn try_from_fmt(args: ::core::fmt::Arguments<'_>) -> Result<Self, #err_ident> {
+                struct Len(usize);
+                impl ::core::fmt::Write for Len {
+                    fn write_str(&mut self, s: &str) -> ::core::fmt::Result {
+                        self.0 += s.len();
+                        Ok(())
+                    }
+                }
+                let mut s = Self::EMPTY;
+                match ::core::fmt::Write::write_fmt(&mut s, args) {
+                    Ok(()) => Ok(s),
+                    Err(_) => {
+                        let mut len = Len(0);
+                        let _ = ::core::fmt::Write::write_fmt(&mut len, args);
+                        Err(#err_ident::new(#krate::ErrorKind::TooLong, len.0))
+                    }
+                }
+            }
+        }
+
+        #format_macro
+
+        // str
+        // ---
+
+        impl ::core::str::FromStr for #ident {
+            type Err = #err_ident;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::new(s).ok_or(#err_ident::new(#krate::ErrorKind::TooLong, s.len()))
+            }
+        }
+
+        // serde
+        // -----
+
+        #[cfg(feature = "serde")]
+        impl #serde::Serialize for #ident {
+            fn serialize<S: #serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+                s.serialize_str(self)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> #serde::Deserialize<'de> for #ident {
+            fn deserialize<D: #serde::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+                struct Visitor;
+                impl<'de> #serde::de::Visitor<'de> for Visitor {
+                    type Value = #ident;
+                    fn expecting(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.write_str(#deser_err_msg)
+                    }
+                    fn visit_str<E: #serde::de::Error>(self, v: &str) -> Result<#ident, E> {
+                        #ident::new(v).ok_or_else(||#serde::de::Error::invalid_length(v.len(), &self))
+                    }
+                }
+                d.deserialize_str(Visitor)
+            }
+        }
+
+        // schemars
+        // --------
+
+        #[cfg(feature = "schemars")]
+        impl #schemars::JsonSchema for #ident {
+            fn schema_name() -> #std::borrow::Cow<'static, str> {
+                #std::borrow::Cow::Borrowed(::core::stringify!(#ident))
+            }
+            fn json_schema(_: &mut #schemars::SchemaGenerator) -> #schemars::Schema {
+                #schemars::json_schema! {{
+                    "type": "string",
+                    "maxLength": #n,
+                }}
+            }
+            fn schema_id() -> #std::borrow::Cow<'static, str> {
+                #std::borrow::Cow::Borrowed(::core::concat!(
+                    ::core::module_path!(),
+                    "::",
+                    ::core::stringify!(#ident)
+                ))
+            }
+        }
+
+        // arbitrary
+        // ---------
+
+        #[doc = #arbitrary_doc]
+        #[cfg(feature = "arbitrary")]
+        impl<'a> #arbitrary::Arbitrary<'a> for #ident {
+            fn arbitrary(u: &mut #arbitrary::Unstructured<'a>) -> #arbitrary::Result<Self> {
+                let len = u.int_in_range(0..=#n)? as usize;
+                let mut it = Self::EMPTY;
+                while (it.len as u8 as usize) < len {
+                    let remaining = len - it.len as u8 as usize;
+                    let width = u.int_in_range(1..=remaining.min(4))?;
+                    // cannot fail: we never grow past `len`
+                    let _ = it.push(#private::arbitrary_char(u, width)?);
+                }
+                Ok(it)
+            }
+            fn size_hint(_: usize) -> (usize, Option<usize>) {
+                // an empty input gives an empty string, otherwise one byte for the length,
+                // then at most two bytes per byte of the string
+                (0, Some(1 + 2 * #n as usize))
+            }
+        }
+
+        // map
+        // ---
+
+        impl #krate::Lookup<#ident> for #ident {
+            fn lookup(&self) -> Option<#ident> {
+                Some(*self)
+            }
+        }
+        impl #krate::Lookup<#ident> for str {
+            fn lookup(&self) -> Option<#ident> {
+                #ident::new(self)
+            }
+        }
+        #(
+            #lookup_cfgs
+            impl #krate::Lookup<#ident> for #lookups {
+                fn lookup(&self) -> Option<#ident> {
+                    #ident::new(self)
+                }
+            }
+        )*
+
+        #[doc = #map_doc]
+        #[cfg(feature = "std")]
+        #[derive(Clone)]
+        #vis struct #map_ident<V> {
+            inner: #std::collections::HashMap<#bits, V, #krate::BuildWordHasher>,
+        }
+
+        #[cfg(feature = "std")]
+        impl<V> #map_ident<V> {
+            pub fn new() -> Self {
+                Self::with_capacity(0)
+            }
+            pub fn with_capacity(capacity: usize) -> Self {
+                Self {
+                    inner: #std::collections::HashMap::with_capacity_and_hasher(capacity, #krate::BuildWordHasher),
+                }
+            }
+            pub fn len(&self) -> usize {
+                self.inner.len()
+            }
+            pub fn is_empty(&self) -> bool {
+                self.inner.is_empty()
+            }
+            pub fn clear(&mut self) {
+                self.inner.clear()
+            }
+            pub fn insert(&mut self, key: #ident, value: V) -> Option<V> {
+                self.inner.insert(key.to_bits(), value)
+            }
+            /// Gets the entry for the given key, to insert or update it with a single probe.
+            pub fn entry(&mut self, key: #ident) -> #krate::Entry<'_, #ident, V> {
+                #krate::Entry::__new(&mut self.inner, key)
+            }
+            pub fn get<Q: ?Sized + #krate::Lookup<#ident>>(&self, key: &Q) -> Option<&V> {
+                self.inner.get(&key.lookup()?.to_bits())
+            }
+            pub fn get_mut<Q: ?Sized + #krate::Lookup<#ident>>(&mut self, key: &Q) -> Option<&mut V> {
+                self.inner.get_mut(&key.lookup()?.to_bits())
+            }
+            pub fn contains_key<Q: ?Sized + #krate::Lookup<#ident>>(&self, key: &Q) -> bool {
+                self.get(key).is_some()
+            }
+            pub fn remove<Q: ?Sized + #krate::Lookup<#ident>>(&mut self, key: &Q) -> Option<V> {
+                self.inner.remove(&key.lookup()?.to_bits())
+            }
+            pub fn iter(&self) -> impl Iterator<Item = (#ident, &V)> {
+                self.inner.iter().map(|(k, v)| (unsafe { #ident::from_bits_unchecked(*k) }, v))
+            }
+            pub fn iter_mut(&mut self) -> impl Iterator<Item = (#ident, &mut V)> {
+                self.inner.iter_mut().map(|(k, v)| (unsafe { #ident::from_bits_unchecked(*k) }, v))
+            }
+            pub fn keys(&self) -> impl Iterator<Item = #ident> {
+                self.inner.keys().map(|k| unsafe { #ident::from_bits_unchecked(*k) })
+            }
+            pub fn values(&self) -> impl Iterator<Item = &V> {
+                self.inner.values()
+            }
+            pub fn values_mut(&mut self) -> impl Iterator<Item = &mut V> {
+                self.inner.values_mut()
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl<V> ::core::default::Default for #map_ident<V> {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl<V: ::core::fmt::Debug> ::core::fmt::Debug for #map_ident<V> {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.debug_map().entries(self.iter()).finish()
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl<V: ::core::cmp::PartialEq> ::core::cmp::PartialEq for #map_ident<V> {
+            fn eq(&self, other: &Self) -> bool {
+                self.inner == other.inner
+            }
+        }
+        #[cfg(feature = "std")]
+        impl<V: ::core::cmp::Eq> ::core::cmp::Eq for #map_ident<V> {}
+
+        #[cfg(feature = "std")]
+        impl<V> ::core::iter::Extend<(#ident, V)> for #map_ident<V> {
+            fn extend<I: IntoIterator<Item = (#ident, V)>>(&mut self, iter: I) {
+                self.inner.extend(iter.into_iter().map(|(k, v)| (k.to_bits(), v)))
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl<V> ::core::iter::IntoIterator for #map_ident<V> {
+            type Item = (#ident, V);
+            type IntoIter = ::core::iter::Map<
+                #std::collections::hash_map::IntoIter<#bits, V>,
+                fn((#bits, V)) -> (#ident, V),
+            >;
+            fn into_iter(self) -> Self::IntoIter {
+                self.inner.into_iter().map(|(k, v)| (unsafe { #ident::from_bits_unchecked(k) }, v))
+            }
+        }
+        #[cfg(feature = "std")]
+        impl<'a, V> ::core::iter::IntoIterator for &'a #map_ident<V> {
+            type Item = (#ident, &'a V);
+            type IntoIter = ::core::iter::Map<
+                #std::collections::hash_map::Iter<'a, #bits, V>,
+                fn((&'a #bits, &'a V)) -> (#ident, &'a V),
+            >;
+            fn into_iter(self) -> Self::IntoIter {
+                self.inner.iter().map(|(k, v)| (unsafe { #ident::from_bits_unchecked(*k) }, v))
+            }
+        }
+        #[cfg(feature = "std")]
+        impl<'a, V> ::core::iter::IntoIterator for &'a mut #map_ident<V> {
+            type Item = (#ident, &'a mut V);
+            type IntoIter = ::core::iter::Map<
+                #std::collections::hash_map::IterMut<'a, #bits, V>,
+                fn((&'a #bits, &'a mut V)) -> (#ident, &'a mut V),
+            >;
+            fn into_iter(self) -> Self::IntoIter {
+                self.inner.iter_mut().map(|(k, v)| (unsafe { #ident::from_bits_unchecked(*k) }, v))
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl<V> ::core::iter::FromIterator<(#ident, V)> for #map_ident<V> {
+            fn from_iter<I: IntoIterator<Item = (#ident, V)>>(iter: I) -> Self {
+                let mut it = Self::new();
+                it.extend(iter);
+                it
+            }
+        }
+
+        // const_default
+
+        #[cfg(feature = "const-default")]
+        impl #const_default::ConstDefault for #ident {
+            const DEFAULT: Self = #ident::EMPTY;
+        }
+
+        // atomic
+        // ------
+
+        #atomic
+
+        // len
+        // ---
+
+        #len
+    }
+}
+
+fn atomic(host: &Host, vis: &TokenStream, ident: &Ident, prim: &str) -> TokenStream {
+    let name = ident.to_string().trim_start_matches("r#").to_owned();
+    let atomic_ident = Ident::new(&format!("Atomic{name}"), Span::call_site());
+    let (atomic_prim, width) = match prim {
+        "u16" => (quote!(::core::sync::atomic::AtomicU16), "16"),
+        "u32" => (quote!(::core::sync::atomic::AtomicU32), "32"),
+        "u64" => (quote!(::core::sync::atomic::AtomicU64), "64"),
+        _ => return seqlock(host, vis, ident, &atomic_ident),
+    };
+    let atomic_prim_doc = atomic_prim.to_string().replace(' ', "");
+    let atomic_doc = indoc::formatdoc! {"
+        A [`{ident}`] which can be shared between threads,
+        backed by an [`{atomic_prim_doc}`].
+    "} + &host.example(|small, _| {
+        indoc::formatdoc! {"
+            ```
+            # use microstring::*;
+            use core::sync::atomic::Ordering;
+            let atomic = {atomic_ident}::new({ident}::new(\"{small}\").unwrap());
+            assert_eq!(atomic.swap({ident}::EMPTY, Ordering::Relaxed), \"{small}\");
+            assert_eq!(atomic.load(Ordering::Relaxed), \"\");
+            ```
+        "}
+    });
+    let get_mut_doc = indoc::formatdoc! {"
+        Returns a mutable reference to the string,
+        which is safe because `self` is borrowed mutably.
+    "} + &host.example(|small, _| {
+        indoc::formatdoc! {"
+            ```
+            # use microstring::*;
+            use core::sync::atomic::Ordering;
+            let mut atomic = {atomic_ident}::default();
+            atomic.get_mut().push_str(\"{small}\").unwrap();
+            assert_eq!(atomic.load(Ordering::Relaxed), \"{small}\");
+            ```
+        "}
+    });
+    let compare_exchange_doc = indoc::formatdoc! {"
+        Stores `new` if the current string is `current`.
+
+        Returns the previous string, which is `current` if and only if it was replaced.
+        See [`{atomic_prim_doc}::compare_exchange`] for the meaning of the orderings.
+    "} + &host.example(|small, _| {
+        indoc::formatdoc! {"
+            ```
+            # use microstring::*;
+            use core::sync::atomic::Ordering::Relaxed;
+            let small = {ident}::new(\"{small}\").unwrap();
+            let atomic = {atomic_ident}::new(small);
+            assert_eq!(atomic.compare_exchange({ident}::EMPTY, small, Relaxed, Relaxed), Err(small));
+            assert_eq!(atomic.compare_exchange(small, {ident}::EMPTY, Relaxed, Relaxed), Ok(small));
+            assert_eq!(atomic.into_inner(), \"\");
+            ```
+        "}
+    });
+    let compare_exchange_weak_doc = indoc::formatdoc! {"
+        Like [`Self::compare_exchange`], but may spuriously fail even if the current string is `current`.
+
+        See [`{atomic_prim_doc}::compare_exchange_weak`].
+    "};
+    let fetch_update_doc = indoc::formatdoc! {"
+        Replaces the current string with the result of `f`, retrying if another thread changed it.
+
+        Returns the previous string, or an error containing it if `f` returned [`None`].
+        See [`{atomic_prim_doc}::fetch_update`] for the meaning of the orderings.
+    "} + &host.example(|_, _| {
+        indoc::formatdoc! {"
+            ```
+            # use microstring::*;
+            use core::sync::atomic::Ordering::Relaxed;
+            let atomic = {atomic_ident}::new({ident}::new(\"a\").unwrap());
+            let push_b = |mut s: {ident}| s.push('b').ok().map(|()| s);
+            assert_eq!(atomic.fetch_update(Relaxed, Relaxed, push_b), Ok({ident}::new(\"a\").unwrap()));
+            assert_eq!(atomic.load(Relaxed), \"ab\");
+            assert_eq!(atomic.fetch_update(Relaxed, Relaxed, |_| None), Err({ident}::new(\"ab\").unwrap()));
+            ```
+        "}
+    });
+    quote! {
+        #[doc = #atomic_doc]
+        #[cfg(target_has_atomic = #width)]
+        #[repr(transparent)]
+        #vis struct #atomic_ident(#atomic_prim);
+
+        #[cfg(target_has_atomic = #width)]
+        impl #atomic_ident {
+            pub const fn new(s: #ident) -> Self {
+                Self(#atomic_prim::new(s.to_ne_bits()))
+            }
+            pub const fn into_inner(self) -> #ident {
+                unsafe { #ident::from_ne_bits_unchecked(self.0.into_inner()) }
+            }
+            #[doc = #get_mut_doc]
+            pub fn get_mut(&mut self) -> &mut #ident {
+                unsafe { &mut *(self.0.get_mut() as *mut _ as *mut #ident) }
+            }
+            pub fn load(&self, order: ::core::sync::atomic::Ordering) -> #ident {
+                unsafe { #ident::from_ne_bits_unchecked(self.0.load(order)) }
+            }
+            pub fn store(&self, s: #ident, order: ::core::sync::atomic::Ordering) {
+                self.0.store(s.to_ne_bits(), order)
+            }
+            pub fn swap(&self, s: #ident, order: ::core::sync::atomic::Ordering) -> #ident {
+                unsafe { #ident::from_ne_bits_unchecked(self.0.swap(s.to_ne_bits(), order)) }
+            }
+            #[doc = #compare_exchange_doc]
+            pub fn compare_exchange(
+                &self,
+                current: #ident,
+                new: #ident,
+                success: ::core::sync::atomic::Ordering,
+                failure: ::core::sync::atomic::Ordering,
+            ) -> Result<#ident, #ident> {
+                match self.0.compare_exchange(current.to_ne_bits(), new.to_ne_bits(), success, failure) {
+                    Ok(it) => Ok(unsafe { #ident::from_ne_bits_unchecked(it) }),
+                    Err(it) => Err(unsafe { #ident::from_ne_bits_unchecked(it) }),
+                }
+            }
+            #[doc = #compare_exchange_weak_doc]
+            pub fn compare_exchange_weak(
+                &self,
+                current: #ident,
+                new: #ident,
+                success: ::core::sync::atomic::Ordering,
+                failure: ::core::sync::atomic::Ordering,
+            ) -> Result<#ident, #ident> {
+                match self.0.compare_exchange_weak(current.to_ne_bits(), new.to_ne_bits(), success, failure) {
+                    Ok(it) => Ok(unsafe { #ident::from_ne_bits_unchecked(it) }),
+                    Err(it) => Err(unsafe { #ident::from_ne_bits_unchecked(it) }),
+                }
+            }
+            #[doc = #fetch_update_doc]
+            pub fn fetch_update(
+                &self,
+                set_order: ::core::sync::atomic::Ordering,
+                fetch_order: ::core::sync::atomic::Ordering,
+                mut f: impl FnMut(#ident) -> Option<#ident>,
+            ) -> Result<#ident, #ident> {
+                let mut prev = self.load(fetch_order);
+                while let Some(next) = f(prev) {
+                    match self.compare_exchange_weak(prev, next, set_order, fetch_order) {
+                        Ok(it) => return Ok(it),
+                        Err(it) => prev = it,
+                    }
+                }
+                Err(prev)
+            }
+        }
+
+        #[cfg(target_has_atomic = #width)]
+        impl ::core::default::Default for #atomic_ident {
+            fn default() -> Self {
+                Self::new(#ident::EMPTY)
+            }
+        }
+
+        #[cfg(target_has_atomic = #width)]
+        impl ::core::convert::From<#ident> for #atomic_ident {
+            fn from(value: #ident) -> Self {
+                Self::new(value)
+            }
+        }
+
+        #[cfg(target_has_atomic = #width)]
+        impl ::core::fmt::Debug for #atomic_ident {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                self.load(::core::sync::atomic::Ordering::Relaxed).fmt(f)
+            }
+        }
+    }
+}
+
+/// Targets don't generally have 128-bit atomics,
+/// so use a sequence lock over two 64-bit halves instead.
+fn seqlock(host: &Host, vis: &TokenStream, ident: &Ident, atomic_ident: &Ident) -> TokenStream {
+    let atomic_doc = indoc::formatdoc! {"
+        A [`{ident}`] which can be shared between threads,
+        backed by a sequence lock.
+
+        Loads never block writers, but may spin while a write is in progress.
+        Loads are [`Acquire`](core::sync::atomic::Ordering::Acquire),
+        and writes are [`Release`](core::sync::atomic::Ordering::Release).
+    "} + &host.example(|small, _| {
+        indoc::formatdoc! {"
+            ```
+            # use microstring::*;
+            let atomic = {atomic_ident}::new({ident}::new(\"{small}\").unwrap());
+            assert_eq!(atomic.swap({ident}::EMPTY), \"{small}\");
+            assert_eq!(atomic.load(), \"\");
+            ```
+        "}
+    });
+    let compare_exchange_doc = indoc::formatdoc! {"
+        Stores `new` if the current string is `current`.
+
+        Returns the previous string, which is `current` if and only if it was replaced.
+    "} + &host.example(|small, _| {
+        indoc::formatdoc! {"
+            ```
+            # use microstring::*;
+            let small = {ident}::new(\"{small}\").unwrap();
+            let atomic = {atomic_ident}::new(small);
+            assert_eq!(atomic.compare_exchange({ident}::EMPTY, small), Err(small));
+            assert_eq!(atomic.compare_exchange(small, {ident}::EMPTY), Ok(small));
+            assert_eq!(atomic.into_inner(), \"\");
+            ```
+        "}
+    });
+    let fetch_update_doc = indoc::formatdoc! {"
+        Replaces the current string with the result of `f`, retrying if another thread changed it.
+
+        Returns the previous string, or an error containing it if `f` returned [`None`].
+    "} + &host.example(|_, _| {
+        indoc::formatdoc! {"
+            ```
+            # use microstring::*;
+            let atomic = {atomic_ident}::new({ident}::new(\"a\").unwrap());
+            let push_b = |mut s: {ident}| s.push('b').ok().map(|()| s);
+            assert_eq!(atomic.fetch_update(push_b), Ok({ident}::new(\"a\").unwrap()));
+            assert_eq!(atomic.load(), \"ab\");
+            assert_eq!(atomic.fetch_update(|_| None), Err({ident}::new(\"ab\").unwrap()));
+            ```
+        "}
+    });
+    quote! {
+        #[doc = #atomic_doc]
+        #[cfg(target_has_atomic = "64")]
+        #vis struct #atomic_ident {
+            /// Odd while a write is in progress.
+            seq: ::core::sync::atomic::AtomicUsize,
+            halves: [::core::sync::atomic::AtomicU64; 2],
+        }
+
+        #[cfg(target_has_atomic = "64")]
+        impl #atomic_ident {
+            pub const fn new(s: #ident) -> Self {
+                let bits = s.to_ne_bits();
+                Self {
+                    seq: ::core::sync::atomic::AtomicUsize::new(0),
+                    halves: [
+                        ::core::sync::atomic::AtomicU64::new(bits as u64),
+                        ::core::sync::atomic::AtomicU64::new((bits >> 64) as u64),
+                    ],
+                }
+            }
+            pub const fn into_inner(self) -> #ident {
+                let [lo, hi] = self.halves;
+                let bits = lo.into_inner() as u128 | (hi.into_inner() as u128) << 64;
+                unsafe { #ident::from_ne_bits_unchecked(bits) }
+            }
+            pub fn load(&self) -> #ident {
+                use ::core::sync::atomic::{Ordering, fence};
+                loop {
+                    let seq = self.seq.load(Ordering::Acquire);
+                    if !seq.is_multiple_of(2) {
+                        ::core::hint::spin_loop();
+                        continue;
+                    }
+                    let bits = self.read();
+                    fence(Ordering::Acquire);
+                    if self.seq.load(Ordering::Relaxed) == seq {
+                        return unsafe { #ident::from_ne_bits_unchecked(bits) };
+                    }
+                }
+            }
+            pub fn store(&self, s: #ident) {
+                self.swap(s);
+            }
+            pub fn swap(&self, s: #ident) -> #ident {
+                let seq = self.lock();
+                let prev = self.read();
+                self.write(s.to_ne_bits());
+                self.unlock(seq);
+                unsafe { #ident::from_ne_bits_unchecked(prev) }
+            }
+            #[doc = #compare_exchange_doc]
+            pub fn compare_exchange(&self, current: #ident, new: #ident) -> Result<#ident, #ident> {
+                let seq = self.lock();
+                let prev = self.read();
+                let res = match prev == current.to_ne_bits() {
+                    true => {
+                        self.write(new.to_ne_bits());
+                        Ok(current)
+                    }
+                    false => Err(unsafe { #ident::from_ne_bits_unchecked(prev) }),
+                };
+                self.unlock(seq);
+                res
+            }
+            #[doc = #fetch_update_doc]
+            pub fn fetch_update(&self, mut f: impl FnMut(#ident) -> Option<#ident>) -> Result<#ident, #ident> {
+                let mut prev = self.load();
+                while let Some(next) = f(prev) {
+                    match self.compare_exchange(prev, next) {
+                        Ok(it) => return Ok(it),
+                        Err(it) => prev = it,
+                    }
+                }
+                Err(prev)
+            }
+            fn read(&self) -> u128 {
+                use ::core::sync::atomic::Ordering;
+                let [lo, hi] = &self.halves;
+                lo.load(Ordering::Relaxed) as u128 | (hi.load(Ordering::Relaxed) as u128) << 64
+            }
+            /// Must only be called between [`Self::lock`] and [`Self::unlock`].
+            fn write(&self, bits: u128) {
+                use ::core::sync::atomic::Ordering;
+                let [lo, hi] = &self.halves;
+                lo.store(bits as u64, Ordering::Relaxed);
+                hi.store((bits >> 64) as u64, Ordering::Relaxed);
+            }
+            /// Returns the (odd) sequence number.
+            fn lock(&self) -> usize {
+                use ::core::sync::atomic::{Ordering, fence};
+                loop {
+                    let seq = self.seq.load(Ordering::Relaxed);
+                    if seq.is_multiple_of(2)
+                        && self
+                            .seq
+                            .compare_exchange_weak(seq, seq.wrapping_add(1), Ordering::Acquire, Ordering::Relaxed)
+                            .is_ok()
+                    {
+                        // readers must not see our writes without the odd sequence number
+                        fence(Ordering::Release);
+                        return seq.wrapping_add(1);
+                    }
+                    ::core::hint::spin_loop();
+                }
+            }
+            fn unlock(&self, seq: usize) {
+                self.seq.store(seq.wrapping_add(1), ::core::sync::atomic::Ordering::Release)
+            }
+        }
+
+        #[cfg(target_has_atomic = "64")]
+        impl ::core::default::Default for #atomic_ident {
+            fn default() -> Self {
+                Self::new(#ident::EMPTY)
+            }
+        }
+
+        #[cfg(target_has_atomic = "64")]
+        impl ::core::convert::From<#ident> for #atomic_ident {
+            fn from(value: #ident) -> Self {
+                Self::new(value)
+            }
+        }
+
+        #[cfg(target_has_atomic = "64")]
+        impl ::core::fmt::Debug for #atomic_ident {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                self.load().fmt(f)
+            }
+        }
+    }
+}
+
+fn len(host: &Host, ident: &Ident, n: u8) -> TokenStream {
+    let fallback = match n {
+        u8::MAX => quote!(),
+        _ => quote!(_ => None),
+    };
+    let (variants, vals) = (0..=n)
+        .map(|i| {
+            let ident = Ident::new(&format!("_{i}"), Span::call_site());
+            let val = Literal::u8_suffixed(i);
+            (ident, val)
+        })
+        .unzip::<_, _, Vec<_>, Vec<_>>();
+    let definition = host.derive_zerocopy(
+        quote! {
+            #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+            #[repr(u8)]
+        },
+        quote! {
+            enum #ident {
+                #(#variants = #vals),*
+            }
+        },
+    );
+    quote! {
+        #definition
+        impl #ident {
+            const fn from_usize(u: usize) -> Option<Self> {
+                const U8_MAX: usize = u8::MAX as _;
+                if u > U8_MAX {
+                    return None;
+                }
+                Self::from_u8(u as u8)
+            }
+            const fn from_u8(u: u8) -> Option<Self> {
+                match u {
+                    #(
+                        #vals => Some(Self::#variants),
+                    )*
+                    #fallback
+                }
+            }
+        }
+    }
+}
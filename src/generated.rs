@@ -1,5 +1,13 @@
 #![cfg_attr(rustfmt, rustfmt::skip)]
 /**A stack-allocated string which can hold up to 3 UTF-8 encoded bytes.
+
+With the `zerocopy` feature, this implements `IntoBytes`, `Immutable`,
+`KnownLayout` and `Unaligned`.
+`TryFromBytes` is deliberately not implemented, because its derive cannot check
+that the bytes are UTF-8 followed by zero padding,
+and neither is `FromZeros`, which requires it.
+Use [`try_ref_from_bytes`](NanoString::try_ref_from_bytes)
+or [`try_slice_from_bytes`](NanoString::try_slice_from_bytes) instead.
 ```
 # use core::mem::size_of;
 # use microstring::*;
@@ -17,7 +25,12 @@ assert_eq! {
 #[repr(C)]
 #[cfg_attr(
     feature = "zerocopy",
-    derive(zerocopy::TryFromBytes, zerocopy::IntoBytes, zerocopy::Immutable)
+    derive(
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout,
+        zerocopy::Unaligned
+    )
 )]
 pub struct NanoString {
     len: NanoStringLen,
@@ -71,15 +84,720 @@ const TOO_BIG: NanoString = NanoString::new("GEEBEEPEE").unwrap();
             )
         }
     }
+    /**Returns an error if the given bytes are not UTF-8, or if <code>[slice::len()] > 3</code>.
+```
+# use microstring::*;
+const STRING: NanoString = match NanoString::from_utf8(b"GBP") {
+    Ok(it) => it,
+    Err(_) => panic!(),
+};
+assert_eq!(NanoString::from_utf8(b"\xFF").unwrap_err().kind(), ErrorKind::InvalidUtf8);
+assert_eq!(NanoString::from_utf8(b"GEEBEEPEE").unwrap_err().kind(), ErrorKind::TooLong);
+```
+*/
+    pub const fn from_utf8(bytes: &[u8]) -> Result<Self, NanoStringError> {
+        if bytes.len() > 3u8 as usize {
+            return Err(NanoStringError::new(crate::ErrorKind::TooLong, bytes.len()));
+        }
+        match ::core::str::from_utf8(bytes) {
+            Ok(s) => {
+                match Self::new(s) {
+                    Some(it) => Ok(it),
+                    None => {
+                        Err(NanoStringError::new(crate::ErrorKind::TooLong, bytes.len()))
+                    }
+                }
+            }
+            Err(_) => {
+                Err(NanoStringError::new(crate::ErrorKind::InvalidUtf8, bytes.len()))
+            }
+        }
+    }
+    /**Returns [`None`] if <code>[slice::len()] > 3</code>.
+
+# Safety
+- The given bytes must be valid UTF-8.
+*/
+    pub const unsafe fn from_utf8_unchecked(bytes: &[u8]) -> Option<Self> {
+        Self::new(unsafe { str::from_utf8_unchecked(bytes) })
+    }
+    /**Truncates the given string to at most 3 bytes, on a [`char`] boundary.
+```
+# use microstring::*;
+const STRING: NanoString = NanoString::new_truncating("GEEBEEPEE");
+assert_eq!(STRING, "GEE");
+```
+*/
+    pub const fn new_truncating(s: &str) -> Self {
+        let mut end = if s.len() < 3u8 as usize { s.len() } else { 3u8 as usize };
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+        match Self::new(s.split_at(end).0) {
+            Some(it) => it,
+            None => unreachable!(),
+        }
+    }
+    /**If the given string is longer than 3 bytes, truncates it on a [`char`] boundary,
+leaving room to append the given `marker`.
+
+# Panics
+- If the string needs truncating, and <code>marker.[len()](str::len) > 3</code>.
+```
+# use microstring::*;
+const STRING: NanoString = NanoString::new_truncating_with_marker("GEEBEEPEE", "…");
+assert_eq!(STRING, "…");
+```
+*/
+    pub const fn new_truncating_with_marker(s: &str, marker: &str) -> Self {
+        if let Some(it) = Self::new(s) {
+            return it;
+        }
+        assert!(marker.len() <= 3u8 as usize, "marker is longer than the capacity");
+        let mut end = 3u8 as usize - marker.len();
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+        let mut it = Self::new_truncating(s.split_at(end).0);
+        match it.push_str(marker) {
+            Ok(()) => it,
+            Err(_) => unreachable!(),
+        }
+    }
+    /// Like [`new_truncating`](Self::new_truncating),
+    /// but never splits a grapheme cluster.
+    #[cfg(feature = "unicode-segmentation")]
+    pub fn new_truncating_graphemes(s: &str) -> Self {
+        use unicode_segmentation::UnicodeSegmentation as _;
+        let end = s
+            .grapheme_indices(true)
+            .map(|(ix, grapheme)| ix + grapheme.len())
+            .take_while(|end| *end <= 3u8 as usize)
+            .last()
+            .unwrap_or(0);
+        Self::new_truncating(&s[..end])
+    }
 }
+/**The error returned when a [`NanoString`] cannot be constructed.
+```
+# use microstring::*;
+let e = "GEEBEEPEE".parse::<NanoString>().unwrap_err();
+assert_eq!(e.kind(), ErrorKind::TooLong);
+assert_eq!(e.input_len(), 9);
+assert_eq!(e.to_string(), "expected a string of at most 3 bytes, but got 9 bytes");
+```
+*/
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct NanoStringError;
+pub struct NanoStringError {
+    kind: crate::ErrorKind,
+    len: usize,
+}
+impl NanoStringError {
+    const fn new(kind: crate::ErrorKind, len: usize) -> Self {
+        Self { kind, len }
+    }
+    /// Why the input was rejected.
+    pub const fn kind(&self) -> crate::ErrorKind {
+        self.kind
+    }
+    /// The length of the rejected input, in bytes.
+    ///
+    /// For operations which append to an existing string,
+    /// this is the length the string would have had.
+    pub const fn input_len(&self) -> usize {
+        self.len
+    }
+    /// The maximum length of the string being constructed, in bytes.
+    pub const fn capacity(&self) -> usize {
+        3u8 as usize
+    }
+}
 impl ::core::fmt::Display for NanoStringError {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        f.write_str("expected a string of at most 3 bytes")
+        let Self { kind, len } = *self;
+        match kind {
+            crate::ErrorKind::TooLong => {
+                write!(f, "expected a string of at most 3 bytes, but got {len} bytes")
+            }
+            crate::ErrorKind::InvalidUtf8 => {
+                write!(
+                    f,
+                    "expected a UTF-8 string of at most 3 bytes, but got {len} bytes of invalid UTF-8"
+                )
+            }
+            crate::ErrorKind::Invalid => {
+                write!(f, "a string of {len} bytes failed validation")
+            }
+        }
     }
 }
 impl ::core::error::Error for NanoStringError {}
+impl NanoString {
+    /// The maximum length, in bytes.
+    pub const CAPACITY: usize = 3u8 as usize;
+    /// The length of this string, in bytes.
+    pub const fn len(&self) -> usize {
+        self.len as u8 as usize
+    }
+    /// Returns `true` if this string has a length of zero.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// The number of bytes which may be pushed before this string is full.
+    pub const fn remaining_capacity(&self) -> usize {
+        Self::CAPACITY - self.len()
+    }
+    /// Equivalent to [`str::as_bytes`], but usable in `const`.
+    ///
+    /// Unlike `as_bytes`, this does not shadow
+    /// [`zerocopy::IntoBytes::as_bytes`](https://docs.rs/zerocopy/latest/zerocopy/trait.IntoBytes.html#method.as_bytes),
+    /// which returns the length as well.
+    pub const fn const_as_bytes(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+    /// Equivalent to `==`, but usable in `const`.
+    pub const fn const_eq(&self, other: &str) -> bool {
+        self.len() == other.len() && self.const_cmp(other).is_eq()
+    }
+    /// Equivalent to [`Ord::cmp`], but usable in `const`.
+    pub const fn const_cmp(&self, other: &str) -> ::core::cmp::Ordering {
+        crate::__private::cmp_bytes(self.const_as_bytes(), other.as_bytes())
+    }
+    /// Equivalent to [`str::starts_with`] with a [`str`] pattern, but usable in `const`.
+    pub const fn const_starts_with(&self, prefix: &str) -> bool {
+        match prefix.len() <= self.len() {
+            true => {
+                let (head, _) = self.const_as_bytes().split_at(prefix.len());
+                crate::__private::cmp_bytes(head, prefix.as_bytes()).is_eq()
+            }
+            false => false,
+        }
+    }
+    /// Equivalent to [`str::ends_with`] with a [`str`] pattern, but usable in `const`.
+    pub const fn const_ends_with(&self, suffix: &str) -> bool {
+        match self.len().checked_sub(suffix.len()) {
+            Some(mid) => {
+                let (_, tail) = self.const_as_bytes().split_at(mid);
+                crate::__private::cmp_bytes(tail, suffix.as_bytes()).is_eq()
+            }
+            None => false,
+        }
+    }
+    /// Converts this string to its ASCII upper case equivalent in-place.
+    pub const fn make_ascii_uppercase(&mut self) {
+        self.as_mut_str().make_ascii_uppercase()
+    }
+    /// Converts this string to its ASCII lower case equivalent in-place.
+    pub const fn make_ascii_lowercase(&mut self) {
+        self.as_mut_str().make_ascii_lowercase()
+    }
+    /**Returns a copy of this string, with ASCII letters converted to upper case.
+
+Other bytes are unchanged.
+```
+# use microstring::*;
+const UPPER: NanoString = NanoString::new("GBP").unwrap().to_ascii_uppercase();
+assert_eq!(UPPER, "GBP");
+for s in ["@AZ", "[`a", "z{", "é"] {
+    assert_eq!(NanoString::new(s).unwrap().to_ascii_uppercase(), s.to_ascii_uppercase().as_str());
+}
+```
+*/
+    pub const fn to_ascii_uppercase(self) -> Self {
+        let bits = self.to_ne_bits();
+        unsafe {
+            Self::from_ne_bits_unchecked(bits ^ Self::ascii_case_mask(bits, b'a', b'z'))
+        }
+    }
+    /**Returns a copy of this string, with ASCII letters converted to lower case.
+
+Other bytes are unchanged.
+```
+# use microstring::*;
+const LOWER: NanoString = NanoString::new("GBP").unwrap().to_ascii_lowercase();
+assert_eq!(LOWER, "gbp");
+for s in ["@AZ", "[`a", "z{", "é"] {
+    assert_eq!(NanoString::new(s).unwrap().to_ascii_lowercase(), s.to_ascii_lowercase().as_str());
+}
+```
+*/
+    pub const fn to_ascii_lowercase(self) -> Self {
+        let bits = self.to_ne_bits();
+        unsafe {
+            Self::from_ne_bits_unchecked(bits ^ Self::ascii_case_mask(bits, b'A', b'Z'))
+        }
+    }
+    /// Returns `0x20` in each byte of `bits` which is in `lo..=hi`, and zero elsewhere,
+    /// so that XOR-ing with it switches the case of those ASCII letters.
+    const fn ascii_case_mask(bits: u32, lo: u8, hi: u8) -> u32 {
+        const ONES: u32 = u32::MAX / 0xFF;
+        const HIGH: u32 = ONES * 0x80;
+        let low = bits & !HIGH;
+        let ge_lo = low + ONES * (0x80 - lo as u32);
+        let gt_hi = low + ONES * (0x7F - hi as u32);
+        ((ge_lo ^ gt_hi) & !bits & HIGH) >> 2
+    }
+}
+impl NanoString {
+    /// Appends the given [`char`] to the end of this string.
+    ///
+    /// Returns an error, leaving this string unchanged, if there is not enough capacity.
+    pub const fn push(&mut self, c: char) -> Result<(), NanoStringError> {
+        self.push_str(c.encode_utf8(&mut [0; 4]))
+    }
+    /**Appends the given string to the end of this one.
+
+Returns an error, leaving this string unchanged, if the result would be
+longer than 3 bytes.
+```
+# use microstring::*;
+let mut string = NanoString::new("GBP").unwrap();
+assert!(string.push_str("GEEBEEPEE").is_err());
+assert_eq!(string, "GBP");
+```
+*/
+    pub const fn push_str(&mut self, s: &str) -> Result<(), NanoStringError> {
+        let len = self.len as u8 as usize;
+        match NanoStringLen::from_usize(len + s.len()) {
+            Some(new_len) => {
+                unsafe {
+                    ::core::ptr::copy_nonoverlapping(
+                        s.as_ptr(),
+                        self.bytes.as_mut_ptr().add(len),
+                        s.len(),
+                    )
+                }
+                self.len = new_len;
+                Ok(())
+            }
+            None => Err(NanoStringError::new(crate::ErrorKind::TooLong, len + s.len())),
+        }
+    }
+    /**Concatenates the given strings.
+
+Returns an error if the result would be longer than 3 bytes.
+```
+# use microstring::*;
+assert_eq!(NanoString::try_concat("GBP", "").unwrap(), "GBP");
+assert!(NanoString::try_concat("GBP", "GEEBEEPEE").is_err());
+```
+*/
+    pub const fn try_concat(left: &str, right: &str) -> Result<Self, NanoStringError> {
+        match Self::new(left) {
+            Some(mut it) => {
+                match it.push_str(right) {
+                    Ok(()) => Ok(it),
+                    Err(e) => Err(e),
+                }
+            }
+            None => {
+                Err(
+                    NanoStringError::new(
+                        crate::ErrorKind::TooLong,
+                        left.len() + right.len(),
+                    ),
+                )
+            }
+        }
+    }
+    /**Removes the last [`char`] from this string and returns it,
+or [`None`] if this string is empty.
+```
+# use microstring::*;
+let mut string = NanoString::new("aé").unwrap();
+assert_eq!(string.pop(), Some('é'));
+assert_eq!(string.to_bits(), NanoString::new("a").unwrap().to_bits());
+assert_eq!(string.pop(), Some('a'));
+assert_eq!(string.pop(), None);
+assert_eq!(string.to_bits(), NanoString::EMPTY.to_bits());
+```
+*/
+    pub fn pop(&mut self) -> Option<char> {
+        let c = self.as_str().chars().next_back()?;
+        unsafe { self.set_len(self.len as u8 as usize - c.len_utf8()) }
+        Some(c)
+    }
+    /**Shortens this string to `new_len` bytes.
+
+Has no effect if `new_len` is greater than the string's current length.
+
+# Panics
+- If `new_len` does not lie on a [`char`] boundary.
+```
+# use microstring::*;
+let mut string = NanoString::new("aé").unwrap();
+string.truncate(3);
+assert_eq!(string, "aé");
+string.truncate(1);
+assert_eq!(string.to_bits(), NanoString::new("a").unwrap().to_bits());
+```
+```should_panic
+# use microstring::*;
+NanoString::new("é").unwrap().truncate(1);
+```
+*/
+    pub const fn truncate(&mut self, new_len: usize) {
+        if new_len < self.len as u8 as usize {
+            assert!(
+                self.as_str().is_char_boundary(new_len),
+                "new_len does not lie on a char boundary"
+            );
+            unsafe { self.set_len(new_len) }
+        }
+    }
+    /**Truncates this string to the empty string.
+```
+# use microstring::*;
+let mut string = NanoString::new("GBP").unwrap();
+string.clear();
+assert_eq!(string.to_bits(), NanoString::EMPTY.to_bits());
+```
+*/
+    pub const fn clear(&mut self) {
+        unsafe { self.set_len(0) }
+    }
+    /// Inserts the given [`char`] at byte position `idx`.
+    ///
+    /// Returns an error, leaving this string unchanged, if there is not enough capacity.
+    ///
+    /// # Panics
+    /// - If `idx` is out of bounds, or does not lie on a [`char`] boundary.
+    pub const fn insert(&mut self, idx: usize, c: char) -> Result<(), NanoStringError> {
+        self.insert_str(idx, c.encode_utf8(&mut [0; 4]))
+    }
+    /**Inserts the given string at byte position `idx`.
+
+Returns an error, leaving this string unchanged, if there is not enough capacity.
+
+# Panics
+- If `idx` is out of bounds, or does not lie on a [`char`] boundary.
+```
+# use microstring::*;
+let mut string = NanoString::new("a").unwrap();
+string.insert_str(0, "é").unwrap();
+assert_eq!(string, "éa");
+assert!(string.insert_str(2, "GEEBEEPEE").is_err());
+assert_eq!(string.to_bits(), NanoString::new("éa").unwrap().to_bits());
+```
+```should_panic
+# use microstring::*;
+let _ = NanoString::new("é").unwrap().insert_str(1, "a");
+```
+*/
+    pub const fn insert_str(
+        &mut self,
+        idx: usize,
+        s: &str,
+    ) -> Result<(), NanoStringError> {
+        assert!(
+            self.as_str().is_char_boundary(idx), "idx does not lie on a char boundary"
+        );
+        let len = self.len as u8 as usize;
+        match NanoStringLen::from_usize(len + s.len()) {
+            Some(new_len) => {
+                unsafe {
+                    let p = self.bytes.as_mut_ptr();
+                    ::core::ptr::copy(p.add(idx), p.add(idx + s.len()), len - idx);
+                    ::core::ptr::copy_nonoverlapping(s.as_ptr(), p.add(idx), s.len());
+                }
+                self.len = new_len;
+                Ok(())
+            }
+            None => Err(NanoStringError::new(crate::ErrorKind::TooLong, len + s.len())),
+        }
+    }
+    /**Removes the [`char`] at byte position `idx` and returns it.
+
+# Panics
+- If `idx` is out of bounds, or does not lie on a [`char`] boundary.
+```
+# use microstring::*;
+let mut string = NanoString::new("éa").unwrap();
+assert_eq!(string.remove(0), 'é');
+assert_eq!(string.to_bits(), NanoString::new("a").unwrap().to_bits());
+```
+```should_panic
+# use microstring::*;
+NanoString::new("é").unwrap().remove(1);
+```
+*/
+    pub fn remove(&mut self, idx: usize) -> char {
+        let Some(c) = self.as_str()[idx..].chars().next() else {
+            panic!("cannot remove a char from the end of a string")
+        };
+        let len = self.len as u8 as usize;
+        let next = idx + c.len_utf8();
+        unsafe {
+            let p = self.bytes.as_mut_ptr();
+            ::core::ptr::copy(p.add(next), p.add(idx), len - next);
+            self.set_len(len - c.len_utf8());
+        }
+        c
+    }
+    /**Retains only the [`char`]s for which `f` returns `true`.
+```
+# use microstring::*;
+let mut string = NanoString::new("éa").unwrap();
+string.retain(|c| c.is_ascii());
+assert_eq!(string.to_bits(), NanoString::new("a").unwrap().to_bits());
+```
+*/
+    pub fn retain(&mut self, mut f: impl FnMut(char) -> bool) {
+        let this = *self;
+        self.clear();
+        for c in this.as_str().chars() {
+            if f(c) {
+                let _ = self.push(c);
+            }
+        }
+    }
+    /// Sets the length, zeroing any bytes past it.
+    ///
+    /// # Safety
+    /// - `new_len` must be at most the current length.
+    /// - `new_len` must lie on a [`char`] boundary.
+    const unsafe fn set_len(&mut self, new_len: usize) {
+        let len = self.len as u8 as usize;
+        unsafe {
+            ::core::ptr::write_bytes(
+                self.bytes.as_mut_ptr().add(new_len),
+                0,
+                len - new_len,
+            )
+        }
+        self.len = match NanoStringLen::from_usize(new_len) {
+            Some(it) => it,
+            None => unreachable!(),
+        };
+    }
+}
+impl NanoString {
+    /**Collects the given [`char`]s into a string.
+
+Returns an error if there are more than 3 bytes of them.
+See also [`Collected`](crate::Collected).
+```
+# use microstring::*;
+assert_eq!(NanoString::try_from_iter("GBP".chars()).unwrap(), "GBP");
+assert!(NanoString::try_from_iter("GEEBEEPEE".chars()).is_err());
+```
+*/
+    pub fn try_from_iter<I: IntoIterator<Item = char>>(
+        iter: I,
+    ) -> Result<Self, NanoStringError> {
+        let mut it = Self::EMPTY;
+        for c in iter {
+            it.push(c)?;
+        }
+        Ok(it)
+    }
+    /**Returns an owning iterator over the bytes of this string.
+```
+# use microstring::*;
+let string = NanoString::new("GBP").unwrap();
+assert!(string.into_bytes().eq("GBP".bytes()));
+assert_eq!(string.into_bytes().len(), 3);
+```
+*/
+    pub fn into_bytes(self) -> crate::IntoBytes<Self> {
+        crate::IntoBytes::new(self)
+    }
+}
+/**An owning iterator over the [`char`]s of this string.
+```
+# use microstring::*;
+let string = NanoString::new("GBP").unwrap();
+assert!(string.into_iter().rev().eq("GBP".chars().rev()));
+```
+*/
+impl ::core::iter::IntoIterator for NanoString {
+    type Item = char;
+    type IntoIter = crate::IntoChars<Self>;
+    fn into_iter(self) -> crate::IntoChars<Self> {
+        crate::IntoChars::new(self)
+    }
+}
+/// # Panics
+/// - If there is not enough capacity.
+///   See [`push`](Self::push) for a fallible alternative.
+impl ::core::iter::Extend<char> for NanoString {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        for c in iter {
+            if let Err(e) = self.push(c) {
+                panic!("{e}")
+            }
+        }
+    }
+}
+/// # Panics
+/// - If there is not enough capacity.
+///   See [`push_str`](Self::push_str) for a fallible alternative.
+impl<'a> ::core::iter::Extend<&'a str> for NanoString {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        for s in iter {
+            if let Err(e) = self.push_str(s) {
+                panic!("{e}")
+            }
+        }
+    }
+}
+impl NanoString {
+    /**Interprets the given bytes as a string, without copying.
+
+The bytes must be laid out as produced by `zerocopy::IntoBytes`:
+a length byte, followed by that many bytes of UTF-8, followed by zero padding.
+Returns [`None`] otherwise.
+
+This is the checked alternative to `zerocopy::TryFromBytes`,
+which is not implemented for this type because it cannot check the contents.
+```
+# use microstring::*;
+let mut bytes = [0; 3 + 1];
+bytes[0] = 3;
+bytes[1..][..3].copy_from_slice(b"GBP");
+assert_eq!(NanoString::try_ref_from_bytes(&bytes).unwrap().as_str(), "GBP");
+bytes[1] = 0xFF;
+assert_eq!(NanoString::try_ref_from_bytes(&bytes), None);
+```
+*/
+    pub const fn try_ref_from_bytes(bytes: &[u8]) -> Option<&Self> {
+        const { assert!(::core::mem::align_of:: < Self > () == 1) }
+        match Self::is_valid(bytes) {
+            true => Some(unsafe { &*bytes.as_ptr().cast::<Self>() }),
+            false => None,
+        }
+    }
+    /// Like [`try_ref_from_bytes`](Self::try_ref_from_bytes),
+    /// but for a contiguous run of strings.
+    pub const fn try_slice_from_bytes(bytes: &[u8]) -> Option<&[Self]> {
+        const { assert!(::core::mem::align_of:: < Self > () == 1) }
+        let size = ::core::mem::size_of::<Self>();
+        if !bytes.len().is_multiple_of(size) {
+            return None;
+        }
+        let mut rest = bytes;
+        while let Some((chunk, tail)) = rest.split_at_checked(size) {
+            if !Self::is_valid(chunk) {
+                return None;
+            }
+            rest = tail;
+        }
+        Some(unsafe {
+            ::core::slice::from_raw_parts(
+                bytes.as_ptr().cast::<Self>(),
+                bytes.len() / size,
+            )
+        })
+    }
+    /// Whether the given bytes are a length byte, that many bytes of UTF-8, and zero padding.
+    const fn is_valid(bytes: &[u8]) -> bool {
+        match bytes {
+            [len, rest @ ..] if rest.len() == 3u8 as usize => {
+                Self::is_valid_parts(*len, rest)
+            }
+            _ => false,
+        }
+    }
+    /// Whether `len` is in range, and `bytes` are that many bytes of UTF-8, followed by zero padding.
+    const fn is_valid_parts(len: u8, bytes: &[u8]) -> bool {
+        let Some(len) = NanoStringLen::from_u8(len) else {
+            return false;
+        };
+        let (string, mut padding) = bytes.split_at(len as u8 as usize);
+        if ::core::str::from_utf8(string).is_err() {
+            return false;
+        }
+        while let [0, tail @ ..] = padding {
+            padding = tail;
+        }
+        padding.is_empty()
+    }
+}
+impl NanoString {
+    /**Packs this string into a [`u32`].
+
+The string's bytes, zero padded to 3 bytes, come first,
+and its length is the last byte.
+This is stable, so may be persisted.
+Comparing the packed representations orders them the same as comparing the strings.
+```
+# use microstring::*;
+const BITS: u32 = NanoString::new("GBP").unwrap().to_bits();
+assert_eq!(BITS, u32::from_be_bytes(*b"GBP\x03"));
+assert_eq!(NanoString::from_bits(BITS), NanoString::new("GBP"));
+```
+*/
+    pub const fn to_bits(self) -> u32 {
+        u32::from_be(self.to_ne_bits()).rotate_left(8)
+    }
+    /// Like [`to_bits`](Self::to_bits), but in native byte order,
+    /// so only suitable for equality.
+    const fn to_ne_bits(self) -> u32 {
+        unsafe { ::core::mem::transmute::<Self, u32>(self) }
+    }
+    /// The inverse of [`to_ne_bits`](Self::to_ne_bits).
+    ///
+    /// # Safety
+    /// - `bits` must have been returned by [`to_ne_bits`](Self::to_ne_bits).
+    const unsafe fn from_ne_bits_unchecked(bits: u32) -> Self {
+        unsafe { ::core::mem::transmute::<u32, Self>(bits) }
+    }
+    /// The inverse of [`to_bits`](Self::to_bits).
+    ///
+    /// Returns [`None`] if the length is out of range,
+    /// the string is not UTF-8, or the padding is not zero.
+    pub const fn from_bits(bits: u32) -> Option<Self> {
+        let [bytes @ .., len] = bits.to_be_bytes();
+        match Self::is_valid_parts(len, &bytes) {
+            true => Some(unsafe { Self::from_bits_unchecked(bits) }),
+            false => None,
+        }
+    }
+    /// The inverse of [`to_bits`](Self::to_bits), without any checks.
+    ///
+    /// # Safety
+    /// - `bits` must have been returned by [`to_bits`](Self::to_bits),
+    ///   or be accepted by [`from_bits`](Self::from_bits).
+    pub const unsafe fn from_bits_unchecked(bits: u32) -> Self {
+        unsafe { ::core::mem::transmute::<u32, Self>(bits.rotate_right(8).to_be()) }
+    }
+}
+impl crate::__private::Sealed for NanoString {}
+impl crate::InlineStr for NanoString {
+    const CAPACITY: usize = 3u8 as usize;
+    const EMPTY: Self = Self::EMPTY;
+    type Error = NanoStringError;
+    type Bits = u32;
+    fn new(s: &str) -> Option<Self> {
+        Self::new(s)
+    }
+    fn as_str(&self) -> &str {
+        self.as_str()
+    }
+    fn as_mut_str(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+    fn from_utf8(bytes: &[u8]) -> Result<Self, NanoStringError> {
+        Self::from_utf8(bytes)
+    }
+    fn to_bits(self) -> u32 {
+        self.to_bits()
+    }
+    fn from_bits(bits: u32) -> Option<Self> {
+        Self::from_bits(bits)
+    }
+    fn push_str(&mut self, s: &str) -> Result<(), NanoStringError> {
+        self.push_str(s)
+    }
+    fn try_from_iter<I: IntoIterator<Item = char>>(
+        iter: I,
+    ) -> Result<Self, NanoStringError> {
+        Self::try_from_iter(iter)
+    }
+    fn __error(kind: crate::ErrorKind, len: usize) -> NanoStringError {
+        NanoStringError::new(kind, len)
+    }
+}
 impl ::core::default::Default for NanoString {
     fn default() -> Self {
         Self::EMPTY
@@ -90,6 +808,7 @@ impl ::core::default::Default for &NanoString {
         &NanoString::EMPTY
     }
 }
+/// Hashes the same as [`str`], as required by [`Borrow<str>`](::core::borrow::Borrow).
 impl ::core::hash::Hash for NanoString {
     fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
         self.as_str().hash(state)
@@ -123,7 +842,20 @@ impl ::core::convert::AsRef<[u8]> for NanoString {
 impl TryFrom<&str> for NanoString {
     type Error = NanoStringError;
     fn try_from(value: &str) -> Result<Self, NanoStringError> {
-        Self::new(value).ok_or(NanoStringError)
+        Self::new(value)
+            .ok_or(NanoStringError::new(crate::ErrorKind::TooLong, value.len()))
+    }
+}
+impl TryFrom<&[u8]> for NanoString {
+    type Error = NanoStringError;
+    fn try_from(value: &[u8]) -> Result<Self, NanoStringError> {
+        Self::from_utf8(value)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for NanoString {
+    type Error = NanoStringError;
+    fn try_from(value: [u8; N]) -> Result<Self, NanoStringError> {
+        Self::from_utf8(&value)
     }
 }
 #[cfg(feature = "std")]
@@ -139,47 +871,258 @@ impl ::core::convert::AsRef<::std::path::Path> for NanoString {
     }
 }
 #[cfg(feature = "alloc")]
+impl ::core::convert::TryFrom<::alloc::string::String> for NanoString {
+    type Error = NanoStringError;
+    fn try_from(value: ::alloc::string::String) -> Result<Self, NanoStringError> {
+        Self::new(&value)
+            .ok_or(NanoStringError::new(crate::ErrorKind::TooLong, value.len()))
+    }
+}
+#[cfg(feature = "alloc")]
 impl ::core::convert::From<NanoString> for ::alloc::string::String {
     fn from(val: NanoString) -> Self {
         Self::from(val.as_str())
     }
 }
 #[cfg(feature = "alloc")]
-impl ::core::convert::TryFrom<::alloc::string::String> for NanoString {
-    type Error = NanoStringError;
-    fn try_from(value: ::alloc::string::String) -> Result<Self, NanoStringError> {
-        Self::new(&value).ok_or(NanoStringError)
+impl ::core::convert::From<NanoString> for ::alloc::boxed::Box<str> {
+    fn from(val: NanoString) -> Self {
+        Self::from(val.as_str())
     }
 }
-impl ::core::borrow::Borrow<str> for NanoString {
-    fn borrow(&self) -> &str {
-        self.as_str()
+#[cfg(feature = "alloc")]
+impl ::core::convert::From<NanoString> for ::alloc::rc::Rc<str> {
+    fn from(val: NanoString) -> Self {
+        Self::from(val.as_str())
     }
 }
-impl ::core::borrow::BorrowMut<str> for NanoString {
-    fn borrow_mut(&mut self) -> &mut str {
-        self.as_mut_str()
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl ::core::convert::From<NanoString> for ::alloc::sync::Arc<str> {
+    fn from(val: NanoString) -> Self {
+        Self::from(val.as_str())
     }
 }
-impl ::core::ops::Deref for NanoString {
-    type Target = str;
-    fn deref(&self) -> &str {
-        self.as_str()
-    }
+#[cfg(feature = "std")]
+impl ::core::convert::From<NanoString> for ::std::ffi::OsString {
+    fn from(val: NanoString) -> Self {
+        Self::from(val.as_str())
+    }
+}
+#[cfg(feature = "std")]
+impl ::core::convert::From<NanoString> for ::std::path::PathBuf {
+    fn from(val: NanoString) -> Self {
+        Self::from(val.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::From<NanoString> for ::alloc::borrow::Cow<'static, str> {
+    fn from(val: NanoString) -> Self {
+        Self::Owned(::alloc::string::String::from(val))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::TryFrom<::alloc::boxed::Box<str>> for NanoString {
+    type Error = NanoStringError;
+    fn try_from(value: ::alloc::boxed::Box<str>) -> Result<Self, NanoStringError> {
+        Self::try_from(&*value)
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::TryFrom<::alloc::borrow::Cow<'_, str>> for NanoString {
+    type Error = NanoStringError;
+    fn try_from(value: ::alloc::borrow::Cow<'_, str>) -> Result<Self, NanoStringError> {
+        Self::try_from(&*value)
+    }
+}
+#[cfg(feature = "std")]
+impl ::core::convert::TryFrom<&::std::ffi::OsStr> for NanoString {
+    type Error = NanoStringError;
+    fn try_from(value: &::std::ffi::OsStr) -> Result<Self, NanoStringError> {
+        match value.to_str() {
+            Some(s) => Self::try_from(s),
+            None => Err(NanoStringError::new(crate::ErrorKind::InvalidUtf8, value.len())),
+        }
+    }
+}
+#[cfg(feature = "std")]
+impl ::core::convert::TryFrom<&::std::path::Path> for NanoString {
+    type Error = NanoStringError;
+    fn try_from(value: &::std::path::Path) -> Result<Self, NanoStringError> {
+        Self::try_from(value.as_os_str())
+    }
+}
+impl ::core::convert::TryFrom<&::core::ffi::CStr> for NanoString {
+    type Error = NanoStringError;
+    fn try_from(value: &::core::ffi::CStr) -> Result<Self, NanoStringError> {
+        Self::from_utf8(value.to_bytes())
+    }
+}
+impl ::core::borrow::Borrow<str> for NanoString {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+impl ::core::borrow::BorrowMut<str> for NanoString {
+    fn borrow_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+impl ::core::ops::Deref for NanoString {
+    type Target = str;
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
 }
 impl ::core::ops::DerefMut for NanoString {
     fn deref_mut(&mut self) -> &mut str {
         self.as_mut_str()
     }
 }
-impl<T: ::core::convert::AsRef<str>> ::core::cmp::PartialEq<T> for NanoString {
-    fn eq(&self, other: &T) -> bool {
-        self.as_str().eq(other.as_ref())
+/// Compares the whole string as a single integer.
+impl ::core::cmp::PartialEq for NanoString {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_ne_bits() == other.to_ne_bits()
+    }
+}
+impl ::core::cmp::PartialOrd for NanoString {
+    fn partial_cmp(&self, other: &Self) -> Option<::core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+/**Orders strings the same way as [`str`].
+
+This compares the packed representation from [`to_bits`](NanoString::to_bits) as a single integer.
+```
+# use microstring::*;
+let mut strings = ["b", "a\0", "", "a"].map(|it| NanoString::new(it).unwrap());
+strings.sort();
+assert_eq!(strings, ["", "a", "a\0", "b"]);
+```
+*/
+impl ::core::cmp::Ord for NanoString {
+    fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+        self.to_bits().cmp(&other.to_bits())
+    }
+}
+impl ::core::cmp::PartialEq<str> for NanoString {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str().eq(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+impl ::core::cmp::PartialOrd<str> for NanoString {
+    fn partial_cmp(&self, other: &str) -> Option<::core::cmp::Ordering> {
+        self.as_str().partial_cmp(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+impl ::core::cmp::PartialEq<NanoString> for str {
+    fn eq(&self, other: &NanoString) -> bool {
+        ::core::convert::AsRef::<str>::as_ref(self).eq(other.as_str())
+    }
+}
+impl ::core::cmp::PartialOrd<NanoString> for str {
+    fn partial_cmp(&self, other: &NanoString) -> Option<::core::cmp::Ordering> {
+        ::core::convert::AsRef::<str>::as_ref(self).partial_cmp(other.as_str())
+    }
+}
+impl ::core::cmp::PartialEq<&str> for NanoString {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str().eq(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+impl ::core::cmp::PartialOrd<&str> for NanoString {
+    fn partial_cmp(&self, other: &&str) -> Option<::core::cmp::Ordering> {
+        self.as_str().partial_cmp(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+impl ::core::cmp::PartialEq<NanoString> for &str {
+    fn eq(&self, other: &NanoString) -> bool {
+        ::core::convert::AsRef::<str>::as_ref(self).eq(other.as_str())
+    }
+}
+impl ::core::cmp::PartialOrd<NanoString> for &str {
+    fn partial_cmp(&self, other: &NanoString) -> Option<::core::cmp::Ordering> {
+        ::core::convert::AsRef::<str>::as_ref(self).partial_cmp(other.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialEq<::alloc::string::String> for NanoString {
+    fn eq(&self, other: &::alloc::string::String) -> bool {
+        self.as_str().eq(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialOrd<::alloc::string::String> for NanoString {
+    fn partial_cmp(
+        &self,
+        other: &::alloc::string::String,
+    ) -> Option<::core::cmp::Ordering> {
+        self.as_str().partial_cmp(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialEq<NanoString> for ::alloc::string::String {
+    fn eq(&self, other: &NanoString) -> bool {
+        ::core::convert::AsRef::<str>::as_ref(self).eq(other.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialOrd<NanoString> for ::alloc::string::String {
+    fn partial_cmp(&self, other: &NanoString) -> Option<::core::cmp::Ordering> {
+        ::core::convert::AsRef::<str>::as_ref(self).partial_cmp(other.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialEq<::alloc::borrow::Cow<'_, str>> for NanoString {
+    fn eq(&self, other: &::alloc::borrow::Cow<'_, str>) -> bool {
+        self.as_str().eq(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialOrd<::alloc::borrow::Cow<'_, str>> for NanoString {
+    fn partial_cmp(
+        &self,
+        other: &::alloc::borrow::Cow<'_, str>,
+    ) -> Option<::core::cmp::Ordering> {
+        self.as_str().partial_cmp(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialEq<NanoString> for ::alloc::borrow::Cow<'_, str> {
+    fn eq(&self, other: &NanoString) -> bool {
+        ::core::convert::AsRef::<str>::as_ref(self).eq(other.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialOrd<NanoString> for ::alloc::borrow::Cow<'_, str> {
+    fn partial_cmp(&self, other: &NanoString) -> Option<::core::cmp::Ordering> {
+        ::core::convert::AsRef::<str>::as_ref(self).partial_cmp(other.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialEq<::alloc::boxed::Box<str>> for NanoString {
+    fn eq(&self, other: &::alloc::boxed::Box<str>) -> bool {
+        self.as_str().eq(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialOrd<::alloc::boxed::Box<str>> for NanoString {
+    fn partial_cmp(
+        &self,
+        other: &::alloc::boxed::Box<str>,
+    ) -> Option<::core::cmp::Ordering> {
+        self.as_str().partial_cmp(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialEq<NanoString> for ::alloc::boxed::Box<str> {
+    fn eq(&self, other: &NanoString) -> bool {
+        ::core::convert::AsRef::<str>::as_ref(self).eq(other.as_str())
     }
 }
-impl<T: ::core::convert::AsRef<str>> ::core::cmp::PartialOrd<T> for NanoString {
-    fn partial_cmp(&self, other: &T) -> Option<::core::cmp::Ordering> {
-        self.as_str().partial_cmp(other.as_ref())
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialOrd<NanoString> for ::alloc::boxed::Box<str> {
+    fn partial_cmp(&self, other: &NanoString) -> Option<::core::cmp::Ordering> {
+        ::core::convert::AsRef::<str>::as_ref(self).partial_cmp(other.as_str())
     }
 }
 impl ::core::fmt::Debug for NanoString {
@@ -192,10 +1135,76 @@ impl ::core::fmt::Display for NanoString {
         self.as_str().fmt(f)
     }
 }
+/**Writes which would overflow the capacity return [`fmt::Error`](::core::fmt::Error).
+
+On error, the string is restored to its state before the call to
+[`write!`](::core::write).
+```
+# use microstring::*;
+use core::fmt::Write as _;
+let mut string = NanoString::EMPTY;
+assert!(write!(string, "{}{}", "GBP", "GEEBEEPEE").is_err());
+assert_eq!(string, "");
+```
+*/
+impl ::core::fmt::Write for NanoString {
+    fn write_str(&mut self, s: &str) -> ::core::fmt::Result {
+        self.push_str(s).map_err(|_| ::core::fmt::Error)
+    }
+    fn write_char(&mut self, c: char) -> ::core::fmt::Result {
+        self.push(c).map_err(|_| ::core::fmt::Error)
+    }
+    fn write_fmt(&mut self, args: ::core::fmt::Arguments<'_>) -> ::core::fmt::Result {
+        let before = *self;
+        ::core::fmt::write(self, args).inspect_err(|_| *self = before)
+    }
+}
+impl NanoString {
+    /**Creates a string from the given [`Arguments`](::core::fmt::Arguments).
+
+See also [`nano_format!`](crate::nano_format).
+*/
+    pub fn try_from_fmt(
+        args: ::core::fmt::Arguments<'_>,
+    ) -> Result<Self, NanoStringError> {
+        struct Len(usize);
+        impl ::core::fmt::Write for Len {
+            fn write_str(&mut self, s: &str) -> ::core::fmt::Result {
+                self.0 += s.len();
+                Ok(())
+            }
+        }
+        let mut s = Self::EMPTY;
+        match ::core::fmt::Write::write_fmt(&mut s, args) {
+            Ok(()) => Ok(s),
+            Err(_) => {
+                let mut len = Len(0);
+                let _ = ::core::fmt::Write::write_fmt(&mut len, args);
+                Err(NanoStringError::new(crate::ErrorKind::TooLong, len.0))
+            }
+        }
+    }
+}
+/**Creates a [`NanoString`] using interpolation of runtime expressions,
+like [`format!`](https://doc.rust-lang.org/std/macro.format.html).
+
+Returns an error if the result would be longer than 3 bytes.
+```
+# use microstring::*;
+assert_eq!(nano_format!("{}", "GBP").unwrap(), "GBP");
+assert!(nano_format!("{}", "GEEBEEPEE").is_err());
+```
+*/
+#[macro_export]
+macro_rules! nano_format {
+    ($($arg:tt)*) => {
+        $crate::NanoString::try_from_fmt(::core::format_args!($($arg)*))
+    };
+}
 impl ::core::str::FromStr for NanoString {
     type Err = NanoStringError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Self::new(s).ok_or(NanoStringError)
+        Self::new(s).ok_or(NanoStringError::new(crate::ErrorKind::TooLong, s.len()))
     }
 }
 #[cfg(feature = "serde")]
@@ -218,10 +1227,7 @@ impl<'de> serde::Deserialize<'de> for NanoString {
             }
             fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<NanoString, E> {
                 NanoString::new(v)
-                    .ok_or_else(|| serde::de::Error::invalid_value(
-                        serde::de::Unexpected::Str(v),
-                        &self,
-                    ))
+                    .ok_or_else(|| serde::de::Error::invalid_length(v.len(), &self))
             }
         }
         d.deserialize_str(Visitor)
@@ -243,15 +1249,413 @@ impl schemars::JsonSchema for NanoString {
         )
     }
 }
+/**Generates strings of every length up to 3 bytes, including multi-byte [`char`]s.
+```
+# use microstring::*;
+use arbitrary::{Arbitrary, Unstructured};
+
+// an empty input consumes nothing
+assert_eq!(NanoString::arbitrary(&mut Unstructured::new(&[])).unwrap(), "");
+let (min, max) = NanoString::size_hint(0);
+assert_eq!(min, 0);
+let max = max.unwrap();
+
+let mut lens = [false; 3 + 1];
+let mut multi_byte = false;
+for seed in 0..=u8::MAX {
+    // the first byte chooses the length
+    let mut state = u32::from(seed) << 8 | 1;
+    let data = [seed].into_iter().chain((1..max).map(|_| {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        state as u8
+    })).collect::<Vec<_>>();
+    let mut u = Unstructured::new(&data);
+    let s = NanoString::arbitrary(&mut u).unwrap();
+    assert!(min <= data.len() - u.len() && data.len() - u.len() <= max);
+    assert!(str::from_utf8(s.as_bytes()).is_ok());
+    assert_eq!(s.to_bits(), NanoString::new(&s).unwrap().to_bits());
+    lens[s.len()] = true;
+    multi_byte |= s.chars().any(|c| c.len_utf8() > 1);
+}
+assert!(lens.iter().all(|it| *it) && multi_byte);
+```
+*/
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for NanoString {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let len = u.int_in_range(0..=3u8)? as usize;
+        let mut it = Self::EMPTY;
+        while (it.len as u8 as usize) < len {
+            let remaining = len - it.len as u8 as usize;
+            let width = u.int_in_range(1..=remaining.min(4))?;
+            let _ = it.push(crate::__private::arbitrary_char(u, width)?);
+        }
+        Ok(it)
+    }
+    fn size_hint(_: usize) -> (usize, Option<usize>) {
+        (0, Some(1 + 2 * 3u8 as usize))
+    }
+}
+impl crate::Lookup<NanoString> for NanoString {
+    fn lookup(&self) -> Option<NanoString> {
+        Some(*self)
+    }
+}
+impl crate::Lookup<NanoString> for str {
+    fn lookup(&self) -> Option<NanoString> {
+        NanoString::new(self)
+    }
+}
+impl crate::Lookup<NanoString> for &str {
+    fn lookup(&self) -> Option<NanoString> {
+        NanoString::new(self)
+    }
+}
+#[cfg(feature = "alloc")]
+impl crate::Lookup<NanoString> for ::alloc::string::String {
+    fn lookup(&self) -> Option<NanoString> {
+        NanoString::new(self)
+    }
+}
+#[cfg(feature = "alloc")]
+impl crate::Lookup<NanoString> for ::alloc::borrow::Cow<'_, str> {
+    fn lookup(&self) -> Option<NanoString> {
+        NanoString::new(self)
+    }
+}
+#[cfg(feature = "alloc")]
+impl crate::Lookup<NanoString> for ::alloc::boxed::Box<str> {
+    fn lookup(&self) -> Option<NanoString> {
+        NanoString::new(self)
+    }
+}
+/**A hash map keyed by [`NanoString`].
+
+Keys are stored and hashed as their packed [`to_bits`](NanoString::to_bits) representation,
+using [`BuildWordHasher`](crate::BuildWordHasher).
+Entries may also be looked up by [`str`] and [`String`](std::string::String).
+```
+# use microstring::*;
+let mut map = NanoMap::new();
+map.insert(NanoString::new("GBP").unwrap(), 1);
+assert_eq!(map.get("GBP"), Some(&1));
+assert_eq!(map.get(&NanoString::new("GBP").unwrap()), Some(&1));
+assert_eq!(map.get(&String::from("GBP")), Some(&1));
+assert_eq!(map.get("GEEBEEPEE"), None);
+
+*map.entry(NanoString::new("GBP").unwrap()).or_default() += 1;
+assert_eq!(map.into_iter().collect::<Vec<_>>(), [(NanoString::new("GBP").unwrap(), 2)]);
+```
+*/
+#[cfg(feature = "std")]
+#[derive(Clone)]
+pub struct NanoMap<V> {
+    inner: ::std::collections::HashMap<u32, V, crate::BuildWordHasher>,
+}
+#[cfg(feature = "std")]
+impl<V> NanoMap<V> {
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            inner: ::std::collections::HashMap::with_capacity_and_hasher(
+                capacity,
+                crate::BuildWordHasher,
+            ),
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+    pub fn clear(&mut self) {
+        self.inner.clear()
+    }
+    pub fn insert(&mut self, key: NanoString, value: V) -> Option<V> {
+        self.inner.insert(key.to_bits(), value)
+    }
+    /// Gets the entry for the given key, to insert or update it with a single probe.
+    pub fn entry(&mut self, key: NanoString) -> crate::Entry<'_, NanoString, V> {
+        crate::Entry::__new(&mut self.inner, key)
+    }
+    pub fn get<Q: ?Sized + crate::Lookup<NanoString>>(&self, key: &Q) -> Option<&V> {
+        self.inner.get(&key.lookup()?.to_bits())
+    }
+    pub fn get_mut<Q: ?Sized + crate::Lookup<NanoString>>(
+        &mut self,
+        key: &Q,
+    ) -> Option<&mut V> {
+        self.inner.get_mut(&key.lookup()?.to_bits())
+    }
+    pub fn contains_key<Q: ?Sized + crate::Lookup<NanoString>>(&self, key: &Q) -> bool {
+        self.get(key).is_some()
+    }
+    pub fn remove<Q: ?Sized + crate::Lookup<NanoString>>(
+        &mut self,
+        key: &Q,
+    ) -> Option<V> {
+        self.inner.remove(&key.lookup()?.to_bits())
+    }
+    pub fn iter(&self) -> impl Iterator<Item = (NanoString, &V)> {
+        self.inner
+            .iter()
+            .map(|(k, v)| (unsafe { NanoString::from_bits_unchecked(*k) }, v))
+    }
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (NanoString, &mut V)> {
+        self.inner
+            .iter_mut()
+            .map(|(k, v)| (unsafe { NanoString::from_bits_unchecked(*k) }, v))
+    }
+    pub fn keys(&self) -> impl Iterator<Item = NanoString> {
+        self.inner.keys().map(|k| unsafe { NanoString::from_bits_unchecked(*k) })
+    }
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.inner.values()
+    }
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut V> {
+        self.inner.values_mut()
+    }
+}
+#[cfg(feature = "std")]
+impl<V> ::core::default::Default for NanoMap<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+#[cfg(feature = "std")]
+impl<V: ::core::fmt::Debug> ::core::fmt::Debug for NanoMap<V> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+#[cfg(feature = "std")]
+impl<V: ::core::cmp::PartialEq> ::core::cmp::PartialEq for NanoMap<V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+#[cfg(feature = "std")]
+impl<V: ::core::cmp::Eq> ::core::cmp::Eq for NanoMap<V> {}
+#[cfg(feature = "std")]
+impl<V> ::core::iter::Extend<(NanoString, V)> for NanoMap<V> {
+    fn extend<I: IntoIterator<Item = (NanoString, V)>>(&mut self, iter: I) {
+        self.inner.extend(iter.into_iter().map(|(k, v)| (k.to_bits(), v)))
+    }
+}
+#[cfg(feature = "std")]
+impl<V> ::core::iter::IntoIterator for NanoMap<V> {
+    type Item = (NanoString, V);
+    type IntoIter = ::core::iter::Map<
+        ::std::collections::hash_map::IntoIter<u32, V>,
+        fn((u32, V)) -> (NanoString, V),
+    >;
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner
+            .into_iter()
+            .map(|(k, v)| (unsafe { NanoString::from_bits_unchecked(k) }, v))
+    }
+}
+#[cfg(feature = "std")]
+impl<'a, V> ::core::iter::IntoIterator for &'a NanoMap<V> {
+    type Item = (NanoString, &'a V);
+    type IntoIter = ::core::iter::Map<
+        ::std::collections::hash_map::Iter<'a, u32, V>,
+        fn((&'a u32, &'a V)) -> (NanoString, &'a V),
+    >;
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner
+            .iter()
+            .map(|(k, v)| (unsafe { NanoString::from_bits_unchecked(*k) }, v))
+    }
+}
+#[cfg(feature = "std")]
+impl<'a, V> ::core::iter::IntoIterator for &'a mut NanoMap<V> {
+    type Item = (NanoString, &'a mut V);
+    type IntoIter = ::core::iter::Map<
+        ::std::collections::hash_map::IterMut<'a, u32, V>,
+        fn((&'a u32, &'a mut V)) -> (NanoString, &'a mut V),
+    >;
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner
+            .iter_mut()
+            .map(|(k, v)| (unsafe { NanoString::from_bits_unchecked(*k) }, v))
+    }
+}
+#[cfg(feature = "std")]
+impl<V> ::core::iter::FromIterator<(NanoString, V)> for NanoMap<V> {
+    fn from_iter<I: IntoIterator<Item = (NanoString, V)>>(iter: I) -> Self {
+        let mut it = Self::new();
+        it.extend(iter);
+        it
+    }
+}
 #[cfg(feature = "const-default")]
 impl const_default::ConstDefault for NanoString {
     const DEFAULT: Self = NanoString::EMPTY;
 }
+/**A [`NanoString`] which can be shared between threads,
+backed by an [`::core::sync::atomic::AtomicU32`].
+```
+# use microstring::*;
+use core::sync::atomic::Ordering;
+let atomic = AtomicNanoString::new(NanoString::new("GBP").unwrap());
+assert_eq!(atomic.swap(NanoString::EMPTY, Ordering::Relaxed), "GBP");
+assert_eq!(atomic.load(Ordering::Relaxed), "");
+```
+*/
+#[cfg(target_has_atomic = "32")]
+#[repr(transparent)]
+pub struct AtomicNanoString(::core::sync::atomic::AtomicU32);
+#[cfg(target_has_atomic = "32")]
+impl AtomicNanoString {
+    pub const fn new(s: NanoString) -> Self {
+        Self(::core::sync::atomic::AtomicU32::new(s.to_ne_bits()))
+    }
+    pub const fn into_inner(self) -> NanoString {
+        unsafe { NanoString::from_ne_bits_unchecked(self.0.into_inner()) }
+    }
+    /**Returns a mutable reference to the string,
+which is safe because `self` is borrowed mutably.
+```
+# use microstring::*;
+use core::sync::atomic::Ordering;
+let mut atomic = AtomicNanoString::default();
+atomic.get_mut().push_str("GBP").unwrap();
+assert_eq!(atomic.load(Ordering::Relaxed), "GBP");
+```
+*/
+    pub fn get_mut(&mut self) -> &mut NanoString {
+        unsafe { &mut *(self.0.get_mut() as *mut _ as *mut NanoString) }
+    }
+    pub fn load(&self, order: ::core::sync::atomic::Ordering) -> NanoString {
+        unsafe { NanoString::from_ne_bits_unchecked(self.0.load(order)) }
+    }
+    pub fn store(&self, s: NanoString, order: ::core::sync::atomic::Ordering) {
+        self.0.store(s.to_ne_bits(), order)
+    }
+    pub fn swap(
+        &self,
+        s: NanoString,
+        order: ::core::sync::atomic::Ordering,
+    ) -> NanoString {
+        unsafe { NanoString::from_ne_bits_unchecked(self.0.swap(s.to_ne_bits(), order)) }
+    }
+    /**Stores `new` if the current string is `current`.
+
+Returns the previous string, which is `current` if and only if it was replaced.
+See [`::core::sync::atomic::AtomicU32::compare_exchange`] for the meaning of the orderings.
+```
+# use microstring::*;
+use core::sync::atomic::Ordering::Relaxed;
+let small = NanoString::new("GBP").unwrap();
+let atomic = AtomicNanoString::new(small);
+assert_eq!(atomic.compare_exchange(NanoString::EMPTY, small, Relaxed, Relaxed), Err(small));
+assert_eq!(atomic.compare_exchange(small, NanoString::EMPTY, Relaxed, Relaxed), Ok(small));
+assert_eq!(atomic.into_inner(), "");
+```
+*/
+    pub fn compare_exchange(
+        &self,
+        current: NanoString,
+        new: NanoString,
+        success: ::core::sync::atomic::Ordering,
+        failure: ::core::sync::atomic::Ordering,
+    ) -> Result<NanoString, NanoString> {
+        match self
+            .0
+            .compare_exchange(current.to_ne_bits(), new.to_ne_bits(), success, failure)
+        {
+            Ok(it) => Ok(unsafe { NanoString::from_ne_bits_unchecked(it) }),
+            Err(it) => Err(unsafe { NanoString::from_ne_bits_unchecked(it) }),
+        }
+    }
+    /**Like [`Self::compare_exchange`], but may spuriously fail even if the current string is `current`.
+
+See [`::core::sync::atomic::AtomicU32::compare_exchange_weak`].
+*/
+    pub fn compare_exchange_weak(
+        &self,
+        current: NanoString,
+        new: NanoString,
+        success: ::core::sync::atomic::Ordering,
+        failure: ::core::sync::atomic::Ordering,
+    ) -> Result<NanoString, NanoString> {
+        match self
+            .0
+            .compare_exchange_weak(
+                current.to_ne_bits(),
+                new.to_ne_bits(),
+                success,
+                failure,
+            )
+        {
+            Ok(it) => Ok(unsafe { NanoString::from_ne_bits_unchecked(it) }),
+            Err(it) => Err(unsafe { NanoString::from_ne_bits_unchecked(it) }),
+        }
+    }
+    /**Replaces the current string with the result of `f`, retrying if another thread changed it.
+
+Returns the previous string, or an error containing it if `f` returned [`None`].
+See [`::core::sync::atomic::AtomicU32::fetch_update`] for the meaning of the orderings.
+```
+# use microstring::*;
+use core::sync::atomic::Ordering::Relaxed;
+let atomic = AtomicNanoString::new(NanoString::new("a").unwrap());
+let push_b = |mut s: NanoString| s.push('b').ok().map(|()| s);
+assert_eq!(atomic.fetch_update(Relaxed, Relaxed, push_b), Ok(NanoString::new("a").unwrap()));
+assert_eq!(atomic.load(Relaxed), "ab");
+assert_eq!(atomic.fetch_update(Relaxed, Relaxed, |_| None), Err(NanoString::new("ab").unwrap()));
+```
+*/
+    pub fn fetch_update(
+        &self,
+        set_order: ::core::sync::atomic::Ordering,
+        fetch_order: ::core::sync::atomic::Ordering,
+        mut f: impl FnMut(NanoString) -> Option<NanoString>,
+    ) -> Result<NanoString, NanoString> {
+        let mut prev = self.load(fetch_order);
+        while let Some(next) = f(prev) {
+            match self.compare_exchange_weak(prev, next, set_order, fetch_order) {
+                Ok(it) => return Ok(it),
+                Err(it) => prev = it,
+            }
+        }
+        Err(prev)
+    }
+}
+#[cfg(target_has_atomic = "32")]
+impl ::core::default::Default for AtomicNanoString {
+    fn default() -> Self {
+        Self::new(NanoString::EMPTY)
+    }
+}
+#[cfg(target_has_atomic = "32")]
+impl ::core::convert::From<NanoString> for AtomicNanoString {
+    fn from(value: NanoString) -> Self {
+        Self::new(value)
+    }
+}
+#[cfg(target_has_atomic = "32")]
+impl ::core::fmt::Debug for AtomicNanoString {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.load(::core::sync::atomic::Ordering::Relaxed).fmt(f)
+    }
+}
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 #[repr(u8)]
 #[cfg_attr(
     feature = "zerocopy",
-    derive(zerocopy::TryFromBytes, zerocopy::IntoBytes, zerocopy::Immutable)
+    derive(
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout,
+        zerocopy::Unaligned
+    )
 )]
 enum NanoStringLen {
     _0 = 0u8,
@@ -278,6 +1682,14 @@ impl NanoStringLen {
     }
 }
 /**A stack-allocated string which can hold up to 7 UTF-8 encoded bytes.
+
+With the `zerocopy` feature, this implements `IntoBytes`, `Immutable`,
+`KnownLayout` and `Unaligned`.
+`TryFromBytes` is deliberately not implemented, because its derive cannot check
+that the bytes are UTF-8 followed by zero padding,
+and neither is `FromZeros`, which requires it.
+Use [`try_ref_from_bytes`](MicroString::try_ref_from_bytes)
+or [`try_slice_from_bytes`](MicroString::try_slice_from_bytes) instead.
 ```
 # use core::mem::size_of;
 # use microstring::*;
@@ -295,7 +1707,12 @@ assert_eq! {
 #[repr(C)]
 #[cfg_attr(
     feature = "zerocopy",
-    derive(zerocopy::TryFromBytes, zerocopy::IntoBytes, zerocopy::Immutable)
+    derive(
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout,
+        zerocopy::Unaligned
+    )
 )]
 pub struct MicroString {
     len: MicroStringLen,
@@ -349,25 +1766,733 @@ const TOO_BIG: MicroString = MicroString::new("12345678").unwrap();
             )
         }
     }
-}
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct MicroStringError;
-impl ::core::fmt::Display for MicroStringError {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        f.write_str("expected a string of at most 7 bytes")
-    }
-}
-impl ::core::error::Error for MicroStringError {}
-impl ::core::default::Default for MicroString {
-    fn default() -> Self {
-        Self::EMPTY
-    }
+    /**Returns an error if the given bytes are not UTF-8, or if <code>[slice::len()] > 7</code>.
+```
+# use microstring::*;
+const STRING: MicroString = match MicroString::from_utf8(b"1234567") {
+    Ok(it) => it,
+    Err(_) => panic!(),
+};
+assert_eq!(MicroString::from_utf8(b"\xFF").unwrap_err().kind(), ErrorKind::InvalidUtf8);
+assert_eq!(MicroString::from_utf8(b"12345678").unwrap_err().kind(), ErrorKind::TooLong);
+```
+*/
+    pub const fn from_utf8(bytes: &[u8]) -> Result<Self, MicroStringError> {
+        if bytes.len() > 7u8 as usize {
+            return Err(MicroStringError::new(crate::ErrorKind::TooLong, bytes.len()));
+        }
+        match ::core::str::from_utf8(bytes) {
+            Ok(s) => {
+                match Self::new(s) {
+                    Some(it) => Ok(it),
+                    None => {
+                        Err(
+                            MicroStringError::new(crate::ErrorKind::TooLong, bytes.len()),
+                        )
+                    }
+                }
+            }
+            Err(_) => {
+                Err(MicroStringError::new(crate::ErrorKind::InvalidUtf8, bytes.len()))
+            }
+        }
+    }
+    /**Returns [`None`] if <code>[slice::len()] > 7</code>.
+
+# Safety
+- The given bytes must be valid UTF-8.
+*/
+    pub const unsafe fn from_utf8_unchecked(bytes: &[u8]) -> Option<Self> {
+        Self::new(unsafe { str::from_utf8_unchecked(bytes) })
+    }
+    /**Truncates the given string to at most 7 bytes, on a [`char`] boundary.
+```
+# use microstring::*;
+const STRING: MicroString = MicroString::new_truncating("12345678");
+assert_eq!(STRING, "1234567");
+```
+*/
+    pub const fn new_truncating(s: &str) -> Self {
+        let mut end = if s.len() < 7u8 as usize { s.len() } else { 7u8 as usize };
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+        match Self::new(s.split_at(end).0) {
+            Some(it) => it,
+            None => unreachable!(),
+        }
+    }
+    /**If the given string is longer than 7 bytes, truncates it on a [`char`] boundary,
+leaving room to append the given `marker`.
+
+# Panics
+- If the string needs truncating, and <code>marker.[len()](str::len) > 7</code>.
+```
+# use microstring::*;
+const STRING: MicroString = MicroString::new_truncating_with_marker("12345678", "…");
+assert_eq!(STRING, "1234…");
+```
+*/
+    pub const fn new_truncating_with_marker(s: &str, marker: &str) -> Self {
+        if let Some(it) = Self::new(s) {
+            return it;
+        }
+        assert!(marker.len() <= 7u8 as usize, "marker is longer than the capacity");
+        let mut end = 7u8 as usize - marker.len();
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+        let mut it = Self::new_truncating(s.split_at(end).0);
+        match it.push_str(marker) {
+            Ok(()) => it,
+            Err(_) => unreachable!(),
+        }
+    }
+    /// Like [`new_truncating`](Self::new_truncating),
+    /// but never splits a grapheme cluster.
+    #[cfg(feature = "unicode-segmentation")]
+    pub fn new_truncating_graphemes(s: &str) -> Self {
+        use unicode_segmentation::UnicodeSegmentation as _;
+        let end = s
+            .grapheme_indices(true)
+            .map(|(ix, grapheme)| ix + grapheme.len())
+            .take_while(|end| *end <= 7u8 as usize)
+            .last()
+            .unwrap_or(0);
+        Self::new_truncating(&s[..end])
+    }
+}
+/**The error returned when a [`MicroString`] cannot be constructed.
+```
+# use microstring::*;
+let e = "12345678".parse::<MicroString>().unwrap_err();
+assert_eq!(e.kind(), ErrorKind::TooLong);
+assert_eq!(e.input_len(), 8);
+assert_eq!(e.to_string(), "expected a string of at most 7 bytes, but got 8 bytes");
+```
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MicroStringError {
+    kind: crate::ErrorKind,
+    len: usize,
+}
+impl MicroStringError {
+    const fn new(kind: crate::ErrorKind, len: usize) -> Self {
+        Self { kind, len }
+    }
+    /// Why the input was rejected.
+    pub const fn kind(&self) -> crate::ErrorKind {
+        self.kind
+    }
+    /// The length of the rejected input, in bytes.
+    ///
+    /// For operations which append to an existing string,
+    /// this is the length the string would have had.
+    pub const fn input_len(&self) -> usize {
+        self.len
+    }
+    /// The maximum length of the string being constructed, in bytes.
+    pub const fn capacity(&self) -> usize {
+        7u8 as usize
+    }
+}
+impl ::core::fmt::Display for MicroStringError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let Self { kind, len } = *self;
+        match kind {
+            crate::ErrorKind::TooLong => {
+                write!(f, "expected a string of at most 7 bytes, but got {len} bytes")
+            }
+            crate::ErrorKind::InvalidUtf8 => {
+                write!(
+                    f,
+                    "expected a UTF-8 string of at most 7 bytes, but got {len} bytes of invalid UTF-8"
+                )
+            }
+            crate::ErrorKind::Invalid => {
+                write!(f, "a string of {len} bytes failed validation")
+            }
+        }
+    }
+}
+impl ::core::error::Error for MicroStringError {}
+impl MicroString {
+    /// The maximum length, in bytes.
+    pub const CAPACITY: usize = 7u8 as usize;
+    /// The length of this string, in bytes.
+    pub const fn len(&self) -> usize {
+        self.len as u8 as usize
+    }
+    /// Returns `true` if this string has a length of zero.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// The number of bytes which may be pushed before this string is full.
+    pub const fn remaining_capacity(&self) -> usize {
+        Self::CAPACITY - self.len()
+    }
+    /// Equivalent to [`str::as_bytes`], but usable in `const`.
+    ///
+    /// Unlike `as_bytes`, this does not shadow
+    /// [`zerocopy::IntoBytes::as_bytes`](https://docs.rs/zerocopy/latest/zerocopy/trait.IntoBytes.html#method.as_bytes),
+    /// which returns the length as well.
+    pub const fn const_as_bytes(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+    /// Equivalent to `==`, but usable in `const`.
+    pub const fn const_eq(&self, other: &str) -> bool {
+        self.len() == other.len() && self.const_cmp(other).is_eq()
+    }
+    /// Equivalent to [`Ord::cmp`], but usable in `const`.
+    pub const fn const_cmp(&self, other: &str) -> ::core::cmp::Ordering {
+        crate::__private::cmp_bytes(self.const_as_bytes(), other.as_bytes())
+    }
+    /// Equivalent to [`str::starts_with`] with a [`str`] pattern, but usable in `const`.
+    pub const fn const_starts_with(&self, prefix: &str) -> bool {
+        match prefix.len() <= self.len() {
+            true => {
+                let (head, _) = self.const_as_bytes().split_at(prefix.len());
+                crate::__private::cmp_bytes(head, prefix.as_bytes()).is_eq()
+            }
+            false => false,
+        }
+    }
+    /// Equivalent to [`str::ends_with`] with a [`str`] pattern, but usable in `const`.
+    pub const fn const_ends_with(&self, suffix: &str) -> bool {
+        match self.len().checked_sub(suffix.len()) {
+            Some(mid) => {
+                let (_, tail) = self.const_as_bytes().split_at(mid);
+                crate::__private::cmp_bytes(tail, suffix.as_bytes()).is_eq()
+            }
+            None => false,
+        }
+    }
+    /// Converts this string to its ASCII upper case equivalent in-place.
+    pub const fn make_ascii_uppercase(&mut self) {
+        self.as_mut_str().make_ascii_uppercase()
+    }
+    /// Converts this string to its ASCII lower case equivalent in-place.
+    pub const fn make_ascii_lowercase(&mut self) {
+        self.as_mut_str().make_ascii_lowercase()
+    }
+    /**Returns a copy of this string, with ASCII letters converted to upper case.
+
+Other bytes are unchanged.
+```
+# use microstring::*;
+const UPPER: MicroString = MicroString::new("1234567").unwrap().to_ascii_uppercase();
+assert_eq!(UPPER, "1234567");
+for s in ["@AZ[`az", "{é"] {
+    assert_eq!(MicroString::new(s).unwrap().to_ascii_uppercase(), s.to_ascii_uppercase().as_str());
+}
+```
+*/
+    pub const fn to_ascii_uppercase(self) -> Self {
+        let bits = self.to_ne_bits();
+        unsafe {
+            Self::from_ne_bits_unchecked(bits ^ Self::ascii_case_mask(bits, b'a', b'z'))
+        }
+    }
+    /**Returns a copy of this string, with ASCII letters converted to lower case.
+
+Other bytes are unchanged.
+```
+# use microstring::*;
+const LOWER: MicroString = MicroString::new("1234567").unwrap().to_ascii_lowercase();
+assert_eq!(LOWER, "1234567");
+for s in ["@AZ[`az", "{é"] {
+    assert_eq!(MicroString::new(s).unwrap().to_ascii_lowercase(), s.to_ascii_lowercase().as_str());
+}
+```
+*/
+    pub const fn to_ascii_lowercase(self) -> Self {
+        let bits = self.to_ne_bits();
+        unsafe {
+            Self::from_ne_bits_unchecked(bits ^ Self::ascii_case_mask(bits, b'A', b'Z'))
+        }
+    }
+    /// Returns `0x20` in each byte of `bits` which is in `lo..=hi`, and zero elsewhere,
+    /// so that XOR-ing with it switches the case of those ASCII letters.
+    const fn ascii_case_mask(bits: u64, lo: u8, hi: u8) -> u64 {
+        const ONES: u64 = u64::MAX / 0xFF;
+        const HIGH: u64 = ONES * 0x80;
+        let low = bits & !HIGH;
+        let ge_lo = low + ONES * (0x80 - lo as u64);
+        let gt_hi = low + ONES * (0x7F - hi as u64);
+        ((ge_lo ^ gt_hi) & !bits & HIGH) >> 2
+    }
+}
+impl MicroString {
+    /// Appends the given [`char`] to the end of this string.
+    ///
+    /// Returns an error, leaving this string unchanged, if there is not enough capacity.
+    pub const fn push(&mut self, c: char) -> Result<(), MicroStringError> {
+        self.push_str(c.encode_utf8(&mut [0; 4]))
+    }
+    /**Appends the given string to the end of this one.
+
+Returns an error, leaving this string unchanged, if the result would be
+longer than 7 bytes.
+```
+# use microstring::*;
+let mut string = MicroString::new("1234567").unwrap();
+assert!(string.push_str("12345678").is_err());
+assert_eq!(string, "1234567");
+```
+*/
+    pub const fn push_str(&mut self, s: &str) -> Result<(), MicroStringError> {
+        let len = self.len as u8 as usize;
+        match MicroStringLen::from_usize(len + s.len()) {
+            Some(new_len) => {
+                unsafe {
+                    ::core::ptr::copy_nonoverlapping(
+                        s.as_ptr(),
+                        self.bytes.as_mut_ptr().add(len),
+                        s.len(),
+                    )
+                }
+                self.len = new_len;
+                Ok(())
+            }
+            None => Err(MicroStringError::new(crate::ErrorKind::TooLong, len + s.len())),
+        }
+    }
+    /**Concatenates the given strings.
+
+Returns an error if the result would be longer than 7 bytes.
+```
+# use microstring::*;
+assert_eq!(MicroString::try_concat("1234567", "").unwrap(), "1234567");
+assert!(MicroString::try_concat("1234567", "12345678").is_err());
+```
+*/
+    pub const fn try_concat(left: &str, right: &str) -> Result<Self, MicroStringError> {
+        match Self::new(left) {
+            Some(mut it) => {
+                match it.push_str(right) {
+                    Ok(()) => Ok(it),
+                    Err(e) => Err(e),
+                }
+            }
+            None => {
+                Err(
+                    MicroStringError::new(
+                        crate::ErrorKind::TooLong,
+                        left.len() + right.len(),
+                    ),
+                )
+            }
+        }
+    }
+    /**Removes the last [`char`] from this string and returns it,
+or [`None`] if this string is empty.
+```
+# use microstring::*;
+let mut string = MicroString::new("aé").unwrap();
+assert_eq!(string.pop(), Some('é'));
+assert_eq!(string.to_bits(), MicroString::new("a").unwrap().to_bits());
+assert_eq!(string.pop(), Some('a'));
+assert_eq!(string.pop(), None);
+assert_eq!(string.to_bits(), MicroString::EMPTY.to_bits());
+```
+*/
+    pub fn pop(&mut self) -> Option<char> {
+        let c = self.as_str().chars().next_back()?;
+        unsafe { self.set_len(self.len as u8 as usize - c.len_utf8()) }
+        Some(c)
+    }
+    /**Shortens this string to `new_len` bytes.
+
+Has no effect if `new_len` is greater than the string's current length.
+
+# Panics
+- If `new_len` does not lie on a [`char`] boundary.
+```
+# use microstring::*;
+let mut string = MicroString::new("aé").unwrap();
+string.truncate(3);
+assert_eq!(string, "aé");
+string.truncate(1);
+assert_eq!(string.to_bits(), MicroString::new("a").unwrap().to_bits());
+```
+```should_panic
+# use microstring::*;
+MicroString::new("é").unwrap().truncate(1);
+```
+*/
+    pub const fn truncate(&mut self, new_len: usize) {
+        if new_len < self.len as u8 as usize {
+            assert!(
+                self.as_str().is_char_boundary(new_len),
+                "new_len does not lie on a char boundary"
+            );
+            unsafe { self.set_len(new_len) }
+        }
+    }
+    /**Truncates this string to the empty string.
+```
+# use microstring::*;
+let mut string = MicroString::new("1234567").unwrap();
+string.clear();
+assert_eq!(string.to_bits(), MicroString::EMPTY.to_bits());
+```
+*/
+    pub const fn clear(&mut self) {
+        unsafe { self.set_len(0) }
+    }
+    /// Inserts the given [`char`] at byte position `idx`.
+    ///
+    /// Returns an error, leaving this string unchanged, if there is not enough capacity.
+    ///
+    /// # Panics
+    /// - If `idx` is out of bounds, or does not lie on a [`char`] boundary.
+    pub const fn insert(&mut self, idx: usize, c: char) -> Result<(), MicroStringError> {
+        self.insert_str(idx, c.encode_utf8(&mut [0; 4]))
+    }
+    /**Inserts the given string at byte position `idx`.
+
+Returns an error, leaving this string unchanged, if there is not enough capacity.
+
+# Panics
+- If `idx` is out of bounds, or does not lie on a [`char`] boundary.
+```
+# use microstring::*;
+let mut string = MicroString::new("a").unwrap();
+string.insert_str(0, "é").unwrap();
+assert_eq!(string, "éa");
+assert!(string.insert_str(2, "12345678").is_err());
+assert_eq!(string.to_bits(), MicroString::new("éa").unwrap().to_bits());
+```
+```should_panic
+# use microstring::*;
+let _ = MicroString::new("é").unwrap().insert_str(1, "a");
+```
+*/
+    pub const fn insert_str(
+        &mut self,
+        idx: usize,
+        s: &str,
+    ) -> Result<(), MicroStringError> {
+        assert!(
+            self.as_str().is_char_boundary(idx), "idx does not lie on a char boundary"
+        );
+        let len = self.len as u8 as usize;
+        match MicroStringLen::from_usize(len + s.len()) {
+            Some(new_len) => {
+                unsafe {
+                    let p = self.bytes.as_mut_ptr();
+                    ::core::ptr::copy(p.add(idx), p.add(idx + s.len()), len - idx);
+                    ::core::ptr::copy_nonoverlapping(s.as_ptr(), p.add(idx), s.len());
+                }
+                self.len = new_len;
+                Ok(())
+            }
+            None => Err(MicroStringError::new(crate::ErrorKind::TooLong, len + s.len())),
+        }
+    }
+    /**Removes the [`char`] at byte position `idx` and returns it.
+
+# Panics
+- If `idx` is out of bounds, or does not lie on a [`char`] boundary.
+```
+# use microstring::*;
+let mut string = MicroString::new("éa").unwrap();
+assert_eq!(string.remove(0), 'é');
+assert_eq!(string.to_bits(), MicroString::new("a").unwrap().to_bits());
+```
+```should_panic
+# use microstring::*;
+MicroString::new("é").unwrap().remove(1);
+```
+*/
+    pub fn remove(&mut self, idx: usize) -> char {
+        let Some(c) = self.as_str()[idx..].chars().next() else {
+            panic!("cannot remove a char from the end of a string")
+        };
+        let len = self.len as u8 as usize;
+        let next = idx + c.len_utf8();
+        unsafe {
+            let p = self.bytes.as_mut_ptr();
+            ::core::ptr::copy(p.add(next), p.add(idx), len - next);
+            self.set_len(len - c.len_utf8());
+        }
+        c
+    }
+    /**Retains only the [`char`]s for which `f` returns `true`.
+```
+# use microstring::*;
+let mut string = MicroString::new("éa").unwrap();
+string.retain(|c| c.is_ascii());
+assert_eq!(string.to_bits(), MicroString::new("a").unwrap().to_bits());
+```
+*/
+    pub fn retain(&mut self, mut f: impl FnMut(char) -> bool) {
+        let this = *self;
+        self.clear();
+        for c in this.as_str().chars() {
+            if f(c) {
+                let _ = self.push(c);
+            }
+        }
+    }
+    /// Sets the length, zeroing any bytes past it.
+    ///
+    /// # Safety
+    /// - `new_len` must be at most the current length.
+    /// - `new_len` must lie on a [`char`] boundary.
+    const unsafe fn set_len(&mut self, new_len: usize) {
+        let len = self.len as u8 as usize;
+        unsafe {
+            ::core::ptr::write_bytes(
+                self.bytes.as_mut_ptr().add(new_len),
+                0,
+                len - new_len,
+            )
+        }
+        self.len = match MicroStringLen::from_usize(new_len) {
+            Some(it) => it,
+            None => unreachable!(),
+        };
+    }
+}
+impl MicroString {
+    /**Collects the given [`char`]s into a string.
+
+Returns an error if there are more than 7 bytes of them.
+See also [`Collected`](crate::Collected).
+```
+# use microstring::*;
+assert_eq!(MicroString::try_from_iter("1234567".chars()).unwrap(), "1234567");
+assert!(MicroString::try_from_iter("12345678".chars()).is_err());
+```
+*/
+    pub fn try_from_iter<I: IntoIterator<Item = char>>(
+        iter: I,
+    ) -> Result<Self, MicroStringError> {
+        let mut it = Self::EMPTY;
+        for c in iter {
+            it.push(c)?;
+        }
+        Ok(it)
+    }
+    /**Returns an owning iterator over the bytes of this string.
+```
+# use microstring::*;
+let string = MicroString::new("1234567").unwrap();
+assert!(string.into_bytes().eq("1234567".bytes()));
+assert_eq!(string.into_bytes().len(), 7);
+```
+*/
+    pub fn into_bytes(self) -> crate::IntoBytes<Self> {
+        crate::IntoBytes::new(self)
+    }
+}
+/**An owning iterator over the [`char`]s of this string.
+```
+# use microstring::*;
+let string = MicroString::new("1234567").unwrap();
+assert!(string.into_iter().rev().eq("1234567".chars().rev()));
+```
+*/
+impl ::core::iter::IntoIterator for MicroString {
+    type Item = char;
+    type IntoIter = crate::IntoChars<Self>;
+    fn into_iter(self) -> crate::IntoChars<Self> {
+        crate::IntoChars::new(self)
+    }
+}
+/// # Panics
+/// - If there is not enough capacity.
+///   See [`push`](Self::push) for a fallible alternative.
+impl ::core::iter::Extend<char> for MicroString {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        for c in iter {
+            if let Err(e) = self.push(c) {
+                panic!("{e}")
+            }
+        }
+    }
+}
+/// # Panics
+/// - If there is not enough capacity.
+///   See [`push_str`](Self::push_str) for a fallible alternative.
+impl<'a> ::core::iter::Extend<&'a str> for MicroString {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        for s in iter {
+            if let Err(e) = self.push_str(s) {
+                panic!("{e}")
+            }
+        }
+    }
+}
+impl MicroString {
+    /**Interprets the given bytes as a string, without copying.
+
+The bytes must be laid out as produced by `zerocopy::IntoBytes`:
+a length byte, followed by that many bytes of UTF-8, followed by zero padding.
+Returns [`None`] otherwise.
+
+This is the checked alternative to `zerocopy::TryFromBytes`,
+which is not implemented for this type because it cannot check the contents.
+```
+# use microstring::*;
+let mut bytes = [0; 7 + 1];
+bytes[0] = 7;
+bytes[1..][..7].copy_from_slice(b"1234567");
+assert_eq!(MicroString::try_ref_from_bytes(&bytes).unwrap().as_str(), "1234567");
+bytes[1] = 0xFF;
+assert_eq!(MicroString::try_ref_from_bytes(&bytes), None);
+```
+*/
+    pub const fn try_ref_from_bytes(bytes: &[u8]) -> Option<&Self> {
+        const { assert!(::core::mem::align_of:: < Self > () == 1) }
+        match Self::is_valid(bytes) {
+            true => Some(unsafe { &*bytes.as_ptr().cast::<Self>() }),
+            false => None,
+        }
+    }
+    /// Like [`try_ref_from_bytes`](Self::try_ref_from_bytes),
+    /// but for a contiguous run of strings.
+    pub const fn try_slice_from_bytes(bytes: &[u8]) -> Option<&[Self]> {
+        const { assert!(::core::mem::align_of:: < Self > () == 1) }
+        let size = ::core::mem::size_of::<Self>();
+        if !bytes.len().is_multiple_of(size) {
+            return None;
+        }
+        let mut rest = bytes;
+        while let Some((chunk, tail)) = rest.split_at_checked(size) {
+            if !Self::is_valid(chunk) {
+                return None;
+            }
+            rest = tail;
+        }
+        Some(unsafe {
+            ::core::slice::from_raw_parts(
+                bytes.as_ptr().cast::<Self>(),
+                bytes.len() / size,
+            )
+        })
+    }
+    /// Whether the given bytes are a length byte, that many bytes of UTF-8, and zero padding.
+    const fn is_valid(bytes: &[u8]) -> bool {
+        match bytes {
+            [len, rest @ ..] if rest.len() == 7u8 as usize => {
+                Self::is_valid_parts(*len, rest)
+            }
+            _ => false,
+        }
+    }
+    /// Whether `len` is in range, and `bytes` are that many bytes of UTF-8, followed by zero padding.
+    const fn is_valid_parts(len: u8, bytes: &[u8]) -> bool {
+        let Some(len) = MicroStringLen::from_u8(len) else {
+            return false;
+        };
+        let (string, mut padding) = bytes.split_at(len as u8 as usize);
+        if ::core::str::from_utf8(string).is_err() {
+            return false;
+        }
+        while let [0, tail @ ..] = padding {
+            padding = tail;
+        }
+        padding.is_empty()
+    }
+}
+impl MicroString {
+    /**Packs this string into a [`u64`].
+
+The string's bytes, zero padded to 7 bytes, come first,
+and its length is the last byte.
+This is stable, so may be persisted.
+Comparing the packed representations orders them the same as comparing the strings.
+```
+# use microstring::*;
+const BITS: u64 = MicroString::new("1234567").unwrap().to_bits();
+assert_eq!(BITS, u64::from_be_bytes(*b"1234567\x07"));
+assert_eq!(MicroString::from_bits(BITS), MicroString::new("1234567"));
+```
+*/
+    pub const fn to_bits(self) -> u64 {
+        u64::from_be(self.to_ne_bits()).rotate_left(8)
+    }
+    /// Like [`to_bits`](Self::to_bits), but in native byte order,
+    /// so only suitable for equality.
+    const fn to_ne_bits(self) -> u64 {
+        unsafe { ::core::mem::transmute::<Self, u64>(self) }
+    }
+    /// The inverse of [`to_ne_bits`](Self::to_ne_bits).
+    ///
+    /// # Safety
+    /// - `bits` must have been returned by [`to_ne_bits`](Self::to_ne_bits).
+    const unsafe fn from_ne_bits_unchecked(bits: u64) -> Self {
+        unsafe { ::core::mem::transmute::<u64, Self>(bits) }
+    }
+    /// The inverse of [`to_bits`](Self::to_bits).
+    ///
+    /// Returns [`None`] if the length is out of range,
+    /// the string is not UTF-8, or the padding is not zero.
+    pub const fn from_bits(bits: u64) -> Option<Self> {
+        let [bytes @ .., len] = bits.to_be_bytes();
+        match Self::is_valid_parts(len, &bytes) {
+            true => Some(unsafe { Self::from_bits_unchecked(bits) }),
+            false => None,
+        }
+    }
+    /// The inverse of [`to_bits`](Self::to_bits), without any checks.
+    ///
+    /// # Safety
+    /// - `bits` must have been returned by [`to_bits`](Self::to_bits),
+    ///   or be accepted by [`from_bits`](Self::from_bits).
+    pub const unsafe fn from_bits_unchecked(bits: u64) -> Self {
+        unsafe { ::core::mem::transmute::<u64, Self>(bits.rotate_right(8).to_be()) }
+    }
+}
+impl crate::__private::Sealed for MicroString {}
+impl crate::InlineStr for MicroString {
+    const CAPACITY: usize = 7u8 as usize;
+    const EMPTY: Self = Self::EMPTY;
+    type Error = MicroStringError;
+    type Bits = u64;
+    fn new(s: &str) -> Option<Self> {
+        Self::new(s)
+    }
+    fn as_str(&self) -> &str {
+        self.as_str()
+    }
+    fn as_mut_str(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+    fn from_utf8(bytes: &[u8]) -> Result<Self, MicroStringError> {
+        Self::from_utf8(bytes)
+    }
+    fn to_bits(self) -> u64 {
+        self.to_bits()
+    }
+    fn from_bits(bits: u64) -> Option<Self> {
+        Self::from_bits(bits)
+    }
+    fn push_str(&mut self, s: &str) -> Result<(), MicroStringError> {
+        self.push_str(s)
+    }
+    fn try_from_iter<I: IntoIterator<Item = char>>(
+        iter: I,
+    ) -> Result<Self, MicroStringError> {
+        Self::try_from_iter(iter)
+    }
+    fn __error(kind: crate::ErrorKind, len: usize) -> MicroStringError {
+        MicroStringError::new(kind, len)
+    }
+}
+impl ::core::default::Default for MicroString {
+    fn default() -> Self {
+        Self::EMPTY
+    }
 }
 impl ::core::default::Default for &MicroString {
     fn default() -> Self {
         &MicroString::EMPTY
     }
 }
+/// Hashes the same as [`str`], as required by [`Borrow<str>`](::core::borrow::Borrow).
 impl ::core::hash::Hash for MicroString {
     fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
         self.as_str().hash(state)
@@ -388,484 +2513,11737 @@ impl ::core::convert::AsRef<str> for MicroString {
         self.as_str()
     }
 }
-impl ::core::convert::AsMut<str> for MicroString {
-    fn as_mut(&mut self) -> &mut str {
-        self.as_mut_str()
+impl ::core::convert::AsMut<str> for MicroString {
+    fn as_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+impl ::core::convert::AsRef<[u8]> for MicroString {
+    fn as_ref(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+}
+impl TryFrom<&str> for MicroString {
+    type Error = MicroStringError;
+    fn try_from(value: &str) -> Result<Self, MicroStringError> {
+        Self::new(value)
+            .ok_or(MicroStringError::new(crate::ErrorKind::TooLong, value.len()))
+    }
+}
+impl TryFrom<&[u8]> for MicroString {
+    type Error = MicroStringError;
+    fn try_from(value: &[u8]) -> Result<Self, MicroStringError> {
+        Self::from_utf8(value)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for MicroString {
+    type Error = MicroStringError;
+    fn try_from(value: [u8; N]) -> Result<Self, MicroStringError> {
+        Self::from_utf8(&value)
+    }
+}
+#[cfg(feature = "std")]
+impl ::core::convert::AsRef<::std::ffi::OsStr> for MicroString {
+    fn as_ref(&self) -> &::std::ffi::OsStr {
+        self.as_str().as_ref()
+    }
+}
+#[cfg(feature = "std")]
+impl ::core::convert::AsRef<::std::path::Path> for MicroString {
+    fn as_ref(&self) -> &::std::path::Path {
+        self.as_str().as_ref()
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::TryFrom<::alloc::string::String> for MicroString {
+    type Error = MicroStringError;
+    fn try_from(value: ::alloc::string::String) -> Result<Self, MicroStringError> {
+        Self::new(&value)
+            .ok_or(MicroStringError::new(crate::ErrorKind::TooLong, value.len()))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::From<MicroString> for ::alloc::string::String {
+    fn from(val: MicroString) -> Self {
+        Self::from(val.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::From<MicroString> for ::alloc::boxed::Box<str> {
+    fn from(val: MicroString) -> Self {
+        Self::from(val.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::From<MicroString> for ::alloc::rc::Rc<str> {
+    fn from(val: MicroString) -> Self {
+        Self::from(val.as_str())
+    }
+}
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl ::core::convert::From<MicroString> for ::alloc::sync::Arc<str> {
+    fn from(val: MicroString) -> Self {
+        Self::from(val.as_str())
+    }
+}
+#[cfg(feature = "std")]
+impl ::core::convert::From<MicroString> for ::std::ffi::OsString {
+    fn from(val: MicroString) -> Self {
+        Self::from(val.as_str())
+    }
+}
+#[cfg(feature = "std")]
+impl ::core::convert::From<MicroString> for ::std::path::PathBuf {
+    fn from(val: MicroString) -> Self {
+        Self::from(val.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::From<MicroString> for ::alloc::borrow::Cow<'static, str> {
+    fn from(val: MicroString) -> Self {
+        Self::Owned(::alloc::string::String::from(val))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::TryFrom<::alloc::boxed::Box<str>> for MicroString {
+    type Error = MicroStringError;
+    fn try_from(value: ::alloc::boxed::Box<str>) -> Result<Self, MicroStringError> {
+        Self::try_from(&*value)
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::TryFrom<::alloc::borrow::Cow<'_, str>> for MicroString {
+    type Error = MicroStringError;
+    fn try_from(value: ::alloc::borrow::Cow<'_, str>) -> Result<Self, MicroStringError> {
+        Self::try_from(&*value)
+    }
+}
+#[cfg(feature = "std")]
+impl ::core::convert::TryFrom<&::std::ffi::OsStr> for MicroString {
+    type Error = MicroStringError;
+    fn try_from(value: &::std::ffi::OsStr) -> Result<Self, MicroStringError> {
+        match value.to_str() {
+            Some(s) => Self::try_from(s),
+            None => {
+                Err(MicroStringError::new(crate::ErrorKind::InvalidUtf8, value.len()))
+            }
+        }
+    }
+}
+#[cfg(feature = "std")]
+impl ::core::convert::TryFrom<&::std::path::Path> for MicroString {
+    type Error = MicroStringError;
+    fn try_from(value: &::std::path::Path) -> Result<Self, MicroStringError> {
+        Self::try_from(value.as_os_str())
+    }
+}
+impl ::core::convert::TryFrom<&::core::ffi::CStr> for MicroString {
+    type Error = MicroStringError;
+    fn try_from(value: &::core::ffi::CStr) -> Result<Self, MicroStringError> {
+        Self::from_utf8(value.to_bytes())
+    }
+}
+impl ::core::borrow::Borrow<str> for MicroString {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+impl ::core::borrow::BorrowMut<str> for MicroString {
+    fn borrow_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+impl ::core::ops::Deref for MicroString {
+    type Target = str;
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+impl ::core::ops::DerefMut for MicroString {
+    fn deref_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+/// Compares the whole string as a single integer.
+impl ::core::cmp::PartialEq for MicroString {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_ne_bits() == other.to_ne_bits()
+    }
+}
+impl ::core::cmp::PartialOrd for MicroString {
+    fn partial_cmp(&self, other: &Self) -> Option<::core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+/**Orders strings the same way as [`str`].
+
+This compares the packed representation from [`to_bits`](MicroString::to_bits) as a single integer.
+```
+# use microstring::*;
+let mut strings = ["b", "a\0", "", "a"].map(|it| MicroString::new(it).unwrap());
+strings.sort();
+assert_eq!(strings, ["", "a", "a\0", "b"]);
+```
+*/
+impl ::core::cmp::Ord for MicroString {
+    fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+        self.to_bits().cmp(&other.to_bits())
+    }
+}
+impl ::core::cmp::PartialEq<str> for MicroString {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str().eq(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+impl ::core::cmp::PartialOrd<str> for MicroString {
+    fn partial_cmp(&self, other: &str) -> Option<::core::cmp::Ordering> {
+        self.as_str().partial_cmp(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+impl ::core::cmp::PartialEq<MicroString> for str {
+    fn eq(&self, other: &MicroString) -> bool {
+        ::core::convert::AsRef::<str>::as_ref(self).eq(other.as_str())
+    }
+}
+impl ::core::cmp::PartialOrd<MicroString> for str {
+    fn partial_cmp(&self, other: &MicroString) -> Option<::core::cmp::Ordering> {
+        ::core::convert::AsRef::<str>::as_ref(self).partial_cmp(other.as_str())
+    }
+}
+impl ::core::cmp::PartialEq<&str> for MicroString {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str().eq(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+impl ::core::cmp::PartialOrd<&str> for MicroString {
+    fn partial_cmp(&self, other: &&str) -> Option<::core::cmp::Ordering> {
+        self.as_str().partial_cmp(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+impl ::core::cmp::PartialEq<MicroString> for &str {
+    fn eq(&self, other: &MicroString) -> bool {
+        ::core::convert::AsRef::<str>::as_ref(self).eq(other.as_str())
+    }
+}
+impl ::core::cmp::PartialOrd<MicroString> for &str {
+    fn partial_cmp(&self, other: &MicroString) -> Option<::core::cmp::Ordering> {
+        ::core::convert::AsRef::<str>::as_ref(self).partial_cmp(other.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialEq<::alloc::string::String> for MicroString {
+    fn eq(&self, other: &::alloc::string::String) -> bool {
+        self.as_str().eq(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialOrd<::alloc::string::String> for MicroString {
+    fn partial_cmp(
+        &self,
+        other: &::alloc::string::String,
+    ) -> Option<::core::cmp::Ordering> {
+        self.as_str().partial_cmp(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialEq<MicroString> for ::alloc::string::String {
+    fn eq(&self, other: &MicroString) -> bool {
+        ::core::convert::AsRef::<str>::as_ref(self).eq(other.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialOrd<MicroString> for ::alloc::string::String {
+    fn partial_cmp(&self, other: &MicroString) -> Option<::core::cmp::Ordering> {
+        ::core::convert::AsRef::<str>::as_ref(self).partial_cmp(other.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialEq<::alloc::borrow::Cow<'_, str>> for MicroString {
+    fn eq(&self, other: &::alloc::borrow::Cow<'_, str>) -> bool {
+        self.as_str().eq(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialOrd<::alloc::borrow::Cow<'_, str>> for MicroString {
+    fn partial_cmp(
+        &self,
+        other: &::alloc::borrow::Cow<'_, str>,
+    ) -> Option<::core::cmp::Ordering> {
+        self.as_str().partial_cmp(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialEq<MicroString> for ::alloc::borrow::Cow<'_, str> {
+    fn eq(&self, other: &MicroString) -> bool {
+        ::core::convert::AsRef::<str>::as_ref(self).eq(other.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialOrd<MicroString> for ::alloc::borrow::Cow<'_, str> {
+    fn partial_cmp(&self, other: &MicroString) -> Option<::core::cmp::Ordering> {
+        ::core::convert::AsRef::<str>::as_ref(self).partial_cmp(other.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialEq<::alloc::boxed::Box<str>> for MicroString {
+    fn eq(&self, other: &::alloc::boxed::Box<str>) -> bool {
+        self.as_str().eq(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialOrd<::alloc::boxed::Box<str>> for MicroString {
+    fn partial_cmp(
+        &self,
+        other: &::alloc::boxed::Box<str>,
+    ) -> Option<::core::cmp::Ordering> {
+        self.as_str().partial_cmp(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialEq<MicroString> for ::alloc::boxed::Box<str> {
+    fn eq(&self, other: &MicroString) -> bool {
+        ::core::convert::AsRef::<str>::as_ref(self).eq(other.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialOrd<MicroString> for ::alloc::boxed::Box<str> {
+    fn partial_cmp(&self, other: &MicroString) -> Option<::core::cmp::Ordering> {
+        ::core::convert::AsRef::<str>::as_ref(self).partial_cmp(other.as_str())
+    }
+}
+impl ::core::fmt::Debug for MicroString {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl ::core::fmt::Display for MicroString {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+/**Writes which would overflow the capacity return [`fmt::Error`](::core::fmt::Error).
+
+On error, the string is restored to its state before the call to
+[`write!`](::core::write).
+```
+# use microstring::*;
+use core::fmt::Write as _;
+let mut string = MicroString::EMPTY;
+assert!(write!(string, "{}{}", "1234567", "12345678").is_err());
+assert_eq!(string, "");
+```
+*/
+impl ::core::fmt::Write for MicroString {
+    fn write_str(&mut self, s: &str) -> ::core::fmt::Result {
+        self.push_str(s).map_err(|_| ::core::fmt::Error)
+    }
+    fn write_char(&mut self, c: char) -> ::core::fmt::Result {
+        self.push(c).map_err(|_| ::core::fmt::Error)
+    }
+    fn write_fmt(&mut self, args: ::core::fmt::Arguments<'_>) -> ::core::fmt::Result {
+        let before = *self;
+        ::core::fmt::write(self, args).inspect_err(|_| *self = before)
+    }
+}
+impl MicroString {
+    /**Creates a string from the given [`Arguments`](::core::fmt::Arguments).
+
+See also [`micro_format!`](crate::micro_format).
+*/
+    pub fn try_from_fmt(
+        args: ::core::fmt::Arguments<'_>,
+    ) -> Result<Self, MicroStringError> {
+        struct Len(usize);
+        impl ::core::fmt::Write for Len {
+            fn write_str(&mut self, s: &str) -> ::core::fmt::Result {
+                self.0 += s.len();
+                Ok(())
+            }
+        }
+        let mut s = Self::EMPTY;
+        match ::core::fmt::Write::write_fmt(&mut s, args) {
+            Ok(()) => Ok(s),
+            Err(_) => {
+                let mut len = Len(0);
+                let _ = ::core::fmt::Write::write_fmt(&mut len, args);
+                Err(MicroStringError::new(crate::ErrorKind::TooLong, len.0))
+            }
+        }
+    }
+}
+/**Creates a [`MicroString`] using interpolation of runtime expressions,
+like [`format!`](https://doc.rust-lang.org/std/macro.format.html).
+
+Returns an error if the result would be longer than 7 bytes.
+```
+# use microstring::*;
+assert_eq!(micro_format!("{}", "1234567").unwrap(), "1234567");
+assert!(micro_format!("{}", "12345678").is_err());
+```
+*/
+#[macro_export]
+macro_rules! micro_format {
+    ($($arg:tt)*) => {
+        $crate::MicroString::try_from_fmt(::core::format_args!($($arg)*))
+    };
+}
+impl ::core::str::FromStr for MicroString {
+    type Err = MicroStringError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s).ok_or(MicroStringError::new(crate::ErrorKind::TooLong, s.len()))
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for MicroString {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(self)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for MicroString {
+    fn deserialize<D: serde::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        struct Visitor;
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = MicroString;
+            fn expecting(
+                &self,
+                f: &mut ::core::fmt::Formatter<'_>,
+            ) -> ::core::fmt::Result {
+                f.write_str("a string of at most 7 bytes")
+            }
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<MicroString, E> {
+                MicroString::new(v)
+                    .ok_or_else(|| serde::de::Error::invalid_length(v.len(), &self))
+            }
+        }
+        d.deserialize_str(Visitor)
+    }
+}
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for MicroString {
+    fn schema_name() -> ::std::borrow::Cow<'static, str> {
+        ::std::borrow::Cow::Borrowed(::core::stringify!(MicroString))
+    }
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema! {
+            { "type" : "string", "maxLength" : 7u8, }
+        }
+    }
+    fn schema_id() -> ::std::borrow::Cow<'static, str> {
+        ::std::borrow::Cow::Borrowed(
+            ::core::concat!(
+                ::core::module_path!(), "::", ::core::stringify!(MicroString)
+            ),
+        )
+    }
+}
+/**Generates strings of every length up to 7 bytes, including multi-byte [`char`]s.
+```
+# use microstring::*;
+use arbitrary::{Arbitrary, Unstructured};
+
+// an empty input consumes nothing
+assert_eq!(MicroString::arbitrary(&mut Unstructured::new(&[])).unwrap(), "");
+let (min, max) = MicroString::size_hint(0);
+assert_eq!(min, 0);
+let max = max.unwrap();
+
+let mut lens = [false; 7 + 1];
+let mut multi_byte = false;
+for seed in 0..=u8::MAX {
+    // the first byte chooses the length
+    let mut state = u32::from(seed) << 8 | 1;
+    let data = [seed].into_iter().chain((1..max).map(|_| {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        state as u8
+    })).collect::<Vec<_>>();
+    let mut u = Unstructured::new(&data);
+    let s = MicroString::arbitrary(&mut u).unwrap();
+    assert!(min <= data.len() - u.len() && data.len() - u.len() <= max);
+    assert!(str::from_utf8(s.as_bytes()).is_ok());
+    assert_eq!(s.to_bits(), MicroString::new(&s).unwrap().to_bits());
+    lens[s.len()] = true;
+    multi_byte |= s.chars().any(|c| c.len_utf8() > 1);
+}
+assert!(lens.iter().all(|it| *it) && multi_byte);
+```
+*/
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for MicroString {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let len = u.int_in_range(0..=7u8)? as usize;
+        let mut it = Self::EMPTY;
+        while (it.len as u8 as usize) < len {
+            let remaining = len - it.len as u8 as usize;
+            let width = u.int_in_range(1..=remaining.min(4))?;
+            let _ = it.push(crate::__private::arbitrary_char(u, width)?);
+        }
+        Ok(it)
+    }
+    fn size_hint(_: usize) -> (usize, Option<usize>) {
+        (0, Some(1 + 2 * 7u8 as usize))
+    }
+}
+impl crate::Lookup<MicroString> for MicroString {
+    fn lookup(&self) -> Option<MicroString> {
+        Some(*self)
+    }
+}
+impl crate::Lookup<MicroString> for str {
+    fn lookup(&self) -> Option<MicroString> {
+        MicroString::new(self)
+    }
+}
+impl crate::Lookup<MicroString> for &str {
+    fn lookup(&self) -> Option<MicroString> {
+        MicroString::new(self)
+    }
+}
+#[cfg(feature = "alloc")]
+impl crate::Lookup<MicroString> for ::alloc::string::String {
+    fn lookup(&self) -> Option<MicroString> {
+        MicroString::new(self)
+    }
+}
+#[cfg(feature = "alloc")]
+impl crate::Lookup<MicroString> for ::alloc::borrow::Cow<'_, str> {
+    fn lookup(&self) -> Option<MicroString> {
+        MicroString::new(self)
+    }
+}
+#[cfg(feature = "alloc")]
+impl crate::Lookup<MicroString> for ::alloc::boxed::Box<str> {
+    fn lookup(&self) -> Option<MicroString> {
+        MicroString::new(self)
+    }
+}
+/**A hash map keyed by [`MicroString`].
+
+Keys are stored and hashed as their packed [`to_bits`](MicroString::to_bits) representation,
+using [`BuildWordHasher`](crate::BuildWordHasher).
+Entries may also be looked up by [`str`] and [`String`](std::string::String).
+```
+# use microstring::*;
+let mut map = MicroMap::new();
+map.insert(MicroString::new("1234567").unwrap(), 1);
+assert_eq!(map.get("1234567"), Some(&1));
+assert_eq!(map.get(&MicroString::new("1234567").unwrap()), Some(&1));
+assert_eq!(map.get(&String::from("1234567")), Some(&1));
+assert_eq!(map.get("12345678"), None);
+
+*map.entry(MicroString::new("1234567").unwrap()).or_default() += 1;
+assert_eq!(map.into_iter().collect::<Vec<_>>(), [(MicroString::new("1234567").unwrap(), 2)]);
+```
+*/
+#[cfg(feature = "std")]
+#[derive(Clone)]
+pub struct MicroMap<V> {
+    inner: ::std::collections::HashMap<u64, V, crate::BuildWordHasher>,
+}
+#[cfg(feature = "std")]
+impl<V> MicroMap<V> {
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            inner: ::std::collections::HashMap::with_capacity_and_hasher(
+                capacity,
+                crate::BuildWordHasher,
+            ),
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+    pub fn clear(&mut self) {
+        self.inner.clear()
+    }
+    pub fn insert(&mut self, key: MicroString, value: V) -> Option<V> {
+        self.inner.insert(key.to_bits(), value)
+    }
+    /// Gets the entry for the given key, to insert or update it with a single probe.
+    pub fn entry(&mut self, key: MicroString) -> crate::Entry<'_, MicroString, V> {
+        crate::Entry::__new(&mut self.inner, key)
+    }
+    pub fn get<Q: ?Sized + crate::Lookup<MicroString>>(&self, key: &Q) -> Option<&V> {
+        self.inner.get(&key.lookup()?.to_bits())
+    }
+    pub fn get_mut<Q: ?Sized + crate::Lookup<MicroString>>(
+        &mut self,
+        key: &Q,
+    ) -> Option<&mut V> {
+        self.inner.get_mut(&key.lookup()?.to_bits())
+    }
+    pub fn contains_key<Q: ?Sized + crate::Lookup<MicroString>>(&self, key: &Q) -> bool {
+        self.get(key).is_some()
+    }
+    pub fn remove<Q: ?Sized + crate::Lookup<MicroString>>(
+        &mut self,
+        key: &Q,
+    ) -> Option<V> {
+        self.inner.remove(&key.lookup()?.to_bits())
+    }
+    pub fn iter(&self) -> impl Iterator<Item = (MicroString, &V)> {
+        self.inner
+            .iter()
+            .map(|(k, v)| (unsafe { MicroString::from_bits_unchecked(*k) }, v))
+    }
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (MicroString, &mut V)> {
+        self.inner
+            .iter_mut()
+            .map(|(k, v)| (unsafe { MicroString::from_bits_unchecked(*k) }, v))
+    }
+    pub fn keys(&self) -> impl Iterator<Item = MicroString> {
+        self.inner.keys().map(|k| unsafe { MicroString::from_bits_unchecked(*k) })
+    }
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.inner.values()
+    }
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut V> {
+        self.inner.values_mut()
+    }
+}
+#[cfg(feature = "std")]
+impl<V> ::core::default::Default for MicroMap<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+#[cfg(feature = "std")]
+impl<V: ::core::fmt::Debug> ::core::fmt::Debug for MicroMap<V> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+#[cfg(feature = "std")]
+impl<V: ::core::cmp::PartialEq> ::core::cmp::PartialEq for MicroMap<V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+#[cfg(feature = "std")]
+impl<V: ::core::cmp::Eq> ::core::cmp::Eq for MicroMap<V> {}
+#[cfg(feature = "std")]
+impl<V> ::core::iter::Extend<(MicroString, V)> for MicroMap<V> {
+    fn extend<I: IntoIterator<Item = (MicroString, V)>>(&mut self, iter: I) {
+        self.inner.extend(iter.into_iter().map(|(k, v)| (k.to_bits(), v)))
+    }
+}
+#[cfg(feature = "std")]
+impl<V> ::core::iter::IntoIterator for MicroMap<V> {
+    type Item = (MicroString, V);
+    type IntoIter = ::core::iter::Map<
+        ::std::collections::hash_map::IntoIter<u64, V>,
+        fn((u64, V)) -> (MicroString, V),
+    >;
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner
+            .into_iter()
+            .map(|(k, v)| (unsafe { MicroString::from_bits_unchecked(k) }, v))
+    }
+}
+#[cfg(feature = "std")]
+impl<'a, V> ::core::iter::IntoIterator for &'a MicroMap<V> {
+    type Item = (MicroString, &'a V);
+    type IntoIter = ::core::iter::Map<
+        ::std::collections::hash_map::Iter<'a, u64, V>,
+        fn((&'a u64, &'a V)) -> (MicroString, &'a V),
+    >;
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner
+            .iter()
+            .map(|(k, v)| (unsafe { MicroString::from_bits_unchecked(*k) }, v))
+    }
+}
+#[cfg(feature = "std")]
+impl<'a, V> ::core::iter::IntoIterator for &'a mut MicroMap<V> {
+    type Item = (MicroString, &'a mut V);
+    type IntoIter = ::core::iter::Map<
+        ::std::collections::hash_map::IterMut<'a, u64, V>,
+        fn((&'a u64, &'a mut V)) -> (MicroString, &'a mut V),
+    >;
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner
+            .iter_mut()
+            .map(|(k, v)| (unsafe { MicroString::from_bits_unchecked(*k) }, v))
+    }
+}
+#[cfg(feature = "std")]
+impl<V> ::core::iter::FromIterator<(MicroString, V)> for MicroMap<V> {
+    fn from_iter<I: IntoIterator<Item = (MicroString, V)>>(iter: I) -> Self {
+        let mut it = Self::new();
+        it.extend(iter);
+        it
+    }
+}
+#[cfg(feature = "const-default")]
+impl const_default::ConstDefault for MicroString {
+    const DEFAULT: Self = MicroString::EMPTY;
+}
+/**A [`MicroString`] which can be shared between threads,
+backed by an [`::core::sync::atomic::AtomicU64`].
+```
+# use microstring::*;
+use core::sync::atomic::Ordering;
+let atomic = AtomicMicroString::new(MicroString::new("1234567").unwrap());
+assert_eq!(atomic.swap(MicroString::EMPTY, Ordering::Relaxed), "1234567");
+assert_eq!(atomic.load(Ordering::Relaxed), "");
+```
+*/
+#[cfg(target_has_atomic = "64")]
+#[repr(transparent)]
+pub struct AtomicMicroString(::core::sync::atomic::AtomicU64);
+#[cfg(target_has_atomic = "64")]
+impl AtomicMicroString {
+    pub const fn new(s: MicroString) -> Self {
+        Self(::core::sync::atomic::AtomicU64::new(s.to_ne_bits()))
+    }
+    pub const fn into_inner(self) -> MicroString {
+        unsafe { MicroString::from_ne_bits_unchecked(self.0.into_inner()) }
+    }
+    /**Returns a mutable reference to the string,
+which is safe because `self` is borrowed mutably.
+```
+# use microstring::*;
+use core::sync::atomic::Ordering;
+let mut atomic = AtomicMicroString::default();
+atomic.get_mut().push_str("1234567").unwrap();
+assert_eq!(atomic.load(Ordering::Relaxed), "1234567");
+```
+*/
+    pub fn get_mut(&mut self) -> &mut MicroString {
+        unsafe { &mut *(self.0.get_mut() as *mut _ as *mut MicroString) }
+    }
+    pub fn load(&self, order: ::core::sync::atomic::Ordering) -> MicroString {
+        unsafe { MicroString::from_ne_bits_unchecked(self.0.load(order)) }
+    }
+    pub fn store(&self, s: MicroString, order: ::core::sync::atomic::Ordering) {
+        self.0.store(s.to_ne_bits(), order)
+    }
+    pub fn swap(
+        &self,
+        s: MicroString,
+        order: ::core::sync::atomic::Ordering,
+    ) -> MicroString {
+        unsafe {
+            MicroString::from_ne_bits_unchecked(self.0.swap(s.to_ne_bits(), order))
+        }
+    }
+    /**Stores `new` if the current string is `current`.
+
+Returns the previous string, which is `current` if and only if it was replaced.
+See [`::core::sync::atomic::AtomicU64::compare_exchange`] for the meaning of the orderings.
+```
+# use microstring::*;
+use core::sync::atomic::Ordering::Relaxed;
+let small = MicroString::new("1234567").unwrap();
+let atomic = AtomicMicroString::new(small);
+assert_eq!(atomic.compare_exchange(MicroString::EMPTY, small, Relaxed, Relaxed), Err(small));
+assert_eq!(atomic.compare_exchange(small, MicroString::EMPTY, Relaxed, Relaxed), Ok(small));
+assert_eq!(atomic.into_inner(), "");
+```
+*/
+    pub fn compare_exchange(
+        &self,
+        current: MicroString,
+        new: MicroString,
+        success: ::core::sync::atomic::Ordering,
+        failure: ::core::sync::atomic::Ordering,
+    ) -> Result<MicroString, MicroString> {
+        match self
+            .0
+            .compare_exchange(current.to_ne_bits(), new.to_ne_bits(), success, failure)
+        {
+            Ok(it) => Ok(unsafe { MicroString::from_ne_bits_unchecked(it) }),
+            Err(it) => Err(unsafe { MicroString::from_ne_bits_unchecked(it) }),
+        }
+    }
+    /**Like [`Self::compare_exchange`], but may spuriously fail even if the current string is `current`.
+
+See [`::core::sync::atomic::AtomicU64::compare_exchange_weak`].
+*/
+    pub fn compare_exchange_weak(
+        &self,
+        current: MicroString,
+        new: MicroString,
+        success: ::core::sync::atomic::Ordering,
+        failure: ::core::sync::atomic::Ordering,
+    ) -> Result<MicroString, MicroString> {
+        match self
+            .0
+            .compare_exchange_weak(
+                current.to_ne_bits(),
+                new.to_ne_bits(),
+                success,
+                failure,
+            )
+        {
+            Ok(it) => Ok(unsafe { MicroString::from_ne_bits_unchecked(it) }),
+            Err(it) => Err(unsafe { MicroString::from_ne_bits_unchecked(it) }),
+        }
+    }
+    /**Replaces the current string with the result of `f`, retrying if another thread changed it.
+
+Returns the previous string, or an error containing it if `f` returned [`None`].
+See [`::core::sync::atomic::AtomicU64::fetch_update`] for the meaning of the orderings.
+```
+# use microstring::*;
+use core::sync::atomic::Ordering::Relaxed;
+let atomic = AtomicMicroString::new(MicroString::new("a").unwrap());
+let push_b = |mut s: MicroString| s.push('b').ok().map(|()| s);
+assert_eq!(atomic.fetch_update(Relaxed, Relaxed, push_b), Ok(MicroString::new("a").unwrap()));
+assert_eq!(atomic.load(Relaxed), "ab");
+assert_eq!(atomic.fetch_update(Relaxed, Relaxed, |_| None), Err(MicroString::new("ab").unwrap()));
+```
+*/
+    pub fn fetch_update(
+        &self,
+        set_order: ::core::sync::atomic::Ordering,
+        fetch_order: ::core::sync::atomic::Ordering,
+        mut f: impl FnMut(MicroString) -> Option<MicroString>,
+    ) -> Result<MicroString, MicroString> {
+        let mut prev = self.load(fetch_order);
+        while let Some(next) = f(prev) {
+            match self.compare_exchange_weak(prev, next, set_order, fetch_order) {
+                Ok(it) => return Ok(it),
+                Err(it) => prev = it,
+            }
+        }
+        Err(prev)
+    }
+}
+#[cfg(target_has_atomic = "64")]
+impl ::core::default::Default for AtomicMicroString {
+    fn default() -> Self {
+        Self::new(MicroString::EMPTY)
+    }
+}
+#[cfg(target_has_atomic = "64")]
+impl ::core::convert::From<MicroString> for AtomicMicroString {
+    fn from(value: MicroString) -> Self {
+        Self::new(value)
+    }
+}
+#[cfg(target_has_atomic = "64")]
+impl ::core::fmt::Debug for AtomicMicroString {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.load(::core::sync::atomic::Ordering::Relaxed).fmt(f)
+    }
+}
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u8)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout,
+        zerocopy::Unaligned
+    )
+)]
+enum MicroStringLen {
+    _0 = 0u8,
+    _1 = 1u8,
+    _2 = 2u8,
+    _3 = 3u8,
+    _4 = 4u8,
+    _5 = 5u8,
+    _6 = 6u8,
+    _7 = 7u8,
+}
+impl MicroStringLen {
+    const fn from_usize(u: usize) -> Option<Self> {
+        const U8_MAX: usize = u8::MAX as _;
+        if u > U8_MAX {
+            return None;
+        }
+        Self::from_u8(u as u8)
+    }
+    const fn from_u8(u: u8) -> Option<Self> {
+        match u {
+            0u8 => Some(Self::_0),
+            1u8 => Some(Self::_1),
+            2u8 => Some(Self::_2),
+            3u8 => Some(Self::_3),
+            4u8 => Some(Self::_4),
+            5u8 => Some(Self::_5),
+            6u8 => Some(Self::_6),
+            7u8 => Some(Self::_7),
+            _ => None,
+        }
+    }
+}
+/**A stack-allocated string which can hold up to 15 UTF-8 encoded bytes.
+
+With the `zerocopy` feature, this implements `IntoBytes`, `Immutable`,
+`KnownLayout` and `Unaligned`.
+`TryFromBytes` is deliberately not implemented, because its derive cannot check
+that the bytes are UTF-8 followed by zero padding,
+and neither is `FromZeros`, which requires it.
+Use [`try_ref_from_bytes`](MilliString::try_ref_from_bytes)
+or [`try_slice_from_bytes`](MilliString::try_slice_from_bytes) instead.
+```
+# use core::mem::size_of;
+# use microstring::*;
+assert_eq! {
+    size_of::<MilliString>(),
+    size_of::<u128>(),
+}
+assert_eq! {
+    size_of::<MilliString>(),
+    size_of::<Option<MilliString>>(),
+}
+```
+*/
+#[derive(Clone, Copy, Eq)]
+#[repr(C)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout,
+        zerocopy::Unaligned
+    )
+)]
+pub struct MilliString {
+    len: MilliStringLen,
+    bytes: [u8; 15u8 as _],
+}
+impl MilliString {
+    pub const EMPTY: Self = Self::new("").unwrap();
+    /**Returns [`None`] if the given <code>[str::len()] > 15</code>.
+```
+# use microstring::*;
+const STRING: MilliString = MilliString::new("hello world :)").unwrap();
+```
+```compile_fail
+# use microstring::*;
+const TOO_BIG: MilliString = MilliString::new("goodbye world :(").unwrap();
+```
+*/
+    pub const fn new(s: &str) -> Option<Self> {
+        match MilliStringLen::from_usize(s.len()) {
+            Some(len) => {
+                let mut bytes = [0; 15u8 as _];
+                unsafe {
+                    ::core::ptr::copy_nonoverlapping(
+                        s.as_ptr(),
+                        bytes.as_mut_ptr(),
+                        s.len(),
+                    )
+                }
+                Some(Self { len, bytes })
+            }
+            None => None,
+        }
+    }
+    pub const fn as_str(&self) -> &str {
+        unsafe {
+            str::from_utf8_unchecked(
+                ::core::slice::from_raw_parts(
+                    self.bytes.as_ptr(),
+                    self.len as u8 as usize,
+                ),
+            )
+        }
+    }
+    pub const fn as_mut_str(&mut self) -> &mut str {
+        unsafe {
+            str::from_utf8_unchecked_mut(
+                ::core::slice::from_raw_parts_mut(
+                    self.bytes.as_mut_ptr(),
+                    self.len as u8 as usize,
+                ),
+            )
+        }
+    }
+    /**Returns an error if the given bytes are not UTF-8, or if <code>[slice::len()] > 15</code>.
+```
+# use microstring::*;
+const STRING: MilliString = match MilliString::from_utf8(b"hello world :)") {
+    Ok(it) => it,
+    Err(_) => panic!(),
+};
+assert_eq!(MilliString::from_utf8(b"\xFF").unwrap_err().kind(), ErrorKind::InvalidUtf8);
+assert_eq!(MilliString::from_utf8(b"goodbye world :(").unwrap_err().kind(), ErrorKind::TooLong);
+```
+*/
+    pub const fn from_utf8(bytes: &[u8]) -> Result<Self, MilliStringError> {
+        if bytes.len() > 15u8 as usize {
+            return Err(MilliStringError::new(crate::ErrorKind::TooLong, bytes.len()));
+        }
+        match ::core::str::from_utf8(bytes) {
+            Ok(s) => {
+                match Self::new(s) {
+                    Some(it) => Ok(it),
+                    None => {
+                        Err(
+                            MilliStringError::new(crate::ErrorKind::TooLong, bytes.len()),
+                        )
+                    }
+                }
+            }
+            Err(_) => {
+                Err(MilliStringError::new(crate::ErrorKind::InvalidUtf8, bytes.len()))
+            }
+        }
+    }
+    /**Returns [`None`] if <code>[slice::len()] > 15</code>.
+
+# Safety
+- The given bytes must be valid UTF-8.
+*/
+    pub const unsafe fn from_utf8_unchecked(bytes: &[u8]) -> Option<Self> {
+        Self::new(unsafe { str::from_utf8_unchecked(bytes) })
+    }
+    /**Truncates the given string to at most 15 bytes, on a [`char`] boundary.
+```
+# use microstring::*;
+const STRING: MilliString = MilliString::new_truncating("goodbye world :(");
+assert_eq!(STRING, "goodbye world :");
+```
+*/
+    pub const fn new_truncating(s: &str) -> Self {
+        let mut end = if s.len() < 15u8 as usize { s.len() } else { 15u8 as usize };
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+        match Self::new(s.split_at(end).0) {
+            Some(it) => it,
+            None => unreachable!(),
+        }
+    }
+    /**If the given string is longer than 15 bytes, truncates it on a [`char`] boundary,
+leaving room to append the given `marker`.
+
+# Panics
+- If the string needs truncating, and <code>marker.[len()](str::len) > 15</code>.
+```
+# use microstring::*;
+const STRING: MilliString = MilliString::new_truncating_with_marker("goodbye world :(", "…");
+assert_eq!(STRING, "goodbye worl…");
+```
+*/
+    pub const fn new_truncating_with_marker(s: &str, marker: &str) -> Self {
+        if let Some(it) = Self::new(s) {
+            return it;
+        }
+        assert!(marker.len() <= 15u8 as usize, "marker is longer than the capacity");
+        let mut end = 15u8 as usize - marker.len();
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+        let mut it = Self::new_truncating(s.split_at(end).0);
+        match it.push_str(marker) {
+            Ok(()) => it,
+            Err(_) => unreachable!(),
+        }
+    }
+    /// Like [`new_truncating`](Self::new_truncating),
+    /// but never splits a grapheme cluster.
+    #[cfg(feature = "unicode-segmentation")]
+    pub fn new_truncating_graphemes(s: &str) -> Self {
+        use unicode_segmentation::UnicodeSegmentation as _;
+        let end = s
+            .grapheme_indices(true)
+            .map(|(ix, grapheme)| ix + grapheme.len())
+            .take_while(|end| *end <= 15u8 as usize)
+            .last()
+            .unwrap_or(0);
+        Self::new_truncating(&s[..end])
+    }
+}
+/**The error returned when a [`MilliString`] cannot be constructed.
+```
+# use microstring::*;
+let e = "goodbye world :(".parse::<MilliString>().unwrap_err();
+assert_eq!(e.kind(), ErrorKind::TooLong);
+assert_eq!(e.input_len(), 16);
+assert_eq!(e.to_string(), "expected a string of at most 15 bytes, but got 16 bytes");
+```
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MilliStringError {
+    kind: crate::ErrorKind,
+    len: usize,
+}
+impl MilliStringError {
+    const fn new(kind: crate::ErrorKind, len: usize) -> Self {
+        Self { kind, len }
+    }
+    /// Why the input was rejected.
+    pub const fn kind(&self) -> crate::ErrorKind {
+        self.kind
+    }
+    /// The length of the rejected input, in bytes.
+    ///
+    /// For operations which append to an existing string,
+    /// this is the length the string would have had.
+    pub const fn input_len(&self) -> usize {
+        self.len
+    }
+    /// The maximum length of the string being constructed, in bytes.
+    pub const fn capacity(&self) -> usize {
+        15u8 as usize
+    }
+}
+impl ::core::fmt::Display for MilliStringError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let Self { kind, len } = *self;
+        match kind {
+            crate::ErrorKind::TooLong => {
+                write!(f, "expected a string of at most 15 bytes, but got {len} bytes")
+            }
+            crate::ErrorKind::InvalidUtf8 => {
+                write!(
+                    f,
+                    "expected a UTF-8 string of at most 15 bytes, but got {len} bytes of invalid UTF-8"
+                )
+            }
+            crate::ErrorKind::Invalid => {
+                write!(f, "a string of {len} bytes failed validation")
+            }
+        }
+    }
+}
+impl ::core::error::Error for MilliStringError {}
+impl MilliString {
+    /// The maximum length, in bytes.
+    pub const CAPACITY: usize = 15u8 as usize;
+    /// The length of this string, in bytes.
+    pub const fn len(&self) -> usize {
+        self.len as u8 as usize
+    }
+    /// Returns `true` if this string has a length of zero.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// The number of bytes which may be pushed before this string is full.
+    pub const fn remaining_capacity(&self) -> usize {
+        Self::CAPACITY - self.len()
+    }
+    /// Equivalent to [`str::as_bytes`], but usable in `const`.
+    ///
+    /// Unlike `as_bytes`, this does not shadow
+    /// [`zerocopy::IntoBytes::as_bytes`](https://docs.rs/zerocopy/latest/zerocopy/trait.IntoBytes.html#method.as_bytes),
+    /// which returns the length as well.
+    pub const fn const_as_bytes(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+    /// Equivalent to `==`, but usable in `const`.
+    pub const fn const_eq(&self, other: &str) -> bool {
+        self.len() == other.len() && self.const_cmp(other).is_eq()
+    }
+    /// Equivalent to [`Ord::cmp`], but usable in `const`.
+    pub const fn const_cmp(&self, other: &str) -> ::core::cmp::Ordering {
+        crate::__private::cmp_bytes(self.const_as_bytes(), other.as_bytes())
+    }
+    /// Equivalent to [`str::starts_with`] with a [`str`] pattern, but usable in `const`.
+    pub const fn const_starts_with(&self, prefix: &str) -> bool {
+        match prefix.len() <= self.len() {
+            true => {
+                let (head, _) = self.const_as_bytes().split_at(prefix.len());
+                crate::__private::cmp_bytes(head, prefix.as_bytes()).is_eq()
+            }
+            false => false,
+        }
+    }
+    /// Equivalent to [`str::ends_with`] with a [`str`] pattern, but usable in `const`.
+    pub const fn const_ends_with(&self, suffix: &str) -> bool {
+        match self.len().checked_sub(suffix.len()) {
+            Some(mid) => {
+                let (_, tail) = self.const_as_bytes().split_at(mid);
+                crate::__private::cmp_bytes(tail, suffix.as_bytes()).is_eq()
+            }
+            None => false,
+        }
+    }
+    /// Converts this string to its ASCII upper case equivalent in-place.
+    pub const fn make_ascii_uppercase(&mut self) {
+        self.as_mut_str().make_ascii_uppercase()
+    }
+    /// Converts this string to its ASCII lower case equivalent in-place.
+    pub const fn make_ascii_lowercase(&mut self) {
+        self.as_mut_str().make_ascii_lowercase()
+    }
+    /**Returns a copy of this string, with ASCII letters converted to upper case.
+
+Other bytes are unchanged.
+```
+# use microstring::*;
+const UPPER: MilliString = MilliString::new("hello world :)").unwrap().to_ascii_uppercase();
+assert_eq!(UPPER, "HELLO WORLD :)");
+for s in ["@AZ[`az{é"] {
+    assert_eq!(MilliString::new(s).unwrap().to_ascii_uppercase(), s.to_ascii_uppercase().as_str());
+}
+```
+*/
+    pub const fn to_ascii_uppercase(self) -> Self {
+        let bits = self.to_ne_bits();
+        unsafe {
+            Self::from_ne_bits_unchecked(bits ^ Self::ascii_case_mask(bits, b'a', b'z'))
+        }
+    }
+    /**Returns a copy of this string, with ASCII letters converted to lower case.
+
+Other bytes are unchanged.
+```
+# use microstring::*;
+const LOWER: MilliString = MilliString::new("HELLO WORLD :)").unwrap().to_ascii_lowercase();
+assert_eq!(LOWER, "hello world :)");
+for s in ["@AZ[`az{é"] {
+    assert_eq!(MilliString::new(s).unwrap().to_ascii_lowercase(), s.to_ascii_lowercase().as_str());
+}
+```
+*/
+    pub const fn to_ascii_lowercase(self) -> Self {
+        let bits = self.to_ne_bits();
+        unsafe {
+            Self::from_ne_bits_unchecked(bits ^ Self::ascii_case_mask(bits, b'A', b'Z'))
+        }
+    }
+    /// Returns `0x20` in each byte of `bits` which is in `lo..=hi`, and zero elsewhere,
+    /// so that XOR-ing with it switches the case of those ASCII letters.
+    const fn ascii_case_mask(bits: u128, lo: u8, hi: u8) -> u128 {
+        const ONES: u128 = u128::MAX / 0xFF;
+        const HIGH: u128 = ONES * 0x80;
+        let low = bits & !HIGH;
+        let ge_lo = low + ONES * (0x80 - lo as u128);
+        let gt_hi = low + ONES * (0x7F - hi as u128);
+        ((ge_lo ^ gt_hi) & !bits & HIGH) >> 2
+    }
+}
+impl MilliString {
+    /// Appends the given [`char`] to the end of this string.
+    ///
+    /// Returns an error, leaving this string unchanged, if there is not enough capacity.
+    pub const fn push(&mut self, c: char) -> Result<(), MilliStringError> {
+        self.push_str(c.encode_utf8(&mut [0; 4]))
+    }
+    /**Appends the given string to the end of this one.
+
+Returns an error, leaving this string unchanged, if the result would be
+longer than 15 bytes.
+```
+# use microstring::*;
+let mut string = MilliString::new("hello world :)").unwrap();
+assert!(string.push_str("goodbye world :(").is_err());
+assert_eq!(string, "hello world :)");
+```
+*/
+    pub const fn push_str(&mut self, s: &str) -> Result<(), MilliStringError> {
+        let len = self.len as u8 as usize;
+        match MilliStringLen::from_usize(len + s.len()) {
+            Some(new_len) => {
+                unsafe {
+                    ::core::ptr::copy_nonoverlapping(
+                        s.as_ptr(),
+                        self.bytes.as_mut_ptr().add(len),
+                        s.len(),
+                    )
+                }
+                self.len = new_len;
+                Ok(())
+            }
+            None => Err(MilliStringError::new(crate::ErrorKind::TooLong, len + s.len())),
+        }
+    }
+    /**Concatenates the given strings.
+
+Returns an error if the result would be longer than 15 bytes.
+```
+# use microstring::*;
+assert_eq!(MilliString::try_concat("hello world :)", "").unwrap(), "hello world :)");
+assert!(MilliString::try_concat("hello world :)", "goodbye world :(").is_err());
+```
+*/
+    pub const fn try_concat(left: &str, right: &str) -> Result<Self, MilliStringError> {
+        match Self::new(left) {
+            Some(mut it) => {
+                match it.push_str(right) {
+                    Ok(()) => Ok(it),
+                    Err(e) => Err(e),
+                }
+            }
+            None => {
+                Err(
+                    MilliStringError::new(
+                        crate::ErrorKind::TooLong,
+                        left.len() + right.len(),
+                    ),
+                )
+            }
+        }
+    }
+    /**Removes the last [`char`] from this string and returns it,
+or [`None`] if this string is empty.
+```
+# use microstring::*;
+let mut string = MilliString::new("aé").unwrap();
+assert_eq!(string.pop(), Some('é'));
+assert_eq!(string.to_bits(), MilliString::new("a").unwrap().to_bits());
+assert_eq!(string.pop(), Some('a'));
+assert_eq!(string.pop(), None);
+assert_eq!(string.to_bits(), MilliString::EMPTY.to_bits());
+```
+*/
+    pub fn pop(&mut self) -> Option<char> {
+        let c = self.as_str().chars().next_back()?;
+        unsafe { self.set_len(self.len as u8 as usize - c.len_utf8()) }
+        Some(c)
+    }
+    /**Shortens this string to `new_len` bytes.
+
+Has no effect if `new_len` is greater than the string's current length.
+
+# Panics
+- If `new_len` does not lie on a [`char`] boundary.
+```
+# use microstring::*;
+let mut string = MilliString::new("aé").unwrap();
+string.truncate(3);
+assert_eq!(string, "aé");
+string.truncate(1);
+assert_eq!(string.to_bits(), MilliString::new("a").unwrap().to_bits());
+```
+```should_panic
+# use microstring::*;
+MilliString::new("é").unwrap().truncate(1);
+```
+*/
+    pub const fn truncate(&mut self, new_len: usize) {
+        if new_len < self.len as u8 as usize {
+            assert!(
+                self.as_str().is_char_boundary(new_len),
+                "new_len does not lie on a char boundary"
+            );
+            unsafe { self.set_len(new_len) }
+        }
+    }
+    /**Truncates this string to the empty string.
+```
+# use microstring::*;
+let mut string = MilliString::new("hello world :)").unwrap();
+string.clear();
+assert_eq!(string.to_bits(), MilliString::EMPTY.to_bits());
+```
+*/
+    pub const fn clear(&mut self) {
+        unsafe { self.set_len(0) }
+    }
+    /// Inserts the given [`char`] at byte position `idx`.
+    ///
+    /// Returns an error, leaving this string unchanged, if there is not enough capacity.
+    ///
+    /// # Panics
+    /// - If `idx` is out of bounds, or does not lie on a [`char`] boundary.
+    pub const fn insert(&mut self, idx: usize, c: char) -> Result<(), MilliStringError> {
+        self.insert_str(idx, c.encode_utf8(&mut [0; 4]))
+    }
+    /**Inserts the given string at byte position `idx`.
+
+Returns an error, leaving this string unchanged, if there is not enough capacity.
+
+# Panics
+- If `idx` is out of bounds, or does not lie on a [`char`] boundary.
+```
+# use microstring::*;
+let mut string = MilliString::new("a").unwrap();
+string.insert_str(0, "é").unwrap();
+assert_eq!(string, "éa");
+assert!(string.insert_str(2, "goodbye world :(").is_err());
+assert_eq!(string.to_bits(), MilliString::new("éa").unwrap().to_bits());
+```
+```should_panic
+# use microstring::*;
+let _ = MilliString::new("é").unwrap().insert_str(1, "a");
+```
+*/
+    pub const fn insert_str(
+        &mut self,
+        idx: usize,
+        s: &str,
+    ) -> Result<(), MilliStringError> {
+        assert!(
+            self.as_str().is_char_boundary(idx), "idx does not lie on a char boundary"
+        );
+        let len = self.len as u8 as usize;
+        match MilliStringLen::from_usize(len + s.len()) {
+            Some(new_len) => {
+                unsafe {
+                    let p = self.bytes.as_mut_ptr();
+                    ::core::ptr::copy(p.add(idx), p.add(idx + s.len()), len - idx);
+                    ::core::ptr::copy_nonoverlapping(s.as_ptr(), p.add(idx), s.len());
+                }
+                self.len = new_len;
+                Ok(())
+            }
+            None => Err(MilliStringError::new(crate::ErrorKind::TooLong, len + s.len())),
+        }
+    }
+    /**Removes the [`char`] at byte position `idx` and returns it.
+
+# Panics
+- If `idx` is out of bounds, or does not lie on a [`char`] boundary.
+```
+# use microstring::*;
+let mut string = MilliString::new("éa").unwrap();
+assert_eq!(string.remove(0), 'é');
+assert_eq!(string.to_bits(), MilliString::new("a").unwrap().to_bits());
+```
+```should_panic
+# use microstring::*;
+MilliString::new("é").unwrap().remove(1);
+```
+*/
+    pub fn remove(&mut self, idx: usize) -> char {
+        let Some(c) = self.as_str()[idx..].chars().next() else {
+            panic!("cannot remove a char from the end of a string")
+        };
+        let len = self.len as u8 as usize;
+        let next = idx + c.len_utf8();
+        unsafe {
+            let p = self.bytes.as_mut_ptr();
+            ::core::ptr::copy(p.add(next), p.add(idx), len - next);
+            self.set_len(len - c.len_utf8());
+        }
+        c
+    }
+    /**Retains only the [`char`]s for which `f` returns `true`.
+```
+# use microstring::*;
+let mut string = MilliString::new("éa").unwrap();
+string.retain(|c| c.is_ascii());
+assert_eq!(string.to_bits(), MilliString::new("a").unwrap().to_bits());
+```
+*/
+    pub fn retain(&mut self, mut f: impl FnMut(char) -> bool) {
+        let this = *self;
+        self.clear();
+        for c in this.as_str().chars() {
+            if f(c) {
+                let _ = self.push(c);
+            }
+        }
+    }
+    /// Sets the length, zeroing any bytes past it.
+    ///
+    /// # Safety
+    /// - `new_len` must be at most the current length.
+    /// - `new_len` must lie on a [`char`] boundary.
+    const unsafe fn set_len(&mut self, new_len: usize) {
+        let len = self.len as u8 as usize;
+        unsafe {
+            ::core::ptr::write_bytes(
+                self.bytes.as_mut_ptr().add(new_len),
+                0,
+                len - new_len,
+            )
+        }
+        self.len = match MilliStringLen::from_usize(new_len) {
+            Some(it) => it,
+            None => unreachable!(),
+        };
+    }
+}
+impl MilliString {
+    /**Collects the given [`char`]s into a string.
+
+Returns an error if there are more than 15 bytes of them.
+See also [`Collected`](crate::Collected).
+```
+# use microstring::*;
+assert_eq!(MilliString::try_from_iter("hello world :)".chars()).unwrap(), "hello world :)");
+assert!(MilliString::try_from_iter("goodbye world :(".chars()).is_err());
+```
+*/
+    pub fn try_from_iter<I: IntoIterator<Item = char>>(
+        iter: I,
+    ) -> Result<Self, MilliStringError> {
+        let mut it = Self::EMPTY;
+        for c in iter {
+            it.push(c)?;
+        }
+        Ok(it)
+    }
+    /**Returns an owning iterator over the bytes of this string.
+```
+# use microstring::*;
+let string = MilliString::new("hello world :)").unwrap();
+assert!(string.into_bytes().eq("hello world :)".bytes()));
+assert_eq!(string.into_bytes().len(), 14);
+```
+*/
+    pub fn into_bytes(self) -> crate::IntoBytes<Self> {
+        crate::IntoBytes::new(self)
+    }
+}
+/**An owning iterator over the [`char`]s of this string.
+```
+# use microstring::*;
+let string = MilliString::new("hello world :)").unwrap();
+assert!(string.into_iter().rev().eq("hello world :)".chars().rev()));
+```
+*/
+impl ::core::iter::IntoIterator for MilliString {
+    type Item = char;
+    type IntoIter = crate::IntoChars<Self>;
+    fn into_iter(self) -> crate::IntoChars<Self> {
+        crate::IntoChars::new(self)
+    }
+}
+/// # Panics
+/// - If there is not enough capacity.
+///   See [`push`](Self::push) for a fallible alternative.
+impl ::core::iter::Extend<char> for MilliString {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        for c in iter {
+            if let Err(e) = self.push(c) {
+                panic!("{e}")
+            }
+        }
+    }
+}
+/// # Panics
+/// - If there is not enough capacity.
+///   See [`push_str`](Self::push_str) for a fallible alternative.
+impl<'a> ::core::iter::Extend<&'a str> for MilliString {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        for s in iter {
+            if let Err(e) = self.push_str(s) {
+                panic!("{e}")
+            }
+        }
+    }
+}
+impl MilliString {
+    /**Interprets the given bytes as a string, without copying.
+
+The bytes must be laid out as produced by `zerocopy::IntoBytes`:
+a length byte, followed by that many bytes of UTF-8, followed by zero padding.
+Returns [`None`] otherwise.
+
+This is the checked alternative to `zerocopy::TryFromBytes`,
+which is not implemented for this type because it cannot check the contents.
+```
+# use microstring::*;
+let mut bytes = [0; 15 + 1];
+bytes[0] = 14;
+bytes[1..][..14].copy_from_slice(b"hello world :)");
+assert_eq!(MilliString::try_ref_from_bytes(&bytes).unwrap().as_str(), "hello world :)");
+bytes[1] = 0xFF;
+assert_eq!(MilliString::try_ref_from_bytes(&bytes), None);
+```
+*/
+    pub const fn try_ref_from_bytes(bytes: &[u8]) -> Option<&Self> {
+        const { assert!(::core::mem::align_of:: < Self > () == 1) }
+        match Self::is_valid(bytes) {
+            true => Some(unsafe { &*bytes.as_ptr().cast::<Self>() }),
+            false => None,
+        }
+    }
+    /// Like [`try_ref_from_bytes`](Self::try_ref_from_bytes),
+    /// but for a contiguous run of strings.
+    pub const fn try_slice_from_bytes(bytes: &[u8]) -> Option<&[Self]> {
+        const { assert!(::core::mem::align_of:: < Self > () == 1) }
+        let size = ::core::mem::size_of::<Self>();
+        if !bytes.len().is_multiple_of(size) {
+            return None;
+        }
+        let mut rest = bytes;
+        while let Some((chunk, tail)) = rest.split_at_checked(size) {
+            if !Self::is_valid(chunk) {
+                return None;
+            }
+            rest = tail;
+        }
+        Some(unsafe {
+            ::core::slice::from_raw_parts(
+                bytes.as_ptr().cast::<Self>(),
+                bytes.len() / size,
+            )
+        })
+    }
+    /// Whether the given bytes are a length byte, that many bytes of UTF-8, and zero padding.
+    const fn is_valid(bytes: &[u8]) -> bool {
+        match bytes {
+            [len, rest @ ..] if rest.len() == 15u8 as usize => {
+                Self::is_valid_parts(*len, rest)
+            }
+            _ => false,
+        }
+    }
+    /// Whether `len` is in range, and `bytes` are that many bytes of UTF-8, followed by zero padding.
+    const fn is_valid_parts(len: u8, bytes: &[u8]) -> bool {
+        let Some(len) = MilliStringLen::from_u8(len) else {
+            return false;
+        };
+        let (string, mut padding) = bytes.split_at(len as u8 as usize);
+        if ::core::str::from_utf8(string).is_err() {
+            return false;
+        }
+        while let [0, tail @ ..] = padding {
+            padding = tail;
+        }
+        padding.is_empty()
+    }
+}
+impl MilliString {
+    /**Packs this string into a [`u128`].
+
+The string's bytes, zero padded to 15 bytes, come first,
+and its length is the last byte.
+This is stable, so may be persisted.
+Comparing the packed representations orders them the same as comparing the strings.
+```
+# use microstring::*;
+const BITS: u128 = MilliString::new("hello world :)").unwrap().to_bits();
+assert_eq!(BITS, u128::from_be_bytes(*b"hello world :)\0\x0e"));
+assert_eq!(MilliString::from_bits(BITS), MilliString::new("hello world :)"));
+```
+*/
+    pub const fn to_bits(self) -> u128 {
+        u128::from_be(self.to_ne_bits()).rotate_left(8)
+    }
+    /// Like [`to_bits`](Self::to_bits), but in native byte order,
+    /// so only suitable for equality.
+    const fn to_ne_bits(self) -> u128 {
+        unsafe { ::core::mem::transmute::<Self, u128>(self) }
+    }
+    /// The inverse of [`to_ne_bits`](Self::to_ne_bits).
+    ///
+    /// # Safety
+    /// - `bits` must have been returned by [`to_ne_bits`](Self::to_ne_bits).
+    const unsafe fn from_ne_bits_unchecked(bits: u128) -> Self {
+        unsafe { ::core::mem::transmute::<u128, Self>(bits) }
+    }
+    /// The inverse of [`to_bits`](Self::to_bits).
+    ///
+    /// Returns [`None`] if the length is out of range,
+    /// the string is not UTF-8, or the padding is not zero.
+    pub const fn from_bits(bits: u128) -> Option<Self> {
+        let [bytes @ .., len] = bits.to_be_bytes();
+        match Self::is_valid_parts(len, &bytes) {
+            true => Some(unsafe { Self::from_bits_unchecked(bits) }),
+            false => None,
+        }
+    }
+    /// The inverse of [`to_bits`](Self::to_bits), without any checks.
+    ///
+    /// # Safety
+    /// - `bits` must have been returned by [`to_bits`](Self::to_bits),
+    ///   or be accepted by [`from_bits`](Self::from_bits).
+    pub const unsafe fn from_bits_unchecked(bits: u128) -> Self {
+        unsafe { ::core::mem::transmute::<u128, Self>(bits.rotate_right(8).to_be()) }
+    }
+}
+impl crate::__private::Sealed for MilliString {}
+impl crate::InlineStr for MilliString {
+    const CAPACITY: usize = 15u8 as usize;
+    const EMPTY: Self = Self::EMPTY;
+    type Error = MilliStringError;
+    type Bits = u128;
+    fn new(s: &str) -> Option<Self> {
+        Self::new(s)
+    }
+    fn as_str(&self) -> &str {
+        self.as_str()
+    }
+    fn as_mut_str(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+    fn from_utf8(bytes: &[u8]) -> Result<Self, MilliStringError> {
+        Self::from_utf8(bytes)
+    }
+    fn to_bits(self) -> u128 {
+        self.to_bits()
+    }
+    fn from_bits(bits: u128) -> Option<Self> {
+        Self::from_bits(bits)
+    }
+    fn push_str(&mut self, s: &str) -> Result<(), MilliStringError> {
+        self.push_str(s)
+    }
+    fn try_from_iter<I: IntoIterator<Item = char>>(
+        iter: I,
+    ) -> Result<Self, MilliStringError> {
+        Self::try_from_iter(iter)
+    }
+    fn __error(kind: crate::ErrorKind, len: usize) -> MilliStringError {
+        MilliStringError::new(kind, len)
+    }
+}
+impl ::core::default::Default for MilliString {
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+impl ::core::default::Default for &MilliString {
+    fn default() -> Self {
+        &MilliString::EMPTY
+    }
+}
+/// Hashes the same as [`str`], as required by [`Borrow<str>`](::core::borrow::Borrow).
+impl ::core::hash::Hash for MilliString {
+    fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+impl ::core::convert::AsRef<Self> for MilliString {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+impl ::core::convert::AsMut<Self> for MilliString {
+    fn as_mut(&mut self) -> &mut Self {
+        self
+    }
+}
+impl ::core::convert::AsRef<str> for MilliString {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+impl ::core::convert::AsMut<str> for MilliString {
+    fn as_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+impl ::core::convert::AsRef<[u8]> for MilliString {
+    fn as_ref(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+}
+impl TryFrom<&str> for MilliString {
+    type Error = MilliStringError;
+    fn try_from(value: &str) -> Result<Self, MilliStringError> {
+        Self::new(value)
+            .ok_or(MilliStringError::new(crate::ErrorKind::TooLong, value.len()))
+    }
+}
+impl TryFrom<&[u8]> for MilliString {
+    type Error = MilliStringError;
+    fn try_from(value: &[u8]) -> Result<Self, MilliStringError> {
+        Self::from_utf8(value)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for MilliString {
+    type Error = MilliStringError;
+    fn try_from(value: [u8; N]) -> Result<Self, MilliStringError> {
+        Self::from_utf8(&value)
+    }
+}
+#[cfg(feature = "std")]
+impl ::core::convert::AsRef<::std::ffi::OsStr> for MilliString {
+    fn as_ref(&self) -> &::std::ffi::OsStr {
+        self.as_str().as_ref()
+    }
+}
+#[cfg(feature = "std")]
+impl ::core::convert::AsRef<::std::path::Path> for MilliString {
+    fn as_ref(&self) -> &::std::path::Path {
+        self.as_str().as_ref()
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::TryFrom<::alloc::string::String> for MilliString {
+    type Error = MilliStringError;
+    fn try_from(value: ::alloc::string::String) -> Result<Self, MilliStringError> {
+        Self::new(&value)
+            .ok_or(MilliStringError::new(crate::ErrorKind::TooLong, value.len()))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::From<MilliString> for ::alloc::string::String {
+    fn from(val: MilliString) -> Self {
+        Self::from(val.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::From<MilliString> for ::alloc::boxed::Box<str> {
+    fn from(val: MilliString) -> Self {
+        Self::from(val.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::From<MilliString> for ::alloc::rc::Rc<str> {
+    fn from(val: MilliString) -> Self {
+        Self::from(val.as_str())
+    }
+}
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl ::core::convert::From<MilliString> for ::alloc::sync::Arc<str> {
+    fn from(val: MilliString) -> Self {
+        Self::from(val.as_str())
+    }
+}
+#[cfg(feature = "std")]
+impl ::core::convert::From<MilliString> for ::std::ffi::OsString {
+    fn from(val: MilliString) -> Self {
+        Self::from(val.as_str())
+    }
+}
+#[cfg(feature = "std")]
+impl ::core::convert::From<MilliString> for ::std::path::PathBuf {
+    fn from(val: MilliString) -> Self {
+        Self::from(val.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::From<MilliString> for ::alloc::borrow::Cow<'static, str> {
+    fn from(val: MilliString) -> Self {
+        Self::Owned(::alloc::string::String::from(val))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::TryFrom<::alloc::boxed::Box<str>> for MilliString {
+    type Error = MilliStringError;
+    fn try_from(value: ::alloc::boxed::Box<str>) -> Result<Self, MilliStringError> {
+        Self::try_from(&*value)
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::TryFrom<::alloc::borrow::Cow<'_, str>> for MilliString {
+    type Error = MilliStringError;
+    fn try_from(value: ::alloc::borrow::Cow<'_, str>) -> Result<Self, MilliStringError> {
+        Self::try_from(&*value)
+    }
+}
+#[cfg(feature = "std")]
+impl ::core::convert::TryFrom<&::std::ffi::OsStr> for MilliString {
+    type Error = MilliStringError;
+    fn try_from(value: &::std::ffi::OsStr) -> Result<Self, MilliStringError> {
+        match value.to_str() {
+            Some(s) => Self::try_from(s),
+            None => {
+                Err(MilliStringError::new(crate::ErrorKind::InvalidUtf8, value.len()))
+            }
+        }
+    }
+}
+#[cfg(feature = "std")]
+impl ::core::convert::TryFrom<&::std::path::Path> for MilliString {
+    type Error = MilliStringError;
+    fn try_from(value: &::std::path::Path) -> Result<Self, MilliStringError> {
+        Self::try_from(value.as_os_str())
+    }
+}
+impl ::core::convert::TryFrom<&::core::ffi::CStr> for MilliString {
+    type Error = MilliStringError;
+    fn try_from(value: &::core::ffi::CStr) -> Result<Self, MilliStringError> {
+        Self::from_utf8(value.to_bytes())
+    }
+}
+impl ::core::borrow::Borrow<str> for MilliString {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+impl ::core::borrow::BorrowMut<str> for MilliString {
+    fn borrow_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+impl ::core::ops::Deref for MilliString {
+    type Target = str;
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+impl ::core::ops::DerefMut for MilliString {
+    fn deref_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+/// Compares the whole string as a single integer.
+impl ::core::cmp::PartialEq for MilliString {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_ne_bits() == other.to_ne_bits()
+    }
+}
+impl ::core::cmp::PartialOrd for MilliString {
+    fn partial_cmp(&self, other: &Self) -> Option<::core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+/**Orders strings the same way as [`str`].
+
+This compares the zero-padded bytes in place, then the length.
+```
+# use microstring::*;
+let mut strings = ["b", "a\0", "", "a"].map(|it| MilliString::new(it).unwrap());
+strings.sort();
+assert_eq!(strings, ["", "a", "a\0", "b"]);
+```
+*/
+impl ::core::cmp::Ord for MilliString {
+    fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+        self.bytes.cmp(&other.bytes).then(self.len.cmp(&other.len))
+    }
+}
+impl ::core::cmp::PartialEq<str> for MilliString {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str().eq(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+impl ::core::cmp::PartialOrd<str> for MilliString {
+    fn partial_cmp(&self, other: &str) -> Option<::core::cmp::Ordering> {
+        self.as_str().partial_cmp(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+impl ::core::cmp::PartialEq<MilliString> for str {
+    fn eq(&self, other: &MilliString) -> bool {
+        ::core::convert::AsRef::<str>::as_ref(self).eq(other.as_str())
+    }
+}
+impl ::core::cmp::PartialOrd<MilliString> for str {
+    fn partial_cmp(&self, other: &MilliString) -> Option<::core::cmp::Ordering> {
+        ::core::convert::AsRef::<str>::as_ref(self).partial_cmp(other.as_str())
+    }
+}
+impl ::core::cmp::PartialEq<&str> for MilliString {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str().eq(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+impl ::core::cmp::PartialOrd<&str> for MilliString {
+    fn partial_cmp(&self, other: &&str) -> Option<::core::cmp::Ordering> {
+        self.as_str().partial_cmp(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+impl ::core::cmp::PartialEq<MilliString> for &str {
+    fn eq(&self, other: &MilliString) -> bool {
+        ::core::convert::AsRef::<str>::as_ref(self).eq(other.as_str())
+    }
+}
+impl ::core::cmp::PartialOrd<MilliString> for &str {
+    fn partial_cmp(&self, other: &MilliString) -> Option<::core::cmp::Ordering> {
+        ::core::convert::AsRef::<str>::as_ref(self).partial_cmp(other.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialEq<::alloc::string::String> for MilliString {
+    fn eq(&self, other: &::alloc::string::String) -> bool {
+        self.as_str().eq(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialOrd<::alloc::string::String> for MilliString {
+    fn partial_cmp(
+        &self,
+        other: &::alloc::string::String,
+    ) -> Option<::core::cmp::Ordering> {
+        self.as_str().partial_cmp(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialEq<MilliString> for ::alloc::string::String {
+    fn eq(&self, other: &MilliString) -> bool {
+        ::core::convert::AsRef::<str>::as_ref(self).eq(other.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialOrd<MilliString> for ::alloc::string::String {
+    fn partial_cmp(&self, other: &MilliString) -> Option<::core::cmp::Ordering> {
+        ::core::convert::AsRef::<str>::as_ref(self).partial_cmp(other.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialEq<::alloc::borrow::Cow<'_, str>> for MilliString {
+    fn eq(&self, other: &::alloc::borrow::Cow<'_, str>) -> bool {
+        self.as_str().eq(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialOrd<::alloc::borrow::Cow<'_, str>> for MilliString {
+    fn partial_cmp(
+        &self,
+        other: &::alloc::borrow::Cow<'_, str>,
+    ) -> Option<::core::cmp::Ordering> {
+        self.as_str().partial_cmp(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialEq<MilliString> for ::alloc::borrow::Cow<'_, str> {
+    fn eq(&self, other: &MilliString) -> bool {
+        ::core::convert::AsRef::<str>::as_ref(self).eq(other.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialOrd<MilliString> for ::alloc::borrow::Cow<'_, str> {
+    fn partial_cmp(&self, other: &MilliString) -> Option<::core::cmp::Ordering> {
+        ::core::convert::AsRef::<str>::as_ref(self).partial_cmp(other.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialEq<::alloc::boxed::Box<str>> for MilliString {
+    fn eq(&self, other: &::alloc::boxed::Box<str>) -> bool {
+        self.as_str().eq(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialOrd<::alloc::boxed::Box<str>> for MilliString {
+    fn partial_cmp(
+        &self,
+        other: &::alloc::boxed::Box<str>,
+    ) -> Option<::core::cmp::Ordering> {
+        self.as_str().partial_cmp(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialEq<MilliString> for ::alloc::boxed::Box<str> {
+    fn eq(&self, other: &MilliString) -> bool {
+        ::core::convert::AsRef::<str>::as_ref(self).eq(other.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialOrd<MilliString> for ::alloc::boxed::Box<str> {
+    fn partial_cmp(&self, other: &MilliString) -> Option<::core::cmp::Ordering> {
+        ::core::convert::AsRef::<str>::as_ref(self).partial_cmp(other.as_str())
+    }
+}
+impl ::core::fmt::Debug for MilliString {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl ::core::fmt::Display for MilliString {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+/**Writes which would overflow the capacity return [`fmt::Error`](::core::fmt::Error).
+
+On error, the string is restored to its state before the call to
+[`write!`](::core::write).
+```
+# use microstring::*;
+use core::fmt::Write as _;
+let mut string = MilliString::EMPTY;
+assert!(write!(string, "{}{}", "hello world :)", "goodbye world :(").is_err());
+assert_eq!(string, "");
+```
+*/
+impl ::core::fmt::Write for MilliString {
+    fn write_str(&mut self, s: &str) -> ::core::fmt::Result {
+        self.push_str(s).map_err(|_| ::core::fmt::Error)
+    }
+    fn write_char(&mut self, c: char) -> ::core::fmt::Result {
+        self.push(c).map_err(|_| ::core::fmt::Error)
+    }
+    fn write_fmt(&mut self, args: ::core::fmt::Arguments<'_>) -> ::core::fmt::Result {
+        let before = *self;
+        ::core::fmt::write(self, args).inspect_err(|_| *self = before)
+    }
+}
+impl MilliString {
+    /**Creates a string from the given [`Arguments`](::core::fmt::Arguments).
+
+See also [`milli_format!`](crate::milli_format).
+*/
+    pub fn try_from_fmt(
+        args: ::core::fmt::Arguments<'_>,
+    ) -> Result<Self, MilliStringError> {
+        struct Len(usize);
+        impl ::core::fmt::Write for Len {
+            fn write_str(&mut self, s: &str) -> ::core::fmt::Result {
+                self.0 += s.len();
+                Ok(())
+            }
+        }
+        let mut s = Self::EMPTY;
+        match ::core::fmt::Write::write_fmt(&mut s, args) {
+            Ok(()) => Ok(s),
+            Err(_) => {
+                let mut len = Len(0);
+                let _ = ::core::fmt::Write::write_fmt(&mut len, args);
+                Err(MilliStringError::new(crate::ErrorKind::TooLong, len.0))
+            }
+        }
+    }
+}
+/**Creates a [`MilliString`] using interpolation of runtime expressions,
+like [`format!`](https://doc.rust-lang.org/std/macro.format.html).
+
+Returns an error if the result would be longer than 15 bytes.
+```
+# use microstring::*;
+assert_eq!(milli_format!("{}", "hello world :)").unwrap(), "hello world :)");
+assert!(milli_format!("{}", "goodbye world :(").is_err());
+```
+*/
+#[macro_export]
+macro_rules! milli_format {
+    ($($arg:tt)*) => {
+        $crate::MilliString::try_from_fmt(::core::format_args!($($arg)*))
+    };
+}
+impl ::core::str::FromStr for MilliString {
+    type Err = MilliStringError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s).ok_or(MilliStringError::new(crate::ErrorKind::TooLong, s.len()))
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for MilliString {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(self)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for MilliString {
+    fn deserialize<D: serde::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        struct Visitor;
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = MilliString;
+            fn expecting(
+                &self,
+                f: &mut ::core::fmt::Formatter<'_>,
+            ) -> ::core::fmt::Result {
+                f.write_str("a string of at most 15 bytes")
+            }
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<MilliString, E> {
+                MilliString::new(v)
+                    .ok_or_else(|| serde::de::Error::invalid_length(v.len(), &self))
+            }
+        }
+        d.deserialize_str(Visitor)
+    }
+}
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for MilliString {
+    fn schema_name() -> ::std::borrow::Cow<'static, str> {
+        ::std::borrow::Cow::Borrowed(::core::stringify!(MilliString))
+    }
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema! {
+            { "type" : "string", "maxLength" : 15u8, }
+        }
+    }
+    fn schema_id() -> ::std::borrow::Cow<'static, str> {
+        ::std::borrow::Cow::Borrowed(
+            ::core::concat!(
+                ::core::module_path!(), "::", ::core::stringify!(MilliString)
+            ),
+        )
+    }
+}
+/**Generates strings of every length up to 15 bytes, including multi-byte [`char`]s.
+```
+# use microstring::*;
+use arbitrary::{Arbitrary, Unstructured};
+
+// an empty input consumes nothing
+assert_eq!(MilliString::arbitrary(&mut Unstructured::new(&[])).unwrap(), "");
+let (min, max) = MilliString::size_hint(0);
+assert_eq!(min, 0);
+let max = max.unwrap();
+
+let mut lens = [false; 15 + 1];
+let mut multi_byte = false;
+for seed in 0..=u8::MAX {
+    // the first byte chooses the length
+    let mut state = u32::from(seed) << 8 | 1;
+    let data = [seed].into_iter().chain((1..max).map(|_| {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        state as u8
+    })).collect::<Vec<_>>();
+    let mut u = Unstructured::new(&data);
+    let s = MilliString::arbitrary(&mut u).unwrap();
+    assert!(min <= data.len() - u.len() && data.len() - u.len() <= max);
+    assert!(str::from_utf8(s.as_bytes()).is_ok());
+    assert_eq!(s.to_bits(), MilliString::new(&s).unwrap().to_bits());
+    lens[s.len()] = true;
+    multi_byte |= s.chars().any(|c| c.len_utf8() > 1);
+}
+assert!(lens.iter().all(|it| *it) && multi_byte);
+```
+*/
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for MilliString {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let len = u.int_in_range(0..=15u8)? as usize;
+        let mut it = Self::EMPTY;
+        while (it.len as u8 as usize) < len {
+            let remaining = len - it.len as u8 as usize;
+            let width = u.int_in_range(1..=remaining.min(4))?;
+            let _ = it.push(crate::__private::arbitrary_char(u, width)?);
+        }
+        Ok(it)
+    }
+    fn size_hint(_: usize) -> (usize, Option<usize>) {
+        (0, Some(1 + 2 * 15u8 as usize))
+    }
+}
+impl crate::Lookup<MilliString> for MilliString {
+    fn lookup(&self) -> Option<MilliString> {
+        Some(*self)
+    }
+}
+impl crate::Lookup<MilliString> for str {
+    fn lookup(&self) -> Option<MilliString> {
+        MilliString::new(self)
+    }
+}
+impl crate::Lookup<MilliString> for &str {
+    fn lookup(&self) -> Option<MilliString> {
+        MilliString::new(self)
+    }
+}
+#[cfg(feature = "alloc")]
+impl crate::Lookup<MilliString> for ::alloc::string::String {
+    fn lookup(&self) -> Option<MilliString> {
+        MilliString::new(self)
+    }
+}
+#[cfg(feature = "alloc")]
+impl crate::Lookup<MilliString> for ::alloc::borrow::Cow<'_, str> {
+    fn lookup(&self) -> Option<MilliString> {
+        MilliString::new(self)
+    }
+}
+#[cfg(feature = "alloc")]
+impl crate::Lookup<MilliString> for ::alloc::boxed::Box<str> {
+    fn lookup(&self) -> Option<MilliString> {
+        MilliString::new(self)
+    }
+}
+/**A hash map keyed by [`MilliString`].
+
+Keys are stored and hashed as their packed [`to_bits`](MilliString::to_bits) representation,
+using [`BuildWordHasher`](crate::BuildWordHasher).
+Entries may also be looked up by [`str`] and [`String`](std::string::String).
+```
+# use microstring::*;
+let mut map = MilliMap::new();
+map.insert(MilliString::new("hello world :)").unwrap(), 1);
+assert_eq!(map.get("hello world :)"), Some(&1));
+assert_eq!(map.get(&MilliString::new("hello world :)").unwrap()), Some(&1));
+assert_eq!(map.get(&String::from("hello world :)")), Some(&1));
+assert_eq!(map.get("goodbye world :("), None);
+
+*map.entry(MilliString::new("hello world :)").unwrap()).or_default() += 1;
+assert_eq!(map.into_iter().collect::<Vec<_>>(), [(MilliString::new("hello world :)").unwrap(), 2)]);
+```
+*/
+#[cfg(feature = "std")]
+#[derive(Clone)]
+pub struct MilliMap<V> {
+    inner: ::std::collections::HashMap<u128, V, crate::BuildWordHasher>,
+}
+#[cfg(feature = "std")]
+impl<V> MilliMap<V> {
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            inner: ::std::collections::HashMap::with_capacity_and_hasher(
+                capacity,
+                crate::BuildWordHasher,
+            ),
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+    pub fn clear(&mut self) {
+        self.inner.clear()
+    }
+    pub fn insert(&mut self, key: MilliString, value: V) -> Option<V> {
+        self.inner.insert(key.to_bits(), value)
+    }
+    /// Gets the entry for the given key, to insert or update it with a single probe.
+    pub fn entry(&mut self, key: MilliString) -> crate::Entry<'_, MilliString, V> {
+        crate::Entry::__new(&mut self.inner, key)
+    }
+    pub fn get<Q: ?Sized + crate::Lookup<MilliString>>(&self, key: &Q) -> Option<&V> {
+        self.inner.get(&key.lookup()?.to_bits())
+    }
+    pub fn get_mut<Q: ?Sized + crate::Lookup<MilliString>>(
+        &mut self,
+        key: &Q,
+    ) -> Option<&mut V> {
+        self.inner.get_mut(&key.lookup()?.to_bits())
+    }
+    pub fn contains_key<Q: ?Sized + crate::Lookup<MilliString>>(&self, key: &Q) -> bool {
+        self.get(key).is_some()
+    }
+    pub fn remove<Q: ?Sized + crate::Lookup<MilliString>>(
+        &mut self,
+        key: &Q,
+    ) -> Option<V> {
+        self.inner.remove(&key.lookup()?.to_bits())
+    }
+    pub fn iter(&self) -> impl Iterator<Item = (MilliString, &V)> {
+        self.inner
+            .iter()
+            .map(|(k, v)| (unsafe { MilliString::from_bits_unchecked(*k) }, v))
+    }
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (MilliString, &mut V)> {
+        self.inner
+            .iter_mut()
+            .map(|(k, v)| (unsafe { MilliString::from_bits_unchecked(*k) }, v))
+    }
+    pub fn keys(&self) -> impl Iterator<Item = MilliString> {
+        self.inner.keys().map(|k| unsafe { MilliString::from_bits_unchecked(*k) })
+    }
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.inner.values()
+    }
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut V> {
+        self.inner.values_mut()
+    }
+}
+#[cfg(feature = "std")]
+impl<V> ::core::default::Default for MilliMap<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+#[cfg(feature = "std")]
+impl<V: ::core::fmt::Debug> ::core::fmt::Debug for MilliMap<V> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+#[cfg(feature = "std")]
+impl<V: ::core::cmp::PartialEq> ::core::cmp::PartialEq for MilliMap<V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+#[cfg(feature = "std")]
+impl<V: ::core::cmp::Eq> ::core::cmp::Eq for MilliMap<V> {}
+#[cfg(feature = "std")]
+impl<V> ::core::iter::Extend<(MilliString, V)> for MilliMap<V> {
+    fn extend<I: IntoIterator<Item = (MilliString, V)>>(&mut self, iter: I) {
+        self.inner.extend(iter.into_iter().map(|(k, v)| (k.to_bits(), v)))
+    }
+}
+#[cfg(feature = "std")]
+impl<V> ::core::iter::IntoIterator for MilliMap<V> {
+    type Item = (MilliString, V);
+    type IntoIter = ::core::iter::Map<
+        ::std::collections::hash_map::IntoIter<u128, V>,
+        fn((u128, V)) -> (MilliString, V),
+    >;
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner
+            .into_iter()
+            .map(|(k, v)| (unsafe { MilliString::from_bits_unchecked(k) }, v))
+    }
+}
+#[cfg(feature = "std")]
+impl<'a, V> ::core::iter::IntoIterator for &'a MilliMap<V> {
+    type Item = (MilliString, &'a V);
+    type IntoIter = ::core::iter::Map<
+        ::std::collections::hash_map::Iter<'a, u128, V>,
+        fn((&'a u128, &'a V)) -> (MilliString, &'a V),
+    >;
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner
+            .iter()
+            .map(|(k, v)| (unsafe { MilliString::from_bits_unchecked(*k) }, v))
+    }
+}
+#[cfg(feature = "std")]
+impl<'a, V> ::core::iter::IntoIterator for &'a mut MilliMap<V> {
+    type Item = (MilliString, &'a mut V);
+    type IntoIter = ::core::iter::Map<
+        ::std::collections::hash_map::IterMut<'a, u128, V>,
+        fn((&'a u128, &'a mut V)) -> (MilliString, &'a mut V),
+    >;
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner
+            .iter_mut()
+            .map(|(k, v)| (unsafe { MilliString::from_bits_unchecked(*k) }, v))
+    }
+}
+#[cfg(feature = "std")]
+impl<V> ::core::iter::FromIterator<(MilliString, V)> for MilliMap<V> {
+    fn from_iter<I: IntoIterator<Item = (MilliString, V)>>(iter: I) -> Self {
+        let mut it = Self::new();
+        it.extend(iter);
+        it
+    }
+}
+#[cfg(feature = "const-default")]
+impl const_default::ConstDefault for MilliString {
+    const DEFAULT: Self = MilliString::EMPTY;
+}
+/**A [`MilliString`] which can be shared between threads,
+backed by a sequence lock.
+
+Loads never block writers, but may spin while a write is in progress.
+Loads are [`Acquire`](core::sync::atomic::Ordering::Acquire),
+and writes are [`Release`](core::sync::atomic::Ordering::Release).
+```
+# use microstring::*;
+let atomic = AtomicMilliString::new(MilliString::new("hello world :)").unwrap());
+assert_eq!(atomic.swap(MilliString::EMPTY), "hello world :)");
+assert_eq!(atomic.load(), "");
+```
+*/
+#[cfg(target_has_atomic = "64")]
+pub struct AtomicMilliString {
+    /// Odd while a write is in progress.
+    seq: ::core::sync::atomic::AtomicUsize,
+    halves: [::core::sync::atomic::AtomicU64; 2],
+}
+#[cfg(target_has_atomic = "64")]
+impl AtomicMilliString {
+    pub const fn new(s: MilliString) -> Self {
+        let bits = s.to_ne_bits();
+        Self {
+            seq: ::core::sync::atomic::AtomicUsize::new(0),
+            halves: [
+                ::core::sync::atomic::AtomicU64::new(bits as u64),
+                ::core::sync::atomic::AtomicU64::new((bits >> 64) as u64),
+            ],
+        }
+    }
+    pub const fn into_inner(self) -> MilliString {
+        let [lo, hi] = self.halves;
+        let bits = lo.into_inner() as u128 | (hi.into_inner() as u128) << 64;
+        unsafe { MilliString::from_ne_bits_unchecked(bits) }
+    }
+    pub fn load(&self) -> MilliString {
+        use ::core::sync::atomic::{Ordering, fence};
+        loop {
+            let seq = self.seq.load(Ordering::Acquire);
+            if !seq.is_multiple_of(2) {
+                ::core::hint::spin_loop();
+                continue;
+            }
+            let bits = self.read();
+            fence(Ordering::Acquire);
+            if self.seq.load(Ordering::Relaxed) == seq {
+                return unsafe { MilliString::from_ne_bits_unchecked(bits) };
+            }
+        }
+    }
+    pub fn store(&self, s: MilliString) {
+        self.swap(s);
+    }
+    pub fn swap(&self, s: MilliString) -> MilliString {
+        let seq = self.lock();
+        let prev = self.read();
+        self.write(s.to_ne_bits());
+        self.unlock(seq);
+        unsafe { MilliString::from_ne_bits_unchecked(prev) }
+    }
+    /**Stores `new` if the current string is `current`.
+
+Returns the previous string, which is `current` if and only if it was replaced.
+```
+# use microstring::*;
+let small = MilliString::new("hello world :)").unwrap();
+let atomic = AtomicMilliString::new(small);
+assert_eq!(atomic.compare_exchange(MilliString::EMPTY, small), Err(small));
+assert_eq!(atomic.compare_exchange(small, MilliString::EMPTY), Ok(small));
+assert_eq!(atomic.into_inner(), "");
+```
+*/
+    pub fn compare_exchange(
+        &self,
+        current: MilliString,
+        new: MilliString,
+    ) -> Result<MilliString, MilliString> {
+        let seq = self.lock();
+        let prev = self.read();
+        let res = match prev == current.to_ne_bits() {
+            true => {
+                self.write(new.to_ne_bits());
+                Ok(current)
+            }
+            false => Err(unsafe { MilliString::from_ne_bits_unchecked(prev) }),
+        };
+        self.unlock(seq);
+        res
+    }
+    /**Replaces the current string with the result of `f`, retrying if another thread changed it.
+
+Returns the previous string, or an error containing it if `f` returned [`None`].
+```
+# use microstring::*;
+let atomic = AtomicMilliString::new(MilliString::new("a").unwrap());
+let push_b = |mut s: MilliString| s.push('b').ok().map(|()| s);
+assert_eq!(atomic.fetch_update(push_b), Ok(MilliString::new("a").unwrap()));
+assert_eq!(atomic.load(), "ab");
+assert_eq!(atomic.fetch_update(|_| None), Err(MilliString::new("ab").unwrap()));
+```
+*/
+    pub fn fetch_update(
+        &self,
+        mut f: impl FnMut(MilliString) -> Option<MilliString>,
+    ) -> Result<MilliString, MilliString> {
+        let mut prev = self.load();
+        while let Some(next) = f(prev) {
+            match self.compare_exchange(prev, next) {
+                Ok(it) => return Ok(it),
+                Err(it) => prev = it,
+            }
+        }
+        Err(prev)
+    }
+    fn read(&self) -> u128 {
+        use ::core::sync::atomic::Ordering;
+        let [lo, hi] = &self.halves;
+        lo.load(Ordering::Relaxed) as u128 | (hi.load(Ordering::Relaxed) as u128) << 64
+    }
+    /// Must only be called between [`Self::lock`] and [`Self::unlock`].
+    fn write(&self, bits: u128) {
+        use ::core::sync::atomic::Ordering;
+        let [lo, hi] = &self.halves;
+        lo.store(bits as u64, Ordering::Relaxed);
+        hi.store((bits >> 64) as u64, Ordering::Relaxed);
+    }
+    /// Returns the (odd) sequence number.
+    fn lock(&self) -> usize {
+        use ::core::sync::atomic::{Ordering, fence};
+        loop {
+            let seq = self.seq.load(Ordering::Relaxed);
+            if seq.is_multiple_of(2)
+                && self
+                    .seq
+                    .compare_exchange_weak(
+                        seq,
+                        seq.wrapping_add(1),
+                        Ordering::Acquire,
+                        Ordering::Relaxed,
+                    )
+                    .is_ok()
+            {
+                fence(Ordering::Release);
+                return seq.wrapping_add(1);
+            }
+            ::core::hint::spin_loop();
+        }
+    }
+    fn unlock(&self, seq: usize) {
+        self.seq.store(seq.wrapping_add(1), ::core::sync::atomic::Ordering::Release)
+    }
+}
+#[cfg(target_has_atomic = "64")]
+impl ::core::default::Default for AtomicMilliString {
+    fn default() -> Self {
+        Self::new(MilliString::EMPTY)
+    }
+}
+#[cfg(target_has_atomic = "64")]
+impl ::core::convert::From<MilliString> for AtomicMilliString {
+    fn from(value: MilliString) -> Self {
+        Self::new(value)
+    }
+}
+#[cfg(target_has_atomic = "64")]
+impl ::core::fmt::Debug for AtomicMilliString {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.load().fmt(f)
+    }
+}
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u8)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout,
+        zerocopy::Unaligned
+    )
+)]
+enum MilliStringLen {
+    _0 = 0u8,
+    _1 = 1u8,
+    _2 = 2u8,
+    _3 = 3u8,
+    _4 = 4u8,
+    _5 = 5u8,
+    _6 = 6u8,
+    _7 = 7u8,
+    _8 = 8u8,
+    _9 = 9u8,
+    _10 = 10u8,
+    _11 = 11u8,
+    _12 = 12u8,
+    _13 = 13u8,
+    _14 = 14u8,
+    _15 = 15u8,
+}
+impl MilliStringLen {
+    const fn from_usize(u: usize) -> Option<Self> {
+        const U8_MAX: usize = u8::MAX as _;
+        if u > U8_MAX {
+            return None;
+        }
+        Self::from_u8(u as u8)
+    }
+    const fn from_u8(u: u8) -> Option<Self> {
+        match u {
+            0u8 => Some(Self::_0),
+            1u8 => Some(Self::_1),
+            2u8 => Some(Self::_2),
+            3u8 => Some(Self::_3),
+            4u8 => Some(Self::_4),
+            5u8 => Some(Self::_5),
+            6u8 => Some(Self::_6),
+            7u8 => Some(Self::_7),
+            8u8 => Some(Self::_8),
+            9u8 => Some(Self::_9),
+            10u8 => Some(Self::_10),
+            11u8 => Some(Self::_11),
+            12u8 => Some(Self::_12),
+            13u8 => Some(Self::_13),
+            14u8 => Some(Self::_14),
+            15u8 => Some(Self::_15),
+            _ => None,
+        }
+    }
+}
+/**A stack-allocated string which can hold up to 31 UTF-8 encoded bytes.
+
+With the `zerocopy` feature, this implements `IntoBytes`, `Immutable`,
+`KnownLayout` and `Unaligned`.
+`TryFromBytes` is deliberately not implemented, because its derive cannot check
+that the bytes are UTF-8 followed by zero padding,
+and neither is `FromZeros`, which requires it.
+Use [`try_ref_from_bytes`](CentiString::try_ref_from_bytes)
+or [`try_slice_from_bytes`](CentiString::try_slice_from_bytes) instead.
+```
+# use core::mem::size_of;
+# use microstring::*;
+assert_eq! {
+    size_of::<CentiString>(),
+    31 + 1,
+}
+assert_eq! {
+    size_of::<CentiString>(),
+    size_of::<Option<CentiString>>(),
+}
+```
+*/
+#[derive(Clone, Copy, Eq)]
+#[repr(C)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout,
+        zerocopy::Unaligned
+    )
+)]
+pub struct CentiString {
+    len: CentiStringLen,
+    bytes: [u8; 31u8 as _],
+}
+impl CentiString {
+    pub const EMPTY: Self = Self::new("").unwrap();
+    /**Returns [`None`] if the given <code>[str::len()] > 31</code>.
+```
+# use microstring::*;
+const STRING: CentiString = CentiString::new("the quick brown fox").unwrap();
+```
+```compile_fail
+# use microstring::*;
+const TOO_BIG: CentiString = CentiString::new("the quick brown fox jumps over the lazy dog").unwrap();
+```
+*/
+    pub const fn new(s: &str) -> Option<Self> {
+        match CentiStringLen::from_usize(s.len()) {
+            Some(len) => {
+                let mut bytes = [0; 31u8 as _];
+                unsafe {
+                    ::core::ptr::copy_nonoverlapping(
+                        s.as_ptr(),
+                        bytes.as_mut_ptr(),
+                        s.len(),
+                    )
+                }
+                Some(Self { len, bytes })
+            }
+            None => None,
+        }
+    }
+    pub const fn as_str(&self) -> &str {
+        unsafe {
+            str::from_utf8_unchecked(
+                ::core::slice::from_raw_parts(
+                    self.bytes.as_ptr(),
+                    self.len as u8 as usize,
+                ),
+            )
+        }
+    }
+    pub const fn as_mut_str(&mut self) -> &mut str {
+        unsafe {
+            str::from_utf8_unchecked_mut(
+                ::core::slice::from_raw_parts_mut(
+                    self.bytes.as_mut_ptr(),
+                    self.len as u8 as usize,
+                ),
+            )
+        }
+    }
+    /**Returns an error if the given bytes are not UTF-8, or if <code>[slice::len()] > 31</code>.
+```
+# use microstring::*;
+const STRING: CentiString = match CentiString::from_utf8(b"the quick brown fox") {
+    Ok(it) => it,
+    Err(_) => panic!(),
+};
+assert_eq!(CentiString::from_utf8(b"\xFF").unwrap_err().kind(), ErrorKind::InvalidUtf8);
+assert_eq!(CentiString::from_utf8(b"the quick brown fox jumps over the lazy dog").unwrap_err().kind(), ErrorKind::TooLong);
+```
+*/
+    pub const fn from_utf8(bytes: &[u8]) -> Result<Self, CentiStringError> {
+        if bytes.len() > 31u8 as usize {
+            return Err(CentiStringError::new(crate::ErrorKind::TooLong, bytes.len()));
+        }
+        match ::core::str::from_utf8(bytes) {
+            Ok(s) => {
+                match Self::new(s) {
+                    Some(it) => Ok(it),
+                    None => {
+                        Err(
+                            CentiStringError::new(crate::ErrorKind::TooLong, bytes.len()),
+                        )
+                    }
+                }
+            }
+            Err(_) => {
+                Err(CentiStringError::new(crate::ErrorKind::InvalidUtf8, bytes.len()))
+            }
+        }
+    }
+    /**Returns [`None`] if <code>[slice::len()] > 31</code>.
+
+# Safety
+- The given bytes must be valid UTF-8.
+*/
+    pub const unsafe fn from_utf8_unchecked(bytes: &[u8]) -> Option<Self> {
+        Self::new(unsafe { str::from_utf8_unchecked(bytes) })
+    }
+    /**Truncates the given string to at most 31 bytes, on a [`char`] boundary.
+```
+# use microstring::*;
+const STRING: CentiString = CentiString::new_truncating("the quick brown fox jumps over the lazy dog");
+assert_eq!(STRING, "the quick brown fox jumps over ");
+```
+*/
+    pub const fn new_truncating(s: &str) -> Self {
+        let mut end = if s.len() < 31u8 as usize { s.len() } else { 31u8 as usize };
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+        match Self::new(s.split_at(end).0) {
+            Some(it) => it,
+            None => unreachable!(),
+        }
+    }
+    /**If the given string is longer than 31 bytes, truncates it on a [`char`] boundary,
+leaving room to append the given `marker`.
+
+# Panics
+- If the string needs truncating, and <code>marker.[len()](str::len) > 31</code>.
+```
+# use microstring::*;
+const STRING: CentiString = CentiString::new_truncating_with_marker("the quick brown fox jumps over the lazy dog", "…");
+assert_eq!(STRING, "the quick brown fox jumps ov…");
+```
+*/
+    pub const fn new_truncating_with_marker(s: &str, marker: &str) -> Self {
+        if let Some(it) = Self::new(s) {
+            return it;
+        }
+        assert!(marker.len() <= 31u8 as usize, "marker is longer than the capacity");
+        let mut end = 31u8 as usize - marker.len();
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+        let mut it = Self::new_truncating(s.split_at(end).0);
+        match it.push_str(marker) {
+            Ok(()) => it,
+            Err(_) => unreachable!(),
+        }
+    }
+    /// Like [`new_truncating`](Self::new_truncating),
+    /// but never splits a grapheme cluster.
+    #[cfg(feature = "unicode-segmentation")]
+    pub fn new_truncating_graphemes(s: &str) -> Self {
+        use unicode_segmentation::UnicodeSegmentation as _;
+        let end = s
+            .grapheme_indices(true)
+            .map(|(ix, grapheme)| ix + grapheme.len())
+            .take_while(|end| *end <= 31u8 as usize)
+            .last()
+            .unwrap_or(0);
+        Self::new_truncating(&s[..end])
+    }
+}
+/**The error returned when a [`CentiString`] cannot be constructed.
+```
+# use microstring::*;
+let e = "the quick brown fox jumps over the lazy dog".parse::<CentiString>().unwrap_err();
+assert_eq!(e.kind(), ErrorKind::TooLong);
+assert_eq!(e.input_len(), 43);
+assert_eq!(e.to_string(), "expected a string of at most 31 bytes, but got 43 bytes");
+```
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CentiStringError {
+    kind: crate::ErrorKind,
+    len: usize,
+}
+impl CentiStringError {
+    const fn new(kind: crate::ErrorKind, len: usize) -> Self {
+        Self { kind, len }
+    }
+    /// Why the input was rejected.
+    pub const fn kind(&self) -> crate::ErrorKind {
+        self.kind
+    }
+    /// The length of the rejected input, in bytes.
+    ///
+    /// For operations which append to an existing string,
+    /// this is the length the string would have had.
+    pub const fn input_len(&self) -> usize {
+        self.len
+    }
+    /// The maximum length of the string being constructed, in bytes.
+    pub const fn capacity(&self) -> usize {
+        31u8 as usize
+    }
+}
+impl ::core::fmt::Display for CentiStringError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let Self { kind, len } = *self;
+        match kind {
+            crate::ErrorKind::TooLong => {
+                write!(f, "expected a string of at most 31 bytes, but got {len} bytes")
+            }
+            crate::ErrorKind::InvalidUtf8 => {
+                write!(
+                    f,
+                    "expected a UTF-8 string of at most 31 bytes, but got {len} bytes of invalid UTF-8"
+                )
+            }
+            crate::ErrorKind::Invalid => {
+                write!(f, "a string of {len} bytes failed validation")
+            }
+        }
+    }
+}
+impl ::core::error::Error for CentiStringError {}
+impl CentiString {
+    /// The maximum length, in bytes.
+    pub const CAPACITY: usize = 31u8 as usize;
+    /// The length of this string, in bytes.
+    pub const fn len(&self) -> usize {
+        self.len as u8 as usize
+    }
+    /// Returns `true` if this string has a length of zero.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// The number of bytes which may be pushed before this string is full.
+    pub const fn remaining_capacity(&self) -> usize {
+        Self::CAPACITY - self.len()
+    }
+    /// Equivalent to [`str::as_bytes`], but usable in `const`.
+    ///
+    /// Unlike `as_bytes`, this does not shadow
+    /// [`zerocopy::IntoBytes::as_bytes`](https://docs.rs/zerocopy/latest/zerocopy/trait.IntoBytes.html#method.as_bytes),
+    /// which returns the length as well.
+    pub const fn const_as_bytes(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+    /// Equivalent to `==`, but usable in `const`.
+    pub const fn const_eq(&self, other: &str) -> bool {
+        self.len() == other.len() && self.const_cmp(other).is_eq()
+    }
+    /// Equivalent to [`Ord::cmp`], but usable in `const`.
+    pub const fn const_cmp(&self, other: &str) -> ::core::cmp::Ordering {
+        crate::__private::cmp_bytes(self.const_as_bytes(), other.as_bytes())
+    }
+    /// Equivalent to [`str::starts_with`] with a [`str`] pattern, but usable in `const`.
+    pub const fn const_starts_with(&self, prefix: &str) -> bool {
+        match prefix.len() <= self.len() {
+            true => {
+                let (head, _) = self.const_as_bytes().split_at(prefix.len());
+                crate::__private::cmp_bytes(head, prefix.as_bytes()).is_eq()
+            }
+            false => false,
+        }
+    }
+    /// Equivalent to [`str::ends_with`] with a [`str`] pattern, but usable in `const`.
+    pub const fn const_ends_with(&self, suffix: &str) -> bool {
+        match self.len().checked_sub(suffix.len()) {
+            Some(mid) => {
+                let (_, tail) = self.const_as_bytes().split_at(mid);
+                crate::__private::cmp_bytes(tail, suffix.as_bytes()).is_eq()
+            }
+            None => false,
+        }
+    }
+    /// Converts this string to its ASCII upper case equivalent in-place.
+    pub const fn make_ascii_uppercase(&mut self) {
+        self.as_mut_str().make_ascii_uppercase()
+    }
+    /// Converts this string to its ASCII lower case equivalent in-place.
+    pub const fn make_ascii_lowercase(&mut self) {
+        self.as_mut_str().make_ascii_lowercase()
+    }
+    /**Returns a copy of this string, with ASCII letters converted to upper case.
+
+Other bytes are unchanged.
+```
+# use microstring::*;
+const UPPER: CentiString = CentiString::new("the quick brown fox").unwrap().to_ascii_uppercase();
+assert_eq!(UPPER, "THE QUICK BROWN FOX");
+for s in ["@AZ[`az{é"] {
+    assert_eq!(CentiString::new(s).unwrap().to_ascii_uppercase(), s.to_ascii_uppercase().as_str());
+}
+```
+*/
+    pub const fn to_ascii_uppercase(mut self) -> Self {
+        self.make_ascii_uppercase();
+        self
+    }
+    /**Returns a copy of this string, with ASCII letters converted to lower case.
+
+Other bytes are unchanged.
+```
+# use microstring::*;
+const LOWER: CentiString = CentiString::new("THE QUICK BROWN FOX").unwrap().to_ascii_lowercase();
+assert_eq!(LOWER, "the quick brown fox");
+for s in ["@AZ[`az{é"] {
+    assert_eq!(CentiString::new(s).unwrap().to_ascii_lowercase(), s.to_ascii_lowercase().as_str());
+}
+```
+*/
+    pub const fn to_ascii_lowercase(mut self) -> Self {
+        self.make_ascii_lowercase();
+        self
+    }
+}
+impl CentiString {
+    /// Appends the given [`char`] to the end of this string.
+    ///
+    /// Returns an error, leaving this string unchanged, if there is not enough capacity.
+    pub const fn push(&mut self, c: char) -> Result<(), CentiStringError> {
+        self.push_str(c.encode_utf8(&mut [0; 4]))
+    }
+    /**Appends the given string to the end of this one.
+
+Returns an error, leaving this string unchanged, if the result would be
+longer than 31 bytes.
+```
+# use microstring::*;
+let mut string = CentiString::new("the quick brown fox").unwrap();
+assert!(string.push_str("the quick brown fox jumps over the lazy dog").is_err());
+assert_eq!(string, "the quick brown fox");
+```
+*/
+    pub const fn push_str(&mut self, s: &str) -> Result<(), CentiStringError> {
+        let len = self.len as u8 as usize;
+        match CentiStringLen::from_usize(len + s.len()) {
+            Some(new_len) => {
+                unsafe {
+                    ::core::ptr::copy_nonoverlapping(
+                        s.as_ptr(),
+                        self.bytes.as_mut_ptr().add(len),
+                        s.len(),
+                    )
+                }
+                self.len = new_len;
+                Ok(())
+            }
+            None => Err(CentiStringError::new(crate::ErrorKind::TooLong, len + s.len())),
+        }
+    }
+    /**Concatenates the given strings.
+
+Returns an error if the result would be longer than 31 bytes.
+```
+# use microstring::*;
+assert_eq!(CentiString::try_concat("the quick brown fox", "").unwrap(), "the quick brown fox");
+assert!(CentiString::try_concat("the quick brown fox", "the quick brown fox jumps over the lazy dog").is_err());
+```
+*/
+    pub const fn try_concat(left: &str, right: &str) -> Result<Self, CentiStringError> {
+        match Self::new(left) {
+            Some(mut it) => {
+                match it.push_str(right) {
+                    Ok(()) => Ok(it),
+                    Err(e) => Err(e),
+                }
+            }
+            None => {
+                Err(
+                    CentiStringError::new(
+                        crate::ErrorKind::TooLong,
+                        left.len() + right.len(),
+                    ),
+                )
+            }
+        }
+    }
+    /**Removes the last [`char`] from this string and returns it,
+or [`None`] if this string is empty.
+```
+# use microstring::*;
+let mut string = CentiString::new("aé").unwrap();
+assert_eq!(string.pop(), Some('é'));
+assert_eq!(string.to_bits(), CentiString::new("a").unwrap().to_bits());
+assert_eq!(string.pop(), Some('a'));
+assert_eq!(string.pop(), None);
+assert_eq!(string.to_bits(), CentiString::EMPTY.to_bits());
+```
+*/
+    pub fn pop(&mut self) -> Option<char> {
+        let c = self.as_str().chars().next_back()?;
+        unsafe { self.set_len(self.len as u8 as usize - c.len_utf8()) }
+        Some(c)
+    }
+    /**Shortens this string to `new_len` bytes.
+
+Has no effect if `new_len` is greater than the string's current length.
+
+# Panics
+- If `new_len` does not lie on a [`char`] boundary.
+```
+# use microstring::*;
+let mut string = CentiString::new("aé").unwrap();
+string.truncate(3);
+assert_eq!(string, "aé");
+string.truncate(1);
+assert_eq!(string.to_bits(), CentiString::new("a").unwrap().to_bits());
+```
+```should_panic
+# use microstring::*;
+CentiString::new("é").unwrap().truncate(1);
+```
+*/
+    pub const fn truncate(&mut self, new_len: usize) {
+        if new_len < self.len as u8 as usize {
+            assert!(
+                self.as_str().is_char_boundary(new_len),
+                "new_len does not lie on a char boundary"
+            );
+            unsafe { self.set_len(new_len) }
+        }
+    }
+    /**Truncates this string to the empty string.
+```
+# use microstring::*;
+let mut string = CentiString::new("the quick brown fox").unwrap();
+string.clear();
+assert_eq!(string.to_bits(), CentiString::EMPTY.to_bits());
+```
+*/
+    pub const fn clear(&mut self) {
+        unsafe { self.set_len(0) }
+    }
+    /// Inserts the given [`char`] at byte position `idx`.
+    ///
+    /// Returns an error, leaving this string unchanged, if there is not enough capacity.
+    ///
+    /// # Panics
+    /// - If `idx` is out of bounds, or does not lie on a [`char`] boundary.
+    pub const fn insert(&mut self, idx: usize, c: char) -> Result<(), CentiStringError> {
+        self.insert_str(idx, c.encode_utf8(&mut [0; 4]))
+    }
+    /**Inserts the given string at byte position `idx`.
+
+Returns an error, leaving this string unchanged, if there is not enough capacity.
+
+# Panics
+- If `idx` is out of bounds, or does not lie on a [`char`] boundary.
+```
+# use microstring::*;
+let mut string = CentiString::new("a").unwrap();
+string.insert_str(0, "é").unwrap();
+assert_eq!(string, "éa");
+assert!(string.insert_str(2, "the quick brown fox jumps over the lazy dog").is_err());
+assert_eq!(string.to_bits(), CentiString::new("éa").unwrap().to_bits());
+```
+```should_panic
+# use microstring::*;
+let _ = CentiString::new("é").unwrap().insert_str(1, "a");
+```
+*/
+    pub const fn insert_str(
+        &mut self,
+        idx: usize,
+        s: &str,
+    ) -> Result<(), CentiStringError> {
+        assert!(
+            self.as_str().is_char_boundary(idx), "idx does not lie on a char boundary"
+        );
+        let len = self.len as u8 as usize;
+        match CentiStringLen::from_usize(len + s.len()) {
+            Some(new_len) => {
+                unsafe {
+                    let p = self.bytes.as_mut_ptr();
+                    ::core::ptr::copy(p.add(idx), p.add(idx + s.len()), len - idx);
+                    ::core::ptr::copy_nonoverlapping(s.as_ptr(), p.add(idx), s.len());
+                }
+                self.len = new_len;
+                Ok(())
+            }
+            None => Err(CentiStringError::new(crate::ErrorKind::TooLong, len + s.len())),
+        }
+    }
+    /**Removes the [`char`] at byte position `idx` and returns it.
+
+# Panics
+- If `idx` is out of bounds, or does not lie on a [`char`] boundary.
+```
+# use microstring::*;
+let mut string = CentiString::new("éa").unwrap();
+assert_eq!(string.remove(0), 'é');
+assert_eq!(string.to_bits(), CentiString::new("a").unwrap().to_bits());
+```
+```should_panic
+# use microstring::*;
+CentiString::new("é").unwrap().remove(1);
+```
+*/
+    pub fn remove(&mut self, idx: usize) -> char {
+        let Some(c) = self.as_str()[idx..].chars().next() else {
+            panic!("cannot remove a char from the end of a string")
+        };
+        let len = self.len as u8 as usize;
+        let next = idx + c.len_utf8();
+        unsafe {
+            let p = self.bytes.as_mut_ptr();
+            ::core::ptr::copy(p.add(next), p.add(idx), len - next);
+            self.set_len(len - c.len_utf8());
+        }
+        c
+    }
+    /**Retains only the [`char`]s for which `f` returns `true`.
+```
+# use microstring::*;
+let mut string = CentiString::new("éa").unwrap();
+string.retain(|c| c.is_ascii());
+assert_eq!(string.to_bits(), CentiString::new("a").unwrap().to_bits());
+```
+*/
+    pub fn retain(&mut self, mut f: impl FnMut(char) -> bool) {
+        let this = *self;
+        self.clear();
+        for c in this.as_str().chars() {
+            if f(c) {
+                let _ = self.push(c);
+            }
+        }
+    }
+    /// Sets the length, zeroing any bytes past it.
+    ///
+    /// # Safety
+    /// - `new_len` must be at most the current length.
+    /// - `new_len` must lie on a [`char`] boundary.
+    const unsafe fn set_len(&mut self, new_len: usize) {
+        let len = self.len as u8 as usize;
+        unsafe {
+            ::core::ptr::write_bytes(
+                self.bytes.as_mut_ptr().add(new_len),
+                0,
+                len - new_len,
+            )
+        }
+        self.len = match CentiStringLen::from_usize(new_len) {
+            Some(it) => it,
+            None => unreachable!(),
+        };
+    }
+}
+impl CentiString {
+    /**Collects the given [`char`]s into a string.
+
+Returns an error if there are more than 31 bytes of them.
+See also [`Collected`](crate::Collected).
+```
+# use microstring::*;
+assert_eq!(CentiString::try_from_iter("the quick brown fox".chars()).unwrap(), "the quick brown fox");
+assert!(CentiString::try_from_iter("the quick brown fox jumps over the lazy dog".chars()).is_err());
+```
+*/
+    pub fn try_from_iter<I: IntoIterator<Item = char>>(
+        iter: I,
+    ) -> Result<Self, CentiStringError> {
+        let mut it = Self::EMPTY;
+        for c in iter {
+            it.push(c)?;
+        }
+        Ok(it)
+    }
+    /**Returns an owning iterator over the bytes of this string.
+```
+# use microstring::*;
+let string = CentiString::new("the quick brown fox").unwrap();
+assert!(string.into_bytes().eq("the quick brown fox".bytes()));
+assert_eq!(string.into_bytes().len(), 19);
+```
+*/
+    pub fn into_bytes(self) -> crate::IntoBytes<Self> {
+        crate::IntoBytes::new(self)
+    }
+}
+/**An owning iterator over the [`char`]s of this string.
+```
+# use microstring::*;
+let string = CentiString::new("the quick brown fox").unwrap();
+assert!(string.into_iter().rev().eq("the quick brown fox".chars().rev()));
+```
+*/
+impl ::core::iter::IntoIterator for CentiString {
+    type Item = char;
+    type IntoIter = crate::IntoChars<Self>;
+    fn into_iter(self) -> crate::IntoChars<Self> {
+        crate::IntoChars::new(self)
+    }
+}
+/// # Panics
+/// - If there is not enough capacity.
+///   See [`push`](Self::push) for a fallible alternative.
+impl ::core::iter::Extend<char> for CentiString {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        for c in iter {
+            if let Err(e) = self.push(c) {
+                panic!("{e}")
+            }
+        }
+    }
+}
+/// # Panics
+/// - If there is not enough capacity.
+///   See [`push_str`](Self::push_str) for a fallible alternative.
+impl<'a> ::core::iter::Extend<&'a str> for CentiString {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        for s in iter {
+            if let Err(e) = self.push_str(s) {
+                panic!("{e}")
+            }
+        }
+    }
+}
+impl CentiString {
+    /**Interprets the given bytes as a string, without copying.
+
+The bytes must be laid out as produced by `zerocopy::IntoBytes`:
+a length byte, followed by that many bytes of UTF-8, followed by zero padding.
+Returns [`None`] otherwise.
+
+This is the checked alternative to `zerocopy::TryFromBytes`,
+which is not implemented for this type because it cannot check the contents.
+```
+# use microstring::*;
+let mut bytes = [0; 31 + 1];
+bytes[0] = 19;
+bytes[1..][..19].copy_from_slice(b"the quick brown fox");
+assert_eq!(CentiString::try_ref_from_bytes(&bytes).unwrap().as_str(), "the quick brown fox");
+bytes[1] = 0xFF;
+assert_eq!(CentiString::try_ref_from_bytes(&bytes), None);
+```
+*/
+    pub const fn try_ref_from_bytes(bytes: &[u8]) -> Option<&Self> {
+        const { assert!(::core::mem::align_of:: < Self > () == 1) }
+        match Self::is_valid(bytes) {
+            true => Some(unsafe { &*bytes.as_ptr().cast::<Self>() }),
+            false => None,
+        }
+    }
+    /// Like [`try_ref_from_bytes`](Self::try_ref_from_bytes),
+    /// but for a contiguous run of strings.
+    pub const fn try_slice_from_bytes(bytes: &[u8]) -> Option<&[Self]> {
+        const { assert!(::core::mem::align_of:: < Self > () == 1) }
+        let size = ::core::mem::size_of::<Self>();
+        if !bytes.len().is_multiple_of(size) {
+            return None;
+        }
+        let mut rest = bytes;
+        while let Some((chunk, tail)) = rest.split_at_checked(size) {
+            if !Self::is_valid(chunk) {
+                return None;
+            }
+            rest = tail;
+        }
+        Some(unsafe {
+            ::core::slice::from_raw_parts(
+                bytes.as_ptr().cast::<Self>(),
+                bytes.len() / size,
+            )
+        })
+    }
+    /// Whether the given bytes are a length byte, that many bytes of UTF-8, and zero padding.
+    const fn is_valid(bytes: &[u8]) -> bool {
+        match bytes {
+            [len, rest @ ..] if rest.len() == 31u8 as usize => {
+                Self::is_valid_parts(*len, rest)
+            }
+            _ => false,
+        }
+    }
+    /// Whether `len` is in range, and `bytes` are that many bytes of UTF-8, followed by zero padding.
+    const fn is_valid_parts(len: u8, bytes: &[u8]) -> bool {
+        let Some(len) = CentiStringLen::from_u8(len) else {
+            return false;
+        };
+        let (string, mut padding) = bytes.split_at(len as u8 as usize);
+        if ::core::str::from_utf8(string).is_err() {
+            return false;
+        }
+        while let [0, tail @ ..] = padding {
+            padding = tail;
+        }
+        padding.is_empty()
+    }
+}
+impl CentiString {
+    /**Packs this string into a byte array.
+
+The string's bytes, zero padded to 31 bytes, come first,
+and its length is the last byte.
+This is stable, so may be persisted.
+Comparing the packed representations orders them the same as comparing the strings.
+```
+# use microstring::*;
+const BITS: [u8; 31 + 1] = CentiString::new("the quick brown fox").unwrap().to_bits();
+assert_eq!(BITS, *b"the quick brown fox\0\0\0\0\0\0\0\0\0\0\0\0\x13");
+assert_eq!(CentiString::from_bits(BITS), CentiString::new("the quick brown fox"));
+```
+*/
+    pub const fn to_bits(self) -> [u8; 31u8 as usize + 1] {
+        let mut bits = [0; 31u8 as usize + 1];
+        let (bytes, len) = bits.split_at_mut(31u8 as usize);
+        bytes.copy_from_slice(&self.bytes);
+        len[0] = self.len as u8;
+        bits
+    }
+    /// The inverse of [`to_bits`](Self::to_bits).
+    ///
+    /// Returns [`None`] if the length is out of range,
+    /// the string is not UTF-8, or the padding is not zero.
+    pub const fn from_bits(bits: [u8; 31u8 as usize + 1]) -> Option<Self> {
+        let [bytes @ .., len] = bits;
+        match Self::is_valid_parts(len, &bytes) {
+            true => Some(unsafe { Self::from_bits_unchecked(bits) }),
+            false => None,
+        }
+    }
+    /// The inverse of [`to_bits`](Self::to_bits), without any checks.
+    ///
+    /// # Safety
+    /// - `bits` must have been returned by [`to_bits`](Self::to_bits),
+    ///   or be accepted by [`from_bits`](Self::from_bits).
+    pub const unsafe fn from_bits_unchecked(bits: [u8; 31u8 as usize + 1]) -> Self {
+        let [bytes @ .., len] = bits;
+        Self {
+            len: unsafe { ::core::mem::transmute::<u8, CentiStringLen>(len) },
+            bytes,
+        }
+    }
+}
+impl crate::__private::Sealed for CentiString {}
+impl crate::InlineStr for CentiString {
+    const CAPACITY: usize = 31u8 as usize;
+    const EMPTY: Self = Self::EMPTY;
+    type Error = CentiStringError;
+    type Bits = [u8; 31u8 as usize + 1];
+    fn new(s: &str) -> Option<Self> {
+        Self::new(s)
+    }
+    fn as_str(&self) -> &str {
+        self.as_str()
+    }
+    fn as_mut_str(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+    fn from_utf8(bytes: &[u8]) -> Result<Self, CentiStringError> {
+        Self::from_utf8(bytes)
+    }
+    fn to_bits(self) -> [u8; 31u8 as usize + 1] {
+        self.to_bits()
+    }
+    fn from_bits(bits: [u8; 31u8 as usize + 1]) -> Option<Self> {
+        Self::from_bits(bits)
+    }
+    fn push_str(&mut self, s: &str) -> Result<(), CentiStringError> {
+        self.push_str(s)
+    }
+    fn try_from_iter<I: IntoIterator<Item = char>>(
+        iter: I,
+    ) -> Result<Self, CentiStringError> {
+        Self::try_from_iter(iter)
+    }
+    fn __error(kind: crate::ErrorKind, len: usize) -> CentiStringError {
+        CentiStringError::new(kind, len)
+    }
+}
+impl ::core::default::Default for CentiString {
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+impl ::core::default::Default for &CentiString {
+    fn default() -> Self {
+        &CentiString::EMPTY
+    }
+}
+/// Hashes the same as [`str`], as required by [`Borrow<str>`](::core::borrow::Borrow).
+impl ::core::hash::Hash for CentiString {
+    fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+impl ::core::convert::AsRef<Self> for CentiString {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+impl ::core::convert::AsMut<Self> for CentiString {
+    fn as_mut(&mut self) -> &mut Self {
+        self
+    }
+}
+impl ::core::convert::AsRef<str> for CentiString {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+impl ::core::convert::AsMut<str> for CentiString {
+    fn as_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+impl ::core::convert::AsRef<[u8]> for CentiString {
+    fn as_ref(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+}
+impl TryFrom<&str> for CentiString {
+    type Error = CentiStringError;
+    fn try_from(value: &str) -> Result<Self, CentiStringError> {
+        Self::new(value)
+            .ok_or(CentiStringError::new(crate::ErrorKind::TooLong, value.len()))
+    }
+}
+impl TryFrom<&[u8]> for CentiString {
+    type Error = CentiStringError;
+    fn try_from(value: &[u8]) -> Result<Self, CentiStringError> {
+        Self::from_utf8(value)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for CentiString {
+    type Error = CentiStringError;
+    fn try_from(value: [u8; N]) -> Result<Self, CentiStringError> {
+        Self::from_utf8(&value)
+    }
+}
+#[cfg(feature = "std")]
+impl ::core::convert::AsRef<::std::ffi::OsStr> for CentiString {
+    fn as_ref(&self) -> &::std::ffi::OsStr {
+        self.as_str().as_ref()
+    }
+}
+#[cfg(feature = "std")]
+impl ::core::convert::AsRef<::std::path::Path> for CentiString {
+    fn as_ref(&self) -> &::std::path::Path {
+        self.as_str().as_ref()
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::TryFrom<::alloc::string::String> for CentiString {
+    type Error = CentiStringError;
+    fn try_from(value: ::alloc::string::String) -> Result<Self, CentiStringError> {
+        Self::new(&value)
+            .ok_or(CentiStringError::new(crate::ErrorKind::TooLong, value.len()))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::From<CentiString> for ::alloc::string::String {
+    fn from(val: CentiString) -> Self {
+        Self::from(val.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::From<CentiString> for ::alloc::boxed::Box<str> {
+    fn from(val: CentiString) -> Self {
+        Self::from(val.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::From<CentiString> for ::alloc::rc::Rc<str> {
+    fn from(val: CentiString) -> Self {
+        Self::from(val.as_str())
+    }
+}
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl ::core::convert::From<CentiString> for ::alloc::sync::Arc<str> {
+    fn from(val: CentiString) -> Self {
+        Self::from(val.as_str())
+    }
+}
+#[cfg(feature = "std")]
+impl ::core::convert::From<CentiString> for ::std::ffi::OsString {
+    fn from(val: CentiString) -> Self {
+        Self::from(val.as_str())
+    }
+}
+#[cfg(feature = "std")]
+impl ::core::convert::From<CentiString> for ::std::path::PathBuf {
+    fn from(val: CentiString) -> Self {
+        Self::from(val.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::From<CentiString> for ::alloc::borrow::Cow<'static, str> {
+    fn from(val: CentiString) -> Self {
+        Self::Owned(::alloc::string::String::from(val))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::TryFrom<::alloc::boxed::Box<str>> for CentiString {
+    type Error = CentiStringError;
+    fn try_from(value: ::alloc::boxed::Box<str>) -> Result<Self, CentiStringError> {
+        Self::try_from(&*value)
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::TryFrom<::alloc::borrow::Cow<'_, str>> for CentiString {
+    type Error = CentiStringError;
+    fn try_from(value: ::alloc::borrow::Cow<'_, str>) -> Result<Self, CentiStringError> {
+        Self::try_from(&*value)
+    }
+}
+#[cfg(feature = "std")]
+impl ::core::convert::TryFrom<&::std::ffi::OsStr> for CentiString {
+    type Error = CentiStringError;
+    fn try_from(value: &::std::ffi::OsStr) -> Result<Self, CentiStringError> {
+        match value.to_str() {
+            Some(s) => Self::try_from(s),
+            None => {
+                Err(CentiStringError::new(crate::ErrorKind::InvalidUtf8, value.len()))
+            }
+        }
+    }
+}
+#[cfg(feature = "std")]
+impl ::core::convert::TryFrom<&::std::path::Path> for CentiString {
+    type Error = CentiStringError;
+    fn try_from(value: &::std::path::Path) -> Result<Self, CentiStringError> {
+        Self::try_from(value.as_os_str())
+    }
+}
+impl ::core::convert::TryFrom<&::core::ffi::CStr> for CentiString {
+    type Error = CentiStringError;
+    fn try_from(value: &::core::ffi::CStr) -> Result<Self, CentiStringError> {
+        Self::from_utf8(value.to_bytes())
+    }
+}
+impl ::core::borrow::Borrow<str> for CentiString {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+impl ::core::borrow::BorrowMut<str> for CentiString {
+    fn borrow_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+impl ::core::ops::Deref for CentiString {
+    type Target = str;
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+impl ::core::ops::DerefMut for CentiString {
+    fn deref_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+/// Compares the whole string as a byte array.
+impl ::core::cmp::PartialEq for CentiString {
+    fn eq(&self, other: &Self) -> bool {
+        self.len as u8 == other.len as u8 && self.bytes == other.bytes
+    }
+}
+impl ::core::cmp::PartialOrd for CentiString {
+    fn partial_cmp(&self, other: &Self) -> Option<::core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+/**Orders strings the same way as [`str`].
+
+This compares the zero-padded bytes in place, then the length.
+```
+# use microstring::*;
+let mut strings = ["b", "a\0", "", "a"].map(|it| CentiString::new(it).unwrap());
+strings.sort();
+assert_eq!(strings, ["", "a", "a\0", "b"]);
+```
+*/
+impl ::core::cmp::Ord for CentiString {
+    fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+        self.bytes.cmp(&other.bytes).then(self.len.cmp(&other.len))
+    }
+}
+impl ::core::cmp::PartialEq<str> for CentiString {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str().eq(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+impl ::core::cmp::PartialOrd<str> for CentiString {
+    fn partial_cmp(&self, other: &str) -> Option<::core::cmp::Ordering> {
+        self.as_str().partial_cmp(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+impl ::core::cmp::PartialEq<CentiString> for str {
+    fn eq(&self, other: &CentiString) -> bool {
+        ::core::convert::AsRef::<str>::as_ref(self).eq(other.as_str())
+    }
+}
+impl ::core::cmp::PartialOrd<CentiString> for str {
+    fn partial_cmp(&self, other: &CentiString) -> Option<::core::cmp::Ordering> {
+        ::core::convert::AsRef::<str>::as_ref(self).partial_cmp(other.as_str())
+    }
+}
+impl ::core::cmp::PartialEq<&str> for CentiString {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str().eq(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+impl ::core::cmp::PartialOrd<&str> for CentiString {
+    fn partial_cmp(&self, other: &&str) -> Option<::core::cmp::Ordering> {
+        self.as_str().partial_cmp(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+impl ::core::cmp::PartialEq<CentiString> for &str {
+    fn eq(&self, other: &CentiString) -> bool {
+        ::core::convert::AsRef::<str>::as_ref(self).eq(other.as_str())
+    }
+}
+impl ::core::cmp::PartialOrd<CentiString> for &str {
+    fn partial_cmp(&self, other: &CentiString) -> Option<::core::cmp::Ordering> {
+        ::core::convert::AsRef::<str>::as_ref(self).partial_cmp(other.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialEq<::alloc::string::String> for CentiString {
+    fn eq(&self, other: &::alloc::string::String) -> bool {
+        self.as_str().eq(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialOrd<::alloc::string::String> for CentiString {
+    fn partial_cmp(
+        &self,
+        other: &::alloc::string::String,
+    ) -> Option<::core::cmp::Ordering> {
+        self.as_str().partial_cmp(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialEq<CentiString> for ::alloc::string::String {
+    fn eq(&self, other: &CentiString) -> bool {
+        ::core::convert::AsRef::<str>::as_ref(self).eq(other.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialOrd<CentiString> for ::alloc::string::String {
+    fn partial_cmp(&self, other: &CentiString) -> Option<::core::cmp::Ordering> {
+        ::core::convert::AsRef::<str>::as_ref(self).partial_cmp(other.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialEq<::alloc::borrow::Cow<'_, str>> for CentiString {
+    fn eq(&self, other: &::alloc::borrow::Cow<'_, str>) -> bool {
+        self.as_str().eq(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialOrd<::alloc::borrow::Cow<'_, str>> for CentiString {
+    fn partial_cmp(
+        &self,
+        other: &::alloc::borrow::Cow<'_, str>,
+    ) -> Option<::core::cmp::Ordering> {
+        self.as_str().partial_cmp(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialEq<CentiString> for ::alloc::borrow::Cow<'_, str> {
+    fn eq(&self, other: &CentiString) -> bool {
+        ::core::convert::AsRef::<str>::as_ref(self).eq(other.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialOrd<CentiString> for ::alloc::borrow::Cow<'_, str> {
+    fn partial_cmp(&self, other: &CentiString) -> Option<::core::cmp::Ordering> {
+        ::core::convert::AsRef::<str>::as_ref(self).partial_cmp(other.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialEq<::alloc::boxed::Box<str>> for CentiString {
+    fn eq(&self, other: &::alloc::boxed::Box<str>) -> bool {
+        self.as_str().eq(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialOrd<::alloc::boxed::Box<str>> for CentiString {
+    fn partial_cmp(
+        &self,
+        other: &::alloc::boxed::Box<str>,
+    ) -> Option<::core::cmp::Ordering> {
+        self.as_str().partial_cmp(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialEq<CentiString> for ::alloc::boxed::Box<str> {
+    fn eq(&self, other: &CentiString) -> bool {
+        ::core::convert::AsRef::<str>::as_ref(self).eq(other.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialOrd<CentiString> for ::alloc::boxed::Box<str> {
+    fn partial_cmp(&self, other: &CentiString) -> Option<::core::cmp::Ordering> {
+        ::core::convert::AsRef::<str>::as_ref(self).partial_cmp(other.as_str())
+    }
+}
+impl ::core::fmt::Debug for CentiString {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl ::core::fmt::Display for CentiString {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+/**Writes which would overflow the capacity return [`fmt::Error`](::core::fmt::Error).
+
+On error, the string is restored to its state before the call to
+[`write!`](::core::write).
+```
+# use microstring::*;
+use core::fmt::Write as _;
+let mut string = CentiString::EMPTY;
+assert!(write!(string, "{}{}", "the quick brown fox", "the quick brown fox jumps over the lazy dog").is_err());
+assert_eq!(string, "");
+```
+*/
+impl ::core::fmt::Write for CentiString {
+    fn write_str(&mut self, s: &str) -> ::core::fmt::Result {
+        self.push_str(s).map_err(|_| ::core::fmt::Error)
+    }
+    fn write_char(&mut self, c: char) -> ::core::fmt::Result {
+        self.push(c).map_err(|_| ::core::fmt::Error)
+    }
+    fn write_fmt(&mut self, args: ::core::fmt::Arguments<'_>) -> ::core::fmt::Result {
+        let before = *self;
+        ::core::fmt::write(self, args).inspect_err(|_| *self = before)
+    }
+}
+impl CentiString {
+    /**Creates a string from the given [`Arguments`](::core::fmt::Arguments).
+
+See also [`centi_format!`](crate::centi_format).
+*/
+    pub fn try_from_fmt(
+        args: ::core::fmt::Arguments<'_>,
+    ) -> Result<Self, CentiStringError> {
+        struct Len(usize);
+        impl ::core::fmt::Write for Len {
+            fn write_str(&mut self, s: &str) -> ::core::fmt::Result {
+                self.0 += s.len();
+                Ok(())
+            }
+        }
+        let mut s = Self::EMPTY;
+        match ::core::fmt::Write::write_fmt(&mut s, args) {
+            Ok(()) => Ok(s),
+            Err(_) => {
+                let mut len = Len(0);
+                let _ = ::core::fmt::Write::write_fmt(&mut len, args);
+                Err(CentiStringError::new(crate::ErrorKind::TooLong, len.0))
+            }
+        }
+    }
+}
+/**Creates a [`CentiString`] using interpolation of runtime expressions,
+like [`format!`](https://doc.rust-lang.org/std/macro.format.html).
+
+Returns an error if the result would be longer than 31 bytes.
+```
+# use microstring::*;
+assert_eq!(centi_format!("{}", "the quick brown fox").unwrap(), "the quick brown fox");
+assert!(centi_format!("{}", "the quick brown fox jumps over the lazy dog").is_err());
+```
+*/
+#[macro_export]
+macro_rules! centi_format {
+    ($($arg:tt)*) => {
+        $crate::CentiString::try_from_fmt(::core::format_args!($($arg)*))
+    };
+}
+impl ::core::str::FromStr for CentiString {
+    type Err = CentiStringError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s).ok_or(CentiStringError::new(crate::ErrorKind::TooLong, s.len()))
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for CentiString {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(self)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CentiString {
+    fn deserialize<D: serde::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        struct Visitor;
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = CentiString;
+            fn expecting(
+                &self,
+                f: &mut ::core::fmt::Formatter<'_>,
+            ) -> ::core::fmt::Result {
+                f.write_str("a string of at most 31 bytes")
+            }
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<CentiString, E> {
+                CentiString::new(v)
+                    .ok_or_else(|| serde::de::Error::invalid_length(v.len(), &self))
+            }
+        }
+        d.deserialize_str(Visitor)
+    }
+}
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for CentiString {
+    fn schema_name() -> ::std::borrow::Cow<'static, str> {
+        ::std::borrow::Cow::Borrowed(::core::stringify!(CentiString))
+    }
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema! {
+            { "type" : "string", "maxLength" : 31u8, }
+        }
+    }
+    fn schema_id() -> ::std::borrow::Cow<'static, str> {
+        ::std::borrow::Cow::Borrowed(
+            ::core::concat!(
+                ::core::module_path!(), "::", ::core::stringify!(CentiString)
+            ),
+        )
+    }
+}
+/**Generates strings of every length up to 31 bytes, including multi-byte [`char`]s.
+```
+# use microstring::*;
+use arbitrary::{Arbitrary, Unstructured};
+
+// an empty input consumes nothing
+assert_eq!(CentiString::arbitrary(&mut Unstructured::new(&[])).unwrap(), "");
+let (min, max) = CentiString::size_hint(0);
+assert_eq!(min, 0);
+let max = max.unwrap();
+
+let mut lens = [false; 31 + 1];
+let mut multi_byte = false;
+for seed in 0..=u8::MAX {
+    // the first byte chooses the length
+    let mut state = u32::from(seed) << 8 | 1;
+    let data = [seed].into_iter().chain((1..max).map(|_| {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        state as u8
+    })).collect::<Vec<_>>();
+    let mut u = Unstructured::new(&data);
+    let s = CentiString::arbitrary(&mut u).unwrap();
+    assert!(min <= data.len() - u.len() && data.len() - u.len() <= max);
+    assert!(str::from_utf8(s.as_bytes()).is_ok());
+    assert_eq!(s.to_bits(), CentiString::new(&s).unwrap().to_bits());
+    lens[s.len()] = true;
+    multi_byte |= s.chars().any(|c| c.len_utf8() > 1);
+}
+assert!(lens.iter().all(|it| *it) && multi_byte);
+```
+*/
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for CentiString {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let len = u.int_in_range(0..=31u8)? as usize;
+        let mut it = Self::EMPTY;
+        while (it.len as u8 as usize) < len {
+            let remaining = len - it.len as u8 as usize;
+            let width = u.int_in_range(1..=remaining.min(4))?;
+            let _ = it.push(crate::__private::arbitrary_char(u, width)?);
+        }
+        Ok(it)
+    }
+    fn size_hint(_: usize) -> (usize, Option<usize>) {
+        (0, Some(1 + 2 * 31u8 as usize))
+    }
+}
+impl crate::Lookup<CentiString> for CentiString {
+    fn lookup(&self) -> Option<CentiString> {
+        Some(*self)
+    }
+}
+impl crate::Lookup<CentiString> for str {
+    fn lookup(&self) -> Option<CentiString> {
+        CentiString::new(self)
+    }
+}
+impl crate::Lookup<CentiString> for &str {
+    fn lookup(&self) -> Option<CentiString> {
+        CentiString::new(self)
+    }
+}
+#[cfg(feature = "alloc")]
+impl crate::Lookup<CentiString> for ::alloc::string::String {
+    fn lookup(&self) -> Option<CentiString> {
+        CentiString::new(self)
+    }
+}
+#[cfg(feature = "alloc")]
+impl crate::Lookup<CentiString> for ::alloc::borrow::Cow<'_, str> {
+    fn lookup(&self) -> Option<CentiString> {
+        CentiString::new(self)
+    }
+}
+#[cfg(feature = "alloc")]
+impl crate::Lookup<CentiString> for ::alloc::boxed::Box<str> {
+    fn lookup(&self) -> Option<CentiString> {
+        CentiString::new(self)
+    }
+}
+/**A hash map keyed by [`CentiString`].
+
+Keys are stored and hashed as their packed [`to_bits`](CentiString::to_bits) representation,
+using [`BuildWordHasher`](crate::BuildWordHasher).
+Entries may also be looked up by [`str`] and [`String`](std::string::String).
+```
+# use microstring::*;
+let mut map = CentiMap::new();
+map.insert(CentiString::new("the quick brown fox").unwrap(), 1);
+assert_eq!(map.get("the quick brown fox"), Some(&1));
+assert_eq!(map.get(&CentiString::new("the quick brown fox").unwrap()), Some(&1));
+assert_eq!(map.get(&String::from("the quick brown fox")), Some(&1));
+assert_eq!(map.get("the quick brown fox jumps over the lazy dog"), None);
+
+*map.entry(CentiString::new("the quick brown fox").unwrap()).or_default() += 1;
+assert_eq!(map.into_iter().collect::<Vec<_>>(), [(CentiString::new("the quick brown fox").unwrap(), 2)]);
+```
+*/
+#[cfg(feature = "std")]
+#[derive(Clone)]
+pub struct CentiMap<V> {
+    inner: ::std::collections::HashMap<
+        [u8; 31u8 as usize + 1],
+        V,
+        crate::BuildWordHasher,
+    >,
+}
+#[cfg(feature = "std")]
+impl<V> CentiMap<V> {
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            inner: ::std::collections::HashMap::with_capacity_and_hasher(
+                capacity,
+                crate::BuildWordHasher,
+            ),
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+    pub fn clear(&mut self) {
+        self.inner.clear()
+    }
+    pub fn insert(&mut self, key: CentiString, value: V) -> Option<V> {
+        self.inner.insert(key.to_bits(), value)
+    }
+    /// Gets the entry for the given key, to insert or update it with a single probe.
+    pub fn entry(&mut self, key: CentiString) -> crate::Entry<'_, CentiString, V> {
+        crate::Entry::__new(&mut self.inner, key)
+    }
+    pub fn get<Q: ?Sized + crate::Lookup<CentiString>>(&self, key: &Q) -> Option<&V> {
+        self.inner.get(&key.lookup()?.to_bits())
+    }
+    pub fn get_mut<Q: ?Sized + crate::Lookup<CentiString>>(
+        &mut self,
+        key: &Q,
+    ) -> Option<&mut V> {
+        self.inner.get_mut(&key.lookup()?.to_bits())
+    }
+    pub fn contains_key<Q: ?Sized + crate::Lookup<CentiString>>(&self, key: &Q) -> bool {
+        self.get(key).is_some()
+    }
+    pub fn remove<Q: ?Sized + crate::Lookup<CentiString>>(
+        &mut self,
+        key: &Q,
+    ) -> Option<V> {
+        self.inner.remove(&key.lookup()?.to_bits())
+    }
+    pub fn iter(&self) -> impl Iterator<Item = (CentiString, &V)> {
+        self.inner
+            .iter()
+            .map(|(k, v)| (unsafe { CentiString::from_bits_unchecked(*k) }, v))
+    }
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (CentiString, &mut V)> {
+        self.inner
+            .iter_mut()
+            .map(|(k, v)| (unsafe { CentiString::from_bits_unchecked(*k) }, v))
+    }
+    pub fn keys(&self) -> impl Iterator<Item = CentiString> {
+        self.inner.keys().map(|k| unsafe { CentiString::from_bits_unchecked(*k) })
+    }
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.inner.values()
+    }
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut V> {
+        self.inner.values_mut()
+    }
+}
+#[cfg(feature = "std")]
+impl<V> ::core::default::Default for CentiMap<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+#[cfg(feature = "std")]
+impl<V: ::core::fmt::Debug> ::core::fmt::Debug for CentiMap<V> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+#[cfg(feature = "std")]
+impl<V: ::core::cmp::PartialEq> ::core::cmp::PartialEq for CentiMap<V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+#[cfg(feature = "std")]
+impl<V: ::core::cmp::Eq> ::core::cmp::Eq for CentiMap<V> {}
+#[cfg(feature = "std")]
+impl<V> ::core::iter::Extend<(CentiString, V)> for CentiMap<V> {
+    fn extend<I: IntoIterator<Item = (CentiString, V)>>(&mut self, iter: I) {
+        self.inner.extend(iter.into_iter().map(|(k, v)| (k.to_bits(), v)))
+    }
+}
+#[cfg(feature = "std")]
+impl<V> ::core::iter::IntoIterator for CentiMap<V> {
+    type Item = (CentiString, V);
+    type IntoIter = ::core::iter::Map<
+        ::std::collections::hash_map::IntoIter<[u8; 31u8 as usize + 1], V>,
+        fn(([u8; 31u8 as usize + 1], V)) -> (CentiString, V),
+    >;
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner
+            .into_iter()
+            .map(|(k, v)| (unsafe { CentiString::from_bits_unchecked(k) }, v))
+    }
+}
+#[cfg(feature = "std")]
+impl<'a, V> ::core::iter::IntoIterator for &'a CentiMap<V> {
+    type Item = (CentiString, &'a V);
+    type IntoIter = ::core::iter::Map<
+        ::std::collections::hash_map::Iter<'a, [u8; 31u8 as usize + 1], V>,
+        fn((&'a [u8; 31u8 as usize + 1], &'a V)) -> (CentiString, &'a V),
+    >;
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner
+            .iter()
+            .map(|(k, v)| (unsafe { CentiString::from_bits_unchecked(*k) }, v))
+    }
+}
+#[cfg(feature = "std")]
+impl<'a, V> ::core::iter::IntoIterator for &'a mut CentiMap<V> {
+    type Item = (CentiString, &'a mut V);
+    type IntoIter = ::core::iter::Map<
+        ::std::collections::hash_map::IterMut<'a, [u8; 31u8 as usize + 1], V>,
+        fn((&'a [u8; 31u8 as usize + 1], &'a mut V)) -> (CentiString, &'a mut V),
+    >;
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner
+            .iter_mut()
+            .map(|(k, v)| (unsafe { CentiString::from_bits_unchecked(*k) }, v))
+    }
+}
+#[cfg(feature = "std")]
+impl<V> ::core::iter::FromIterator<(CentiString, V)> for CentiMap<V> {
+    fn from_iter<I: IntoIterator<Item = (CentiString, V)>>(iter: I) -> Self {
+        let mut it = Self::new();
+        it.extend(iter);
+        it
+    }
+}
+#[cfg(feature = "const-default")]
+impl const_default::ConstDefault for CentiString {
+    const DEFAULT: Self = CentiString::EMPTY;
+}
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u8)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout,
+        zerocopy::Unaligned
+    )
+)]
+enum CentiStringLen {
+    _0 = 0u8,
+    _1 = 1u8,
+    _2 = 2u8,
+    _3 = 3u8,
+    _4 = 4u8,
+    _5 = 5u8,
+    _6 = 6u8,
+    _7 = 7u8,
+    _8 = 8u8,
+    _9 = 9u8,
+    _10 = 10u8,
+    _11 = 11u8,
+    _12 = 12u8,
+    _13 = 13u8,
+    _14 = 14u8,
+    _15 = 15u8,
+    _16 = 16u8,
+    _17 = 17u8,
+    _18 = 18u8,
+    _19 = 19u8,
+    _20 = 20u8,
+    _21 = 21u8,
+    _22 = 22u8,
+    _23 = 23u8,
+    _24 = 24u8,
+    _25 = 25u8,
+    _26 = 26u8,
+    _27 = 27u8,
+    _28 = 28u8,
+    _29 = 29u8,
+    _30 = 30u8,
+    _31 = 31u8,
+}
+impl CentiStringLen {
+    const fn from_usize(u: usize) -> Option<Self> {
+        const U8_MAX: usize = u8::MAX as _;
+        if u > U8_MAX {
+            return None;
+        }
+        Self::from_u8(u as u8)
+    }
+    const fn from_u8(u: u8) -> Option<Self> {
+        match u {
+            0u8 => Some(Self::_0),
+            1u8 => Some(Self::_1),
+            2u8 => Some(Self::_2),
+            3u8 => Some(Self::_3),
+            4u8 => Some(Self::_4),
+            5u8 => Some(Self::_5),
+            6u8 => Some(Self::_6),
+            7u8 => Some(Self::_7),
+            8u8 => Some(Self::_8),
+            9u8 => Some(Self::_9),
+            10u8 => Some(Self::_10),
+            11u8 => Some(Self::_11),
+            12u8 => Some(Self::_12),
+            13u8 => Some(Self::_13),
+            14u8 => Some(Self::_14),
+            15u8 => Some(Self::_15),
+            16u8 => Some(Self::_16),
+            17u8 => Some(Self::_17),
+            18u8 => Some(Self::_18),
+            19u8 => Some(Self::_19),
+            20u8 => Some(Self::_20),
+            21u8 => Some(Self::_21),
+            22u8 => Some(Self::_22),
+            23u8 => Some(Self::_23),
+            24u8 => Some(Self::_24),
+            25u8 => Some(Self::_25),
+            26u8 => Some(Self::_26),
+            27u8 => Some(Self::_27),
+            28u8 => Some(Self::_28),
+            29u8 => Some(Self::_29),
+            30u8 => Some(Self::_30),
+            31u8 => Some(Self::_31),
+            _ => None,
+        }
+    }
+}
+/**A stack-allocated string which can hold up to 63 UTF-8 encoded bytes.
+
+With the `zerocopy` feature, this implements `IntoBytes`, `Immutable`,
+`KnownLayout` and `Unaligned`.
+`TryFromBytes` is deliberately not implemented, because its derive cannot check
+that the bytes are UTF-8 followed by zero padding,
+and neither is `FromZeros`, which requires it.
+Use [`try_ref_from_bytes`](DeciString::try_ref_from_bytes)
+or [`try_slice_from_bytes`](DeciString::try_slice_from_bytes) instead.
+```
+# use core::mem::size_of;
+# use microstring::*;
+assert_eq! {
+    size_of::<DeciString>(),
+    63 + 1,
+}
+assert_eq! {
+    size_of::<DeciString>(),
+    size_of::<Option<DeciString>>(),
+}
+```
+*/
+#[derive(Clone, Copy, Eq)]
+#[repr(C)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout,
+        zerocopy::Unaligned
+    )
+)]
+pub struct DeciString {
+    len: DeciStringLen,
+    bytes: [u8; 63u8 as _],
+}
+impl DeciString {
+    pub const EMPTY: Self = Self::new("").unwrap();
+    /**Returns [`None`] if the given <code>[str::len()] > 63</code>.
+```
+# use microstring::*;
+const STRING: DeciString = DeciString::new("the quick brown fox jumps over the lazy dog").unwrap();
+```
+```compile_fail
+# use microstring::*;
+const TOO_BIG: DeciString = DeciString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home").unwrap();
+```
+*/
+    pub const fn new(s: &str) -> Option<Self> {
+        match DeciStringLen::from_usize(s.len()) {
+            Some(len) => {
+                let mut bytes = [0; 63u8 as _];
+                unsafe {
+                    ::core::ptr::copy_nonoverlapping(
+                        s.as_ptr(),
+                        bytes.as_mut_ptr(),
+                        s.len(),
+                    )
+                }
+                Some(Self { len, bytes })
+            }
+            None => None,
+        }
+    }
+    pub const fn as_str(&self) -> &str {
+        unsafe {
+            str::from_utf8_unchecked(
+                ::core::slice::from_raw_parts(
+                    self.bytes.as_ptr(),
+                    self.len as u8 as usize,
+                ),
+            )
+        }
+    }
+    pub const fn as_mut_str(&mut self) -> &mut str {
+        unsafe {
+            str::from_utf8_unchecked_mut(
+                ::core::slice::from_raw_parts_mut(
+                    self.bytes.as_mut_ptr(),
+                    self.len as u8 as usize,
+                ),
+            )
+        }
+    }
+    /**Returns an error if the given bytes are not UTF-8, or if <code>[slice::len()] > 63</code>.
+```
+# use microstring::*;
+const STRING: DeciString = match DeciString::from_utf8(b"the quick brown fox jumps over the lazy dog") {
+    Ok(it) => it,
+    Err(_) => panic!(),
+};
+assert_eq!(DeciString::from_utf8(b"\xFF").unwrap_err().kind(), ErrorKind::InvalidUtf8);
+assert_eq!(DeciString::from_utf8(b"the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home").unwrap_err().kind(), ErrorKind::TooLong);
+```
+*/
+    pub const fn from_utf8(bytes: &[u8]) -> Result<Self, DeciStringError> {
+        if bytes.len() > 63u8 as usize {
+            return Err(DeciStringError::new(crate::ErrorKind::TooLong, bytes.len()));
+        }
+        match ::core::str::from_utf8(bytes) {
+            Ok(s) => {
+                match Self::new(s) {
+                    Some(it) => Ok(it),
+                    None => {
+                        Err(DeciStringError::new(crate::ErrorKind::TooLong, bytes.len()))
+                    }
+                }
+            }
+            Err(_) => {
+                Err(DeciStringError::new(crate::ErrorKind::InvalidUtf8, bytes.len()))
+            }
+        }
+    }
+    /**Returns [`None`] if <code>[slice::len()] > 63</code>.
+
+# Safety
+- The given bytes must be valid UTF-8.
+*/
+    pub const unsafe fn from_utf8_unchecked(bytes: &[u8]) -> Option<Self> {
+        Self::new(unsafe { str::from_utf8_unchecked(bytes) })
+    }
+    /**Truncates the given string to at most 63 bytes, on a [`char`] boundary.
+```
+# use microstring::*;
+const STRING: DeciString = DeciString::new_truncating("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home");
+assert_eq!(STRING, "the quick brown fox jumps over the lazy dog, then the lazy dog ");
+```
+*/
+    pub const fn new_truncating(s: &str) -> Self {
+        let mut end = if s.len() < 63u8 as usize { s.len() } else { 63u8 as usize };
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+        match Self::new(s.split_at(end).0) {
+            Some(it) => it,
+            None => unreachable!(),
+        }
+    }
+    /**If the given string is longer than 63 bytes, truncates it on a [`char`] boundary,
+leaving room to append the given `marker`.
+
+# Panics
+- If the string needs truncating, and <code>marker.[len()](str::len) > 63</code>.
+```
+# use microstring::*;
+const STRING: DeciString = DeciString::new_truncating_with_marker("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home", "…");
+assert_eq!(STRING, "the quick brown fox jumps over the lazy dog, then the lazy d…");
+```
+*/
+    pub const fn new_truncating_with_marker(s: &str, marker: &str) -> Self {
+        if let Some(it) = Self::new(s) {
+            return it;
+        }
+        assert!(marker.len() <= 63u8 as usize, "marker is longer than the capacity");
+        let mut end = 63u8 as usize - marker.len();
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+        let mut it = Self::new_truncating(s.split_at(end).0);
+        match it.push_str(marker) {
+            Ok(()) => it,
+            Err(_) => unreachable!(),
+        }
+    }
+    /// Like [`new_truncating`](Self::new_truncating),
+    /// but never splits a grapheme cluster.
+    #[cfg(feature = "unicode-segmentation")]
+    pub fn new_truncating_graphemes(s: &str) -> Self {
+        use unicode_segmentation::UnicodeSegmentation as _;
+        let end = s
+            .grapheme_indices(true)
+            .map(|(ix, grapheme)| ix + grapheme.len())
+            .take_while(|end| *end <= 63u8 as usize)
+            .last()
+            .unwrap_or(0);
+        Self::new_truncating(&s[..end])
+    }
+}
+/**The error returned when a [`DeciString`] cannot be constructed.
+```
+# use microstring::*;
+let e = "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home".parse::<DeciString>().unwrap_err();
+assert_eq!(e.kind(), ErrorKind::TooLong);
+assert_eq!(e.input_len(), 94);
+assert_eq!(e.to_string(), "expected a string of at most 63 bytes, but got 94 bytes");
+```
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DeciStringError {
+    kind: crate::ErrorKind,
+    len: usize,
+}
+impl DeciStringError {
+    const fn new(kind: crate::ErrorKind, len: usize) -> Self {
+        Self { kind, len }
+    }
+    /// Why the input was rejected.
+    pub const fn kind(&self) -> crate::ErrorKind {
+        self.kind
+    }
+    /// The length of the rejected input, in bytes.
+    ///
+    /// For operations which append to an existing string,
+    /// this is the length the string would have had.
+    pub const fn input_len(&self) -> usize {
+        self.len
+    }
+    /// The maximum length of the string being constructed, in bytes.
+    pub const fn capacity(&self) -> usize {
+        63u8 as usize
+    }
+}
+impl ::core::fmt::Display for DeciStringError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let Self { kind, len } = *self;
+        match kind {
+            crate::ErrorKind::TooLong => {
+                write!(f, "expected a string of at most 63 bytes, but got {len} bytes")
+            }
+            crate::ErrorKind::InvalidUtf8 => {
+                write!(
+                    f,
+                    "expected a UTF-8 string of at most 63 bytes, but got {len} bytes of invalid UTF-8"
+                )
+            }
+            crate::ErrorKind::Invalid => {
+                write!(f, "a string of {len} bytes failed validation")
+            }
+        }
+    }
+}
+impl ::core::error::Error for DeciStringError {}
+impl DeciString {
+    /// The maximum length, in bytes.
+    pub const CAPACITY: usize = 63u8 as usize;
+    /// The length of this string, in bytes.
+    pub const fn len(&self) -> usize {
+        self.len as u8 as usize
+    }
+    /// Returns `true` if this string has a length of zero.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// The number of bytes which may be pushed before this string is full.
+    pub const fn remaining_capacity(&self) -> usize {
+        Self::CAPACITY - self.len()
+    }
+    /// Equivalent to [`str::as_bytes`], but usable in `const`.
+    ///
+    /// Unlike `as_bytes`, this does not shadow
+    /// [`zerocopy::IntoBytes::as_bytes`](https://docs.rs/zerocopy/latest/zerocopy/trait.IntoBytes.html#method.as_bytes),
+    /// which returns the length as well.
+    pub const fn const_as_bytes(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+    /// Equivalent to `==`, but usable in `const`.
+    pub const fn const_eq(&self, other: &str) -> bool {
+        self.len() == other.len() && self.const_cmp(other).is_eq()
+    }
+    /// Equivalent to [`Ord::cmp`], but usable in `const`.
+    pub const fn const_cmp(&self, other: &str) -> ::core::cmp::Ordering {
+        crate::__private::cmp_bytes(self.const_as_bytes(), other.as_bytes())
+    }
+    /// Equivalent to [`str::starts_with`] with a [`str`] pattern, but usable in `const`.
+    pub const fn const_starts_with(&self, prefix: &str) -> bool {
+        match prefix.len() <= self.len() {
+            true => {
+                let (head, _) = self.const_as_bytes().split_at(prefix.len());
+                crate::__private::cmp_bytes(head, prefix.as_bytes()).is_eq()
+            }
+            false => false,
+        }
+    }
+    /// Equivalent to [`str::ends_with`] with a [`str`] pattern, but usable in `const`.
+    pub const fn const_ends_with(&self, suffix: &str) -> bool {
+        match self.len().checked_sub(suffix.len()) {
+            Some(mid) => {
+                let (_, tail) = self.const_as_bytes().split_at(mid);
+                crate::__private::cmp_bytes(tail, suffix.as_bytes()).is_eq()
+            }
+            None => false,
+        }
+    }
+    /// Converts this string to its ASCII upper case equivalent in-place.
+    pub const fn make_ascii_uppercase(&mut self) {
+        self.as_mut_str().make_ascii_uppercase()
+    }
+    /// Converts this string to its ASCII lower case equivalent in-place.
+    pub const fn make_ascii_lowercase(&mut self) {
+        self.as_mut_str().make_ascii_lowercase()
+    }
+    /**Returns a copy of this string, with ASCII letters converted to upper case.
+
+Other bytes are unchanged.
+```
+# use microstring::*;
+const UPPER: DeciString = DeciString::new("the quick brown fox jumps over the lazy dog").unwrap().to_ascii_uppercase();
+assert_eq!(UPPER, "THE QUICK BROWN FOX JUMPS OVER THE LAZY DOG");
+for s in ["@AZ[`az{é"] {
+    assert_eq!(DeciString::new(s).unwrap().to_ascii_uppercase(), s.to_ascii_uppercase().as_str());
+}
+```
+*/
+    pub const fn to_ascii_uppercase(mut self) -> Self {
+        self.make_ascii_uppercase();
+        self
+    }
+    /**Returns a copy of this string, with ASCII letters converted to lower case.
+
+Other bytes are unchanged.
+```
+# use microstring::*;
+const LOWER: DeciString = DeciString::new("THE QUICK BROWN FOX JUMPS OVER THE LAZY DOG").unwrap().to_ascii_lowercase();
+assert_eq!(LOWER, "the quick brown fox jumps over the lazy dog");
+for s in ["@AZ[`az{é"] {
+    assert_eq!(DeciString::new(s).unwrap().to_ascii_lowercase(), s.to_ascii_lowercase().as_str());
+}
+```
+*/
+    pub const fn to_ascii_lowercase(mut self) -> Self {
+        self.make_ascii_lowercase();
+        self
+    }
+}
+impl DeciString {
+    /// Appends the given [`char`] to the end of this string.
+    ///
+    /// Returns an error, leaving this string unchanged, if there is not enough capacity.
+    pub const fn push(&mut self, c: char) -> Result<(), DeciStringError> {
+        self.push_str(c.encode_utf8(&mut [0; 4]))
+    }
+    /**Appends the given string to the end of this one.
+
+Returns an error, leaving this string unchanged, if the result would be
+longer than 63 bytes.
+```
+# use microstring::*;
+let mut string = DeciString::new("the quick brown fox jumps over the lazy dog").unwrap();
+assert!(string.push_str("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home").is_err());
+assert_eq!(string, "the quick brown fox jumps over the lazy dog");
+```
+*/
+    pub const fn push_str(&mut self, s: &str) -> Result<(), DeciStringError> {
+        let len = self.len as u8 as usize;
+        match DeciStringLen::from_usize(len + s.len()) {
+            Some(new_len) => {
+                unsafe {
+                    ::core::ptr::copy_nonoverlapping(
+                        s.as_ptr(),
+                        self.bytes.as_mut_ptr().add(len),
+                        s.len(),
+                    )
+                }
+                self.len = new_len;
+                Ok(())
+            }
+            None => Err(DeciStringError::new(crate::ErrorKind::TooLong, len + s.len())),
+        }
+    }
+    /**Concatenates the given strings.
+
+Returns an error if the result would be longer than 63 bytes.
+```
+# use microstring::*;
+assert_eq!(DeciString::try_concat("the quick brown fox jumps over the lazy dog", "").unwrap(), "the quick brown fox jumps over the lazy dog");
+assert!(DeciString::try_concat("the quick brown fox jumps over the lazy dog", "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home").is_err());
+```
+*/
+    pub const fn try_concat(left: &str, right: &str) -> Result<Self, DeciStringError> {
+        match Self::new(left) {
+            Some(mut it) => {
+                match it.push_str(right) {
+                    Ok(()) => Ok(it),
+                    Err(e) => Err(e),
+                }
+            }
+            None => {
+                Err(
+                    DeciStringError::new(
+                        crate::ErrorKind::TooLong,
+                        left.len() + right.len(),
+                    ),
+                )
+            }
+        }
+    }
+    /**Removes the last [`char`] from this string and returns it,
+or [`None`] if this string is empty.
+```
+# use microstring::*;
+let mut string = DeciString::new("aé").unwrap();
+assert_eq!(string.pop(), Some('é'));
+assert_eq!(string.to_bits(), DeciString::new("a").unwrap().to_bits());
+assert_eq!(string.pop(), Some('a'));
+assert_eq!(string.pop(), None);
+assert_eq!(string.to_bits(), DeciString::EMPTY.to_bits());
+```
+*/
+    pub fn pop(&mut self) -> Option<char> {
+        let c = self.as_str().chars().next_back()?;
+        unsafe { self.set_len(self.len as u8 as usize - c.len_utf8()) }
+        Some(c)
+    }
+    /**Shortens this string to `new_len` bytes.
+
+Has no effect if `new_len` is greater than the string's current length.
+
+# Panics
+- If `new_len` does not lie on a [`char`] boundary.
+```
+# use microstring::*;
+let mut string = DeciString::new("aé").unwrap();
+string.truncate(3);
+assert_eq!(string, "aé");
+string.truncate(1);
+assert_eq!(string.to_bits(), DeciString::new("a").unwrap().to_bits());
+```
+```should_panic
+# use microstring::*;
+DeciString::new("é").unwrap().truncate(1);
+```
+*/
+    pub const fn truncate(&mut self, new_len: usize) {
+        if new_len < self.len as u8 as usize {
+            assert!(
+                self.as_str().is_char_boundary(new_len),
+                "new_len does not lie on a char boundary"
+            );
+            unsafe { self.set_len(new_len) }
+        }
+    }
+    /**Truncates this string to the empty string.
+```
+# use microstring::*;
+let mut string = DeciString::new("the quick brown fox jumps over the lazy dog").unwrap();
+string.clear();
+assert_eq!(string.to_bits(), DeciString::EMPTY.to_bits());
+```
+*/
+    pub const fn clear(&mut self) {
+        unsafe { self.set_len(0) }
+    }
+    /// Inserts the given [`char`] at byte position `idx`.
+    ///
+    /// Returns an error, leaving this string unchanged, if there is not enough capacity.
+    ///
+    /// # Panics
+    /// - If `idx` is out of bounds, or does not lie on a [`char`] boundary.
+    pub const fn insert(&mut self, idx: usize, c: char) -> Result<(), DeciStringError> {
+        self.insert_str(idx, c.encode_utf8(&mut [0; 4]))
+    }
+    /**Inserts the given string at byte position `idx`.
+
+Returns an error, leaving this string unchanged, if there is not enough capacity.
+
+# Panics
+- If `idx` is out of bounds, or does not lie on a [`char`] boundary.
+```
+# use microstring::*;
+let mut string = DeciString::new("a").unwrap();
+string.insert_str(0, "é").unwrap();
+assert_eq!(string, "éa");
+assert!(string.insert_str(2, "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home").is_err());
+assert_eq!(string.to_bits(), DeciString::new("éa").unwrap().to_bits());
+```
+```should_panic
+# use microstring::*;
+let _ = DeciString::new("é").unwrap().insert_str(1, "a");
+```
+*/
+    pub const fn insert_str(
+        &mut self,
+        idx: usize,
+        s: &str,
+    ) -> Result<(), DeciStringError> {
+        assert!(
+            self.as_str().is_char_boundary(idx), "idx does not lie on a char boundary"
+        );
+        let len = self.len as u8 as usize;
+        match DeciStringLen::from_usize(len + s.len()) {
+            Some(new_len) => {
+                unsafe {
+                    let p = self.bytes.as_mut_ptr();
+                    ::core::ptr::copy(p.add(idx), p.add(idx + s.len()), len - idx);
+                    ::core::ptr::copy_nonoverlapping(s.as_ptr(), p.add(idx), s.len());
+                }
+                self.len = new_len;
+                Ok(())
+            }
+            None => Err(DeciStringError::new(crate::ErrorKind::TooLong, len + s.len())),
+        }
+    }
+    /**Removes the [`char`] at byte position `idx` and returns it.
+
+# Panics
+- If `idx` is out of bounds, or does not lie on a [`char`] boundary.
+```
+# use microstring::*;
+let mut string = DeciString::new("éa").unwrap();
+assert_eq!(string.remove(0), 'é');
+assert_eq!(string.to_bits(), DeciString::new("a").unwrap().to_bits());
+```
+```should_panic
+# use microstring::*;
+DeciString::new("é").unwrap().remove(1);
+```
+*/
+    pub fn remove(&mut self, idx: usize) -> char {
+        let Some(c) = self.as_str()[idx..].chars().next() else {
+            panic!("cannot remove a char from the end of a string")
+        };
+        let len = self.len as u8 as usize;
+        let next = idx + c.len_utf8();
+        unsafe {
+            let p = self.bytes.as_mut_ptr();
+            ::core::ptr::copy(p.add(next), p.add(idx), len - next);
+            self.set_len(len - c.len_utf8());
+        }
+        c
+    }
+    /**Retains only the [`char`]s for which `f` returns `true`.
+```
+# use microstring::*;
+let mut string = DeciString::new("éa").unwrap();
+string.retain(|c| c.is_ascii());
+assert_eq!(string.to_bits(), DeciString::new("a").unwrap().to_bits());
+```
+*/
+    pub fn retain(&mut self, mut f: impl FnMut(char) -> bool) {
+        let this = *self;
+        self.clear();
+        for c in this.as_str().chars() {
+            if f(c) {
+                let _ = self.push(c);
+            }
+        }
+    }
+    /// Sets the length, zeroing any bytes past it.
+    ///
+    /// # Safety
+    /// - `new_len` must be at most the current length.
+    /// - `new_len` must lie on a [`char`] boundary.
+    const unsafe fn set_len(&mut self, new_len: usize) {
+        let len = self.len as u8 as usize;
+        unsafe {
+            ::core::ptr::write_bytes(
+                self.bytes.as_mut_ptr().add(new_len),
+                0,
+                len - new_len,
+            )
+        }
+        self.len = match DeciStringLen::from_usize(new_len) {
+            Some(it) => it,
+            None => unreachable!(),
+        };
+    }
+}
+impl DeciString {
+    /**Collects the given [`char`]s into a string.
+
+Returns an error if there are more than 63 bytes of them.
+See also [`Collected`](crate::Collected).
+```
+# use microstring::*;
+assert_eq!(DeciString::try_from_iter("the quick brown fox jumps over the lazy dog".chars()).unwrap(), "the quick brown fox jumps over the lazy dog");
+assert!(DeciString::try_from_iter("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home".chars()).is_err());
+```
+*/
+    pub fn try_from_iter<I: IntoIterator<Item = char>>(
+        iter: I,
+    ) -> Result<Self, DeciStringError> {
+        let mut it = Self::EMPTY;
+        for c in iter {
+            it.push(c)?;
+        }
+        Ok(it)
+    }
+    /**Returns an owning iterator over the bytes of this string.
+```
+# use microstring::*;
+let string = DeciString::new("the quick brown fox jumps over the lazy dog").unwrap();
+assert!(string.into_bytes().eq("the quick brown fox jumps over the lazy dog".bytes()));
+assert_eq!(string.into_bytes().len(), 43);
+```
+*/
+    pub fn into_bytes(self) -> crate::IntoBytes<Self> {
+        crate::IntoBytes::new(self)
+    }
+}
+/**An owning iterator over the [`char`]s of this string.
+```
+# use microstring::*;
+let string = DeciString::new("the quick brown fox jumps over the lazy dog").unwrap();
+assert!(string.into_iter().rev().eq("the quick brown fox jumps over the lazy dog".chars().rev()));
+```
+*/
+impl ::core::iter::IntoIterator for DeciString {
+    type Item = char;
+    type IntoIter = crate::IntoChars<Self>;
+    fn into_iter(self) -> crate::IntoChars<Self> {
+        crate::IntoChars::new(self)
+    }
+}
+/// # Panics
+/// - If there is not enough capacity.
+///   See [`push`](Self::push) for a fallible alternative.
+impl ::core::iter::Extend<char> for DeciString {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        for c in iter {
+            if let Err(e) = self.push(c) {
+                panic!("{e}")
+            }
+        }
+    }
+}
+/// # Panics
+/// - If there is not enough capacity.
+///   See [`push_str`](Self::push_str) for a fallible alternative.
+impl<'a> ::core::iter::Extend<&'a str> for DeciString {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        for s in iter {
+            if let Err(e) = self.push_str(s) {
+                panic!("{e}")
+            }
+        }
+    }
+}
+impl DeciString {
+    /**Interprets the given bytes as a string, without copying.
+
+The bytes must be laid out as produced by `zerocopy::IntoBytes`:
+a length byte, followed by that many bytes of UTF-8, followed by zero padding.
+Returns [`None`] otherwise.
+
+This is the checked alternative to `zerocopy::TryFromBytes`,
+which is not implemented for this type because it cannot check the contents.
+```
+# use microstring::*;
+let mut bytes = [0; 63 + 1];
+bytes[0] = 43;
+bytes[1..][..43].copy_from_slice(b"the quick brown fox jumps over the lazy dog");
+assert_eq!(DeciString::try_ref_from_bytes(&bytes).unwrap().as_str(), "the quick brown fox jumps over the lazy dog");
+bytes[1] = 0xFF;
+assert_eq!(DeciString::try_ref_from_bytes(&bytes), None);
+```
+*/
+    pub const fn try_ref_from_bytes(bytes: &[u8]) -> Option<&Self> {
+        const { assert!(::core::mem::align_of:: < Self > () == 1) }
+        match Self::is_valid(bytes) {
+            true => Some(unsafe { &*bytes.as_ptr().cast::<Self>() }),
+            false => None,
+        }
+    }
+    /// Like [`try_ref_from_bytes`](Self::try_ref_from_bytes),
+    /// but for a contiguous run of strings.
+    pub const fn try_slice_from_bytes(bytes: &[u8]) -> Option<&[Self]> {
+        const { assert!(::core::mem::align_of:: < Self > () == 1) }
+        let size = ::core::mem::size_of::<Self>();
+        if !bytes.len().is_multiple_of(size) {
+            return None;
+        }
+        let mut rest = bytes;
+        while let Some((chunk, tail)) = rest.split_at_checked(size) {
+            if !Self::is_valid(chunk) {
+                return None;
+            }
+            rest = tail;
+        }
+        Some(unsafe {
+            ::core::slice::from_raw_parts(
+                bytes.as_ptr().cast::<Self>(),
+                bytes.len() / size,
+            )
+        })
+    }
+    /// Whether the given bytes are a length byte, that many bytes of UTF-8, and zero padding.
+    const fn is_valid(bytes: &[u8]) -> bool {
+        match bytes {
+            [len, rest @ ..] if rest.len() == 63u8 as usize => {
+                Self::is_valid_parts(*len, rest)
+            }
+            _ => false,
+        }
+    }
+    /// Whether `len` is in range, and `bytes` are that many bytes of UTF-8, followed by zero padding.
+    const fn is_valid_parts(len: u8, bytes: &[u8]) -> bool {
+        let Some(len) = DeciStringLen::from_u8(len) else {
+            return false;
+        };
+        let (string, mut padding) = bytes.split_at(len as u8 as usize);
+        if ::core::str::from_utf8(string).is_err() {
+            return false;
+        }
+        while let [0, tail @ ..] = padding {
+            padding = tail;
+        }
+        padding.is_empty()
+    }
+}
+impl DeciString {
+    /**Packs this string into a byte array.
+
+The string's bytes, zero padded to 63 bytes, come first,
+and its length is the last byte.
+This is stable, so may be persisted.
+Comparing the packed representations orders them the same as comparing the strings.
+```
+# use microstring::*;
+const BITS: [u8; 63 + 1] = DeciString::new("the quick brown fox jumps over the lazy dog").unwrap().to_bits();
+assert_eq!(BITS, *b"the quick brown fox jumps over the lazy dog\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x2b");
+assert_eq!(DeciString::from_bits(BITS), DeciString::new("the quick brown fox jumps over the lazy dog"));
+```
+*/
+    pub const fn to_bits(self) -> [u8; 63u8 as usize + 1] {
+        let mut bits = [0; 63u8 as usize + 1];
+        let (bytes, len) = bits.split_at_mut(63u8 as usize);
+        bytes.copy_from_slice(&self.bytes);
+        len[0] = self.len as u8;
+        bits
+    }
+    /// The inverse of [`to_bits`](Self::to_bits).
+    ///
+    /// Returns [`None`] if the length is out of range,
+    /// the string is not UTF-8, or the padding is not zero.
+    pub const fn from_bits(bits: [u8; 63u8 as usize + 1]) -> Option<Self> {
+        let [bytes @ .., len] = bits;
+        match Self::is_valid_parts(len, &bytes) {
+            true => Some(unsafe { Self::from_bits_unchecked(bits) }),
+            false => None,
+        }
+    }
+    /// The inverse of [`to_bits`](Self::to_bits), without any checks.
+    ///
+    /// # Safety
+    /// - `bits` must have been returned by [`to_bits`](Self::to_bits),
+    ///   or be accepted by [`from_bits`](Self::from_bits).
+    pub const unsafe fn from_bits_unchecked(bits: [u8; 63u8 as usize + 1]) -> Self {
+        let [bytes @ .., len] = bits;
+        Self {
+            len: unsafe { ::core::mem::transmute::<u8, DeciStringLen>(len) },
+            bytes,
+        }
+    }
+}
+impl crate::__private::Sealed for DeciString {}
+impl crate::InlineStr for DeciString {
+    const CAPACITY: usize = 63u8 as usize;
+    const EMPTY: Self = Self::EMPTY;
+    type Error = DeciStringError;
+    type Bits = [u8; 63u8 as usize + 1];
+    fn new(s: &str) -> Option<Self> {
+        Self::new(s)
+    }
+    fn as_str(&self) -> &str {
+        self.as_str()
+    }
+    fn as_mut_str(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+    fn from_utf8(bytes: &[u8]) -> Result<Self, DeciStringError> {
+        Self::from_utf8(bytes)
+    }
+    fn to_bits(self) -> [u8; 63u8 as usize + 1] {
+        self.to_bits()
+    }
+    fn from_bits(bits: [u8; 63u8 as usize + 1]) -> Option<Self> {
+        Self::from_bits(bits)
+    }
+    fn push_str(&mut self, s: &str) -> Result<(), DeciStringError> {
+        self.push_str(s)
+    }
+    fn try_from_iter<I: IntoIterator<Item = char>>(
+        iter: I,
+    ) -> Result<Self, DeciStringError> {
+        Self::try_from_iter(iter)
+    }
+    fn __error(kind: crate::ErrorKind, len: usize) -> DeciStringError {
+        DeciStringError::new(kind, len)
+    }
+}
+impl ::core::default::Default for DeciString {
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+impl ::core::default::Default for &DeciString {
+    fn default() -> Self {
+        &DeciString::EMPTY
+    }
+}
+/// Hashes the same as [`str`], as required by [`Borrow<str>`](::core::borrow::Borrow).
+impl ::core::hash::Hash for DeciString {
+    fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+impl ::core::convert::AsRef<Self> for DeciString {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+impl ::core::convert::AsMut<Self> for DeciString {
+    fn as_mut(&mut self) -> &mut Self {
+        self
+    }
+}
+impl ::core::convert::AsRef<str> for DeciString {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+impl ::core::convert::AsMut<str> for DeciString {
+    fn as_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+impl ::core::convert::AsRef<[u8]> for DeciString {
+    fn as_ref(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+}
+impl TryFrom<&str> for DeciString {
+    type Error = DeciStringError;
+    fn try_from(value: &str) -> Result<Self, DeciStringError> {
+        Self::new(value)
+            .ok_or(DeciStringError::new(crate::ErrorKind::TooLong, value.len()))
+    }
+}
+impl TryFrom<&[u8]> for DeciString {
+    type Error = DeciStringError;
+    fn try_from(value: &[u8]) -> Result<Self, DeciStringError> {
+        Self::from_utf8(value)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for DeciString {
+    type Error = DeciStringError;
+    fn try_from(value: [u8; N]) -> Result<Self, DeciStringError> {
+        Self::from_utf8(&value)
+    }
+}
+#[cfg(feature = "std")]
+impl ::core::convert::AsRef<::std::ffi::OsStr> for DeciString {
+    fn as_ref(&self) -> &::std::ffi::OsStr {
+        self.as_str().as_ref()
+    }
+}
+#[cfg(feature = "std")]
+impl ::core::convert::AsRef<::std::path::Path> for DeciString {
+    fn as_ref(&self) -> &::std::path::Path {
+        self.as_str().as_ref()
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::TryFrom<::alloc::string::String> for DeciString {
+    type Error = DeciStringError;
+    fn try_from(value: ::alloc::string::String) -> Result<Self, DeciStringError> {
+        Self::new(&value)
+            .ok_or(DeciStringError::new(crate::ErrorKind::TooLong, value.len()))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::From<DeciString> for ::alloc::string::String {
+    fn from(val: DeciString) -> Self {
+        Self::from(val.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::From<DeciString> for ::alloc::boxed::Box<str> {
+    fn from(val: DeciString) -> Self {
+        Self::from(val.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::From<DeciString> for ::alloc::rc::Rc<str> {
+    fn from(val: DeciString) -> Self {
+        Self::from(val.as_str())
+    }
+}
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl ::core::convert::From<DeciString> for ::alloc::sync::Arc<str> {
+    fn from(val: DeciString) -> Self {
+        Self::from(val.as_str())
+    }
+}
+#[cfg(feature = "std")]
+impl ::core::convert::From<DeciString> for ::std::ffi::OsString {
+    fn from(val: DeciString) -> Self {
+        Self::from(val.as_str())
+    }
+}
+#[cfg(feature = "std")]
+impl ::core::convert::From<DeciString> for ::std::path::PathBuf {
+    fn from(val: DeciString) -> Self {
+        Self::from(val.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::From<DeciString> for ::alloc::borrow::Cow<'static, str> {
+    fn from(val: DeciString) -> Self {
+        Self::Owned(::alloc::string::String::from(val))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::TryFrom<::alloc::boxed::Box<str>> for DeciString {
+    type Error = DeciStringError;
+    fn try_from(value: ::alloc::boxed::Box<str>) -> Result<Self, DeciStringError> {
+        Self::try_from(&*value)
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::TryFrom<::alloc::borrow::Cow<'_, str>> for DeciString {
+    type Error = DeciStringError;
+    fn try_from(value: ::alloc::borrow::Cow<'_, str>) -> Result<Self, DeciStringError> {
+        Self::try_from(&*value)
+    }
+}
+#[cfg(feature = "std")]
+impl ::core::convert::TryFrom<&::std::ffi::OsStr> for DeciString {
+    type Error = DeciStringError;
+    fn try_from(value: &::std::ffi::OsStr) -> Result<Self, DeciStringError> {
+        match value.to_str() {
+            Some(s) => Self::try_from(s),
+            None => Err(DeciStringError::new(crate::ErrorKind::InvalidUtf8, value.len())),
+        }
+    }
+}
+#[cfg(feature = "std")]
+impl ::core::convert::TryFrom<&::std::path::Path> for DeciString {
+    type Error = DeciStringError;
+    fn try_from(value: &::std::path::Path) -> Result<Self, DeciStringError> {
+        Self::try_from(value.as_os_str())
+    }
+}
+impl ::core::convert::TryFrom<&::core::ffi::CStr> for DeciString {
+    type Error = DeciStringError;
+    fn try_from(value: &::core::ffi::CStr) -> Result<Self, DeciStringError> {
+        Self::from_utf8(value.to_bytes())
+    }
+}
+impl ::core::borrow::Borrow<str> for DeciString {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+impl ::core::borrow::BorrowMut<str> for DeciString {
+    fn borrow_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+impl ::core::ops::Deref for DeciString {
+    type Target = str;
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+impl ::core::ops::DerefMut for DeciString {
+    fn deref_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+/// Compares the whole string as a byte array.
+impl ::core::cmp::PartialEq for DeciString {
+    fn eq(&self, other: &Self) -> bool {
+        self.len as u8 == other.len as u8 && self.bytes == other.bytes
+    }
+}
+impl ::core::cmp::PartialOrd for DeciString {
+    fn partial_cmp(&self, other: &Self) -> Option<::core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+/**Orders strings the same way as [`str`].
+
+This compares the zero-padded bytes in place, then the length.
+```
+# use microstring::*;
+let mut strings = ["b", "a\0", "", "a"].map(|it| DeciString::new(it).unwrap());
+strings.sort();
+assert_eq!(strings, ["", "a", "a\0", "b"]);
+```
+*/
+impl ::core::cmp::Ord for DeciString {
+    fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+        self.bytes.cmp(&other.bytes).then(self.len.cmp(&other.len))
+    }
+}
+impl ::core::cmp::PartialEq<str> for DeciString {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str().eq(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+impl ::core::cmp::PartialOrd<str> for DeciString {
+    fn partial_cmp(&self, other: &str) -> Option<::core::cmp::Ordering> {
+        self.as_str().partial_cmp(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+impl ::core::cmp::PartialEq<DeciString> for str {
+    fn eq(&self, other: &DeciString) -> bool {
+        ::core::convert::AsRef::<str>::as_ref(self).eq(other.as_str())
+    }
+}
+impl ::core::cmp::PartialOrd<DeciString> for str {
+    fn partial_cmp(&self, other: &DeciString) -> Option<::core::cmp::Ordering> {
+        ::core::convert::AsRef::<str>::as_ref(self).partial_cmp(other.as_str())
+    }
+}
+impl ::core::cmp::PartialEq<&str> for DeciString {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str().eq(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+impl ::core::cmp::PartialOrd<&str> for DeciString {
+    fn partial_cmp(&self, other: &&str) -> Option<::core::cmp::Ordering> {
+        self.as_str().partial_cmp(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+impl ::core::cmp::PartialEq<DeciString> for &str {
+    fn eq(&self, other: &DeciString) -> bool {
+        ::core::convert::AsRef::<str>::as_ref(self).eq(other.as_str())
+    }
+}
+impl ::core::cmp::PartialOrd<DeciString> for &str {
+    fn partial_cmp(&self, other: &DeciString) -> Option<::core::cmp::Ordering> {
+        ::core::convert::AsRef::<str>::as_ref(self).partial_cmp(other.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialEq<::alloc::string::String> for DeciString {
+    fn eq(&self, other: &::alloc::string::String) -> bool {
+        self.as_str().eq(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialOrd<::alloc::string::String> for DeciString {
+    fn partial_cmp(
+        &self,
+        other: &::alloc::string::String,
+    ) -> Option<::core::cmp::Ordering> {
+        self.as_str().partial_cmp(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialEq<DeciString> for ::alloc::string::String {
+    fn eq(&self, other: &DeciString) -> bool {
+        ::core::convert::AsRef::<str>::as_ref(self).eq(other.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialOrd<DeciString> for ::alloc::string::String {
+    fn partial_cmp(&self, other: &DeciString) -> Option<::core::cmp::Ordering> {
+        ::core::convert::AsRef::<str>::as_ref(self).partial_cmp(other.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialEq<::alloc::borrow::Cow<'_, str>> for DeciString {
+    fn eq(&self, other: &::alloc::borrow::Cow<'_, str>) -> bool {
+        self.as_str().eq(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialOrd<::alloc::borrow::Cow<'_, str>> for DeciString {
+    fn partial_cmp(
+        &self,
+        other: &::alloc::borrow::Cow<'_, str>,
+    ) -> Option<::core::cmp::Ordering> {
+        self.as_str().partial_cmp(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialEq<DeciString> for ::alloc::borrow::Cow<'_, str> {
+    fn eq(&self, other: &DeciString) -> bool {
+        ::core::convert::AsRef::<str>::as_ref(self).eq(other.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialOrd<DeciString> for ::alloc::borrow::Cow<'_, str> {
+    fn partial_cmp(&self, other: &DeciString) -> Option<::core::cmp::Ordering> {
+        ::core::convert::AsRef::<str>::as_ref(self).partial_cmp(other.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialEq<::alloc::boxed::Box<str>> for DeciString {
+    fn eq(&self, other: &::alloc::boxed::Box<str>) -> bool {
+        self.as_str().eq(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialOrd<::alloc::boxed::Box<str>> for DeciString {
+    fn partial_cmp(
+        &self,
+        other: &::alloc::boxed::Box<str>,
+    ) -> Option<::core::cmp::Ordering> {
+        self.as_str().partial_cmp(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialEq<DeciString> for ::alloc::boxed::Box<str> {
+    fn eq(&self, other: &DeciString) -> bool {
+        ::core::convert::AsRef::<str>::as_ref(self).eq(other.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialOrd<DeciString> for ::alloc::boxed::Box<str> {
+    fn partial_cmp(&self, other: &DeciString) -> Option<::core::cmp::Ordering> {
+        ::core::convert::AsRef::<str>::as_ref(self).partial_cmp(other.as_str())
+    }
+}
+impl ::core::fmt::Debug for DeciString {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl ::core::fmt::Display for DeciString {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+/**Writes which would overflow the capacity return [`fmt::Error`](::core::fmt::Error).
+
+On error, the string is restored to its state before the call to
+[`write!`](::core::write).
+```
+# use microstring::*;
+use core::fmt::Write as _;
+let mut string = DeciString::EMPTY;
+assert!(write!(string, "{}{}", "the quick brown fox jumps over the lazy dog", "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home").is_err());
+assert_eq!(string, "");
+```
+*/
+impl ::core::fmt::Write for DeciString {
+    fn write_str(&mut self, s: &str) -> ::core::fmt::Result {
+        self.push_str(s).map_err(|_| ::core::fmt::Error)
+    }
+    fn write_char(&mut self, c: char) -> ::core::fmt::Result {
+        self.push(c).map_err(|_| ::core::fmt::Error)
+    }
+    fn write_fmt(&mut self, args: ::core::fmt::Arguments<'_>) -> ::core::fmt::Result {
+        let before = *self;
+        ::core::fmt::write(self, args).inspect_err(|_| *self = before)
+    }
+}
+impl DeciString {
+    /**Creates a string from the given [`Arguments`](::core::fmt::Arguments).
+
+See also [`deci_format!`](crate::deci_format).
+*/
+    pub fn try_from_fmt(
+        args: ::core::fmt::Arguments<'_>,
+    ) -> Result<Self, DeciStringError> {
+        struct Len(usize);
+        impl ::core::fmt::Write for Len {
+            fn write_str(&mut self, s: &str) -> ::core::fmt::Result {
+                self.0 += s.len();
+                Ok(())
+            }
+        }
+        let mut s = Self::EMPTY;
+        match ::core::fmt::Write::write_fmt(&mut s, args) {
+            Ok(()) => Ok(s),
+            Err(_) => {
+                let mut len = Len(0);
+                let _ = ::core::fmt::Write::write_fmt(&mut len, args);
+                Err(DeciStringError::new(crate::ErrorKind::TooLong, len.0))
+            }
+        }
+    }
+}
+/**Creates a [`DeciString`] using interpolation of runtime expressions,
+like [`format!`](https://doc.rust-lang.org/std/macro.format.html).
+
+Returns an error if the result would be longer than 63 bytes.
+```
+# use microstring::*;
+assert_eq!(deci_format!("{}", "the quick brown fox jumps over the lazy dog").unwrap(), "the quick brown fox jumps over the lazy dog");
+assert!(deci_format!("{}", "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home").is_err());
+```
+*/
+#[macro_export]
+macro_rules! deci_format {
+    ($($arg:tt)*) => {
+        $crate::DeciString::try_from_fmt(::core::format_args!($($arg)*))
+    };
+}
+impl ::core::str::FromStr for DeciString {
+    type Err = DeciStringError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s).ok_or(DeciStringError::new(crate::ErrorKind::TooLong, s.len()))
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for DeciString {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(self)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for DeciString {
+    fn deserialize<D: serde::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        struct Visitor;
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = DeciString;
+            fn expecting(
+                &self,
+                f: &mut ::core::fmt::Formatter<'_>,
+            ) -> ::core::fmt::Result {
+                f.write_str("a string of at most 63 bytes")
+            }
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<DeciString, E> {
+                DeciString::new(v)
+                    .ok_or_else(|| serde::de::Error::invalid_length(v.len(), &self))
+            }
+        }
+        d.deserialize_str(Visitor)
+    }
+}
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for DeciString {
+    fn schema_name() -> ::std::borrow::Cow<'static, str> {
+        ::std::borrow::Cow::Borrowed(::core::stringify!(DeciString))
+    }
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema! {
+            { "type" : "string", "maxLength" : 63u8, }
+        }
+    }
+    fn schema_id() -> ::std::borrow::Cow<'static, str> {
+        ::std::borrow::Cow::Borrowed(
+            ::core::concat!(::core::module_path!(), "::", ::core::stringify!(DeciString)),
+        )
+    }
+}
+/**Generates strings of every length up to 63 bytes, including multi-byte [`char`]s.
+```
+# use microstring::*;
+use arbitrary::{Arbitrary, Unstructured};
+
+// an empty input consumes nothing
+assert_eq!(DeciString::arbitrary(&mut Unstructured::new(&[])).unwrap(), "");
+let (min, max) = DeciString::size_hint(0);
+assert_eq!(min, 0);
+let max = max.unwrap();
+
+let mut lens = [false; 63 + 1];
+let mut multi_byte = false;
+for seed in 0..=u8::MAX {
+    // the first byte chooses the length
+    let mut state = u32::from(seed) << 8 | 1;
+    let data = [seed].into_iter().chain((1..max).map(|_| {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        state as u8
+    })).collect::<Vec<_>>();
+    let mut u = Unstructured::new(&data);
+    let s = DeciString::arbitrary(&mut u).unwrap();
+    assert!(min <= data.len() - u.len() && data.len() - u.len() <= max);
+    assert!(str::from_utf8(s.as_bytes()).is_ok());
+    assert_eq!(s.to_bits(), DeciString::new(&s).unwrap().to_bits());
+    lens[s.len()] = true;
+    multi_byte |= s.chars().any(|c| c.len_utf8() > 1);
+}
+assert!(lens.iter().all(|it| *it) && multi_byte);
+```
+*/
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DeciString {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let len = u.int_in_range(0..=63u8)? as usize;
+        let mut it = Self::EMPTY;
+        while (it.len as u8 as usize) < len {
+            let remaining = len - it.len as u8 as usize;
+            let width = u.int_in_range(1..=remaining.min(4))?;
+            let _ = it.push(crate::__private::arbitrary_char(u, width)?);
+        }
+        Ok(it)
+    }
+    fn size_hint(_: usize) -> (usize, Option<usize>) {
+        (0, Some(1 + 2 * 63u8 as usize))
+    }
+}
+impl crate::Lookup<DeciString> for DeciString {
+    fn lookup(&self) -> Option<DeciString> {
+        Some(*self)
+    }
+}
+impl crate::Lookup<DeciString> for str {
+    fn lookup(&self) -> Option<DeciString> {
+        DeciString::new(self)
+    }
+}
+impl crate::Lookup<DeciString> for &str {
+    fn lookup(&self) -> Option<DeciString> {
+        DeciString::new(self)
+    }
+}
+#[cfg(feature = "alloc")]
+impl crate::Lookup<DeciString> for ::alloc::string::String {
+    fn lookup(&self) -> Option<DeciString> {
+        DeciString::new(self)
+    }
+}
+#[cfg(feature = "alloc")]
+impl crate::Lookup<DeciString> for ::alloc::borrow::Cow<'_, str> {
+    fn lookup(&self) -> Option<DeciString> {
+        DeciString::new(self)
+    }
+}
+#[cfg(feature = "alloc")]
+impl crate::Lookup<DeciString> for ::alloc::boxed::Box<str> {
+    fn lookup(&self) -> Option<DeciString> {
+        DeciString::new(self)
+    }
+}
+/**A hash map keyed by [`DeciString`].
+
+Keys are stored and hashed as their packed [`to_bits`](DeciString::to_bits) representation,
+using [`BuildWordHasher`](crate::BuildWordHasher).
+Entries may also be looked up by [`str`] and [`String`](std::string::String).
+```
+# use microstring::*;
+let mut map = DeciMap::new();
+map.insert(DeciString::new("the quick brown fox jumps over the lazy dog").unwrap(), 1);
+assert_eq!(map.get("the quick brown fox jumps over the lazy dog"), Some(&1));
+assert_eq!(map.get(&DeciString::new("the quick brown fox jumps over the lazy dog").unwrap()), Some(&1));
+assert_eq!(map.get(&String::from("the quick brown fox jumps over the lazy dog")), Some(&1));
+assert_eq!(map.get("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home"), None);
+
+*map.entry(DeciString::new("the quick brown fox jumps over the lazy dog").unwrap()).or_default() += 1;
+assert_eq!(map.into_iter().collect::<Vec<_>>(), [(DeciString::new("the quick brown fox jumps over the lazy dog").unwrap(), 2)]);
+```
+*/
+#[cfg(feature = "std")]
+#[derive(Clone)]
+pub struct DeciMap<V> {
+    inner: ::std::collections::HashMap<
+        [u8; 63u8 as usize + 1],
+        V,
+        crate::BuildWordHasher,
+    >,
+}
+#[cfg(feature = "std")]
+impl<V> DeciMap<V> {
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            inner: ::std::collections::HashMap::with_capacity_and_hasher(
+                capacity,
+                crate::BuildWordHasher,
+            ),
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+    pub fn clear(&mut self) {
+        self.inner.clear()
+    }
+    pub fn insert(&mut self, key: DeciString, value: V) -> Option<V> {
+        self.inner.insert(key.to_bits(), value)
+    }
+    /// Gets the entry for the given key, to insert or update it with a single probe.
+    pub fn entry(&mut self, key: DeciString) -> crate::Entry<'_, DeciString, V> {
+        crate::Entry::__new(&mut self.inner, key)
+    }
+    pub fn get<Q: ?Sized + crate::Lookup<DeciString>>(&self, key: &Q) -> Option<&V> {
+        self.inner.get(&key.lookup()?.to_bits())
+    }
+    pub fn get_mut<Q: ?Sized + crate::Lookup<DeciString>>(
+        &mut self,
+        key: &Q,
+    ) -> Option<&mut V> {
+        self.inner.get_mut(&key.lookup()?.to_bits())
+    }
+    pub fn contains_key<Q: ?Sized + crate::Lookup<DeciString>>(&self, key: &Q) -> bool {
+        self.get(key).is_some()
+    }
+    pub fn remove<Q: ?Sized + crate::Lookup<DeciString>>(
+        &mut self,
+        key: &Q,
+    ) -> Option<V> {
+        self.inner.remove(&key.lookup()?.to_bits())
+    }
+    pub fn iter(&self) -> impl Iterator<Item = (DeciString, &V)> {
+        self.inner
+            .iter()
+            .map(|(k, v)| (unsafe { DeciString::from_bits_unchecked(*k) }, v))
+    }
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (DeciString, &mut V)> {
+        self.inner
+            .iter_mut()
+            .map(|(k, v)| (unsafe { DeciString::from_bits_unchecked(*k) }, v))
+    }
+    pub fn keys(&self) -> impl Iterator<Item = DeciString> {
+        self.inner.keys().map(|k| unsafe { DeciString::from_bits_unchecked(*k) })
+    }
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.inner.values()
+    }
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut V> {
+        self.inner.values_mut()
+    }
+}
+#[cfg(feature = "std")]
+impl<V> ::core::default::Default for DeciMap<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+#[cfg(feature = "std")]
+impl<V: ::core::fmt::Debug> ::core::fmt::Debug for DeciMap<V> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+#[cfg(feature = "std")]
+impl<V: ::core::cmp::PartialEq> ::core::cmp::PartialEq for DeciMap<V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+#[cfg(feature = "std")]
+impl<V: ::core::cmp::Eq> ::core::cmp::Eq for DeciMap<V> {}
+#[cfg(feature = "std")]
+impl<V> ::core::iter::Extend<(DeciString, V)> for DeciMap<V> {
+    fn extend<I: IntoIterator<Item = (DeciString, V)>>(&mut self, iter: I) {
+        self.inner.extend(iter.into_iter().map(|(k, v)| (k.to_bits(), v)))
+    }
+}
+#[cfg(feature = "std")]
+impl<V> ::core::iter::IntoIterator for DeciMap<V> {
+    type Item = (DeciString, V);
+    type IntoIter = ::core::iter::Map<
+        ::std::collections::hash_map::IntoIter<[u8; 63u8 as usize + 1], V>,
+        fn(([u8; 63u8 as usize + 1], V)) -> (DeciString, V),
+    >;
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner
+            .into_iter()
+            .map(|(k, v)| (unsafe { DeciString::from_bits_unchecked(k) }, v))
+    }
+}
+#[cfg(feature = "std")]
+impl<'a, V> ::core::iter::IntoIterator for &'a DeciMap<V> {
+    type Item = (DeciString, &'a V);
+    type IntoIter = ::core::iter::Map<
+        ::std::collections::hash_map::Iter<'a, [u8; 63u8 as usize + 1], V>,
+        fn((&'a [u8; 63u8 as usize + 1], &'a V)) -> (DeciString, &'a V),
+    >;
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner
+            .iter()
+            .map(|(k, v)| (unsafe { DeciString::from_bits_unchecked(*k) }, v))
+    }
+}
+#[cfg(feature = "std")]
+impl<'a, V> ::core::iter::IntoIterator for &'a mut DeciMap<V> {
+    type Item = (DeciString, &'a mut V);
+    type IntoIter = ::core::iter::Map<
+        ::std::collections::hash_map::IterMut<'a, [u8; 63u8 as usize + 1], V>,
+        fn((&'a [u8; 63u8 as usize + 1], &'a mut V)) -> (DeciString, &'a mut V),
+    >;
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner
+            .iter_mut()
+            .map(|(k, v)| (unsafe { DeciString::from_bits_unchecked(*k) }, v))
+    }
+}
+#[cfg(feature = "std")]
+impl<V> ::core::iter::FromIterator<(DeciString, V)> for DeciMap<V> {
+    fn from_iter<I: IntoIterator<Item = (DeciString, V)>>(iter: I) -> Self {
+        let mut it = Self::new();
+        it.extend(iter);
+        it
+    }
+}
+#[cfg(feature = "const-default")]
+impl const_default::ConstDefault for DeciString {
+    const DEFAULT: Self = DeciString::EMPTY;
+}
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u8)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout,
+        zerocopy::Unaligned
+    )
+)]
+enum DeciStringLen {
+    _0 = 0u8,
+    _1 = 1u8,
+    _2 = 2u8,
+    _3 = 3u8,
+    _4 = 4u8,
+    _5 = 5u8,
+    _6 = 6u8,
+    _7 = 7u8,
+    _8 = 8u8,
+    _9 = 9u8,
+    _10 = 10u8,
+    _11 = 11u8,
+    _12 = 12u8,
+    _13 = 13u8,
+    _14 = 14u8,
+    _15 = 15u8,
+    _16 = 16u8,
+    _17 = 17u8,
+    _18 = 18u8,
+    _19 = 19u8,
+    _20 = 20u8,
+    _21 = 21u8,
+    _22 = 22u8,
+    _23 = 23u8,
+    _24 = 24u8,
+    _25 = 25u8,
+    _26 = 26u8,
+    _27 = 27u8,
+    _28 = 28u8,
+    _29 = 29u8,
+    _30 = 30u8,
+    _31 = 31u8,
+    _32 = 32u8,
+    _33 = 33u8,
+    _34 = 34u8,
+    _35 = 35u8,
+    _36 = 36u8,
+    _37 = 37u8,
+    _38 = 38u8,
+    _39 = 39u8,
+    _40 = 40u8,
+    _41 = 41u8,
+    _42 = 42u8,
+    _43 = 43u8,
+    _44 = 44u8,
+    _45 = 45u8,
+    _46 = 46u8,
+    _47 = 47u8,
+    _48 = 48u8,
+    _49 = 49u8,
+    _50 = 50u8,
+    _51 = 51u8,
+    _52 = 52u8,
+    _53 = 53u8,
+    _54 = 54u8,
+    _55 = 55u8,
+    _56 = 56u8,
+    _57 = 57u8,
+    _58 = 58u8,
+    _59 = 59u8,
+    _60 = 60u8,
+    _61 = 61u8,
+    _62 = 62u8,
+    _63 = 63u8,
+}
+impl DeciStringLen {
+    const fn from_usize(u: usize) -> Option<Self> {
+        const U8_MAX: usize = u8::MAX as _;
+        if u > U8_MAX {
+            return None;
+        }
+        Self::from_u8(u as u8)
+    }
+    const fn from_u8(u: u8) -> Option<Self> {
+        match u {
+            0u8 => Some(Self::_0),
+            1u8 => Some(Self::_1),
+            2u8 => Some(Self::_2),
+            3u8 => Some(Self::_3),
+            4u8 => Some(Self::_4),
+            5u8 => Some(Self::_5),
+            6u8 => Some(Self::_6),
+            7u8 => Some(Self::_7),
+            8u8 => Some(Self::_8),
+            9u8 => Some(Self::_9),
+            10u8 => Some(Self::_10),
+            11u8 => Some(Self::_11),
+            12u8 => Some(Self::_12),
+            13u8 => Some(Self::_13),
+            14u8 => Some(Self::_14),
+            15u8 => Some(Self::_15),
+            16u8 => Some(Self::_16),
+            17u8 => Some(Self::_17),
+            18u8 => Some(Self::_18),
+            19u8 => Some(Self::_19),
+            20u8 => Some(Self::_20),
+            21u8 => Some(Self::_21),
+            22u8 => Some(Self::_22),
+            23u8 => Some(Self::_23),
+            24u8 => Some(Self::_24),
+            25u8 => Some(Self::_25),
+            26u8 => Some(Self::_26),
+            27u8 => Some(Self::_27),
+            28u8 => Some(Self::_28),
+            29u8 => Some(Self::_29),
+            30u8 => Some(Self::_30),
+            31u8 => Some(Self::_31),
+            32u8 => Some(Self::_32),
+            33u8 => Some(Self::_33),
+            34u8 => Some(Self::_34),
+            35u8 => Some(Self::_35),
+            36u8 => Some(Self::_36),
+            37u8 => Some(Self::_37),
+            38u8 => Some(Self::_38),
+            39u8 => Some(Self::_39),
+            40u8 => Some(Self::_40),
+            41u8 => Some(Self::_41),
+            42u8 => Some(Self::_42),
+            43u8 => Some(Self::_43),
+            44u8 => Some(Self::_44),
+            45u8 => Some(Self::_45),
+            46u8 => Some(Self::_46),
+            47u8 => Some(Self::_47),
+            48u8 => Some(Self::_48),
+            49u8 => Some(Self::_49),
+            50u8 => Some(Self::_50),
+            51u8 => Some(Self::_51),
+            52u8 => Some(Self::_52),
+            53u8 => Some(Self::_53),
+            54u8 => Some(Self::_54),
+            55u8 => Some(Self::_55),
+            56u8 => Some(Self::_56),
+            57u8 => Some(Self::_57),
+            58u8 => Some(Self::_58),
+            59u8 => Some(Self::_59),
+            60u8 => Some(Self::_60),
+            61u8 => Some(Self::_61),
+            62u8 => Some(Self::_62),
+            63u8 => Some(Self::_63),
+            _ => None,
+        }
+    }
+}
+/**A stack-allocated string which can hold up to 127 UTF-8 encoded bytes.
+
+With the `zerocopy` feature, this implements `IntoBytes`, `Immutable`,
+`KnownLayout` and `Unaligned`.
+`TryFromBytes` is deliberately not implemented, because its derive cannot check
+that the bytes are UTF-8 followed by zero padding,
+and neither is `FromZeros`, which requires it.
+Use [`try_ref_from_bytes`](DecaString::try_ref_from_bytes)
+or [`try_slice_from_bytes`](DecaString::try_slice_from_bytes) instead.
+```
+# use core::mem::size_of;
+# use microstring::*;
+assert_eq! {
+    size_of::<DecaString>(),
+    127 + 1,
+}
+assert_eq! {
+    size_of::<DecaString>(),
+    size_of::<Option<DecaString>>(),
+}
+```
+*/
+#[derive(Clone, Copy, Eq)]
+#[repr(C)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout,
+        zerocopy::Unaligned
+    )
+)]
+pub struct DecaString {
+    len: DecaStringLen,
+    bytes: [u8; 127u8 as _],
+}
+impl DecaString {
+    pub const EMPTY: Self = Self::new("").unwrap();
+    /**Returns [`None`] if the given <code>[str::len()] > 127</code>.
+```
+# use microstring::*;
+const STRING: DecaString = DecaString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home").unwrap();
+```
+```compile_fail
+# use microstring::*;
+const TOO_BIG: DecaString = DecaString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills").unwrap();
+```
+*/
+    pub const fn new(s: &str) -> Option<Self> {
+        match DecaStringLen::from_usize(s.len()) {
+            Some(len) => {
+                let mut bytes = [0; 127u8 as _];
+                unsafe {
+                    ::core::ptr::copy_nonoverlapping(
+                        s.as_ptr(),
+                        bytes.as_mut_ptr(),
+                        s.len(),
+                    )
+                }
+                Some(Self { len, bytes })
+            }
+            None => None,
+        }
+    }
+    pub const fn as_str(&self) -> &str {
+        unsafe {
+            str::from_utf8_unchecked(
+                ::core::slice::from_raw_parts(
+                    self.bytes.as_ptr(),
+                    self.len as u8 as usize,
+                ),
+            )
+        }
+    }
+    pub const fn as_mut_str(&mut self) -> &mut str {
+        unsafe {
+            str::from_utf8_unchecked_mut(
+                ::core::slice::from_raw_parts_mut(
+                    self.bytes.as_mut_ptr(),
+                    self.len as u8 as usize,
+                ),
+            )
+        }
+    }
+    /**Returns an error if the given bytes are not UTF-8, or if <code>[slice::len()] > 127</code>.
+```
+# use microstring::*;
+const STRING: DecaString = match DecaString::from_utf8(b"the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home") {
+    Ok(it) => it,
+    Err(_) => panic!(),
+};
+assert_eq!(DecaString::from_utf8(b"\xFF").unwrap_err().kind(), ErrorKind::InvalidUtf8);
+assert_eq!(DecaString::from_utf8(b"the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills").unwrap_err().kind(), ErrorKind::TooLong);
+```
+*/
+    pub const fn from_utf8(bytes: &[u8]) -> Result<Self, DecaStringError> {
+        if bytes.len() > 127u8 as usize {
+            return Err(DecaStringError::new(crate::ErrorKind::TooLong, bytes.len()));
+        }
+        match ::core::str::from_utf8(bytes) {
+            Ok(s) => {
+                match Self::new(s) {
+                    Some(it) => Ok(it),
+                    None => {
+                        Err(DecaStringError::new(crate::ErrorKind::TooLong, bytes.len()))
+                    }
+                }
+            }
+            Err(_) => {
+                Err(DecaStringError::new(crate::ErrorKind::InvalidUtf8, bytes.len()))
+            }
+        }
+    }
+    /**Returns [`None`] if <code>[slice::len()] > 127</code>.
+
+# Safety
+- The given bytes must be valid UTF-8.
+*/
+    pub const unsafe fn from_utf8_unchecked(bytes: &[u8]) -> Option<Self> {
+        Self::new(unsafe { str::from_utf8_unchecked(bytes) })
+    }
+    /**Truncates the given string to at most 127 bytes, on a [`char`] boundary.
+```
+# use microstring::*;
+const STRING: DecaString = DecaString::new_truncating("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills");
+assert_eq!(STRING, "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fi");
+```
+*/
+    pub const fn new_truncating(s: &str) -> Self {
+        let mut end = if s.len() < 127u8 as usize { s.len() } else { 127u8 as usize };
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+        match Self::new(s.split_at(end).0) {
+            Some(it) => it,
+            None => unreachable!(),
+        }
+    }
+    /**If the given string is longer than 127 bytes, truncates it on a [`char`] boundary,
+leaving room to append the given `marker`.
+
+# Panics
+- If the string needs truncating, and <code>marker.[len()](str::len) > 127</code>.
+```
+# use microstring::*;
+const STRING: DecaString = DecaString::new_truncating_with_marker("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills", "…");
+assert_eq!(STRING, "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the…");
+```
+*/
+    pub const fn new_truncating_with_marker(s: &str, marker: &str) -> Self {
+        if let Some(it) = Self::new(s) {
+            return it;
+        }
+        assert!(marker.len() <= 127u8 as usize, "marker is longer than the capacity");
+        let mut end = 127u8 as usize - marker.len();
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+        let mut it = Self::new_truncating(s.split_at(end).0);
+        match it.push_str(marker) {
+            Ok(()) => it,
+            Err(_) => unreachable!(),
+        }
+    }
+    /// Like [`new_truncating`](Self::new_truncating),
+    /// but never splits a grapheme cluster.
+    #[cfg(feature = "unicode-segmentation")]
+    pub fn new_truncating_graphemes(s: &str) -> Self {
+        use unicode_segmentation::UnicodeSegmentation as _;
+        let end = s
+            .grapheme_indices(true)
+            .map(|(ix, grapheme)| ix + grapheme.len())
+            .take_while(|end| *end <= 127u8 as usize)
+            .last()
+            .unwrap_or(0);
+        Self::new_truncating(&s[..end])
+    }
+}
+/**The error returned when a [`DecaString`] cannot be constructed.
+```
+# use microstring::*;
+let e = "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills".parse::<DecaString>().unwrap_err();
+assert_eq!(e.kind(), ErrorKind::TooLong);
+assert_eq!(e.input_len(), 164);
+assert_eq!(e.to_string(), "expected a string of at most 127 bytes, but got 164 bytes");
+```
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DecaStringError {
+    kind: crate::ErrorKind,
+    len: usize,
+}
+impl DecaStringError {
+    const fn new(kind: crate::ErrorKind, len: usize) -> Self {
+        Self { kind, len }
+    }
+    /// Why the input was rejected.
+    pub const fn kind(&self) -> crate::ErrorKind {
+        self.kind
+    }
+    /// The length of the rejected input, in bytes.
+    ///
+    /// For operations which append to an existing string,
+    /// this is the length the string would have had.
+    pub const fn input_len(&self) -> usize {
+        self.len
+    }
+    /// The maximum length of the string being constructed, in bytes.
+    pub const fn capacity(&self) -> usize {
+        127u8 as usize
+    }
+}
+impl ::core::fmt::Display for DecaStringError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let Self { kind, len } = *self;
+        match kind {
+            crate::ErrorKind::TooLong => {
+                write!(f, "expected a string of at most 127 bytes, but got {len} bytes")
+            }
+            crate::ErrorKind::InvalidUtf8 => {
+                write!(
+                    f,
+                    "expected a UTF-8 string of at most 127 bytes, but got {len} bytes of invalid UTF-8"
+                )
+            }
+            crate::ErrorKind::Invalid => {
+                write!(f, "a string of {len} bytes failed validation")
+            }
+        }
+    }
+}
+impl ::core::error::Error for DecaStringError {}
+impl DecaString {
+    /// The maximum length, in bytes.
+    pub const CAPACITY: usize = 127u8 as usize;
+    /// The length of this string, in bytes.
+    pub const fn len(&self) -> usize {
+        self.len as u8 as usize
+    }
+    /// Returns `true` if this string has a length of zero.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// The number of bytes which may be pushed before this string is full.
+    pub const fn remaining_capacity(&self) -> usize {
+        Self::CAPACITY - self.len()
+    }
+    /// Equivalent to [`str::as_bytes`], but usable in `const`.
+    ///
+    /// Unlike `as_bytes`, this does not shadow
+    /// [`zerocopy::IntoBytes::as_bytes`](https://docs.rs/zerocopy/latest/zerocopy/trait.IntoBytes.html#method.as_bytes),
+    /// which returns the length as well.
+    pub const fn const_as_bytes(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+    /// Equivalent to `==`, but usable in `const`.
+    pub const fn const_eq(&self, other: &str) -> bool {
+        self.len() == other.len() && self.const_cmp(other).is_eq()
+    }
+    /// Equivalent to [`Ord::cmp`], but usable in `const`.
+    pub const fn const_cmp(&self, other: &str) -> ::core::cmp::Ordering {
+        crate::__private::cmp_bytes(self.const_as_bytes(), other.as_bytes())
+    }
+    /// Equivalent to [`str::starts_with`] with a [`str`] pattern, but usable in `const`.
+    pub const fn const_starts_with(&self, prefix: &str) -> bool {
+        match prefix.len() <= self.len() {
+            true => {
+                let (head, _) = self.const_as_bytes().split_at(prefix.len());
+                crate::__private::cmp_bytes(head, prefix.as_bytes()).is_eq()
+            }
+            false => false,
+        }
+    }
+    /// Equivalent to [`str::ends_with`] with a [`str`] pattern, but usable in `const`.
+    pub const fn const_ends_with(&self, suffix: &str) -> bool {
+        match self.len().checked_sub(suffix.len()) {
+            Some(mid) => {
+                let (_, tail) = self.const_as_bytes().split_at(mid);
+                crate::__private::cmp_bytes(tail, suffix.as_bytes()).is_eq()
+            }
+            None => false,
+        }
+    }
+    /// Converts this string to its ASCII upper case equivalent in-place.
+    pub const fn make_ascii_uppercase(&mut self) {
+        self.as_mut_str().make_ascii_uppercase()
+    }
+    /// Converts this string to its ASCII lower case equivalent in-place.
+    pub const fn make_ascii_lowercase(&mut self) {
+        self.as_mut_str().make_ascii_lowercase()
+    }
+    /**Returns a copy of this string, with ASCII letters converted to upper case.
+
+Other bytes are unchanged.
+```
+# use microstring::*;
+const UPPER: DecaString = DecaString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home").unwrap().to_ascii_uppercase();
+assert_eq!(UPPER, "THE QUICK BROWN FOX JUMPS OVER THE LAZY DOG, THEN THE LAZY DOG CHASES THE QUICK BROWN FOX HOME");
+for s in ["@AZ[`az{é"] {
+    assert_eq!(DecaString::new(s).unwrap().to_ascii_uppercase(), s.to_ascii_uppercase().as_str());
+}
+```
+*/
+    pub const fn to_ascii_uppercase(mut self) -> Self {
+        self.make_ascii_uppercase();
+        self
+    }
+    /**Returns a copy of this string, with ASCII letters converted to lower case.
+
+Other bytes are unchanged.
+```
+# use microstring::*;
+const LOWER: DecaString = DecaString::new("THE QUICK BROWN FOX JUMPS OVER THE LAZY DOG, THEN THE LAZY DOG CHASES THE QUICK BROWN FOX HOME").unwrap().to_ascii_lowercase();
+assert_eq!(LOWER, "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home");
+for s in ["@AZ[`az{é"] {
+    assert_eq!(DecaString::new(s).unwrap().to_ascii_lowercase(), s.to_ascii_lowercase().as_str());
+}
+```
+*/
+    pub const fn to_ascii_lowercase(mut self) -> Self {
+        self.make_ascii_lowercase();
+        self
+    }
+}
+impl DecaString {
+    /// Appends the given [`char`] to the end of this string.
+    ///
+    /// Returns an error, leaving this string unchanged, if there is not enough capacity.
+    pub const fn push(&mut self, c: char) -> Result<(), DecaStringError> {
+        self.push_str(c.encode_utf8(&mut [0; 4]))
+    }
+    /**Appends the given string to the end of this one.
+
+Returns an error, leaving this string unchanged, if the result would be
+longer than 127 bytes.
+```
+# use microstring::*;
+let mut string = DecaString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home").unwrap();
+assert!(string.push_str("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills").is_err());
+assert_eq!(string, "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home");
+```
+*/
+    pub const fn push_str(&mut self, s: &str) -> Result<(), DecaStringError> {
+        let len = self.len as u8 as usize;
+        match DecaStringLen::from_usize(len + s.len()) {
+            Some(new_len) => {
+                unsafe {
+                    ::core::ptr::copy_nonoverlapping(
+                        s.as_ptr(),
+                        self.bytes.as_mut_ptr().add(len),
+                        s.len(),
+                    )
+                }
+                self.len = new_len;
+                Ok(())
+            }
+            None => Err(DecaStringError::new(crate::ErrorKind::TooLong, len + s.len())),
+        }
+    }
+    /**Concatenates the given strings.
+
+Returns an error if the result would be longer than 127 bytes.
+```
+# use microstring::*;
+assert_eq!(DecaString::try_concat("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home", "").unwrap(), "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home");
+assert!(DecaString::try_concat("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home", "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills").is_err());
+```
+*/
+    pub const fn try_concat(left: &str, right: &str) -> Result<Self, DecaStringError> {
+        match Self::new(left) {
+            Some(mut it) => {
+                match it.push_str(right) {
+                    Ok(()) => Ok(it),
+                    Err(e) => Err(e),
+                }
+            }
+            None => {
+                Err(
+                    DecaStringError::new(
+                        crate::ErrorKind::TooLong,
+                        left.len() + right.len(),
+                    ),
+                )
+            }
+        }
+    }
+    /**Removes the last [`char`] from this string and returns it,
+or [`None`] if this string is empty.
+```
+# use microstring::*;
+let mut string = DecaString::new("aé").unwrap();
+assert_eq!(string.pop(), Some('é'));
+assert_eq!(string.to_bits(), DecaString::new("a").unwrap().to_bits());
+assert_eq!(string.pop(), Some('a'));
+assert_eq!(string.pop(), None);
+assert_eq!(string.to_bits(), DecaString::EMPTY.to_bits());
+```
+*/
+    pub fn pop(&mut self) -> Option<char> {
+        let c = self.as_str().chars().next_back()?;
+        unsafe { self.set_len(self.len as u8 as usize - c.len_utf8()) }
+        Some(c)
+    }
+    /**Shortens this string to `new_len` bytes.
+
+Has no effect if `new_len` is greater than the string's current length.
+
+# Panics
+- If `new_len` does not lie on a [`char`] boundary.
+```
+# use microstring::*;
+let mut string = DecaString::new("aé").unwrap();
+string.truncate(3);
+assert_eq!(string, "aé");
+string.truncate(1);
+assert_eq!(string.to_bits(), DecaString::new("a").unwrap().to_bits());
+```
+```should_panic
+# use microstring::*;
+DecaString::new("é").unwrap().truncate(1);
+```
+*/
+    pub const fn truncate(&mut self, new_len: usize) {
+        if new_len < self.len as u8 as usize {
+            assert!(
+                self.as_str().is_char_boundary(new_len),
+                "new_len does not lie on a char boundary"
+            );
+            unsafe { self.set_len(new_len) }
+        }
+    }
+    /**Truncates this string to the empty string.
+```
+# use microstring::*;
+let mut string = DecaString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home").unwrap();
+string.clear();
+assert_eq!(string.to_bits(), DecaString::EMPTY.to_bits());
+```
+*/
+    pub const fn clear(&mut self) {
+        unsafe { self.set_len(0) }
+    }
+    /// Inserts the given [`char`] at byte position `idx`.
+    ///
+    /// Returns an error, leaving this string unchanged, if there is not enough capacity.
+    ///
+    /// # Panics
+    /// - If `idx` is out of bounds, or does not lie on a [`char`] boundary.
+    pub const fn insert(&mut self, idx: usize, c: char) -> Result<(), DecaStringError> {
+        self.insert_str(idx, c.encode_utf8(&mut [0; 4]))
+    }
+    /**Inserts the given string at byte position `idx`.
+
+Returns an error, leaving this string unchanged, if there is not enough capacity.
+
+# Panics
+- If `idx` is out of bounds, or does not lie on a [`char`] boundary.
+```
+# use microstring::*;
+let mut string = DecaString::new("a").unwrap();
+string.insert_str(0, "é").unwrap();
+assert_eq!(string, "éa");
+assert!(string.insert_str(2, "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills").is_err());
+assert_eq!(string.to_bits(), DecaString::new("éa").unwrap().to_bits());
+```
+```should_panic
+# use microstring::*;
+let _ = DecaString::new("é").unwrap().insert_str(1, "a");
+```
+*/
+    pub const fn insert_str(
+        &mut self,
+        idx: usize,
+        s: &str,
+    ) -> Result<(), DecaStringError> {
+        assert!(
+            self.as_str().is_char_boundary(idx), "idx does not lie on a char boundary"
+        );
+        let len = self.len as u8 as usize;
+        match DecaStringLen::from_usize(len + s.len()) {
+            Some(new_len) => {
+                unsafe {
+                    let p = self.bytes.as_mut_ptr();
+                    ::core::ptr::copy(p.add(idx), p.add(idx + s.len()), len - idx);
+                    ::core::ptr::copy_nonoverlapping(s.as_ptr(), p.add(idx), s.len());
+                }
+                self.len = new_len;
+                Ok(())
+            }
+            None => Err(DecaStringError::new(crate::ErrorKind::TooLong, len + s.len())),
+        }
+    }
+    /**Removes the [`char`] at byte position `idx` and returns it.
+
+# Panics
+- If `idx` is out of bounds, or does not lie on a [`char`] boundary.
+```
+# use microstring::*;
+let mut string = DecaString::new("éa").unwrap();
+assert_eq!(string.remove(0), 'é');
+assert_eq!(string.to_bits(), DecaString::new("a").unwrap().to_bits());
+```
+```should_panic
+# use microstring::*;
+DecaString::new("é").unwrap().remove(1);
+```
+*/
+    pub fn remove(&mut self, idx: usize) -> char {
+        let Some(c) = self.as_str()[idx..].chars().next() else {
+            panic!("cannot remove a char from the end of a string")
+        };
+        let len = self.len as u8 as usize;
+        let next = idx + c.len_utf8();
+        unsafe {
+            let p = self.bytes.as_mut_ptr();
+            ::core::ptr::copy(p.add(next), p.add(idx), len - next);
+            self.set_len(len - c.len_utf8());
+        }
+        c
+    }
+    /**Retains only the [`char`]s for which `f` returns `true`.
+```
+# use microstring::*;
+let mut string = DecaString::new("éa").unwrap();
+string.retain(|c| c.is_ascii());
+assert_eq!(string.to_bits(), DecaString::new("a").unwrap().to_bits());
+```
+*/
+    pub fn retain(&mut self, mut f: impl FnMut(char) -> bool) {
+        let this = *self;
+        self.clear();
+        for c in this.as_str().chars() {
+            if f(c) {
+                let _ = self.push(c);
+            }
+        }
+    }
+    /// Sets the length, zeroing any bytes past it.
+    ///
+    /// # Safety
+    /// - `new_len` must be at most the current length.
+    /// - `new_len` must lie on a [`char`] boundary.
+    const unsafe fn set_len(&mut self, new_len: usize) {
+        let len = self.len as u8 as usize;
+        unsafe {
+            ::core::ptr::write_bytes(
+                self.bytes.as_mut_ptr().add(new_len),
+                0,
+                len - new_len,
+            )
+        }
+        self.len = match DecaStringLen::from_usize(new_len) {
+            Some(it) => it,
+            None => unreachable!(),
+        };
+    }
+}
+impl DecaString {
+    /**Collects the given [`char`]s into a string.
+
+Returns an error if there are more than 127 bytes of them.
+See also [`Collected`](crate::Collected).
+```
+# use microstring::*;
+assert_eq!(DecaString::try_from_iter("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home".chars()).unwrap(), "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home");
+assert!(DecaString::try_from_iter("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills".chars()).is_err());
+```
+*/
+    pub fn try_from_iter<I: IntoIterator<Item = char>>(
+        iter: I,
+    ) -> Result<Self, DecaStringError> {
+        let mut it = Self::EMPTY;
+        for c in iter {
+            it.push(c)?;
+        }
+        Ok(it)
+    }
+    /**Returns an owning iterator over the bytes of this string.
+```
+# use microstring::*;
+let string = DecaString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home").unwrap();
+assert!(string.into_bytes().eq("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home".bytes()));
+assert_eq!(string.into_bytes().len(), 94);
+```
+*/
+    pub fn into_bytes(self) -> crate::IntoBytes<Self> {
+        crate::IntoBytes::new(self)
+    }
+}
+/**An owning iterator over the [`char`]s of this string.
+```
+# use microstring::*;
+let string = DecaString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home").unwrap();
+assert!(string.into_iter().rev().eq("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home".chars().rev()));
+```
+*/
+impl ::core::iter::IntoIterator for DecaString {
+    type Item = char;
+    type IntoIter = crate::IntoChars<Self>;
+    fn into_iter(self) -> crate::IntoChars<Self> {
+        crate::IntoChars::new(self)
+    }
+}
+/// # Panics
+/// - If there is not enough capacity.
+///   See [`push`](Self::push) for a fallible alternative.
+impl ::core::iter::Extend<char> for DecaString {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        for c in iter {
+            if let Err(e) = self.push(c) {
+                panic!("{e}")
+            }
+        }
+    }
+}
+/// # Panics
+/// - If there is not enough capacity.
+///   See [`push_str`](Self::push_str) for a fallible alternative.
+impl<'a> ::core::iter::Extend<&'a str> for DecaString {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        for s in iter {
+            if let Err(e) = self.push_str(s) {
+                panic!("{e}")
+            }
+        }
+    }
+}
+impl DecaString {
+    /**Interprets the given bytes as a string, without copying.
+
+The bytes must be laid out as produced by `zerocopy::IntoBytes`:
+a length byte, followed by that many bytes of UTF-8, followed by zero padding.
+Returns [`None`] otherwise.
+
+This is the checked alternative to `zerocopy::TryFromBytes`,
+which is not implemented for this type because it cannot check the contents.
+```
+# use microstring::*;
+let mut bytes = [0; 127 + 1];
+bytes[0] = 94;
+bytes[1..][..94].copy_from_slice(b"the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home");
+assert_eq!(DecaString::try_ref_from_bytes(&bytes).unwrap().as_str(), "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home");
+bytes[1] = 0xFF;
+assert_eq!(DecaString::try_ref_from_bytes(&bytes), None);
+```
+*/
+    pub const fn try_ref_from_bytes(bytes: &[u8]) -> Option<&Self> {
+        const { assert!(::core::mem::align_of:: < Self > () == 1) }
+        match Self::is_valid(bytes) {
+            true => Some(unsafe { &*bytes.as_ptr().cast::<Self>() }),
+            false => None,
+        }
+    }
+    /// Like [`try_ref_from_bytes`](Self::try_ref_from_bytes),
+    /// but for a contiguous run of strings.
+    pub const fn try_slice_from_bytes(bytes: &[u8]) -> Option<&[Self]> {
+        const { assert!(::core::mem::align_of:: < Self > () == 1) }
+        let size = ::core::mem::size_of::<Self>();
+        if !bytes.len().is_multiple_of(size) {
+            return None;
+        }
+        let mut rest = bytes;
+        while let Some((chunk, tail)) = rest.split_at_checked(size) {
+            if !Self::is_valid(chunk) {
+                return None;
+            }
+            rest = tail;
+        }
+        Some(unsafe {
+            ::core::slice::from_raw_parts(
+                bytes.as_ptr().cast::<Self>(),
+                bytes.len() / size,
+            )
+        })
+    }
+    /// Whether the given bytes are a length byte, that many bytes of UTF-8, and zero padding.
+    const fn is_valid(bytes: &[u8]) -> bool {
+        match bytes {
+            [len, rest @ ..] if rest.len() == 127u8 as usize => {
+                Self::is_valid_parts(*len, rest)
+            }
+            _ => false,
+        }
+    }
+    /// Whether `len` is in range, and `bytes` are that many bytes of UTF-8, followed by zero padding.
+    const fn is_valid_parts(len: u8, bytes: &[u8]) -> bool {
+        let Some(len) = DecaStringLen::from_u8(len) else {
+            return false;
+        };
+        let (string, mut padding) = bytes.split_at(len as u8 as usize);
+        if ::core::str::from_utf8(string).is_err() {
+            return false;
+        }
+        while let [0, tail @ ..] = padding {
+            padding = tail;
+        }
+        padding.is_empty()
+    }
+}
+impl DecaString {
+    /**Packs this string into a byte array.
+
+The string's bytes, zero padded to 127 bytes, come first,
+and its length is the last byte.
+This is stable, so may be persisted.
+Comparing the packed representations orders them the same as comparing the strings.
+```
+# use microstring::*;
+const BITS: [u8; 127 + 1] = DecaString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home").unwrap().to_bits();
+assert_eq!(BITS, *b"the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x5e");
+assert_eq!(DecaString::from_bits(BITS), DecaString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home"));
+```
+*/
+    pub const fn to_bits(self) -> [u8; 127u8 as usize + 1] {
+        let mut bits = [0; 127u8 as usize + 1];
+        let (bytes, len) = bits.split_at_mut(127u8 as usize);
+        bytes.copy_from_slice(&self.bytes);
+        len[0] = self.len as u8;
+        bits
+    }
+    /// The inverse of [`to_bits`](Self::to_bits).
+    ///
+    /// Returns [`None`] if the length is out of range,
+    /// the string is not UTF-8, or the padding is not zero.
+    pub const fn from_bits(bits: [u8; 127u8 as usize + 1]) -> Option<Self> {
+        let [bytes @ .., len] = bits;
+        match Self::is_valid_parts(len, &bytes) {
+            true => Some(unsafe { Self::from_bits_unchecked(bits) }),
+            false => None,
+        }
+    }
+    /// The inverse of [`to_bits`](Self::to_bits), without any checks.
+    ///
+    /// # Safety
+    /// - `bits` must have been returned by [`to_bits`](Self::to_bits),
+    ///   or be accepted by [`from_bits`](Self::from_bits).
+    pub const unsafe fn from_bits_unchecked(bits: [u8; 127u8 as usize + 1]) -> Self {
+        let [bytes @ .., len] = bits;
+        Self {
+            len: unsafe { ::core::mem::transmute::<u8, DecaStringLen>(len) },
+            bytes,
+        }
+    }
+}
+impl crate::__private::Sealed for DecaString {}
+impl crate::InlineStr for DecaString {
+    const CAPACITY: usize = 127u8 as usize;
+    const EMPTY: Self = Self::EMPTY;
+    type Error = DecaStringError;
+    type Bits = [u8; 127u8 as usize + 1];
+    fn new(s: &str) -> Option<Self> {
+        Self::new(s)
+    }
+    fn as_str(&self) -> &str {
+        self.as_str()
+    }
+    fn as_mut_str(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+    fn from_utf8(bytes: &[u8]) -> Result<Self, DecaStringError> {
+        Self::from_utf8(bytes)
+    }
+    fn to_bits(self) -> [u8; 127u8 as usize + 1] {
+        self.to_bits()
+    }
+    fn from_bits(bits: [u8; 127u8 as usize + 1]) -> Option<Self> {
+        Self::from_bits(bits)
+    }
+    fn push_str(&mut self, s: &str) -> Result<(), DecaStringError> {
+        self.push_str(s)
+    }
+    fn try_from_iter<I: IntoIterator<Item = char>>(
+        iter: I,
+    ) -> Result<Self, DecaStringError> {
+        Self::try_from_iter(iter)
+    }
+    fn __error(kind: crate::ErrorKind, len: usize) -> DecaStringError {
+        DecaStringError::new(kind, len)
+    }
+}
+impl ::core::default::Default for DecaString {
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+impl ::core::default::Default for &DecaString {
+    fn default() -> Self {
+        &DecaString::EMPTY
+    }
+}
+/// Hashes the same as [`str`], as required by [`Borrow<str>`](::core::borrow::Borrow).
+impl ::core::hash::Hash for DecaString {
+    fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+impl ::core::convert::AsRef<Self> for DecaString {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+impl ::core::convert::AsMut<Self> for DecaString {
+    fn as_mut(&mut self) -> &mut Self {
+        self
+    }
+}
+impl ::core::convert::AsRef<str> for DecaString {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+impl ::core::convert::AsMut<str> for DecaString {
+    fn as_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+impl ::core::convert::AsRef<[u8]> for DecaString {
+    fn as_ref(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+}
+impl TryFrom<&str> for DecaString {
+    type Error = DecaStringError;
+    fn try_from(value: &str) -> Result<Self, DecaStringError> {
+        Self::new(value)
+            .ok_or(DecaStringError::new(crate::ErrorKind::TooLong, value.len()))
+    }
+}
+impl TryFrom<&[u8]> for DecaString {
+    type Error = DecaStringError;
+    fn try_from(value: &[u8]) -> Result<Self, DecaStringError> {
+        Self::from_utf8(value)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for DecaString {
+    type Error = DecaStringError;
+    fn try_from(value: [u8; N]) -> Result<Self, DecaStringError> {
+        Self::from_utf8(&value)
+    }
+}
+#[cfg(feature = "std")]
+impl ::core::convert::AsRef<::std::ffi::OsStr> for DecaString {
+    fn as_ref(&self) -> &::std::ffi::OsStr {
+        self.as_str().as_ref()
+    }
+}
+#[cfg(feature = "std")]
+impl ::core::convert::AsRef<::std::path::Path> for DecaString {
+    fn as_ref(&self) -> &::std::path::Path {
+        self.as_str().as_ref()
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::TryFrom<::alloc::string::String> for DecaString {
+    type Error = DecaStringError;
+    fn try_from(value: ::alloc::string::String) -> Result<Self, DecaStringError> {
+        Self::new(&value)
+            .ok_or(DecaStringError::new(crate::ErrorKind::TooLong, value.len()))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::From<DecaString> for ::alloc::string::String {
+    fn from(val: DecaString) -> Self {
+        Self::from(val.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::From<DecaString> for ::alloc::boxed::Box<str> {
+    fn from(val: DecaString) -> Self {
+        Self::from(val.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::From<DecaString> for ::alloc::rc::Rc<str> {
+    fn from(val: DecaString) -> Self {
+        Self::from(val.as_str())
+    }
+}
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl ::core::convert::From<DecaString> for ::alloc::sync::Arc<str> {
+    fn from(val: DecaString) -> Self {
+        Self::from(val.as_str())
+    }
+}
+#[cfg(feature = "std")]
+impl ::core::convert::From<DecaString> for ::std::ffi::OsString {
+    fn from(val: DecaString) -> Self {
+        Self::from(val.as_str())
+    }
+}
+#[cfg(feature = "std")]
+impl ::core::convert::From<DecaString> for ::std::path::PathBuf {
+    fn from(val: DecaString) -> Self {
+        Self::from(val.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::From<DecaString> for ::alloc::borrow::Cow<'static, str> {
+    fn from(val: DecaString) -> Self {
+        Self::Owned(::alloc::string::String::from(val))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::TryFrom<::alloc::boxed::Box<str>> for DecaString {
+    type Error = DecaStringError;
+    fn try_from(value: ::alloc::boxed::Box<str>) -> Result<Self, DecaStringError> {
+        Self::try_from(&*value)
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::TryFrom<::alloc::borrow::Cow<'_, str>> for DecaString {
+    type Error = DecaStringError;
+    fn try_from(value: ::alloc::borrow::Cow<'_, str>) -> Result<Self, DecaStringError> {
+        Self::try_from(&*value)
+    }
+}
+#[cfg(feature = "std")]
+impl ::core::convert::TryFrom<&::std::ffi::OsStr> for DecaString {
+    type Error = DecaStringError;
+    fn try_from(value: &::std::ffi::OsStr) -> Result<Self, DecaStringError> {
+        match value.to_str() {
+            Some(s) => Self::try_from(s),
+            None => Err(DecaStringError::new(crate::ErrorKind::InvalidUtf8, value.len())),
+        }
+    }
+}
+#[cfg(feature = "std")]
+impl ::core::convert::TryFrom<&::std::path::Path> for DecaString {
+    type Error = DecaStringError;
+    fn try_from(value: &::std::path::Path) -> Result<Self, DecaStringError> {
+        Self::try_from(value.as_os_str())
+    }
+}
+impl ::core::convert::TryFrom<&::core::ffi::CStr> for DecaString {
+    type Error = DecaStringError;
+    fn try_from(value: &::core::ffi::CStr) -> Result<Self, DecaStringError> {
+        Self::from_utf8(value.to_bytes())
+    }
+}
+impl ::core::borrow::Borrow<str> for DecaString {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+impl ::core::borrow::BorrowMut<str> for DecaString {
+    fn borrow_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+impl ::core::ops::Deref for DecaString {
+    type Target = str;
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+impl ::core::ops::DerefMut for DecaString {
+    fn deref_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+/// Compares the whole string as a byte array.
+impl ::core::cmp::PartialEq for DecaString {
+    fn eq(&self, other: &Self) -> bool {
+        self.len as u8 == other.len as u8 && self.bytes == other.bytes
+    }
+}
+impl ::core::cmp::PartialOrd for DecaString {
+    fn partial_cmp(&self, other: &Self) -> Option<::core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+/**Orders strings the same way as [`str`].
+
+This compares the zero-padded bytes in place, then the length.
+```
+# use microstring::*;
+let mut strings = ["b", "a\0", "", "a"].map(|it| DecaString::new(it).unwrap());
+strings.sort();
+assert_eq!(strings, ["", "a", "a\0", "b"]);
+```
+*/
+impl ::core::cmp::Ord for DecaString {
+    fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+        self.bytes.cmp(&other.bytes).then(self.len.cmp(&other.len))
+    }
+}
+impl ::core::cmp::PartialEq<str> for DecaString {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str().eq(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+impl ::core::cmp::PartialOrd<str> for DecaString {
+    fn partial_cmp(&self, other: &str) -> Option<::core::cmp::Ordering> {
+        self.as_str().partial_cmp(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+impl ::core::cmp::PartialEq<DecaString> for str {
+    fn eq(&self, other: &DecaString) -> bool {
+        ::core::convert::AsRef::<str>::as_ref(self).eq(other.as_str())
+    }
+}
+impl ::core::cmp::PartialOrd<DecaString> for str {
+    fn partial_cmp(&self, other: &DecaString) -> Option<::core::cmp::Ordering> {
+        ::core::convert::AsRef::<str>::as_ref(self).partial_cmp(other.as_str())
+    }
+}
+impl ::core::cmp::PartialEq<&str> for DecaString {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str().eq(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+impl ::core::cmp::PartialOrd<&str> for DecaString {
+    fn partial_cmp(&self, other: &&str) -> Option<::core::cmp::Ordering> {
+        self.as_str().partial_cmp(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+impl ::core::cmp::PartialEq<DecaString> for &str {
+    fn eq(&self, other: &DecaString) -> bool {
+        ::core::convert::AsRef::<str>::as_ref(self).eq(other.as_str())
+    }
+}
+impl ::core::cmp::PartialOrd<DecaString> for &str {
+    fn partial_cmp(&self, other: &DecaString) -> Option<::core::cmp::Ordering> {
+        ::core::convert::AsRef::<str>::as_ref(self).partial_cmp(other.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialEq<::alloc::string::String> for DecaString {
+    fn eq(&self, other: &::alloc::string::String) -> bool {
+        self.as_str().eq(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialOrd<::alloc::string::String> for DecaString {
+    fn partial_cmp(
+        &self,
+        other: &::alloc::string::String,
+    ) -> Option<::core::cmp::Ordering> {
+        self.as_str().partial_cmp(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialEq<DecaString> for ::alloc::string::String {
+    fn eq(&self, other: &DecaString) -> bool {
+        ::core::convert::AsRef::<str>::as_ref(self).eq(other.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialOrd<DecaString> for ::alloc::string::String {
+    fn partial_cmp(&self, other: &DecaString) -> Option<::core::cmp::Ordering> {
+        ::core::convert::AsRef::<str>::as_ref(self).partial_cmp(other.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialEq<::alloc::borrow::Cow<'_, str>> for DecaString {
+    fn eq(&self, other: &::alloc::borrow::Cow<'_, str>) -> bool {
+        self.as_str().eq(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialOrd<::alloc::borrow::Cow<'_, str>> for DecaString {
+    fn partial_cmp(
+        &self,
+        other: &::alloc::borrow::Cow<'_, str>,
+    ) -> Option<::core::cmp::Ordering> {
+        self.as_str().partial_cmp(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialEq<DecaString> for ::alloc::borrow::Cow<'_, str> {
+    fn eq(&self, other: &DecaString) -> bool {
+        ::core::convert::AsRef::<str>::as_ref(self).eq(other.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialOrd<DecaString> for ::alloc::borrow::Cow<'_, str> {
+    fn partial_cmp(&self, other: &DecaString) -> Option<::core::cmp::Ordering> {
+        ::core::convert::AsRef::<str>::as_ref(self).partial_cmp(other.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialEq<::alloc::boxed::Box<str>> for DecaString {
+    fn eq(&self, other: &::alloc::boxed::Box<str>) -> bool {
+        self.as_str().eq(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialOrd<::alloc::boxed::Box<str>> for DecaString {
+    fn partial_cmp(
+        &self,
+        other: &::alloc::boxed::Box<str>,
+    ) -> Option<::core::cmp::Ordering> {
+        self.as_str().partial_cmp(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialEq<DecaString> for ::alloc::boxed::Box<str> {
+    fn eq(&self, other: &DecaString) -> bool {
+        ::core::convert::AsRef::<str>::as_ref(self).eq(other.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialOrd<DecaString> for ::alloc::boxed::Box<str> {
+    fn partial_cmp(&self, other: &DecaString) -> Option<::core::cmp::Ordering> {
+        ::core::convert::AsRef::<str>::as_ref(self).partial_cmp(other.as_str())
+    }
+}
+impl ::core::fmt::Debug for DecaString {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl ::core::fmt::Display for DecaString {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+/**Writes which would overflow the capacity return [`fmt::Error`](::core::fmt::Error).
+
+On error, the string is restored to its state before the call to
+[`write!`](::core::write).
+```
+# use microstring::*;
+use core::fmt::Write as _;
+let mut string = DecaString::EMPTY;
+assert!(write!(string, "{}{}", "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home", "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills").is_err());
+assert_eq!(string, "");
+```
+*/
+impl ::core::fmt::Write for DecaString {
+    fn write_str(&mut self, s: &str) -> ::core::fmt::Result {
+        self.push_str(s).map_err(|_| ::core::fmt::Error)
+    }
+    fn write_char(&mut self, c: char) -> ::core::fmt::Result {
+        self.push(c).map_err(|_| ::core::fmt::Error)
+    }
+    fn write_fmt(&mut self, args: ::core::fmt::Arguments<'_>) -> ::core::fmt::Result {
+        let before = *self;
+        ::core::fmt::write(self, args).inspect_err(|_| *self = before)
+    }
+}
+impl DecaString {
+    /**Creates a string from the given [`Arguments`](::core::fmt::Arguments).
+
+See also [`deca_format!`](crate::deca_format).
+*/
+    pub fn try_from_fmt(
+        args: ::core::fmt::Arguments<'_>,
+    ) -> Result<Self, DecaStringError> {
+        struct Len(usize);
+        impl ::core::fmt::Write for Len {
+            fn write_str(&mut self, s: &str) -> ::core::fmt::Result {
+                self.0 += s.len();
+                Ok(())
+            }
+        }
+        let mut s = Self::EMPTY;
+        match ::core::fmt::Write::write_fmt(&mut s, args) {
+            Ok(()) => Ok(s),
+            Err(_) => {
+                let mut len = Len(0);
+                let _ = ::core::fmt::Write::write_fmt(&mut len, args);
+                Err(DecaStringError::new(crate::ErrorKind::TooLong, len.0))
+            }
+        }
+    }
+}
+/**Creates a [`DecaString`] using interpolation of runtime expressions,
+like [`format!`](https://doc.rust-lang.org/std/macro.format.html).
+
+Returns an error if the result would be longer than 127 bytes.
+```
+# use microstring::*;
+assert_eq!(deca_format!("{}", "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home").unwrap(), "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home");
+assert!(deca_format!("{}", "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills").is_err());
+```
+*/
+#[macro_export]
+macro_rules! deca_format {
+    ($($arg:tt)*) => {
+        $crate::DecaString::try_from_fmt(::core::format_args!($($arg)*))
+    };
+}
+impl ::core::str::FromStr for DecaString {
+    type Err = DecaStringError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s).ok_or(DecaStringError::new(crate::ErrorKind::TooLong, s.len()))
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for DecaString {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(self)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for DecaString {
+    fn deserialize<D: serde::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        struct Visitor;
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = DecaString;
+            fn expecting(
+                &self,
+                f: &mut ::core::fmt::Formatter<'_>,
+            ) -> ::core::fmt::Result {
+                f.write_str("a string of at most 127 bytes")
+            }
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<DecaString, E> {
+                DecaString::new(v)
+                    .ok_or_else(|| serde::de::Error::invalid_length(v.len(), &self))
+            }
+        }
+        d.deserialize_str(Visitor)
+    }
+}
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for DecaString {
+    fn schema_name() -> ::std::borrow::Cow<'static, str> {
+        ::std::borrow::Cow::Borrowed(::core::stringify!(DecaString))
+    }
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema! {
+            { "type" : "string", "maxLength" : 127u8, }
+        }
+    }
+    fn schema_id() -> ::std::borrow::Cow<'static, str> {
+        ::std::borrow::Cow::Borrowed(
+            ::core::concat!(::core::module_path!(), "::", ::core::stringify!(DecaString)),
+        )
+    }
+}
+/**Generates strings of every length up to 127 bytes, including multi-byte [`char`]s.
+```
+# use microstring::*;
+use arbitrary::{Arbitrary, Unstructured};
+
+// an empty input consumes nothing
+assert_eq!(DecaString::arbitrary(&mut Unstructured::new(&[])).unwrap(), "");
+let (min, max) = DecaString::size_hint(0);
+assert_eq!(min, 0);
+let max = max.unwrap();
+
+let mut lens = [false; 127 + 1];
+let mut multi_byte = false;
+for seed in 0..=u8::MAX {
+    // the first byte chooses the length
+    let mut state = u32::from(seed) << 8 | 1;
+    let data = [seed].into_iter().chain((1..max).map(|_| {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        state as u8
+    })).collect::<Vec<_>>();
+    let mut u = Unstructured::new(&data);
+    let s = DecaString::arbitrary(&mut u).unwrap();
+    assert!(min <= data.len() - u.len() && data.len() - u.len() <= max);
+    assert!(str::from_utf8(s.as_bytes()).is_ok());
+    assert_eq!(s.to_bits(), DecaString::new(&s).unwrap().to_bits());
+    lens[s.len()] = true;
+    multi_byte |= s.chars().any(|c| c.len_utf8() > 1);
+}
+assert!(lens.iter().all(|it| *it) && multi_byte);
+```
+*/
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DecaString {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let len = u.int_in_range(0..=127u8)? as usize;
+        let mut it = Self::EMPTY;
+        while (it.len as u8 as usize) < len {
+            let remaining = len - it.len as u8 as usize;
+            let width = u.int_in_range(1..=remaining.min(4))?;
+            let _ = it.push(crate::__private::arbitrary_char(u, width)?);
+        }
+        Ok(it)
+    }
+    fn size_hint(_: usize) -> (usize, Option<usize>) {
+        (0, Some(1 + 2 * 127u8 as usize))
+    }
+}
+impl crate::Lookup<DecaString> for DecaString {
+    fn lookup(&self) -> Option<DecaString> {
+        Some(*self)
+    }
+}
+impl crate::Lookup<DecaString> for str {
+    fn lookup(&self) -> Option<DecaString> {
+        DecaString::new(self)
+    }
+}
+impl crate::Lookup<DecaString> for &str {
+    fn lookup(&self) -> Option<DecaString> {
+        DecaString::new(self)
+    }
+}
+#[cfg(feature = "alloc")]
+impl crate::Lookup<DecaString> for ::alloc::string::String {
+    fn lookup(&self) -> Option<DecaString> {
+        DecaString::new(self)
+    }
+}
+#[cfg(feature = "alloc")]
+impl crate::Lookup<DecaString> for ::alloc::borrow::Cow<'_, str> {
+    fn lookup(&self) -> Option<DecaString> {
+        DecaString::new(self)
+    }
+}
+#[cfg(feature = "alloc")]
+impl crate::Lookup<DecaString> for ::alloc::boxed::Box<str> {
+    fn lookup(&self) -> Option<DecaString> {
+        DecaString::new(self)
+    }
+}
+/**A hash map keyed by [`DecaString`].
+
+Keys are stored and hashed as their packed [`to_bits`](DecaString::to_bits) representation,
+using [`BuildWordHasher`](crate::BuildWordHasher).
+Entries may also be looked up by [`str`] and [`String`](std::string::String).
+```
+# use microstring::*;
+let mut map = DecaMap::new();
+map.insert(DecaString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home").unwrap(), 1);
+assert_eq!(map.get("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home"), Some(&1));
+assert_eq!(map.get(&DecaString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home").unwrap()), Some(&1));
+assert_eq!(map.get(&String::from("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home")), Some(&1));
+assert_eq!(map.get("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills"), None);
+
+*map.entry(DecaString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home").unwrap()).or_default() += 1;
+assert_eq!(map.into_iter().collect::<Vec<_>>(), [(DecaString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home").unwrap(), 2)]);
+```
+*/
+#[cfg(feature = "std")]
+#[derive(Clone)]
+pub struct DecaMap<V> {
+    inner: ::std::collections::HashMap<
+        [u8; 127u8 as usize + 1],
+        V,
+        crate::BuildWordHasher,
+    >,
+}
+#[cfg(feature = "std")]
+impl<V> DecaMap<V> {
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            inner: ::std::collections::HashMap::with_capacity_and_hasher(
+                capacity,
+                crate::BuildWordHasher,
+            ),
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+    pub fn clear(&mut self) {
+        self.inner.clear()
+    }
+    pub fn insert(&mut self, key: DecaString, value: V) -> Option<V> {
+        self.inner.insert(key.to_bits(), value)
+    }
+    /// Gets the entry for the given key, to insert or update it with a single probe.
+    pub fn entry(&mut self, key: DecaString) -> crate::Entry<'_, DecaString, V> {
+        crate::Entry::__new(&mut self.inner, key)
+    }
+    pub fn get<Q: ?Sized + crate::Lookup<DecaString>>(&self, key: &Q) -> Option<&V> {
+        self.inner.get(&key.lookup()?.to_bits())
+    }
+    pub fn get_mut<Q: ?Sized + crate::Lookup<DecaString>>(
+        &mut self,
+        key: &Q,
+    ) -> Option<&mut V> {
+        self.inner.get_mut(&key.lookup()?.to_bits())
+    }
+    pub fn contains_key<Q: ?Sized + crate::Lookup<DecaString>>(&self, key: &Q) -> bool {
+        self.get(key).is_some()
+    }
+    pub fn remove<Q: ?Sized + crate::Lookup<DecaString>>(
+        &mut self,
+        key: &Q,
+    ) -> Option<V> {
+        self.inner.remove(&key.lookup()?.to_bits())
+    }
+    pub fn iter(&self) -> impl Iterator<Item = (DecaString, &V)> {
+        self.inner
+            .iter()
+            .map(|(k, v)| (unsafe { DecaString::from_bits_unchecked(*k) }, v))
+    }
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (DecaString, &mut V)> {
+        self.inner
+            .iter_mut()
+            .map(|(k, v)| (unsafe { DecaString::from_bits_unchecked(*k) }, v))
+    }
+    pub fn keys(&self) -> impl Iterator<Item = DecaString> {
+        self.inner.keys().map(|k| unsafe { DecaString::from_bits_unchecked(*k) })
+    }
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.inner.values()
+    }
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut V> {
+        self.inner.values_mut()
+    }
+}
+#[cfg(feature = "std")]
+impl<V> ::core::default::Default for DecaMap<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+#[cfg(feature = "std")]
+impl<V: ::core::fmt::Debug> ::core::fmt::Debug for DecaMap<V> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+#[cfg(feature = "std")]
+impl<V: ::core::cmp::PartialEq> ::core::cmp::PartialEq for DecaMap<V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+#[cfg(feature = "std")]
+impl<V: ::core::cmp::Eq> ::core::cmp::Eq for DecaMap<V> {}
+#[cfg(feature = "std")]
+impl<V> ::core::iter::Extend<(DecaString, V)> for DecaMap<V> {
+    fn extend<I: IntoIterator<Item = (DecaString, V)>>(&mut self, iter: I) {
+        self.inner.extend(iter.into_iter().map(|(k, v)| (k.to_bits(), v)))
+    }
+}
+#[cfg(feature = "std")]
+impl<V> ::core::iter::IntoIterator for DecaMap<V> {
+    type Item = (DecaString, V);
+    type IntoIter = ::core::iter::Map<
+        ::std::collections::hash_map::IntoIter<[u8; 127u8 as usize + 1], V>,
+        fn(([u8; 127u8 as usize + 1], V)) -> (DecaString, V),
+    >;
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner
+            .into_iter()
+            .map(|(k, v)| (unsafe { DecaString::from_bits_unchecked(k) }, v))
+    }
+}
+#[cfg(feature = "std")]
+impl<'a, V> ::core::iter::IntoIterator for &'a DecaMap<V> {
+    type Item = (DecaString, &'a V);
+    type IntoIter = ::core::iter::Map<
+        ::std::collections::hash_map::Iter<'a, [u8; 127u8 as usize + 1], V>,
+        fn((&'a [u8; 127u8 as usize + 1], &'a V)) -> (DecaString, &'a V),
+    >;
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner
+            .iter()
+            .map(|(k, v)| (unsafe { DecaString::from_bits_unchecked(*k) }, v))
+    }
+}
+#[cfg(feature = "std")]
+impl<'a, V> ::core::iter::IntoIterator for &'a mut DecaMap<V> {
+    type Item = (DecaString, &'a mut V);
+    type IntoIter = ::core::iter::Map<
+        ::std::collections::hash_map::IterMut<'a, [u8; 127u8 as usize + 1], V>,
+        fn((&'a [u8; 127u8 as usize + 1], &'a mut V)) -> (DecaString, &'a mut V),
+    >;
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner
+            .iter_mut()
+            .map(|(k, v)| (unsafe { DecaString::from_bits_unchecked(*k) }, v))
+    }
+}
+#[cfg(feature = "std")]
+impl<V> ::core::iter::FromIterator<(DecaString, V)> for DecaMap<V> {
+    fn from_iter<I: IntoIterator<Item = (DecaString, V)>>(iter: I) -> Self {
+        let mut it = Self::new();
+        it.extend(iter);
+        it
+    }
+}
+#[cfg(feature = "const-default")]
+impl const_default::ConstDefault for DecaString {
+    const DEFAULT: Self = DecaString::EMPTY;
+}
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u8)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout,
+        zerocopy::Unaligned
+    )
+)]
+enum DecaStringLen {
+    _0 = 0u8,
+    _1 = 1u8,
+    _2 = 2u8,
+    _3 = 3u8,
+    _4 = 4u8,
+    _5 = 5u8,
+    _6 = 6u8,
+    _7 = 7u8,
+    _8 = 8u8,
+    _9 = 9u8,
+    _10 = 10u8,
+    _11 = 11u8,
+    _12 = 12u8,
+    _13 = 13u8,
+    _14 = 14u8,
+    _15 = 15u8,
+    _16 = 16u8,
+    _17 = 17u8,
+    _18 = 18u8,
+    _19 = 19u8,
+    _20 = 20u8,
+    _21 = 21u8,
+    _22 = 22u8,
+    _23 = 23u8,
+    _24 = 24u8,
+    _25 = 25u8,
+    _26 = 26u8,
+    _27 = 27u8,
+    _28 = 28u8,
+    _29 = 29u8,
+    _30 = 30u8,
+    _31 = 31u8,
+    _32 = 32u8,
+    _33 = 33u8,
+    _34 = 34u8,
+    _35 = 35u8,
+    _36 = 36u8,
+    _37 = 37u8,
+    _38 = 38u8,
+    _39 = 39u8,
+    _40 = 40u8,
+    _41 = 41u8,
+    _42 = 42u8,
+    _43 = 43u8,
+    _44 = 44u8,
+    _45 = 45u8,
+    _46 = 46u8,
+    _47 = 47u8,
+    _48 = 48u8,
+    _49 = 49u8,
+    _50 = 50u8,
+    _51 = 51u8,
+    _52 = 52u8,
+    _53 = 53u8,
+    _54 = 54u8,
+    _55 = 55u8,
+    _56 = 56u8,
+    _57 = 57u8,
+    _58 = 58u8,
+    _59 = 59u8,
+    _60 = 60u8,
+    _61 = 61u8,
+    _62 = 62u8,
+    _63 = 63u8,
+    _64 = 64u8,
+    _65 = 65u8,
+    _66 = 66u8,
+    _67 = 67u8,
+    _68 = 68u8,
+    _69 = 69u8,
+    _70 = 70u8,
+    _71 = 71u8,
+    _72 = 72u8,
+    _73 = 73u8,
+    _74 = 74u8,
+    _75 = 75u8,
+    _76 = 76u8,
+    _77 = 77u8,
+    _78 = 78u8,
+    _79 = 79u8,
+    _80 = 80u8,
+    _81 = 81u8,
+    _82 = 82u8,
+    _83 = 83u8,
+    _84 = 84u8,
+    _85 = 85u8,
+    _86 = 86u8,
+    _87 = 87u8,
+    _88 = 88u8,
+    _89 = 89u8,
+    _90 = 90u8,
+    _91 = 91u8,
+    _92 = 92u8,
+    _93 = 93u8,
+    _94 = 94u8,
+    _95 = 95u8,
+    _96 = 96u8,
+    _97 = 97u8,
+    _98 = 98u8,
+    _99 = 99u8,
+    _100 = 100u8,
+    _101 = 101u8,
+    _102 = 102u8,
+    _103 = 103u8,
+    _104 = 104u8,
+    _105 = 105u8,
+    _106 = 106u8,
+    _107 = 107u8,
+    _108 = 108u8,
+    _109 = 109u8,
+    _110 = 110u8,
+    _111 = 111u8,
+    _112 = 112u8,
+    _113 = 113u8,
+    _114 = 114u8,
+    _115 = 115u8,
+    _116 = 116u8,
+    _117 = 117u8,
+    _118 = 118u8,
+    _119 = 119u8,
+    _120 = 120u8,
+    _121 = 121u8,
+    _122 = 122u8,
+    _123 = 123u8,
+    _124 = 124u8,
+    _125 = 125u8,
+    _126 = 126u8,
+    _127 = 127u8,
+}
+impl DecaStringLen {
+    const fn from_usize(u: usize) -> Option<Self> {
+        const U8_MAX: usize = u8::MAX as _;
+        if u > U8_MAX {
+            return None;
+        }
+        Self::from_u8(u as u8)
+    }
+    const fn from_u8(u: u8) -> Option<Self> {
+        match u {
+            0u8 => Some(Self::_0),
+            1u8 => Some(Self::_1),
+            2u8 => Some(Self::_2),
+            3u8 => Some(Self::_3),
+            4u8 => Some(Self::_4),
+            5u8 => Some(Self::_5),
+            6u8 => Some(Self::_6),
+            7u8 => Some(Self::_7),
+            8u8 => Some(Self::_8),
+            9u8 => Some(Self::_9),
+            10u8 => Some(Self::_10),
+            11u8 => Some(Self::_11),
+            12u8 => Some(Self::_12),
+            13u8 => Some(Self::_13),
+            14u8 => Some(Self::_14),
+            15u8 => Some(Self::_15),
+            16u8 => Some(Self::_16),
+            17u8 => Some(Self::_17),
+            18u8 => Some(Self::_18),
+            19u8 => Some(Self::_19),
+            20u8 => Some(Self::_20),
+            21u8 => Some(Self::_21),
+            22u8 => Some(Self::_22),
+            23u8 => Some(Self::_23),
+            24u8 => Some(Self::_24),
+            25u8 => Some(Self::_25),
+            26u8 => Some(Self::_26),
+            27u8 => Some(Self::_27),
+            28u8 => Some(Self::_28),
+            29u8 => Some(Self::_29),
+            30u8 => Some(Self::_30),
+            31u8 => Some(Self::_31),
+            32u8 => Some(Self::_32),
+            33u8 => Some(Self::_33),
+            34u8 => Some(Self::_34),
+            35u8 => Some(Self::_35),
+            36u8 => Some(Self::_36),
+            37u8 => Some(Self::_37),
+            38u8 => Some(Self::_38),
+            39u8 => Some(Self::_39),
+            40u8 => Some(Self::_40),
+            41u8 => Some(Self::_41),
+            42u8 => Some(Self::_42),
+            43u8 => Some(Self::_43),
+            44u8 => Some(Self::_44),
+            45u8 => Some(Self::_45),
+            46u8 => Some(Self::_46),
+            47u8 => Some(Self::_47),
+            48u8 => Some(Self::_48),
+            49u8 => Some(Self::_49),
+            50u8 => Some(Self::_50),
+            51u8 => Some(Self::_51),
+            52u8 => Some(Self::_52),
+            53u8 => Some(Self::_53),
+            54u8 => Some(Self::_54),
+            55u8 => Some(Self::_55),
+            56u8 => Some(Self::_56),
+            57u8 => Some(Self::_57),
+            58u8 => Some(Self::_58),
+            59u8 => Some(Self::_59),
+            60u8 => Some(Self::_60),
+            61u8 => Some(Self::_61),
+            62u8 => Some(Self::_62),
+            63u8 => Some(Self::_63),
+            64u8 => Some(Self::_64),
+            65u8 => Some(Self::_65),
+            66u8 => Some(Self::_66),
+            67u8 => Some(Self::_67),
+            68u8 => Some(Self::_68),
+            69u8 => Some(Self::_69),
+            70u8 => Some(Self::_70),
+            71u8 => Some(Self::_71),
+            72u8 => Some(Self::_72),
+            73u8 => Some(Self::_73),
+            74u8 => Some(Self::_74),
+            75u8 => Some(Self::_75),
+            76u8 => Some(Self::_76),
+            77u8 => Some(Self::_77),
+            78u8 => Some(Self::_78),
+            79u8 => Some(Self::_79),
+            80u8 => Some(Self::_80),
+            81u8 => Some(Self::_81),
+            82u8 => Some(Self::_82),
+            83u8 => Some(Self::_83),
+            84u8 => Some(Self::_84),
+            85u8 => Some(Self::_85),
+            86u8 => Some(Self::_86),
+            87u8 => Some(Self::_87),
+            88u8 => Some(Self::_88),
+            89u8 => Some(Self::_89),
+            90u8 => Some(Self::_90),
+            91u8 => Some(Self::_91),
+            92u8 => Some(Self::_92),
+            93u8 => Some(Self::_93),
+            94u8 => Some(Self::_94),
+            95u8 => Some(Self::_95),
+            96u8 => Some(Self::_96),
+            97u8 => Some(Self::_97),
+            98u8 => Some(Self::_98),
+            99u8 => Some(Self::_99),
+            100u8 => Some(Self::_100),
+            101u8 => Some(Self::_101),
+            102u8 => Some(Self::_102),
+            103u8 => Some(Self::_103),
+            104u8 => Some(Self::_104),
+            105u8 => Some(Self::_105),
+            106u8 => Some(Self::_106),
+            107u8 => Some(Self::_107),
+            108u8 => Some(Self::_108),
+            109u8 => Some(Self::_109),
+            110u8 => Some(Self::_110),
+            111u8 => Some(Self::_111),
+            112u8 => Some(Self::_112),
+            113u8 => Some(Self::_113),
+            114u8 => Some(Self::_114),
+            115u8 => Some(Self::_115),
+            116u8 => Some(Self::_116),
+            117u8 => Some(Self::_117),
+            118u8 => Some(Self::_118),
+            119u8 => Some(Self::_119),
+            120u8 => Some(Self::_120),
+            121u8 => Some(Self::_121),
+            122u8 => Some(Self::_122),
+            123u8 => Some(Self::_123),
+            124u8 => Some(Self::_124),
+            125u8 => Some(Self::_125),
+            126u8 => Some(Self::_126),
+            127u8 => Some(Self::_127),
+            _ => None,
+        }
+    }
+}
+/**A stack-allocated string which can hold up to 255 UTF-8 encoded bytes.
+
+Every length is valid, so there is no niche for [`Option`] to use.
+
+With the `zerocopy` feature, this implements `IntoBytes`, `Immutable`,
+`KnownLayout` and `Unaligned`.
+`TryFromBytes` is deliberately not implemented, because its derive cannot check
+that the bytes are UTF-8 followed by zero padding,
+and neither is `FromZeros`, which requires it.
+Use [`try_ref_from_bytes`](HectoString::try_ref_from_bytes)
+or [`try_slice_from_bytes`](HectoString::try_slice_from_bytes) instead.
+```
+# use core::mem::size_of;
+# use microstring::*;
+assert_eq! {
+    size_of::<HectoString>(),
+    255 + 1,
+}
+assert_eq! {
+    size_of::<HectoString>() + 1,
+    size_of::<Option<HectoString>>(),
+}
+```
+*/
+#[derive(Clone, Copy, Eq)]
+#[repr(C)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout,
+        zerocopy::Unaligned
+    )
+)]
+pub struct HectoString {
+    len: HectoStringLen,
+    bytes: [u8; 255u8 as _],
+}
+impl HectoString {
+    pub const EMPTY: Self = Self::new("").unwrap();
+    /**Returns [`None`] if the given <code>[str::len()] > 255</code>.
+```
+# use microstring::*;
+const STRING: HectoString = HectoString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills").unwrap();
+```
+```compile_fail
+# use microstring::*;
+const TOO_BIG: HectoString = HectoString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills, and at dawn the quick brown fox wakes, stretches, and trots off into the woods to find its breakfast, while the lazy dog dreams on").unwrap();
+```
+*/
+    pub const fn new(s: &str) -> Option<Self> {
+        match HectoStringLen::from_usize(s.len()) {
+            Some(len) => {
+                let mut bytes = [0; 255u8 as _];
+                unsafe {
+                    ::core::ptr::copy_nonoverlapping(
+                        s.as_ptr(),
+                        bytes.as_mut_ptr(),
+                        s.len(),
+                    )
+                }
+                Some(Self { len, bytes })
+            }
+            None => None,
+        }
+    }
+    pub const fn as_str(&self) -> &str {
+        unsafe {
+            str::from_utf8_unchecked(
+                ::core::slice::from_raw_parts(
+                    self.bytes.as_ptr(),
+                    self.len as u8 as usize,
+                ),
+            )
+        }
+    }
+    pub const fn as_mut_str(&mut self) -> &mut str {
+        unsafe {
+            str::from_utf8_unchecked_mut(
+                ::core::slice::from_raw_parts_mut(
+                    self.bytes.as_mut_ptr(),
+                    self.len as u8 as usize,
+                ),
+            )
+        }
+    }
+    /**Returns an error if the given bytes are not UTF-8, or if <code>[slice::len()] > 255</code>.
+```
+# use microstring::*;
+const STRING: HectoString = match HectoString::from_utf8(b"the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills") {
+    Ok(it) => it,
+    Err(_) => panic!(),
+};
+assert_eq!(HectoString::from_utf8(b"\xFF").unwrap_err().kind(), ErrorKind::InvalidUtf8);
+assert_eq!(HectoString::from_utf8(b"the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills, and at dawn the quick brown fox wakes, stretches, and trots off into the woods to find its breakfast, while the lazy dog dreams on").unwrap_err().kind(), ErrorKind::TooLong);
+```
+*/
+    pub const fn from_utf8(bytes: &[u8]) -> Result<Self, HectoStringError> {
+        if bytes.len() > 255u8 as usize {
+            return Err(HectoStringError::new(crate::ErrorKind::TooLong, bytes.len()));
+        }
+        match ::core::str::from_utf8(bytes) {
+            Ok(s) => {
+                match Self::new(s) {
+                    Some(it) => Ok(it),
+                    None => {
+                        Err(
+                            HectoStringError::new(crate::ErrorKind::TooLong, bytes.len()),
+                        )
+                    }
+                }
+            }
+            Err(_) => {
+                Err(HectoStringError::new(crate::ErrorKind::InvalidUtf8, bytes.len()))
+            }
+        }
+    }
+    /**Returns [`None`] if <code>[slice::len()] > 255</code>.
+
+# Safety
+- The given bytes must be valid UTF-8.
+*/
+    pub const unsafe fn from_utf8_unchecked(bytes: &[u8]) -> Option<Self> {
+        Self::new(unsafe { str::from_utf8_unchecked(bytes) })
+    }
+    /**Truncates the given string to at most 255 bytes, on a [`char`] boundary.
+```
+# use microstring::*;
+const STRING: HectoString = HectoString::new_truncating("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills, and at dawn the quick brown fox wakes, stretches, and trots off into the woods to find its breakfast, while the lazy dog dreams on");
+assert_eq!(STRING, "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills, and at dawn the quick brown fox wakes, stretches, and trots off into the woods to find it");
+```
+*/
+    pub const fn new_truncating(s: &str) -> Self {
+        let mut end = if s.len() < 255u8 as usize { s.len() } else { 255u8 as usize };
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+        match Self::new(s.split_at(end).0) {
+            Some(it) => it,
+            None => unreachable!(),
+        }
+    }
+    /**If the given string is longer than 255 bytes, truncates it on a [`char`] boundary,
+leaving room to append the given `marker`.
+
+# Panics
+- If the string needs truncating, and <code>marker.[len()](str::len) > 255</code>.
+```
+# use microstring::*;
+const STRING: HectoString = HectoString::new_truncating_with_marker("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills, and at dawn the quick brown fox wakes, stretches, and trots off into the woods to find its breakfast, while the lazy dog dreams on", "…");
+assert_eq!(STRING, "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills, and at dawn the quick brown fox wakes, stretches, and trots off into the woods to find…");
+```
+*/
+    pub const fn new_truncating_with_marker(s: &str, marker: &str) -> Self {
+        if let Some(it) = Self::new(s) {
+            return it;
+        }
+        assert!(marker.len() <= 255u8 as usize, "marker is longer than the capacity");
+        let mut end = 255u8 as usize - marker.len();
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+        let mut it = Self::new_truncating(s.split_at(end).0);
+        match it.push_str(marker) {
+            Ok(()) => it,
+            Err(_) => unreachable!(),
+        }
+    }
+    /// Like [`new_truncating`](Self::new_truncating),
+    /// but never splits a grapheme cluster.
+    #[cfg(feature = "unicode-segmentation")]
+    pub fn new_truncating_graphemes(s: &str) -> Self {
+        use unicode_segmentation::UnicodeSegmentation as _;
+        let end = s
+            .grapheme_indices(true)
+            .map(|(ix, grapheme)| ix + grapheme.len())
+            .take_while(|end| *end <= 255u8 as usize)
+            .last()
+            .unwrap_or(0);
+        Self::new_truncating(&s[..end])
+    }
+}
+/**The error returned when a [`HectoString`] cannot be constructed.
+```
+# use microstring::*;
+let e = "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills, and at dawn the quick brown fox wakes, stretches, and trots off into the woods to find its breakfast, while the lazy dog dreams on".parse::<HectoString>().unwrap_err();
+assert_eq!(e.kind(), ErrorKind::TooLong);
+assert_eq!(e.input_len(), 296);
+assert_eq!(e.to_string(), "expected a string of at most 255 bytes, but got 296 bytes");
+```
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HectoStringError {
+    kind: crate::ErrorKind,
+    len: usize,
+}
+impl HectoStringError {
+    const fn new(kind: crate::ErrorKind, len: usize) -> Self {
+        Self { kind, len }
+    }
+    /// Why the input was rejected.
+    pub const fn kind(&self) -> crate::ErrorKind {
+        self.kind
+    }
+    /// The length of the rejected input, in bytes.
+    ///
+    /// For operations which append to an existing string,
+    /// this is the length the string would have had.
+    pub const fn input_len(&self) -> usize {
+        self.len
+    }
+    /// The maximum length of the string being constructed, in bytes.
+    pub const fn capacity(&self) -> usize {
+        255u8 as usize
+    }
+}
+impl ::core::fmt::Display for HectoStringError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let Self { kind, len } = *self;
+        match kind {
+            crate::ErrorKind::TooLong => {
+                write!(f, "expected a string of at most 255 bytes, but got {len} bytes")
+            }
+            crate::ErrorKind::InvalidUtf8 => {
+                write!(
+                    f,
+                    "expected a UTF-8 string of at most 255 bytes, but got {len} bytes of invalid UTF-8"
+                )
+            }
+            crate::ErrorKind::Invalid => {
+                write!(f, "a string of {len} bytes failed validation")
+            }
+        }
+    }
+}
+impl ::core::error::Error for HectoStringError {}
+impl HectoString {
+    /// The maximum length, in bytes.
+    pub const CAPACITY: usize = 255u8 as usize;
+    /// The length of this string, in bytes.
+    pub const fn len(&self) -> usize {
+        self.len as u8 as usize
+    }
+    /// Returns `true` if this string has a length of zero.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// The number of bytes which may be pushed before this string is full.
+    pub const fn remaining_capacity(&self) -> usize {
+        Self::CAPACITY - self.len()
+    }
+    /// Equivalent to [`str::as_bytes`], but usable in `const`.
+    ///
+    /// Unlike `as_bytes`, this does not shadow
+    /// [`zerocopy::IntoBytes::as_bytes`](https://docs.rs/zerocopy/latest/zerocopy/trait.IntoBytes.html#method.as_bytes),
+    /// which returns the length as well.
+    pub const fn const_as_bytes(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+    /// Equivalent to `==`, but usable in `const`.
+    pub const fn const_eq(&self, other: &str) -> bool {
+        self.len() == other.len() && self.const_cmp(other).is_eq()
+    }
+    /// Equivalent to [`Ord::cmp`], but usable in `const`.
+    pub const fn const_cmp(&self, other: &str) -> ::core::cmp::Ordering {
+        crate::__private::cmp_bytes(self.const_as_bytes(), other.as_bytes())
+    }
+    /// Equivalent to [`str::starts_with`] with a [`str`] pattern, but usable in `const`.
+    pub const fn const_starts_with(&self, prefix: &str) -> bool {
+        match prefix.len() <= self.len() {
+            true => {
+                let (head, _) = self.const_as_bytes().split_at(prefix.len());
+                crate::__private::cmp_bytes(head, prefix.as_bytes()).is_eq()
+            }
+            false => false,
+        }
+    }
+    /// Equivalent to [`str::ends_with`] with a [`str`] pattern, but usable in `const`.
+    pub const fn const_ends_with(&self, suffix: &str) -> bool {
+        match self.len().checked_sub(suffix.len()) {
+            Some(mid) => {
+                let (_, tail) = self.const_as_bytes().split_at(mid);
+                crate::__private::cmp_bytes(tail, suffix.as_bytes()).is_eq()
+            }
+            None => false,
+        }
+    }
+    /// Converts this string to its ASCII upper case equivalent in-place.
+    pub const fn make_ascii_uppercase(&mut self) {
+        self.as_mut_str().make_ascii_uppercase()
+    }
+    /// Converts this string to its ASCII lower case equivalent in-place.
+    pub const fn make_ascii_lowercase(&mut self) {
+        self.as_mut_str().make_ascii_lowercase()
+    }
+    /**Returns a copy of this string, with ASCII letters converted to upper case.
+
+Other bytes are unchanged.
+```
+# use microstring::*;
+const UPPER: HectoString = HectoString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills").unwrap().to_ascii_uppercase();
+assert_eq!(UPPER, "THE QUICK BROWN FOX JUMPS OVER THE LAZY DOG, THEN THE LAZY DOG CHASES THE QUICK BROWN FOX HOME, WHERE THEY BOTH SLEEP BY THE FIRE UNTIL THE SUN RISES OVER THE HILLS");
+for s in ["@AZ[`az{é"] {
+    assert_eq!(HectoString::new(s).unwrap().to_ascii_uppercase(), s.to_ascii_uppercase().as_str());
+}
+```
+*/
+    pub const fn to_ascii_uppercase(mut self) -> Self {
+        self.make_ascii_uppercase();
+        self
+    }
+    /**Returns a copy of this string, with ASCII letters converted to lower case.
+
+Other bytes are unchanged.
+```
+# use microstring::*;
+const LOWER: HectoString = HectoString::new("THE QUICK BROWN FOX JUMPS OVER THE LAZY DOG, THEN THE LAZY DOG CHASES THE QUICK BROWN FOX HOME, WHERE THEY BOTH SLEEP BY THE FIRE UNTIL THE SUN RISES OVER THE HILLS").unwrap().to_ascii_lowercase();
+assert_eq!(LOWER, "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills");
+for s in ["@AZ[`az{é"] {
+    assert_eq!(HectoString::new(s).unwrap().to_ascii_lowercase(), s.to_ascii_lowercase().as_str());
+}
+```
+*/
+    pub const fn to_ascii_lowercase(mut self) -> Self {
+        self.make_ascii_lowercase();
+        self
+    }
+}
+impl HectoString {
+    /// Appends the given [`char`] to the end of this string.
+    ///
+    /// Returns an error, leaving this string unchanged, if there is not enough capacity.
+    pub const fn push(&mut self, c: char) -> Result<(), HectoStringError> {
+        self.push_str(c.encode_utf8(&mut [0; 4]))
+    }
+    /**Appends the given string to the end of this one.
+
+Returns an error, leaving this string unchanged, if the result would be
+longer than 255 bytes.
+```
+# use microstring::*;
+let mut string = HectoString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills").unwrap();
+assert!(string.push_str("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills, and at dawn the quick brown fox wakes, stretches, and trots off into the woods to find its breakfast, while the lazy dog dreams on").is_err());
+assert_eq!(string, "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills");
+```
+*/
+    pub const fn push_str(&mut self, s: &str) -> Result<(), HectoStringError> {
+        let len = self.len as u8 as usize;
+        match HectoStringLen::from_usize(len + s.len()) {
+            Some(new_len) => {
+                unsafe {
+                    ::core::ptr::copy_nonoverlapping(
+                        s.as_ptr(),
+                        self.bytes.as_mut_ptr().add(len),
+                        s.len(),
+                    )
+                }
+                self.len = new_len;
+                Ok(())
+            }
+            None => Err(HectoStringError::new(crate::ErrorKind::TooLong, len + s.len())),
+        }
+    }
+    /**Concatenates the given strings.
+
+Returns an error if the result would be longer than 255 bytes.
+```
+# use microstring::*;
+assert_eq!(HectoString::try_concat("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills", "").unwrap(), "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills");
+assert!(HectoString::try_concat("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills", "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills, and at dawn the quick brown fox wakes, stretches, and trots off into the woods to find its breakfast, while the lazy dog dreams on").is_err());
+```
+*/
+    pub const fn try_concat(left: &str, right: &str) -> Result<Self, HectoStringError> {
+        match Self::new(left) {
+            Some(mut it) => {
+                match it.push_str(right) {
+                    Ok(()) => Ok(it),
+                    Err(e) => Err(e),
+                }
+            }
+            None => {
+                Err(
+                    HectoStringError::new(
+                        crate::ErrorKind::TooLong,
+                        left.len() + right.len(),
+                    ),
+                )
+            }
+        }
+    }
+    /**Removes the last [`char`] from this string and returns it,
+or [`None`] if this string is empty.
+```
+# use microstring::*;
+let mut string = HectoString::new("aé").unwrap();
+assert_eq!(string.pop(), Some('é'));
+assert_eq!(string.to_bits(), HectoString::new("a").unwrap().to_bits());
+assert_eq!(string.pop(), Some('a'));
+assert_eq!(string.pop(), None);
+assert_eq!(string.to_bits(), HectoString::EMPTY.to_bits());
+```
+*/
+    pub fn pop(&mut self) -> Option<char> {
+        let c = self.as_str().chars().next_back()?;
+        unsafe { self.set_len(self.len as u8 as usize - c.len_utf8()) }
+        Some(c)
+    }
+    /**Shortens this string to `new_len` bytes.
+
+Has no effect if `new_len` is greater than the string's current length.
+
+# Panics
+- If `new_len` does not lie on a [`char`] boundary.
+```
+# use microstring::*;
+let mut string = HectoString::new("aé").unwrap();
+string.truncate(3);
+assert_eq!(string, "aé");
+string.truncate(1);
+assert_eq!(string.to_bits(), HectoString::new("a").unwrap().to_bits());
+```
+```should_panic
+# use microstring::*;
+HectoString::new("é").unwrap().truncate(1);
+```
+*/
+    pub const fn truncate(&mut self, new_len: usize) {
+        if new_len < self.len as u8 as usize {
+            assert!(
+                self.as_str().is_char_boundary(new_len),
+                "new_len does not lie on a char boundary"
+            );
+            unsafe { self.set_len(new_len) }
+        }
+    }
+    /**Truncates this string to the empty string.
+```
+# use microstring::*;
+let mut string = HectoString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills").unwrap();
+string.clear();
+assert_eq!(string.to_bits(), HectoString::EMPTY.to_bits());
+```
+*/
+    pub const fn clear(&mut self) {
+        unsafe { self.set_len(0) }
+    }
+    /// Inserts the given [`char`] at byte position `idx`.
+    ///
+    /// Returns an error, leaving this string unchanged, if there is not enough capacity.
+    ///
+    /// # Panics
+    /// - If `idx` is out of bounds, or does not lie on a [`char`] boundary.
+    pub const fn insert(&mut self, idx: usize, c: char) -> Result<(), HectoStringError> {
+        self.insert_str(idx, c.encode_utf8(&mut [0; 4]))
+    }
+    /**Inserts the given string at byte position `idx`.
+
+Returns an error, leaving this string unchanged, if there is not enough capacity.
+
+# Panics
+- If `idx` is out of bounds, or does not lie on a [`char`] boundary.
+```
+# use microstring::*;
+let mut string = HectoString::new("a").unwrap();
+string.insert_str(0, "é").unwrap();
+assert_eq!(string, "éa");
+assert!(string.insert_str(2, "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills, and at dawn the quick brown fox wakes, stretches, and trots off into the woods to find its breakfast, while the lazy dog dreams on").is_err());
+assert_eq!(string.to_bits(), HectoString::new("éa").unwrap().to_bits());
+```
+```should_panic
+# use microstring::*;
+let _ = HectoString::new("é").unwrap().insert_str(1, "a");
+```
+*/
+    pub const fn insert_str(
+        &mut self,
+        idx: usize,
+        s: &str,
+    ) -> Result<(), HectoStringError> {
+        assert!(
+            self.as_str().is_char_boundary(idx), "idx does not lie on a char boundary"
+        );
+        let len = self.len as u8 as usize;
+        match HectoStringLen::from_usize(len + s.len()) {
+            Some(new_len) => {
+                unsafe {
+                    let p = self.bytes.as_mut_ptr();
+                    ::core::ptr::copy(p.add(idx), p.add(idx + s.len()), len - idx);
+                    ::core::ptr::copy_nonoverlapping(s.as_ptr(), p.add(idx), s.len());
+                }
+                self.len = new_len;
+                Ok(())
+            }
+            None => Err(HectoStringError::new(crate::ErrorKind::TooLong, len + s.len())),
+        }
+    }
+    /**Removes the [`char`] at byte position `idx` and returns it.
+
+# Panics
+- If `idx` is out of bounds, or does not lie on a [`char`] boundary.
+```
+# use microstring::*;
+let mut string = HectoString::new("éa").unwrap();
+assert_eq!(string.remove(0), 'é');
+assert_eq!(string.to_bits(), HectoString::new("a").unwrap().to_bits());
+```
+```should_panic
+# use microstring::*;
+HectoString::new("é").unwrap().remove(1);
+```
+*/
+    pub fn remove(&mut self, idx: usize) -> char {
+        let Some(c) = self.as_str()[idx..].chars().next() else {
+            panic!("cannot remove a char from the end of a string")
+        };
+        let len = self.len as u8 as usize;
+        let next = idx + c.len_utf8();
+        unsafe {
+            let p = self.bytes.as_mut_ptr();
+            ::core::ptr::copy(p.add(next), p.add(idx), len - next);
+            self.set_len(len - c.len_utf8());
+        }
+        c
+    }
+    /**Retains only the [`char`]s for which `f` returns `true`.
+```
+# use microstring::*;
+let mut string = HectoString::new("éa").unwrap();
+string.retain(|c| c.is_ascii());
+assert_eq!(string.to_bits(), HectoString::new("a").unwrap().to_bits());
+```
+*/
+    pub fn retain(&mut self, mut f: impl FnMut(char) -> bool) {
+        let this = *self;
+        self.clear();
+        for c in this.as_str().chars() {
+            if f(c) {
+                let _ = self.push(c);
+            }
+        }
+    }
+    /// Sets the length, zeroing any bytes past it.
+    ///
+    /// # Safety
+    /// - `new_len` must be at most the current length.
+    /// - `new_len` must lie on a [`char`] boundary.
+    const unsafe fn set_len(&mut self, new_len: usize) {
+        let len = self.len as u8 as usize;
+        unsafe {
+            ::core::ptr::write_bytes(
+                self.bytes.as_mut_ptr().add(new_len),
+                0,
+                len - new_len,
+            )
+        }
+        self.len = match HectoStringLen::from_usize(new_len) {
+            Some(it) => it,
+            None => unreachable!(),
+        };
+    }
+}
+impl HectoString {
+    /**Collects the given [`char`]s into a string.
+
+Returns an error if there are more than 255 bytes of them.
+See also [`Collected`](crate::Collected).
+```
+# use microstring::*;
+assert_eq!(HectoString::try_from_iter("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills".chars()).unwrap(), "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills");
+assert!(HectoString::try_from_iter("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills, and at dawn the quick brown fox wakes, stretches, and trots off into the woods to find its breakfast, while the lazy dog dreams on".chars()).is_err());
+```
+*/
+    pub fn try_from_iter<I: IntoIterator<Item = char>>(
+        iter: I,
+    ) -> Result<Self, HectoStringError> {
+        let mut it = Self::EMPTY;
+        for c in iter {
+            it.push(c)?;
+        }
+        Ok(it)
+    }
+    /**Returns an owning iterator over the bytes of this string.
+```
+# use microstring::*;
+let string = HectoString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills").unwrap();
+assert!(string.into_bytes().eq("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills".bytes()));
+assert_eq!(string.into_bytes().len(), 164);
+```
+*/
+    pub fn into_bytes(self) -> crate::IntoBytes<Self> {
+        crate::IntoBytes::new(self)
+    }
+}
+/**An owning iterator over the [`char`]s of this string.
+```
+# use microstring::*;
+let string = HectoString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills").unwrap();
+assert!(string.into_iter().rev().eq("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills".chars().rev()));
+```
+*/
+impl ::core::iter::IntoIterator for HectoString {
+    type Item = char;
+    type IntoIter = crate::IntoChars<Self>;
+    fn into_iter(self) -> crate::IntoChars<Self> {
+        crate::IntoChars::new(self)
+    }
+}
+/// # Panics
+/// - If there is not enough capacity.
+///   See [`push`](Self::push) for a fallible alternative.
+impl ::core::iter::Extend<char> for HectoString {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        for c in iter {
+            if let Err(e) = self.push(c) {
+                panic!("{e}")
+            }
+        }
+    }
+}
+/// # Panics
+/// - If there is not enough capacity.
+///   See [`push_str`](Self::push_str) for a fallible alternative.
+impl<'a> ::core::iter::Extend<&'a str> for HectoString {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        for s in iter {
+            if let Err(e) = self.push_str(s) {
+                panic!("{e}")
+            }
+        }
+    }
+}
+impl HectoString {
+    /**Interprets the given bytes as a string, without copying.
+
+The bytes must be laid out as produced by `zerocopy::IntoBytes`:
+a length byte, followed by that many bytes of UTF-8, followed by zero padding.
+Returns [`None`] otherwise.
+
+This is the checked alternative to `zerocopy::TryFromBytes`,
+which is not implemented for this type because it cannot check the contents.
+```
+# use microstring::*;
+let mut bytes = [0; 255 + 1];
+bytes[0] = 164;
+bytes[1..][..164].copy_from_slice(b"the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills");
+assert_eq!(HectoString::try_ref_from_bytes(&bytes).unwrap().as_str(), "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills");
+bytes[1] = 0xFF;
+assert_eq!(HectoString::try_ref_from_bytes(&bytes), None);
+```
+*/
+    pub const fn try_ref_from_bytes(bytes: &[u8]) -> Option<&Self> {
+        const { assert!(::core::mem::align_of:: < Self > () == 1) }
+        match Self::is_valid(bytes) {
+            true => Some(unsafe { &*bytes.as_ptr().cast::<Self>() }),
+            false => None,
+        }
+    }
+    /// Like [`try_ref_from_bytes`](Self::try_ref_from_bytes),
+    /// but for a contiguous run of strings.
+    pub const fn try_slice_from_bytes(bytes: &[u8]) -> Option<&[Self]> {
+        const { assert!(::core::mem::align_of:: < Self > () == 1) }
+        let size = ::core::mem::size_of::<Self>();
+        if !bytes.len().is_multiple_of(size) {
+            return None;
+        }
+        let mut rest = bytes;
+        while let Some((chunk, tail)) = rest.split_at_checked(size) {
+            if !Self::is_valid(chunk) {
+                return None;
+            }
+            rest = tail;
+        }
+        Some(unsafe {
+            ::core::slice::from_raw_parts(
+                bytes.as_ptr().cast::<Self>(),
+                bytes.len() / size,
+            )
+        })
+    }
+    /// Whether the given bytes are a length byte, that many bytes of UTF-8, and zero padding.
+    const fn is_valid(bytes: &[u8]) -> bool {
+        match bytes {
+            [len, rest @ ..] if rest.len() == 255u8 as usize => {
+                Self::is_valid_parts(*len, rest)
+            }
+            _ => false,
+        }
+    }
+    /// Whether `len` is in range, and `bytes` are that many bytes of UTF-8, followed by zero padding.
+    const fn is_valid_parts(len: u8, bytes: &[u8]) -> bool {
+        let Some(len) = HectoStringLen::from_u8(len) else {
+            return false;
+        };
+        let (string, mut padding) = bytes.split_at(len as u8 as usize);
+        if ::core::str::from_utf8(string).is_err() {
+            return false;
+        }
+        while let [0, tail @ ..] = padding {
+            padding = tail;
+        }
+        padding.is_empty()
+    }
+}
+impl HectoString {
+    /**Packs this string into a byte array.
+
+The string's bytes, zero padded to 255 bytes, come first,
+and its length is the last byte.
+This is stable, so may be persisted.
+Comparing the packed representations orders them the same as comparing the strings.
+```
+# use microstring::*;
+const BITS: [u8; 255 + 1] = HectoString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills").unwrap().to_bits();
+assert_eq!(BITS, *b"the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\xa4");
+assert_eq!(HectoString::from_bits(BITS), HectoString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills"));
+```
+*/
+    pub const fn to_bits(self) -> [u8; 255u8 as usize + 1] {
+        let mut bits = [0; 255u8 as usize + 1];
+        let (bytes, len) = bits.split_at_mut(255u8 as usize);
+        bytes.copy_from_slice(&self.bytes);
+        len[0] = self.len as u8;
+        bits
+    }
+    /// The inverse of [`to_bits`](Self::to_bits).
+    ///
+    /// Returns [`None`] if the length is out of range,
+    /// the string is not UTF-8, or the padding is not zero.
+    pub const fn from_bits(bits: [u8; 255u8 as usize + 1]) -> Option<Self> {
+        let [bytes @ .., len] = bits;
+        match Self::is_valid_parts(len, &bytes) {
+            true => Some(unsafe { Self::from_bits_unchecked(bits) }),
+            false => None,
+        }
+    }
+    /// The inverse of [`to_bits`](Self::to_bits), without any checks.
+    ///
+    /// # Safety
+    /// - `bits` must have been returned by [`to_bits`](Self::to_bits),
+    ///   or be accepted by [`from_bits`](Self::from_bits).
+    pub const unsafe fn from_bits_unchecked(bits: [u8; 255u8 as usize + 1]) -> Self {
+        let [bytes @ .., len] = bits;
+        Self {
+            len: unsafe { ::core::mem::transmute::<u8, HectoStringLen>(len) },
+            bytes,
+        }
+    }
+}
+impl crate::__private::Sealed for HectoString {}
+impl crate::InlineStr for HectoString {
+    const CAPACITY: usize = 255u8 as usize;
+    const EMPTY: Self = Self::EMPTY;
+    type Error = HectoStringError;
+    type Bits = [u8; 255u8 as usize + 1];
+    fn new(s: &str) -> Option<Self> {
+        Self::new(s)
+    }
+    fn as_str(&self) -> &str {
+        self.as_str()
+    }
+    fn as_mut_str(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+    fn from_utf8(bytes: &[u8]) -> Result<Self, HectoStringError> {
+        Self::from_utf8(bytes)
+    }
+    fn to_bits(self) -> [u8; 255u8 as usize + 1] {
+        self.to_bits()
+    }
+    fn from_bits(bits: [u8; 255u8 as usize + 1]) -> Option<Self> {
+        Self::from_bits(bits)
+    }
+    fn push_str(&mut self, s: &str) -> Result<(), HectoStringError> {
+        self.push_str(s)
+    }
+    fn try_from_iter<I: IntoIterator<Item = char>>(
+        iter: I,
+    ) -> Result<Self, HectoStringError> {
+        Self::try_from_iter(iter)
+    }
+    fn __error(kind: crate::ErrorKind, len: usize) -> HectoStringError {
+        HectoStringError::new(kind, len)
+    }
+}
+impl ::core::default::Default for HectoString {
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+impl ::core::default::Default for &HectoString {
+    fn default() -> Self {
+        &HectoString::EMPTY
+    }
+}
+/// Hashes the same as [`str`], as required by [`Borrow<str>`](::core::borrow::Borrow).
+impl ::core::hash::Hash for HectoString {
+    fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+impl ::core::convert::AsRef<Self> for HectoString {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+impl ::core::convert::AsMut<Self> for HectoString {
+    fn as_mut(&mut self) -> &mut Self {
+        self
+    }
+}
+impl ::core::convert::AsRef<str> for HectoString {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+impl ::core::convert::AsMut<str> for HectoString {
+    fn as_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+impl ::core::convert::AsRef<[u8]> for HectoString {
+    fn as_ref(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+}
+impl TryFrom<&str> for HectoString {
+    type Error = HectoStringError;
+    fn try_from(value: &str) -> Result<Self, HectoStringError> {
+        Self::new(value)
+            .ok_or(HectoStringError::new(crate::ErrorKind::TooLong, value.len()))
+    }
+}
+impl TryFrom<&[u8]> for HectoString {
+    type Error = HectoStringError;
+    fn try_from(value: &[u8]) -> Result<Self, HectoStringError> {
+        Self::from_utf8(value)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for HectoString {
+    type Error = HectoStringError;
+    fn try_from(value: [u8; N]) -> Result<Self, HectoStringError> {
+        Self::from_utf8(&value)
+    }
+}
+#[cfg(feature = "std")]
+impl ::core::convert::AsRef<::std::ffi::OsStr> for HectoString {
+    fn as_ref(&self) -> &::std::ffi::OsStr {
+        self.as_str().as_ref()
+    }
+}
+#[cfg(feature = "std")]
+impl ::core::convert::AsRef<::std::path::Path> for HectoString {
+    fn as_ref(&self) -> &::std::path::Path {
+        self.as_str().as_ref()
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::TryFrom<::alloc::string::String> for HectoString {
+    type Error = HectoStringError;
+    fn try_from(value: ::alloc::string::String) -> Result<Self, HectoStringError> {
+        Self::new(&value)
+            .ok_or(HectoStringError::new(crate::ErrorKind::TooLong, value.len()))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::From<HectoString> for ::alloc::string::String {
+    fn from(val: HectoString) -> Self {
+        Self::from(val.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::From<HectoString> for ::alloc::boxed::Box<str> {
+    fn from(val: HectoString) -> Self {
+        Self::from(val.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::From<HectoString> for ::alloc::rc::Rc<str> {
+    fn from(val: HectoString) -> Self {
+        Self::from(val.as_str())
+    }
+}
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl ::core::convert::From<HectoString> for ::alloc::sync::Arc<str> {
+    fn from(val: HectoString) -> Self {
+        Self::from(val.as_str())
+    }
+}
+#[cfg(feature = "std")]
+impl ::core::convert::From<HectoString> for ::std::ffi::OsString {
+    fn from(val: HectoString) -> Self {
+        Self::from(val.as_str())
+    }
+}
+#[cfg(feature = "std")]
+impl ::core::convert::From<HectoString> for ::std::path::PathBuf {
+    fn from(val: HectoString) -> Self {
+        Self::from(val.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::From<HectoString> for ::alloc::borrow::Cow<'static, str> {
+    fn from(val: HectoString) -> Self {
+        Self::Owned(::alloc::string::String::from(val))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::TryFrom<::alloc::boxed::Box<str>> for HectoString {
+    type Error = HectoStringError;
+    fn try_from(value: ::alloc::boxed::Box<str>) -> Result<Self, HectoStringError> {
+        Self::try_from(&*value)
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::convert::TryFrom<::alloc::borrow::Cow<'_, str>> for HectoString {
+    type Error = HectoStringError;
+    fn try_from(value: ::alloc::borrow::Cow<'_, str>) -> Result<Self, HectoStringError> {
+        Self::try_from(&*value)
+    }
+}
+#[cfg(feature = "std")]
+impl ::core::convert::TryFrom<&::std::ffi::OsStr> for HectoString {
+    type Error = HectoStringError;
+    fn try_from(value: &::std::ffi::OsStr) -> Result<Self, HectoStringError> {
+        match value.to_str() {
+            Some(s) => Self::try_from(s),
+            None => {
+                Err(HectoStringError::new(crate::ErrorKind::InvalidUtf8, value.len()))
+            }
+        }
+    }
+}
+#[cfg(feature = "std")]
+impl ::core::convert::TryFrom<&::std::path::Path> for HectoString {
+    type Error = HectoStringError;
+    fn try_from(value: &::std::path::Path) -> Result<Self, HectoStringError> {
+        Self::try_from(value.as_os_str())
+    }
+}
+impl ::core::convert::TryFrom<&::core::ffi::CStr> for HectoString {
+    type Error = HectoStringError;
+    fn try_from(value: &::core::ffi::CStr) -> Result<Self, HectoStringError> {
+        Self::from_utf8(value.to_bytes())
+    }
+}
+impl ::core::borrow::Borrow<str> for HectoString {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+impl ::core::borrow::BorrowMut<str> for HectoString {
+    fn borrow_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+impl ::core::ops::Deref for HectoString {
+    type Target = str;
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+impl ::core::ops::DerefMut for HectoString {
+    fn deref_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+/// Compares the whole string as a byte array.
+impl ::core::cmp::PartialEq for HectoString {
+    fn eq(&self, other: &Self) -> bool {
+        self.len as u8 == other.len as u8 && self.bytes == other.bytes
+    }
+}
+impl ::core::cmp::PartialOrd for HectoString {
+    fn partial_cmp(&self, other: &Self) -> Option<::core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+/**Orders strings the same way as [`str`].
+
+This compares the zero-padded bytes in place, then the length.
+```
+# use microstring::*;
+let mut strings = ["b", "a\0", "", "a"].map(|it| HectoString::new(it).unwrap());
+strings.sort();
+assert_eq!(strings, ["", "a", "a\0", "b"]);
+```
+*/
+impl ::core::cmp::Ord for HectoString {
+    fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+        self.bytes.cmp(&other.bytes).then(self.len.cmp(&other.len))
+    }
+}
+impl ::core::cmp::PartialEq<str> for HectoString {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str().eq(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+impl ::core::cmp::PartialOrd<str> for HectoString {
+    fn partial_cmp(&self, other: &str) -> Option<::core::cmp::Ordering> {
+        self.as_str().partial_cmp(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+impl ::core::cmp::PartialEq<HectoString> for str {
+    fn eq(&self, other: &HectoString) -> bool {
+        ::core::convert::AsRef::<str>::as_ref(self).eq(other.as_str())
+    }
+}
+impl ::core::cmp::PartialOrd<HectoString> for str {
+    fn partial_cmp(&self, other: &HectoString) -> Option<::core::cmp::Ordering> {
+        ::core::convert::AsRef::<str>::as_ref(self).partial_cmp(other.as_str())
+    }
+}
+impl ::core::cmp::PartialEq<&str> for HectoString {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str().eq(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+impl ::core::cmp::PartialOrd<&str> for HectoString {
+    fn partial_cmp(&self, other: &&str) -> Option<::core::cmp::Ordering> {
+        self.as_str().partial_cmp(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+impl ::core::cmp::PartialEq<HectoString> for &str {
+    fn eq(&self, other: &HectoString) -> bool {
+        ::core::convert::AsRef::<str>::as_ref(self).eq(other.as_str())
+    }
+}
+impl ::core::cmp::PartialOrd<HectoString> for &str {
+    fn partial_cmp(&self, other: &HectoString) -> Option<::core::cmp::Ordering> {
+        ::core::convert::AsRef::<str>::as_ref(self).partial_cmp(other.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialEq<::alloc::string::String> for HectoString {
+    fn eq(&self, other: &::alloc::string::String) -> bool {
+        self.as_str().eq(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialOrd<::alloc::string::String> for HectoString {
+    fn partial_cmp(
+        &self,
+        other: &::alloc::string::String,
+    ) -> Option<::core::cmp::Ordering> {
+        self.as_str().partial_cmp(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialEq<HectoString> for ::alloc::string::String {
+    fn eq(&self, other: &HectoString) -> bool {
+        ::core::convert::AsRef::<str>::as_ref(self).eq(other.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialOrd<HectoString> for ::alloc::string::String {
+    fn partial_cmp(&self, other: &HectoString) -> Option<::core::cmp::Ordering> {
+        ::core::convert::AsRef::<str>::as_ref(self).partial_cmp(other.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialEq<::alloc::borrow::Cow<'_, str>> for HectoString {
+    fn eq(&self, other: &::alloc::borrow::Cow<'_, str>) -> bool {
+        self.as_str().eq(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialOrd<::alloc::borrow::Cow<'_, str>> for HectoString {
+    fn partial_cmp(
+        &self,
+        other: &::alloc::borrow::Cow<'_, str>,
+    ) -> Option<::core::cmp::Ordering> {
+        self.as_str().partial_cmp(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialEq<HectoString> for ::alloc::borrow::Cow<'_, str> {
+    fn eq(&self, other: &HectoString) -> bool {
+        ::core::convert::AsRef::<str>::as_ref(self).eq(other.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialOrd<HectoString> for ::alloc::borrow::Cow<'_, str> {
+    fn partial_cmp(&self, other: &HectoString) -> Option<::core::cmp::Ordering> {
+        ::core::convert::AsRef::<str>::as_ref(self).partial_cmp(other.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialEq<::alloc::boxed::Box<str>> for HectoString {
+    fn eq(&self, other: &::alloc::boxed::Box<str>) -> bool {
+        self.as_str().eq(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialOrd<::alloc::boxed::Box<str>> for HectoString {
+    fn partial_cmp(
+        &self,
+        other: &::alloc::boxed::Box<str>,
+    ) -> Option<::core::cmp::Ordering> {
+        self.as_str().partial_cmp(::core::convert::AsRef::<str>::as_ref(other))
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialEq<HectoString> for ::alloc::boxed::Box<str> {
+    fn eq(&self, other: &HectoString) -> bool {
+        ::core::convert::AsRef::<str>::as_ref(self).eq(other.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl ::core::cmp::PartialOrd<HectoString> for ::alloc::boxed::Box<str> {
+    fn partial_cmp(&self, other: &HectoString) -> Option<::core::cmp::Ordering> {
+        ::core::convert::AsRef::<str>::as_ref(self).partial_cmp(other.as_str())
+    }
+}
+impl ::core::fmt::Debug for HectoString {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl ::core::fmt::Display for HectoString {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+/**Writes which would overflow the capacity return [`fmt::Error`](::core::fmt::Error).
+
+On error, the string is restored to its state before the call to
+[`write!`](::core::write).
+```
+# use microstring::*;
+use core::fmt::Write as _;
+let mut string = HectoString::EMPTY;
+assert!(write!(string, "{}{}", "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills", "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills, and at dawn the quick brown fox wakes, stretches, and trots off into the woods to find its breakfast, while the lazy dog dreams on").is_err());
+assert_eq!(string, "");
+```
+*/
+impl ::core::fmt::Write for HectoString {
+    fn write_str(&mut self, s: &str) -> ::core::fmt::Result {
+        self.push_str(s).map_err(|_| ::core::fmt::Error)
+    }
+    fn write_char(&mut self, c: char) -> ::core::fmt::Result {
+        self.push(c).map_err(|_| ::core::fmt::Error)
+    }
+    fn write_fmt(&mut self, args: ::core::fmt::Arguments<'_>) -> ::core::fmt::Result {
+        let before = *self;
+        ::core::fmt::write(self, args).inspect_err(|_| *self = before)
+    }
+}
+impl HectoString {
+    /**Creates a string from the given [`Arguments`](::core::fmt::Arguments).
+
+See also [`hecto_format!`](crate::hecto_format).
+*/
+    pub fn try_from_fmt(
+        args: ::core::fmt::Arguments<'_>,
+    ) -> Result<Self, HectoStringError> {
+        struct Len(usize);
+        impl ::core::fmt::Write for Len {
+            fn write_str(&mut self, s: &str) -> ::core::fmt::Result {
+                self.0 += s.len();
+                Ok(())
+            }
+        }
+        let mut s = Self::EMPTY;
+        match ::core::fmt::Write::write_fmt(&mut s, args) {
+            Ok(()) => Ok(s),
+            Err(_) => {
+                let mut len = Len(0);
+                let _ = ::core::fmt::Write::write_fmt(&mut len, args);
+                Err(HectoStringError::new(crate::ErrorKind::TooLong, len.0))
+            }
+        }
+    }
+}
+/**Creates a [`HectoString`] using interpolation of runtime expressions,
+like [`format!`](https://doc.rust-lang.org/std/macro.format.html).
+
+Returns an error if the result would be longer than 255 bytes.
+```
+# use microstring::*;
+assert_eq!(hecto_format!("{}", "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills").unwrap(), "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills");
+assert!(hecto_format!("{}", "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills, and at dawn the quick brown fox wakes, stretches, and trots off into the woods to find its breakfast, while the lazy dog dreams on").is_err());
+```
+*/
+#[macro_export]
+macro_rules! hecto_format {
+    ($($arg:tt)*) => {
+        $crate::HectoString::try_from_fmt(::core::format_args!($($arg)*))
+    };
+}
+impl ::core::str::FromStr for HectoString {
+    type Err = HectoStringError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s).ok_or(HectoStringError::new(crate::ErrorKind::TooLong, s.len()))
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for HectoString {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(self)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for HectoString {
+    fn deserialize<D: serde::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        struct Visitor;
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = HectoString;
+            fn expecting(
+                &self,
+                f: &mut ::core::fmt::Formatter<'_>,
+            ) -> ::core::fmt::Result {
+                f.write_str("a string of at most 255 bytes")
+            }
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<HectoString, E> {
+                HectoString::new(v)
+                    .ok_or_else(|| serde::de::Error::invalid_length(v.len(), &self))
+            }
+        }
+        d.deserialize_str(Visitor)
+    }
+}
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for HectoString {
+    fn schema_name() -> ::std::borrow::Cow<'static, str> {
+        ::std::borrow::Cow::Borrowed(::core::stringify!(HectoString))
+    }
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema! {
+            { "type" : "string", "maxLength" : 255u8, }
+        }
+    }
+    fn schema_id() -> ::std::borrow::Cow<'static, str> {
+        ::std::borrow::Cow::Borrowed(
+            ::core::concat!(
+                ::core::module_path!(), "::", ::core::stringify!(HectoString)
+            ),
+        )
+    }
+}
+/**Generates strings of every length up to 255 bytes, including multi-byte [`char`]s.
+```
+# use microstring::*;
+use arbitrary::{Arbitrary, Unstructured};
+
+// an empty input consumes nothing
+assert_eq!(HectoString::arbitrary(&mut Unstructured::new(&[])).unwrap(), "");
+let (min, max) = HectoString::size_hint(0);
+assert_eq!(min, 0);
+let max = max.unwrap();
+
+let mut lens = [false; 255 + 1];
+let mut multi_byte = false;
+for seed in 0..=u8::MAX {
+    // the first byte chooses the length
+    let mut state = u32::from(seed) << 8 | 1;
+    let data = [seed].into_iter().chain((1..max).map(|_| {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        state as u8
+    })).collect::<Vec<_>>();
+    let mut u = Unstructured::new(&data);
+    let s = HectoString::arbitrary(&mut u).unwrap();
+    assert!(min <= data.len() - u.len() && data.len() - u.len() <= max);
+    assert!(str::from_utf8(s.as_bytes()).is_ok());
+    assert_eq!(s.to_bits(), HectoString::new(&s).unwrap().to_bits());
+    lens[s.len()] = true;
+    multi_byte |= s.chars().any(|c| c.len_utf8() > 1);
+}
+assert!(lens.iter().all(|it| *it) && multi_byte);
+```
+*/
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for HectoString {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let len = u.int_in_range(0..=255u8)? as usize;
+        let mut it = Self::EMPTY;
+        while (it.len as u8 as usize) < len {
+            let remaining = len - it.len as u8 as usize;
+            let width = u.int_in_range(1..=remaining.min(4))?;
+            let _ = it.push(crate::__private::arbitrary_char(u, width)?);
+        }
+        Ok(it)
+    }
+    fn size_hint(_: usize) -> (usize, Option<usize>) {
+        (0, Some(1 + 2 * 255u8 as usize))
+    }
+}
+impl crate::Lookup<HectoString> for HectoString {
+    fn lookup(&self) -> Option<HectoString> {
+        Some(*self)
+    }
+}
+impl crate::Lookup<HectoString> for str {
+    fn lookup(&self) -> Option<HectoString> {
+        HectoString::new(self)
+    }
+}
+impl crate::Lookup<HectoString> for &str {
+    fn lookup(&self) -> Option<HectoString> {
+        HectoString::new(self)
+    }
+}
+#[cfg(feature = "alloc")]
+impl crate::Lookup<HectoString> for ::alloc::string::String {
+    fn lookup(&self) -> Option<HectoString> {
+        HectoString::new(self)
+    }
+}
+#[cfg(feature = "alloc")]
+impl crate::Lookup<HectoString> for ::alloc::borrow::Cow<'_, str> {
+    fn lookup(&self) -> Option<HectoString> {
+        HectoString::new(self)
+    }
+}
+#[cfg(feature = "alloc")]
+impl crate::Lookup<HectoString> for ::alloc::boxed::Box<str> {
+    fn lookup(&self) -> Option<HectoString> {
+        HectoString::new(self)
+    }
+}
+/**A hash map keyed by [`HectoString`].
+
+Keys are stored and hashed as their packed [`to_bits`](HectoString::to_bits) representation,
+using [`BuildWordHasher`](crate::BuildWordHasher).
+Entries may also be looked up by [`str`] and [`String`](std::string::String).
+```
+# use microstring::*;
+let mut map = HectoMap::new();
+map.insert(HectoString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills").unwrap(), 1);
+assert_eq!(map.get("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills"), Some(&1));
+assert_eq!(map.get(&HectoString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills").unwrap()), Some(&1));
+assert_eq!(map.get(&String::from("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills")), Some(&1));
+assert_eq!(map.get("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills, and at dawn the quick brown fox wakes, stretches, and trots off into the woods to find its breakfast, while the lazy dog dreams on"), None);
+
+*map.entry(HectoString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills").unwrap()).or_default() += 1;
+assert_eq!(map.into_iter().collect::<Vec<_>>(), [(HectoString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills").unwrap(), 2)]);
+```
+*/
+#[cfg(feature = "std")]
+#[derive(Clone)]
+pub struct HectoMap<V> {
+    inner: ::std::collections::HashMap<
+        [u8; 255u8 as usize + 1],
+        V,
+        crate::BuildWordHasher,
+    >,
+}
+#[cfg(feature = "std")]
+impl<V> HectoMap<V> {
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            inner: ::std::collections::HashMap::with_capacity_and_hasher(
+                capacity,
+                crate::BuildWordHasher,
+            ),
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+    pub fn clear(&mut self) {
+        self.inner.clear()
+    }
+    pub fn insert(&mut self, key: HectoString, value: V) -> Option<V> {
+        self.inner.insert(key.to_bits(), value)
+    }
+    /// Gets the entry for the given key, to insert or update it with a single probe.
+    pub fn entry(&mut self, key: HectoString) -> crate::Entry<'_, HectoString, V> {
+        crate::Entry::__new(&mut self.inner, key)
+    }
+    pub fn get<Q: ?Sized + crate::Lookup<HectoString>>(&self, key: &Q) -> Option<&V> {
+        self.inner.get(&key.lookup()?.to_bits())
+    }
+    pub fn get_mut<Q: ?Sized + crate::Lookup<HectoString>>(
+        &mut self,
+        key: &Q,
+    ) -> Option<&mut V> {
+        self.inner.get_mut(&key.lookup()?.to_bits())
+    }
+    pub fn contains_key<Q: ?Sized + crate::Lookup<HectoString>>(&self, key: &Q) -> bool {
+        self.get(key).is_some()
+    }
+    pub fn remove<Q: ?Sized + crate::Lookup<HectoString>>(
+        &mut self,
+        key: &Q,
+    ) -> Option<V> {
+        self.inner.remove(&key.lookup()?.to_bits())
+    }
+    pub fn iter(&self) -> impl Iterator<Item = (HectoString, &V)> {
+        self.inner
+            .iter()
+            .map(|(k, v)| (unsafe { HectoString::from_bits_unchecked(*k) }, v))
+    }
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (HectoString, &mut V)> {
+        self.inner
+            .iter_mut()
+            .map(|(k, v)| (unsafe { HectoString::from_bits_unchecked(*k) }, v))
+    }
+    pub fn keys(&self) -> impl Iterator<Item = HectoString> {
+        self.inner.keys().map(|k| unsafe { HectoString::from_bits_unchecked(*k) })
+    }
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.inner.values()
+    }
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut V> {
+        self.inner.values_mut()
+    }
+}
+#[cfg(feature = "std")]
+impl<V> ::core::default::Default for HectoMap<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+#[cfg(feature = "std")]
+impl<V: ::core::fmt::Debug> ::core::fmt::Debug for HectoMap<V> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+#[cfg(feature = "std")]
+impl<V: ::core::cmp::PartialEq> ::core::cmp::PartialEq for HectoMap<V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+#[cfg(feature = "std")]
+impl<V: ::core::cmp::Eq> ::core::cmp::Eq for HectoMap<V> {}
+#[cfg(feature = "std")]
+impl<V> ::core::iter::Extend<(HectoString, V)> for HectoMap<V> {
+    fn extend<I: IntoIterator<Item = (HectoString, V)>>(&mut self, iter: I) {
+        self.inner.extend(iter.into_iter().map(|(k, v)| (k.to_bits(), v)))
+    }
+}
+#[cfg(feature = "std")]
+impl<V> ::core::iter::IntoIterator for HectoMap<V> {
+    type Item = (HectoString, V);
+    type IntoIter = ::core::iter::Map<
+        ::std::collections::hash_map::IntoIter<[u8; 255u8 as usize + 1], V>,
+        fn(([u8; 255u8 as usize + 1], V)) -> (HectoString, V),
+    >;
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner
+            .into_iter()
+            .map(|(k, v)| (unsafe { HectoString::from_bits_unchecked(k) }, v))
+    }
+}
+#[cfg(feature = "std")]
+impl<'a, V> ::core::iter::IntoIterator for &'a HectoMap<V> {
+    type Item = (HectoString, &'a V);
+    type IntoIter = ::core::iter::Map<
+        ::std::collections::hash_map::Iter<'a, [u8; 255u8 as usize + 1], V>,
+        fn((&'a [u8; 255u8 as usize + 1], &'a V)) -> (HectoString, &'a V),
+    >;
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner
+            .iter()
+            .map(|(k, v)| (unsafe { HectoString::from_bits_unchecked(*k) }, v))
+    }
+}
+#[cfg(feature = "std")]
+impl<'a, V> ::core::iter::IntoIterator for &'a mut HectoMap<V> {
+    type Item = (HectoString, &'a mut V);
+    type IntoIter = ::core::iter::Map<
+        ::std::collections::hash_map::IterMut<'a, [u8; 255u8 as usize + 1], V>,
+        fn((&'a [u8; 255u8 as usize + 1], &'a mut V)) -> (HectoString, &'a mut V),
+    >;
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner
+            .iter_mut()
+            .map(|(k, v)| (unsafe { HectoString::from_bits_unchecked(*k) }, v))
+    }
+}
+#[cfg(feature = "std")]
+impl<V> ::core::iter::FromIterator<(HectoString, V)> for HectoMap<V> {
+    fn from_iter<I: IntoIterator<Item = (HectoString, V)>>(iter: I) -> Self {
+        let mut it = Self::new();
+        it.extend(iter);
+        it
+    }
+}
+#[cfg(feature = "const-default")]
+impl const_default::ConstDefault for HectoString {
+    const DEFAULT: Self = HectoString::EMPTY;
+}
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u8)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout,
+        zerocopy::Unaligned
+    )
+)]
+enum HectoStringLen {
+    _0 = 0u8,
+    _1 = 1u8,
+    _2 = 2u8,
+    _3 = 3u8,
+    _4 = 4u8,
+    _5 = 5u8,
+    _6 = 6u8,
+    _7 = 7u8,
+    _8 = 8u8,
+    _9 = 9u8,
+    _10 = 10u8,
+    _11 = 11u8,
+    _12 = 12u8,
+    _13 = 13u8,
+    _14 = 14u8,
+    _15 = 15u8,
+    _16 = 16u8,
+    _17 = 17u8,
+    _18 = 18u8,
+    _19 = 19u8,
+    _20 = 20u8,
+    _21 = 21u8,
+    _22 = 22u8,
+    _23 = 23u8,
+    _24 = 24u8,
+    _25 = 25u8,
+    _26 = 26u8,
+    _27 = 27u8,
+    _28 = 28u8,
+    _29 = 29u8,
+    _30 = 30u8,
+    _31 = 31u8,
+    _32 = 32u8,
+    _33 = 33u8,
+    _34 = 34u8,
+    _35 = 35u8,
+    _36 = 36u8,
+    _37 = 37u8,
+    _38 = 38u8,
+    _39 = 39u8,
+    _40 = 40u8,
+    _41 = 41u8,
+    _42 = 42u8,
+    _43 = 43u8,
+    _44 = 44u8,
+    _45 = 45u8,
+    _46 = 46u8,
+    _47 = 47u8,
+    _48 = 48u8,
+    _49 = 49u8,
+    _50 = 50u8,
+    _51 = 51u8,
+    _52 = 52u8,
+    _53 = 53u8,
+    _54 = 54u8,
+    _55 = 55u8,
+    _56 = 56u8,
+    _57 = 57u8,
+    _58 = 58u8,
+    _59 = 59u8,
+    _60 = 60u8,
+    _61 = 61u8,
+    _62 = 62u8,
+    _63 = 63u8,
+    _64 = 64u8,
+    _65 = 65u8,
+    _66 = 66u8,
+    _67 = 67u8,
+    _68 = 68u8,
+    _69 = 69u8,
+    _70 = 70u8,
+    _71 = 71u8,
+    _72 = 72u8,
+    _73 = 73u8,
+    _74 = 74u8,
+    _75 = 75u8,
+    _76 = 76u8,
+    _77 = 77u8,
+    _78 = 78u8,
+    _79 = 79u8,
+    _80 = 80u8,
+    _81 = 81u8,
+    _82 = 82u8,
+    _83 = 83u8,
+    _84 = 84u8,
+    _85 = 85u8,
+    _86 = 86u8,
+    _87 = 87u8,
+    _88 = 88u8,
+    _89 = 89u8,
+    _90 = 90u8,
+    _91 = 91u8,
+    _92 = 92u8,
+    _93 = 93u8,
+    _94 = 94u8,
+    _95 = 95u8,
+    _96 = 96u8,
+    _97 = 97u8,
+    _98 = 98u8,
+    _99 = 99u8,
+    _100 = 100u8,
+    _101 = 101u8,
+    _102 = 102u8,
+    _103 = 103u8,
+    _104 = 104u8,
+    _105 = 105u8,
+    _106 = 106u8,
+    _107 = 107u8,
+    _108 = 108u8,
+    _109 = 109u8,
+    _110 = 110u8,
+    _111 = 111u8,
+    _112 = 112u8,
+    _113 = 113u8,
+    _114 = 114u8,
+    _115 = 115u8,
+    _116 = 116u8,
+    _117 = 117u8,
+    _118 = 118u8,
+    _119 = 119u8,
+    _120 = 120u8,
+    _121 = 121u8,
+    _122 = 122u8,
+    _123 = 123u8,
+    _124 = 124u8,
+    _125 = 125u8,
+    _126 = 126u8,
+    _127 = 127u8,
+    _128 = 128u8,
+    _129 = 129u8,
+    _130 = 130u8,
+    _131 = 131u8,
+    _132 = 132u8,
+    _133 = 133u8,
+    _134 = 134u8,
+    _135 = 135u8,
+    _136 = 136u8,
+    _137 = 137u8,
+    _138 = 138u8,
+    _139 = 139u8,
+    _140 = 140u8,
+    _141 = 141u8,
+    _142 = 142u8,
+    _143 = 143u8,
+    _144 = 144u8,
+    _145 = 145u8,
+    _146 = 146u8,
+    _147 = 147u8,
+    _148 = 148u8,
+    _149 = 149u8,
+    _150 = 150u8,
+    _151 = 151u8,
+    _152 = 152u8,
+    _153 = 153u8,
+    _154 = 154u8,
+    _155 = 155u8,
+    _156 = 156u8,
+    _157 = 157u8,
+    _158 = 158u8,
+    _159 = 159u8,
+    _160 = 160u8,
+    _161 = 161u8,
+    _162 = 162u8,
+    _163 = 163u8,
+    _164 = 164u8,
+    _165 = 165u8,
+    _166 = 166u8,
+    _167 = 167u8,
+    _168 = 168u8,
+    _169 = 169u8,
+    _170 = 170u8,
+    _171 = 171u8,
+    _172 = 172u8,
+    _173 = 173u8,
+    _174 = 174u8,
+    _175 = 175u8,
+    _176 = 176u8,
+    _177 = 177u8,
+    _178 = 178u8,
+    _179 = 179u8,
+    _180 = 180u8,
+    _181 = 181u8,
+    _182 = 182u8,
+    _183 = 183u8,
+    _184 = 184u8,
+    _185 = 185u8,
+    _186 = 186u8,
+    _187 = 187u8,
+    _188 = 188u8,
+    _189 = 189u8,
+    _190 = 190u8,
+    _191 = 191u8,
+    _192 = 192u8,
+    _193 = 193u8,
+    _194 = 194u8,
+    _195 = 195u8,
+    _196 = 196u8,
+    _197 = 197u8,
+    _198 = 198u8,
+    _199 = 199u8,
+    _200 = 200u8,
+    _201 = 201u8,
+    _202 = 202u8,
+    _203 = 203u8,
+    _204 = 204u8,
+    _205 = 205u8,
+    _206 = 206u8,
+    _207 = 207u8,
+    _208 = 208u8,
+    _209 = 209u8,
+    _210 = 210u8,
+    _211 = 211u8,
+    _212 = 212u8,
+    _213 = 213u8,
+    _214 = 214u8,
+    _215 = 215u8,
+    _216 = 216u8,
+    _217 = 217u8,
+    _218 = 218u8,
+    _219 = 219u8,
+    _220 = 220u8,
+    _221 = 221u8,
+    _222 = 222u8,
+    _223 = 223u8,
+    _224 = 224u8,
+    _225 = 225u8,
+    _226 = 226u8,
+    _227 = 227u8,
+    _228 = 228u8,
+    _229 = 229u8,
+    _230 = 230u8,
+    _231 = 231u8,
+    _232 = 232u8,
+    _233 = 233u8,
+    _234 = 234u8,
+    _235 = 235u8,
+    _236 = 236u8,
+    _237 = 237u8,
+    _238 = 238u8,
+    _239 = 239u8,
+    _240 = 240u8,
+    _241 = 241u8,
+    _242 = 242u8,
+    _243 = 243u8,
+    _244 = 244u8,
+    _245 = 245u8,
+    _246 = 246u8,
+    _247 = 247u8,
+    _248 = 248u8,
+    _249 = 249u8,
+    _250 = 250u8,
+    _251 = 251u8,
+    _252 = 252u8,
+    _253 = 253u8,
+    _254 = 254u8,
+    _255 = 255u8,
+}
+impl HectoStringLen {
+    const fn from_usize(u: usize) -> Option<Self> {
+        const U8_MAX: usize = u8::MAX as _;
+        if u > U8_MAX {
+            return None;
+        }
+        Self::from_u8(u as u8)
+    }
+    const fn from_u8(u: u8) -> Option<Self> {
+        match u {
+            0u8 => Some(Self::_0),
+            1u8 => Some(Self::_1),
+            2u8 => Some(Self::_2),
+            3u8 => Some(Self::_3),
+            4u8 => Some(Self::_4),
+            5u8 => Some(Self::_5),
+            6u8 => Some(Self::_6),
+            7u8 => Some(Self::_7),
+            8u8 => Some(Self::_8),
+            9u8 => Some(Self::_9),
+            10u8 => Some(Self::_10),
+            11u8 => Some(Self::_11),
+            12u8 => Some(Self::_12),
+            13u8 => Some(Self::_13),
+            14u8 => Some(Self::_14),
+            15u8 => Some(Self::_15),
+            16u8 => Some(Self::_16),
+            17u8 => Some(Self::_17),
+            18u8 => Some(Self::_18),
+            19u8 => Some(Self::_19),
+            20u8 => Some(Self::_20),
+            21u8 => Some(Self::_21),
+            22u8 => Some(Self::_22),
+            23u8 => Some(Self::_23),
+            24u8 => Some(Self::_24),
+            25u8 => Some(Self::_25),
+            26u8 => Some(Self::_26),
+            27u8 => Some(Self::_27),
+            28u8 => Some(Self::_28),
+            29u8 => Some(Self::_29),
+            30u8 => Some(Self::_30),
+            31u8 => Some(Self::_31),
+            32u8 => Some(Self::_32),
+            33u8 => Some(Self::_33),
+            34u8 => Some(Self::_34),
+            35u8 => Some(Self::_35),
+            36u8 => Some(Self::_36),
+            37u8 => Some(Self::_37),
+            38u8 => Some(Self::_38),
+            39u8 => Some(Self::_39),
+            40u8 => Some(Self::_40),
+            41u8 => Some(Self::_41),
+            42u8 => Some(Self::_42),
+            43u8 => Some(Self::_43),
+            44u8 => Some(Self::_44),
+            45u8 => Some(Self::_45),
+            46u8 => Some(Self::_46),
+            47u8 => Some(Self::_47),
+            48u8 => Some(Self::_48),
+            49u8 => Some(Self::_49),
+            50u8 => Some(Self::_50),
+            51u8 => Some(Self::_51),
+            52u8 => Some(Self::_52),
+            53u8 => Some(Self::_53),
+            54u8 => Some(Self::_54),
+            55u8 => Some(Self::_55),
+            56u8 => Some(Self::_56),
+            57u8 => Some(Self::_57),
+            58u8 => Some(Self::_58),
+            59u8 => Some(Self::_59),
+            60u8 => Some(Self::_60),
+            61u8 => Some(Self::_61),
+            62u8 => Some(Self::_62),
+            63u8 => Some(Self::_63),
+            64u8 => Some(Self::_64),
+            65u8 => Some(Self::_65),
+            66u8 => Some(Self::_66),
+            67u8 => Some(Self::_67),
+            68u8 => Some(Self::_68),
+            69u8 => Some(Self::_69),
+            70u8 => Some(Self::_70),
+            71u8 => Some(Self::_71),
+            72u8 => Some(Self::_72),
+            73u8 => Some(Self::_73),
+            74u8 => Some(Self::_74),
+            75u8 => Some(Self::_75),
+            76u8 => Some(Self::_76),
+            77u8 => Some(Self::_77),
+            78u8 => Some(Self::_78),
+            79u8 => Some(Self::_79),
+            80u8 => Some(Self::_80),
+            81u8 => Some(Self::_81),
+            82u8 => Some(Self::_82),
+            83u8 => Some(Self::_83),
+            84u8 => Some(Self::_84),
+            85u8 => Some(Self::_85),
+            86u8 => Some(Self::_86),
+            87u8 => Some(Self::_87),
+            88u8 => Some(Self::_88),
+            89u8 => Some(Self::_89),
+            90u8 => Some(Self::_90),
+            91u8 => Some(Self::_91),
+            92u8 => Some(Self::_92),
+            93u8 => Some(Self::_93),
+            94u8 => Some(Self::_94),
+            95u8 => Some(Self::_95),
+            96u8 => Some(Self::_96),
+            97u8 => Some(Self::_97),
+            98u8 => Some(Self::_98),
+            99u8 => Some(Self::_99),
+            100u8 => Some(Self::_100),
+            101u8 => Some(Self::_101),
+            102u8 => Some(Self::_102),
+            103u8 => Some(Self::_103),
+            104u8 => Some(Self::_104),
+            105u8 => Some(Self::_105),
+            106u8 => Some(Self::_106),
+            107u8 => Some(Self::_107),
+            108u8 => Some(Self::_108),
+            109u8 => Some(Self::_109),
+            110u8 => Some(Self::_110),
+            111u8 => Some(Self::_111),
+            112u8 => Some(Self::_112),
+            113u8 => Some(Self::_113),
+            114u8 => Some(Self::_114),
+            115u8 => Some(Self::_115),
+            116u8 => Some(Self::_116),
+            117u8 => Some(Self::_117),
+            118u8 => Some(Self::_118),
+            119u8 => Some(Self::_119),
+            120u8 => Some(Self::_120),
+            121u8 => Some(Self::_121),
+            122u8 => Some(Self::_122),
+            123u8 => Some(Self::_123),
+            124u8 => Some(Self::_124),
+            125u8 => Some(Self::_125),
+            126u8 => Some(Self::_126),
+            127u8 => Some(Self::_127),
+            128u8 => Some(Self::_128),
+            129u8 => Some(Self::_129),
+            130u8 => Some(Self::_130),
+            131u8 => Some(Self::_131),
+            132u8 => Some(Self::_132),
+            133u8 => Some(Self::_133),
+            134u8 => Some(Self::_134),
+            135u8 => Some(Self::_135),
+            136u8 => Some(Self::_136),
+            137u8 => Some(Self::_137),
+            138u8 => Some(Self::_138),
+            139u8 => Some(Self::_139),
+            140u8 => Some(Self::_140),
+            141u8 => Some(Self::_141),
+            142u8 => Some(Self::_142),
+            143u8 => Some(Self::_143),
+            144u8 => Some(Self::_144),
+            145u8 => Some(Self::_145),
+            146u8 => Some(Self::_146),
+            147u8 => Some(Self::_147),
+            148u8 => Some(Self::_148),
+            149u8 => Some(Self::_149),
+            150u8 => Some(Self::_150),
+            151u8 => Some(Self::_151),
+            152u8 => Some(Self::_152),
+            153u8 => Some(Self::_153),
+            154u8 => Some(Self::_154),
+            155u8 => Some(Self::_155),
+            156u8 => Some(Self::_156),
+            157u8 => Some(Self::_157),
+            158u8 => Some(Self::_158),
+            159u8 => Some(Self::_159),
+            160u8 => Some(Self::_160),
+            161u8 => Some(Self::_161),
+            162u8 => Some(Self::_162),
+            163u8 => Some(Self::_163),
+            164u8 => Some(Self::_164),
+            165u8 => Some(Self::_165),
+            166u8 => Some(Self::_166),
+            167u8 => Some(Self::_167),
+            168u8 => Some(Self::_168),
+            169u8 => Some(Self::_169),
+            170u8 => Some(Self::_170),
+            171u8 => Some(Self::_171),
+            172u8 => Some(Self::_172),
+            173u8 => Some(Self::_173),
+            174u8 => Some(Self::_174),
+            175u8 => Some(Self::_175),
+            176u8 => Some(Self::_176),
+            177u8 => Some(Self::_177),
+            178u8 => Some(Self::_178),
+            179u8 => Some(Self::_179),
+            180u8 => Some(Self::_180),
+            181u8 => Some(Self::_181),
+            182u8 => Some(Self::_182),
+            183u8 => Some(Self::_183),
+            184u8 => Some(Self::_184),
+            185u8 => Some(Self::_185),
+            186u8 => Some(Self::_186),
+            187u8 => Some(Self::_187),
+            188u8 => Some(Self::_188),
+            189u8 => Some(Self::_189),
+            190u8 => Some(Self::_190),
+            191u8 => Some(Self::_191),
+            192u8 => Some(Self::_192),
+            193u8 => Some(Self::_193),
+            194u8 => Some(Self::_194),
+            195u8 => Some(Self::_195),
+            196u8 => Some(Self::_196),
+            197u8 => Some(Self::_197),
+            198u8 => Some(Self::_198),
+            199u8 => Some(Self::_199),
+            200u8 => Some(Self::_200),
+            201u8 => Some(Self::_201),
+            202u8 => Some(Self::_202),
+            203u8 => Some(Self::_203),
+            204u8 => Some(Self::_204),
+            205u8 => Some(Self::_205),
+            206u8 => Some(Self::_206),
+            207u8 => Some(Self::_207),
+            208u8 => Some(Self::_208),
+            209u8 => Some(Self::_209),
+            210u8 => Some(Self::_210),
+            211u8 => Some(Self::_211),
+            212u8 => Some(Self::_212),
+            213u8 => Some(Self::_213),
+            214u8 => Some(Self::_214),
+            215u8 => Some(Self::_215),
+            216u8 => Some(Self::_216),
+            217u8 => Some(Self::_217),
+            218u8 => Some(Self::_218),
+            219u8 => Some(Self::_219),
+            220u8 => Some(Self::_220),
+            221u8 => Some(Self::_221),
+            222u8 => Some(Self::_222),
+            223u8 => Some(Self::_223),
+            224u8 => Some(Self::_224),
+            225u8 => Some(Self::_225),
+            226u8 => Some(Self::_226),
+            227u8 => Some(Self::_227),
+            228u8 => Some(Self::_228),
+            229u8 => Some(Self::_229),
+            230u8 => Some(Self::_230),
+            231u8 => Some(Self::_231),
+            232u8 => Some(Self::_232),
+            233u8 => Some(Self::_233),
+            234u8 => Some(Self::_234),
+            235u8 => Some(Self::_235),
+            236u8 => Some(Self::_236),
+            237u8 => Some(Self::_237),
+            238u8 => Some(Self::_238),
+            239u8 => Some(Self::_239),
+            240u8 => Some(Self::_240),
+            241u8 => Some(Self::_241),
+            242u8 => Some(Self::_242),
+            243u8 => Some(Self::_243),
+            244u8 => Some(Self::_244),
+            245u8 => Some(Self::_245),
+            246u8 => Some(Self::_246),
+            247u8 => Some(Self::_247),
+            248u8 => Some(Self::_248),
+            249u8 => Some(Self::_249),
+            250u8 => Some(Self::_250),
+            251u8 => Some(Self::_251),
+            252u8 => Some(Self::_252),
+            253u8 => Some(Self::_253),
+            254u8 => Some(Self::_254),
+            255u8 => Some(Self::_255),
+        }
+    }
+}
+impl MicroString {
+    /**Converts a [`NanoString`] into a [`MicroString`], which always succeeds since <code>3 <= 7</code>.
+
+The sizes also convert the other way with [`TryFrom`], and compare with each other.
+```
+# use microstring::*;
+let narrow = NanoString::new("GBP").unwrap();
+let wide = MicroString::from(narrow);
+assert_eq!(wide, narrow);
+assert_eq!(NanoString::try_from(wide).unwrap(), narrow);
+
+let long = MicroString::new("1234567").unwrap();
+assert_eq!(NanoString::try_from(long).unwrap_err().kind(), ErrorKind::TooLong);
+assert_ne!(long, narrow);
+assert_eq!(narrow.partial_cmp(&long), Some(core::cmp::Ordering::Greater));
+assert_eq!(long.partial_cmp(&narrow), Some(core::cmp::Ordering::Less));
+```
+*/
+    pub const fn from_nano_string(s: NanoString) -> Self {
+        match Self::new(s.as_str()) {
+            Some(it) => it,
+            None => unreachable!(),
+        }
+    }
+}
+impl ::core::convert::From<NanoString> for MicroString {
+    fn from(value: NanoString) -> Self {
+        Self::from_nano_string(value)
+    }
+}
+impl ::core::convert::TryFrom<MicroString> for NanoString {
+    type Error = NanoStringError;
+    fn try_from(value: MicroString) -> Result<Self, NanoStringError> {
+        Self::try_from(value.as_str())
+    }
+}
+impl ::core::cmp::PartialEq<MicroString> for NanoString {
+    fn eq(&self, other: &MicroString) -> bool {
+        MicroString::from_nano_string(*self).eq(other)
+    }
+}
+impl ::core::cmp::PartialEq<NanoString> for MicroString {
+    fn eq(&self, other: &NanoString) -> bool {
+        self.eq(&MicroString::from_nano_string(*other))
+    }
+}
+impl ::core::cmp::PartialOrd<MicroString> for NanoString {
+    fn partial_cmp(&self, other: &MicroString) -> Option<::core::cmp::Ordering> {
+        MicroString::from_nano_string(*self).partial_cmp(other)
+    }
+}
+impl ::core::cmp::PartialOrd<NanoString> for MicroString {
+    fn partial_cmp(&self, other: &NanoString) -> Option<::core::cmp::Ordering> {
+        self.partial_cmp(&MicroString::from_nano_string(*other))
+    }
+}
+impl MilliString {
+    /**Converts a [`NanoString`] into a [`MilliString`], which always succeeds since <code>3 <= 15</code>.
+
+The sizes also convert the other way with [`TryFrom`], and compare with each other.
+```
+# use microstring::*;
+let narrow = NanoString::new("GBP").unwrap();
+let wide = MilliString::from(narrow);
+assert_eq!(wide, narrow);
+assert_eq!(NanoString::try_from(wide).unwrap(), narrow);
+
+let long = MilliString::new("hello world :)").unwrap();
+assert_eq!(NanoString::try_from(long).unwrap_err().kind(), ErrorKind::TooLong);
+assert_ne!(long, narrow);
+assert_eq!(narrow.partial_cmp(&long), Some(core::cmp::Ordering::Less));
+assert_eq!(long.partial_cmp(&narrow), Some(core::cmp::Ordering::Greater));
+```
+*/
+    pub const fn from_nano_string(s: NanoString) -> Self {
+        match Self::new(s.as_str()) {
+            Some(it) => it,
+            None => unreachable!(),
+        }
+    }
+}
+impl ::core::convert::From<NanoString> for MilliString {
+    fn from(value: NanoString) -> Self {
+        Self::from_nano_string(value)
+    }
+}
+impl ::core::convert::TryFrom<MilliString> for NanoString {
+    type Error = NanoStringError;
+    fn try_from(value: MilliString) -> Result<Self, NanoStringError> {
+        Self::try_from(value.as_str())
+    }
+}
+impl ::core::cmp::PartialEq<MilliString> for NanoString {
+    fn eq(&self, other: &MilliString) -> bool {
+        MilliString::from_nano_string(*self).eq(other)
+    }
+}
+impl ::core::cmp::PartialEq<NanoString> for MilliString {
+    fn eq(&self, other: &NanoString) -> bool {
+        self.eq(&MilliString::from_nano_string(*other))
+    }
+}
+impl ::core::cmp::PartialOrd<MilliString> for NanoString {
+    fn partial_cmp(&self, other: &MilliString) -> Option<::core::cmp::Ordering> {
+        MilliString::from_nano_string(*self).partial_cmp(other)
+    }
+}
+impl ::core::cmp::PartialOrd<NanoString> for MilliString {
+    fn partial_cmp(&self, other: &NanoString) -> Option<::core::cmp::Ordering> {
+        self.partial_cmp(&MilliString::from_nano_string(*other))
+    }
+}
+impl CentiString {
+    /**Converts a [`NanoString`] into a [`CentiString`], which always succeeds since <code>3 <= 31</code>.
+
+The sizes also convert the other way with [`TryFrom`], and compare with each other.
+```
+# use microstring::*;
+let narrow = NanoString::new("GBP").unwrap();
+let wide = CentiString::from(narrow);
+assert_eq!(wide, narrow);
+assert_eq!(NanoString::try_from(wide).unwrap(), narrow);
+
+let long = CentiString::new("the quick brown fox").unwrap();
+assert_eq!(NanoString::try_from(long).unwrap_err().kind(), ErrorKind::TooLong);
+assert_ne!(long, narrow);
+assert_eq!(narrow.partial_cmp(&long), Some(core::cmp::Ordering::Less));
+assert_eq!(long.partial_cmp(&narrow), Some(core::cmp::Ordering::Greater));
+```
+*/
+    pub const fn from_nano_string(s: NanoString) -> Self {
+        match Self::new(s.as_str()) {
+            Some(it) => it,
+            None => unreachable!(),
+        }
+    }
+}
+impl ::core::convert::From<NanoString> for CentiString {
+    fn from(value: NanoString) -> Self {
+        Self::from_nano_string(value)
+    }
+}
+impl ::core::convert::TryFrom<CentiString> for NanoString {
+    type Error = NanoStringError;
+    fn try_from(value: CentiString) -> Result<Self, NanoStringError> {
+        Self::try_from(value.as_str())
+    }
+}
+impl ::core::cmp::PartialEq<CentiString> for NanoString {
+    fn eq(&self, other: &CentiString) -> bool {
+        CentiString::from_nano_string(*self).eq(other)
+    }
+}
+impl ::core::cmp::PartialEq<NanoString> for CentiString {
+    fn eq(&self, other: &NanoString) -> bool {
+        self.eq(&CentiString::from_nano_string(*other))
+    }
+}
+impl ::core::cmp::PartialOrd<CentiString> for NanoString {
+    fn partial_cmp(&self, other: &CentiString) -> Option<::core::cmp::Ordering> {
+        CentiString::from_nano_string(*self).partial_cmp(other)
+    }
+}
+impl ::core::cmp::PartialOrd<NanoString> for CentiString {
+    fn partial_cmp(&self, other: &NanoString) -> Option<::core::cmp::Ordering> {
+        self.partial_cmp(&CentiString::from_nano_string(*other))
+    }
+}
+impl DeciString {
+    /**Converts a [`NanoString`] into a [`DeciString`], which always succeeds since <code>3 <= 63</code>.
+
+The sizes also convert the other way with [`TryFrom`], and compare with each other.
+```
+# use microstring::*;
+let narrow = NanoString::new("GBP").unwrap();
+let wide = DeciString::from(narrow);
+assert_eq!(wide, narrow);
+assert_eq!(NanoString::try_from(wide).unwrap(), narrow);
+
+let long = DeciString::new("the quick brown fox jumps over the lazy dog").unwrap();
+assert_eq!(NanoString::try_from(long).unwrap_err().kind(), ErrorKind::TooLong);
+assert_ne!(long, narrow);
+assert_eq!(narrow.partial_cmp(&long), Some(core::cmp::Ordering::Less));
+assert_eq!(long.partial_cmp(&narrow), Some(core::cmp::Ordering::Greater));
+```
+*/
+    pub const fn from_nano_string(s: NanoString) -> Self {
+        match Self::new(s.as_str()) {
+            Some(it) => it,
+            None => unreachable!(),
+        }
+    }
+}
+impl ::core::convert::From<NanoString> for DeciString {
+    fn from(value: NanoString) -> Self {
+        Self::from_nano_string(value)
+    }
+}
+impl ::core::convert::TryFrom<DeciString> for NanoString {
+    type Error = NanoStringError;
+    fn try_from(value: DeciString) -> Result<Self, NanoStringError> {
+        Self::try_from(value.as_str())
+    }
+}
+impl ::core::cmp::PartialEq<DeciString> for NanoString {
+    fn eq(&self, other: &DeciString) -> bool {
+        DeciString::from_nano_string(*self).eq(other)
+    }
+}
+impl ::core::cmp::PartialEq<NanoString> for DeciString {
+    fn eq(&self, other: &NanoString) -> bool {
+        self.eq(&DeciString::from_nano_string(*other))
+    }
+}
+impl ::core::cmp::PartialOrd<DeciString> for NanoString {
+    fn partial_cmp(&self, other: &DeciString) -> Option<::core::cmp::Ordering> {
+        DeciString::from_nano_string(*self).partial_cmp(other)
+    }
+}
+impl ::core::cmp::PartialOrd<NanoString> for DeciString {
+    fn partial_cmp(&self, other: &NanoString) -> Option<::core::cmp::Ordering> {
+        self.partial_cmp(&DeciString::from_nano_string(*other))
+    }
+}
+impl DecaString {
+    /**Converts a [`NanoString`] into a [`DecaString`], which always succeeds since <code>3 <= 127</code>.
+
+The sizes also convert the other way with [`TryFrom`], and compare with each other.
+```
+# use microstring::*;
+let narrow = NanoString::new("GBP").unwrap();
+let wide = DecaString::from(narrow);
+assert_eq!(wide, narrow);
+assert_eq!(NanoString::try_from(wide).unwrap(), narrow);
+
+let long = DecaString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home").unwrap();
+assert_eq!(NanoString::try_from(long).unwrap_err().kind(), ErrorKind::TooLong);
+assert_ne!(long, narrow);
+assert_eq!(narrow.partial_cmp(&long), Some(core::cmp::Ordering::Less));
+assert_eq!(long.partial_cmp(&narrow), Some(core::cmp::Ordering::Greater));
+```
+*/
+    pub const fn from_nano_string(s: NanoString) -> Self {
+        match Self::new(s.as_str()) {
+            Some(it) => it,
+            None => unreachable!(),
+        }
+    }
+}
+impl ::core::convert::From<NanoString> for DecaString {
+    fn from(value: NanoString) -> Self {
+        Self::from_nano_string(value)
+    }
+}
+impl ::core::convert::TryFrom<DecaString> for NanoString {
+    type Error = NanoStringError;
+    fn try_from(value: DecaString) -> Result<Self, NanoStringError> {
+        Self::try_from(value.as_str())
+    }
+}
+impl ::core::cmp::PartialEq<DecaString> for NanoString {
+    fn eq(&self, other: &DecaString) -> bool {
+        DecaString::from_nano_string(*self).eq(other)
+    }
+}
+impl ::core::cmp::PartialEq<NanoString> for DecaString {
+    fn eq(&self, other: &NanoString) -> bool {
+        self.eq(&DecaString::from_nano_string(*other))
+    }
+}
+impl ::core::cmp::PartialOrd<DecaString> for NanoString {
+    fn partial_cmp(&self, other: &DecaString) -> Option<::core::cmp::Ordering> {
+        DecaString::from_nano_string(*self).partial_cmp(other)
+    }
+}
+impl ::core::cmp::PartialOrd<NanoString> for DecaString {
+    fn partial_cmp(&self, other: &NanoString) -> Option<::core::cmp::Ordering> {
+        self.partial_cmp(&DecaString::from_nano_string(*other))
+    }
+}
+impl HectoString {
+    /**Converts a [`NanoString`] into a [`HectoString`], which always succeeds since <code>3 <= 255</code>.
+
+The sizes also convert the other way with [`TryFrom`], and compare with each other.
+```
+# use microstring::*;
+let narrow = NanoString::new("GBP").unwrap();
+let wide = HectoString::from(narrow);
+assert_eq!(wide, narrow);
+assert_eq!(NanoString::try_from(wide).unwrap(), narrow);
+
+let long = HectoString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills").unwrap();
+assert_eq!(NanoString::try_from(long).unwrap_err().kind(), ErrorKind::TooLong);
+assert_ne!(long, narrow);
+assert_eq!(narrow.partial_cmp(&long), Some(core::cmp::Ordering::Less));
+assert_eq!(long.partial_cmp(&narrow), Some(core::cmp::Ordering::Greater));
+```
+*/
+    pub const fn from_nano_string(s: NanoString) -> Self {
+        match Self::new(s.as_str()) {
+            Some(it) => it,
+            None => unreachable!(),
+        }
+    }
+}
+impl ::core::convert::From<NanoString> for HectoString {
+    fn from(value: NanoString) -> Self {
+        Self::from_nano_string(value)
+    }
+}
+impl ::core::convert::TryFrom<HectoString> for NanoString {
+    type Error = NanoStringError;
+    fn try_from(value: HectoString) -> Result<Self, NanoStringError> {
+        Self::try_from(value.as_str())
+    }
+}
+impl ::core::cmp::PartialEq<HectoString> for NanoString {
+    fn eq(&self, other: &HectoString) -> bool {
+        HectoString::from_nano_string(*self).eq(other)
+    }
+}
+impl ::core::cmp::PartialEq<NanoString> for HectoString {
+    fn eq(&self, other: &NanoString) -> bool {
+        self.eq(&HectoString::from_nano_string(*other))
+    }
+}
+impl ::core::cmp::PartialOrd<HectoString> for NanoString {
+    fn partial_cmp(&self, other: &HectoString) -> Option<::core::cmp::Ordering> {
+        HectoString::from_nano_string(*self).partial_cmp(other)
+    }
+}
+impl ::core::cmp::PartialOrd<NanoString> for HectoString {
+    fn partial_cmp(&self, other: &NanoString) -> Option<::core::cmp::Ordering> {
+        self.partial_cmp(&HectoString::from_nano_string(*other))
+    }
+}
+impl MilliString {
+    /**Converts a [`MicroString`] into a [`MilliString`], which always succeeds since <code>7 <= 15</code>.
+
+The sizes also convert the other way with [`TryFrom`], and compare with each other.
+```
+# use microstring::*;
+let narrow = MicroString::new("1234567").unwrap();
+let wide = MilliString::from(narrow);
+assert_eq!(wide, narrow);
+assert_eq!(MicroString::try_from(wide).unwrap(), narrow);
+
+let long = MilliString::new("hello world :)").unwrap();
+assert_eq!(MicroString::try_from(long).unwrap_err().kind(), ErrorKind::TooLong);
+assert_ne!(long, narrow);
+assert_eq!(narrow.partial_cmp(&long), Some(core::cmp::Ordering::Less));
+assert_eq!(long.partial_cmp(&narrow), Some(core::cmp::Ordering::Greater));
+```
+*/
+    pub const fn from_micro_string(s: MicroString) -> Self {
+        match Self::new(s.as_str()) {
+            Some(it) => it,
+            None => unreachable!(),
+        }
+    }
+}
+impl ::core::convert::From<MicroString> for MilliString {
+    fn from(value: MicroString) -> Self {
+        Self::from_micro_string(value)
+    }
+}
+impl ::core::convert::TryFrom<MilliString> for MicroString {
+    type Error = MicroStringError;
+    fn try_from(value: MilliString) -> Result<Self, MicroStringError> {
+        Self::try_from(value.as_str())
+    }
+}
+impl ::core::cmp::PartialEq<MilliString> for MicroString {
+    fn eq(&self, other: &MilliString) -> bool {
+        MilliString::from_micro_string(*self).eq(other)
+    }
+}
+impl ::core::cmp::PartialEq<MicroString> for MilliString {
+    fn eq(&self, other: &MicroString) -> bool {
+        self.eq(&MilliString::from_micro_string(*other))
+    }
+}
+impl ::core::cmp::PartialOrd<MilliString> for MicroString {
+    fn partial_cmp(&self, other: &MilliString) -> Option<::core::cmp::Ordering> {
+        MilliString::from_micro_string(*self).partial_cmp(other)
+    }
+}
+impl ::core::cmp::PartialOrd<MicroString> for MilliString {
+    fn partial_cmp(&self, other: &MicroString) -> Option<::core::cmp::Ordering> {
+        self.partial_cmp(&MilliString::from_micro_string(*other))
+    }
+}
+impl CentiString {
+    /**Converts a [`MicroString`] into a [`CentiString`], which always succeeds since <code>7 <= 31</code>.
+
+The sizes also convert the other way with [`TryFrom`], and compare with each other.
+```
+# use microstring::*;
+let narrow = MicroString::new("1234567").unwrap();
+let wide = CentiString::from(narrow);
+assert_eq!(wide, narrow);
+assert_eq!(MicroString::try_from(wide).unwrap(), narrow);
+
+let long = CentiString::new("the quick brown fox").unwrap();
+assert_eq!(MicroString::try_from(long).unwrap_err().kind(), ErrorKind::TooLong);
+assert_ne!(long, narrow);
+assert_eq!(narrow.partial_cmp(&long), Some(core::cmp::Ordering::Less));
+assert_eq!(long.partial_cmp(&narrow), Some(core::cmp::Ordering::Greater));
+```
+*/
+    pub const fn from_micro_string(s: MicroString) -> Self {
+        match Self::new(s.as_str()) {
+            Some(it) => it,
+            None => unreachable!(),
+        }
+    }
+}
+impl ::core::convert::From<MicroString> for CentiString {
+    fn from(value: MicroString) -> Self {
+        Self::from_micro_string(value)
+    }
+}
+impl ::core::convert::TryFrom<CentiString> for MicroString {
+    type Error = MicroStringError;
+    fn try_from(value: CentiString) -> Result<Self, MicroStringError> {
+        Self::try_from(value.as_str())
+    }
+}
+impl ::core::cmp::PartialEq<CentiString> for MicroString {
+    fn eq(&self, other: &CentiString) -> bool {
+        CentiString::from_micro_string(*self).eq(other)
+    }
+}
+impl ::core::cmp::PartialEq<MicroString> for CentiString {
+    fn eq(&self, other: &MicroString) -> bool {
+        self.eq(&CentiString::from_micro_string(*other))
+    }
+}
+impl ::core::cmp::PartialOrd<CentiString> for MicroString {
+    fn partial_cmp(&self, other: &CentiString) -> Option<::core::cmp::Ordering> {
+        CentiString::from_micro_string(*self).partial_cmp(other)
+    }
+}
+impl ::core::cmp::PartialOrd<MicroString> for CentiString {
+    fn partial_cmp(&self, other: &MicroString) -> Option<::core::cmp::Ordering> {
+        self.partial_cmp(&CentiString::from_micro_string(*other))
+    }
+}
+impl DeciString {
+    /**Converts a [`MicroString`] into a [`DeciString`], which always succeeds since <code>7 <= 63</code>.
+
+The sizes also convert the other way with [`TryFrom`], and compare with each other.
+```
+# use microstring::*;
+let narrow = MicroString::new("1234567").unwrap();
+let wide = DeciString::from(narrow);
+assert_eq!(wide, narrow);
+assert_eq!(MicroString::try_from(wide).unwrap(), narrow);
+
+let long = DeciString::new("the quick brown fox jumps over the lazy dog").unwrap();
+assert_eq!(MicroString::try_from(long).unwrap_err().kind(), ErrorKind::TooLong);
+assert_ne!(long, narrow);
+assert_eq!(narrow.partial_cmp(&long), Some(core::cmp::Ordering::Less));
+assert_eq!(long.partial_cmp(&narrow), Some(core::cmp::Ordering::Greater));
+```
+*/
+    pub const fn from_micro_string(s: MicroString) -> Self {
+        match Self::new(s.as_str()) {
+            Some(it) => it,
+            None => unreachable!(),
+        }
+    }
+}
+impl ::core::convert::From<MicroString> for DeciString {
+    fn from(value: MicroString) -> Self {
+        Self::from_micro_string(value)
+    }
+}
+impl ::core::convert::TryFrom<DeciString> for MicroString {
+    type Error = MicroStringError;
+    fn try_from(value: DeciString) -> Result<Self, MicroStringError> {
+        Self::try_from(value.as_str())
+    }
+}
+impl ::core::cmp::PartialEq<DeciString> for MicroString {
+    fn eq(&self, other: &DeciString) -> bool {
+        DeciString::from_micro_string(*self).eq(other)
+    }
+}
+impl ::core::cmp::PartialEq<MicroString> for DeciString {
+    fn eq(&self, other: &MicroString) -> bool {
+        self.eq(&DeciString::from_micro_string(*other))
+    }
+}
+impl ::core::cmp::PartialOrd<DeciString> for MicroString {
+    fn partial_cmp(&self, other: &DeciString) -> Option<::core::cmp::Ordering> {
+        DeciString::from_micro_string(*self).partial_cmp(other)
+    }
+}
+impl ::core::cmp::PartialOrd<MicroString> for DeciString {
+    fn partial_cmp(&self, other: &MicroString) -> Option<::core::cmp::Ordering> {
+        self.partial_cmp(&DeciString::from_micro_string(*other))
+    }
+}
+impl DecaString {
+    /**Converts a [`MicroString`] into a [`DecaString`], which always succeeds since <code>7 <= 127</code>.
+
+The sizes also convert the other way with [`TryFrom`], and compare with each other.
+```
+# use microstring::*;
+let narrow = MicroString::new("1234567").unwrap();
+let wide = DecaString::from(narrow);
+assert_eq!(wide, narrow);
+assert_eq!(MicroString::try_from(wide).unwrap(), narrow);
+
+let long = DecaString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home").unwrap();
+assert_eq!(MicroString::try_from(long).unwrap_err().kind(), ErrorKind::TooLong);
+assert_ne!(long, narrow);
+assert_eq!(narrow.partial_cmp(&long), Some(core::cmp::Ordering::Less));
+assert_eq!(long.partial_cmp(&narrow), Some(core::cmp::Ordering::Greater));
+```
+*/
+    pub const fn from_micro_string(s: MicroString) -> Self {
+        match Self::new(s.as_str()) {
+            Some(it) => it,
+            None => unreachable!(),
+        }
+    }
+}
+impl ::core::convert::From<MicroString> for DecaString {
+    fn from(value: MicroString) -> Self {
+        Self::from_micro_string(value)
+    }
+}
+impl ::core::convert::TryFrom<DecaString> for MicroString {
+    type Error = MicroStringError;
+    fn try_from(value: DecaString) -> Result<Self, MicroStringError> {
+        Self::try_from(value.as_str())
+    }
+}
+impl ::core::cmp::PartialEq<DecaString> for MicroString {
+    fn eq(&self, other: &DecaString) -> bool {
+        DecaString::from_micro_string(*self).eq(other)
+    }
+}
+impl ::core::cmp::PartialEq<MicroString> for DecaString {
+    fn eq(&self, other: &MicroString) -> bool {
+        self.eq(&DecaString::from_micro_string(*other))
+    }
+}
+impl ::core::cmp::PartialOrd<DecaString> for MicroString {
+    fn partial_cmp(&self, other: &DecaString) -> Option<::core::cmp::Ordering> {
+        DecaString::from_micro_string(*self).partial_cmp(other)
+    }
+}
+impl ::core::cmp::PartialOrd<MicroString> for DecaString {
+    fn partial_cmp(&self, other: &MicroString) -> Option<::core::cmp::Ordering> {
+        self.partial_cmp(&DecaString::from_micro_string(*other))
+    }
+}
+impl HectoString {
+    /**Converts a [`MicroString`] into a [`HectoString`], which always succeeds since <code>7 <= 255</code>.
+
+The sizes also convert the other way with [`TryFrom`], and compare with each other.
+```
+# use microstring::*;
+let narrow = MicroString::new("1234567").unwrap();
+let wide = HectoString::from(narrow);
+assert_eq!(wide, narrow);
+assert_eq!(MicroString::try_from(wide).unwrap(), narrow);
+
+let long = HectoString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills").unwrap();
+assert_eq!(MicroString::try_from(long).unwrap_err().kind(), ErrorKind::TooLong);
+assert_ne!(long, narrow);
+assert_eq!(narrow.partial_cmp(&long), Some(core::cmp::Ordering::Less));
+assert_eq!(long.partial_cmp(&narrow), Some(core::cmp::Ordering::Greater));
+```
+*/
+    pub const fn from_micro_string(s: MicroString) -> Self {
+        match Self::new(s.as_str()) {
+            Some(it) => it,
+            None => unreachable!(),
+        }
+    }
+}
+impl ::core::convert::From<MicroString> for HectoString {
+    fn from(value: MicroString) -> Self {
+        Self::from_micro_string(value)
+    }
+}
+impl ::core::convert::TryFrom<HectoString> for MicroString {
+    type Error = MicroStringError;
+    fn try_from(value: HectoString) -> Result<Self, MicroStringError> {
+        Self::try_from(value.as_str())
+    }
+}
+impl ::core::cmp::PartialEq<HectoString> for MicroString {
+    fn eq(&self, other: &HectoString) -> bool {
+        HectoString::from_micro_string(*self).eq(other)
+    }
+}
+impl ::core::cmp::PartialEq<MicroString> for HectoString {
+    fn eq(&self, other: &MicroString) -> bool {
+        self.eq(&HectoString::from_micro_string(*other))
+    }
+}
+impl ::core::cmp::PartialOrd<HectoString> for MicroString {
+    fn partial_cmp(&self, other: &HectoString) -> Option<::core::cmp::Ordering> {
+        HectoString::from_micro_string(*self).partial_cmp(other)
+    }
+}
+impl ::core::cmp::PartialOrd<MicroString> for HectoString {
+    fn partial_cmp(&self, other: &MicroString) -> Option<::core::cmp::Ordering> {
+        self.partial_cmp(&HectoString::from_micro_string(*other))
+    }
+}
+impl CentiString {
+    /**Converts a [`MilliString`] into a [`CentiString`], which always succeeds since <code>15 <= 31</code>.
+
+The sizes also convert the other way with [`TryFrom`], and compare with each other.
+```
+# use microstring::*;
+let narrow = MilliString::new("hello world :)").unwrap();
+let wide = CentiString::from(narrow);
+assert_eq!(wide, narrow);
+assert_eq!(MilliString::try_from(wide).unwrap(), narrow);
+
+let long = CentiString::new("the quick brown fox").unwrap();
+assert_eq!(MilliString::try_from(long).unwrap_err().kind(), ErrorKind::TooLong);
+assert_ne!(long, narrow);
+assert_eq!(narrow.partial_cmp(&long), Some(core::cmp::Ordering::Less));
+assert_eq!(long.partial_cmp(&narrow), Some(core::cmp::Ordering::Greater));
+```
+*/
+    pub const fn from_milli_string(s: MilliString) -> Self {
+        match Self::new(s.as_str()) {
+            Some(it) => it,
+            None => unreachable!(),
+        }
+    }
+}
+impl ::core::convert::From<MilliString> for CentiString {
+    fn from(value: MilliString) -> Self {
+        Self::from_milli_string(value)
+    }
+}
+impl ::core::convert::TryFrom<CentiString> for MilliString {
+    type Error = MilliStringError;
+    fn try_from(value: CentiString) -> Result<Self, MilliStringError> {
+        Self::try_from(value.as_str())
+    }
+}
+impl ::core::cmp::PartialEq<CentiString> for MilliString {
+    fn eq(&self, other: &CentiString) -> bool {
+        CentiString::from_milli_string(*self).eq(other)
+    }
+}
+impl ::core::cmp::PartialEq<MilliString> for CentiString {
+    fn eq(&self, other: &MilliString) -> bool {
+        self.eq(&CentiString::from_milli_string(*other))
+    }
+}
+impl ::core::cmp::PartialOrd<CentiString> for MilliString {
+    fn partial_cmp(&self, other: &CentiString) -> Option<::core::cmp::Ordering> {
+        CentiString::from_milli_string(*self).partial_cmp(other)
+    }
+}
+impl ::core::cmp::PartialOrd<MilliString> for CentiString {
+    fn partial_cmp(&self, other: &MilliString) -> Option<::core::cmp::Ordering> {
+        self.partial_cmp(&CentiString::from_milli_string(*other))
+    }
+}
+impl DeciString {
+    /**Converts a [`MilliString`] into a [`DeciString`], which always succeeds since <code>15 <= 63</code>.
+
+The sizes also convert the other way with [`TryFrom`], and compare with each other.
+```
+# use microstring::*;
+let narrow = MilliString::new("hello world :)").unwrap();
+let wide = DeciString::from(narrow);
+assert_eq!(wide, narrow);
+assert_eq!(MilliString::try_from(wide).unwrap(), narrow);
+
+let long = DeciString::new("the quick brown fox jumps over the lazy dog").unwrap();
+assert_eq!(MilliString::try_from(long).unwrap_err().kind(), ErrorKind::TooLong);
+assert_ne!(long, narrow);
+assert_eq!(narrow.partial_cmp(&long), Some(core::cmp::Ordering::Less));
+assert_eq!(long.partial_cmp(&narrow), Some(core::cmp::Ordering::Greater));
+```
+*/
+    pub const fn from_milli_string(s: MilliString) -> Self {
+        match Self::new(s.as_str()) {
+            Some(it) => it,
+            None => unreachable!(),
+        }
+    }
+}
+impl ::core::convert::From<MilliString> for DeciString {
+    fn from(value: MilliString) -> Self {
+        Self::from_milli_string(value)
+    }
+}
+impl ::core::convert::TryFrom<DeciString> for MilliString {
+    type Error = MilliStringError;
+    fn try_from(value: DeciString) -> Result<Self, MilliStringError> {
+        Self::try_from(value.as_str())
+    }
+}
+impl ::core::cmp::PartialEq<DeciString> for MilliString {
+    fn eq(&self, other: &DeciString) -> bool {
+        DeciString::from_milli_string(*self).eq(other)
+    }
+}
+impl ::core::cmp::PartialEq<MilliString> for DeciString {
+    fn eq(&self, other: &MilliString) -> bool {
+        self.eq(&DeciString::from_milli_string(*other))
+    }
+}
+impl ::core::cmp::PartialOrd<DeciString> for MilliString {
+    fn partial_cmp(&self, other: &DeciString) -> Option<::core::cmp::Ordering> {
+        DeciString::from_milli_string(*self).partial_cmp(other)
+    }
+}
+impl ::core::cmp::PartialOrd<MilliString> for DeciString {
+    fn partial_cmp(&self, other: &MilliString) -> Option<::core::cmp::Ordering> {
+        self.partial_cmp(&DeciString::from_milli_string(*other))
+    }
+}
+impl DecaString {
+    /**Converts a [`MilliString`] into a [`DecaString`], which always succeeds since <code>15 <= 127</code>.
+
+The sizes also convert the other way with [`TryFrom`], and compare with each other.
+```
+# use microstring::*;
+let narrow = MilliString::new("hello world :)").unwrap();
+let wide = DecaString::from(narrow);
+assert_eq!(wide, narrow);
+assert_eq!(MilliString::try_from(wide).unwrap(), narrow);
+
+let long = DecaString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home").unwrap();
+assert_eq!(MilliString::try_from(long).unwrap_err().kind(), ErrorKind::TooLong);
+assert_ne!(long, narrow);
+assert_eq!(narrow.partial_cmp(&long), Some(core::cmp::Ordering::Less));
+assert_eq!(long.partial_cmp(&narrow), Some(core::cmp::Ordering::Greater));
+```
+*/
+    pub const fn from_milli_string(s: MilliString) -> Self {
+        match Self::new(s.as_str()) {
+            Some(it) => it,
+            None => unreachable!(),
+        }
+    }
+}
+impl ::core::convert::From<MilliString> for DecaString {
+    fn from(value: MilliString) -> Self {
+        Self::from_milli_string(value)
+    }
+}
+impl ::core::convert::TryFrom<DecaString> for MilliString {
+    type Error = MilliStringError;
+    fn try_from(value: DecaString) -> Result<Self, MilliStringError> {
+        Self::try_from(value.as_str())
+    }
+}
+impl ::core::cmp::PartialEq<DecaString> for MilliString {
+    fn eq(&self, other: &DecaString) -> bool {
+        DecaString::from_milli_string(*self).eq(other)
+    }
+}
+impl ::core::cmp::PartialEq<MilliString> for DecaString {
+    fn eq(&self, other: &MilliString) -> bool {
+        self.eq(&DecaString::from_milli_string(*other))
+    }
+}
+impl ::core::cmp::PartialOrd<DecaString> for MilliString {
+    fn partial_cmp(&self, other: &DecaString) -> Option<::core::cmp::Ordering> {
+        DecaString::from_milli_string(*self).partial_cmp(other)
+    }
+}
+impl ::core::cmp::PartialOrd<MilliString> for DecaString {
+    fn partial_cmp(&self, other: &MilliString) -> Option<::core::cmp::Ordering> {
+        self.partial_cmp(&DecaString::from_milli_string(*other))
+    }
+}
+impl HectoString {
+    /**Converts a [`MilliString`] into a [`HectoString`], which always succeeds since <code>15 <= 255</code>.
+
+The sizes also convert the other way with [`TryFrom`], and compare with each other.
+```
+# use microstring::*;
+let narrow = MilliString::new("hello world :)").unwrap();
+let wide = HectoString::from(narrow);
+assert_eq!(wide, narrow);
+assert_eq!(MilliString::try_from(wide).unwrap(), narrow);
+
+let long = HectoString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills").unwrap();
+assert_eq!(MilliString::try_from(long).unwrap_err().kind(), ErrorKind::TooLong);
+assert_ne!(long, narrow);
+assert_eq!(narrow.partial_cmp(&long), Some(core::cmp::Ordering::Less));
+assert_eq!(long.partial_cmp(&narrow), Some(core::cmp::Ordering::Greater));
+```
+*/
+    pub const fn from_milli_string(s: MilliString) -> Self {
+        match Self::new(s.as_str()) {
+            Some(it) => it,
+            None => unreachable!(),
+        }
+    }
+}
+impl ::core::convert::From<MilliString> for HectoString {
+    fn from(value: MilliString) -> Self {
+        Self::from_milli_string(value)
+    }
+}
+impl ::core::convert::TryFrom<HectoString> for MilliString {
+    type Error = MilliStringError;
+    fn try_from(value: HectoString) -> Result<Self, MilliStringError> {
+        Self::try_from(value.as_str())
+    }
+}
+impl ::core::cmp::PartialEq<HectoString> for MilliString {
+    fn eq(&self, other: &HectoString) -> bool {
+        HectoString::from_milli_string(*self).eq(other)
+    }
+}
+impl ::core::cmp::PartialEq<MilliString> for HectoString {
+    fn eq(&self, other: &MilliString) -> bool {
+        self.eq(&HectoString::from_milli_string(*other))
+    }
+}
+impl ::core::cmp::PartialOrd<HectoString> for MilliString {
+    fn partial_cmp(&self, other: &HectoString) -> Option<::core::cmp::Ordering> {
+        HectoString::from_milli_string(*self).partial_cmp(other)
+    }
+}
+impl ::core::cmp::PartialOrd<MilliString> for HectoString {
+    fn partial_cmp(&self, other: &MilliString) -> Option<::core::cmp::Ordering> {
+        self.partial_cmp(&HectoString::from_milli_string(*other))
+    }
+}
+impl DeciString {
+    /**Converts a [`CentiString`] into a [`DeciString`], which always succeeds since <code>31 <= 63</code>.
+
+The sizes also convert the other way with [`TryFrom`], and compare with each other.
+```
+# use microstring::*;
+let narrow = CentiString::new("the quick brown fox").unwrap();
+let wide = DeciString::from(narrow);
+assert_eq!(wide, narrow);
+assert_eq!(CentiString::try_from(wide).unwrap(), narrow);
+
+let long = DeciString::new("the quick brown fox jumps over the lazy dog").unwrap();
+assert_eq!(CentiString::try_from(long).unwrap_err().kind(), ErrorKind::TooLong);
+assert_ne!(long, narrow);
+assert_eq!(narrow.partial_cmp(&long), Some(core::cmp::Ordering::Less));
+assert_eq!(long.partial_cmp(&narrow), Some(core::cmp::Ordering::Greater));
+```
+*/
+    pub const fn from_centi_string(s: CentiString) -> Self {
+        match Self::new(s.as_str()) {
+            Some(it) => it,
+            None => unreachable!(),
+        }
+    }
+}
+impl ::core::convert::From<CentiString> for DeciString {
+    fn from(value: CentiString) -> Self {
+        Self::from_centi_string(value)
+    }
+}
+impl ::core::convert::TryFrom<DeciString> for CentiString {
+    type Error = CentiStringError;
+    fn try_from(value: DeciString) -> Result<Self, CentiStringError> {
+        Self::try_from(value.as_str())
+    }
+}
+impl ::core::cmp::PartialEq<DeciString> for CentiString {
+    fn eq(&self, other: &DeciString) -> bool {
+        DeciString::from_centi_string(*self).eq(other)
+    }
+}
+impl ::core::cmp::PartialEq<CentiString> for DeciString {
+    fn eq(&self, other: &CentiString) -> bool {
+        self.eq(&DeciString::from_centi_string(*other))
+    }
+}
+impl ::core::cmp::PartialOrd<DeciString> for CentiString {
+    fn partial_cmp(&self, other: &DeciString) -> Option<::core::cmp::Ordering> {
+        DeciString::from_centi_string(*self).partial_cmp(other)
+    }
+}
+impl ::core::cmp::PartialOrd<CentiString> for DeciString {
+    fn partial_cmp(&self, other: &CentiString) -> Option<::core::cmp::Ordering> {
+        self.partial_cmp(&DeciString::from_centi_string(*other))
+    }
+}
+impl DecaString {
+    /**Converts a [`CentiString`] into a [`DecaString`], which always succeeds since <code>31 <= 127</code>.
+
+The sizes also convert the other way with [`TryFrom`], and compare with each other.
+```
+# use microstring::*;
+let narrow = CentiString::new("the quick brown fox").unwrap();
+let wide = DecaString::from(narrow);
+assert_eq!(wide, narrow);
+assert_eq!(CentiString::try_from(wide).unwrap(), narrow);
+
+let long = DecaString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home").unwrap();
+assert_eq!(CentiString::try_from(long).unwrap_err().kind(), ErrorKind::TooLong);
+assert_ne!(long, narrow);
+assert_eq!(narrow.partial_cmp(&long), Some(core::cmp::Ordering::Less));
+assert_eq!(long.partial_cmp(&narrow), Some(core::cmp::Ordering::Greater));
+```
+*/
+    pub const fn from_centi_string(s: CentiString) -> Self {
+        match Self::new(s.as_str()) {
+            Some(it) => it,
+            None => unreachable!(),
+        }
+    }
+}
+impl ::core::convert::From<CentiString> for DecaString {
+    fn from(value: CentiString) -> Self {
+        Self::from_centi_string(value)
+    }
+}
+impl ::core::convert::TryFrom<DecaString> for CentiString {
+    type Error = CentiStringError;
+    fn try_from(value: DecaString) -> Result<Self, CentiStringError> {
+        Self::try_from(value.as_str())
+    }
+}
+impl ::core::cmp::PartialEq<DecaString> for CentiString {
+    fn eq(&self, other: &DecaString) -> bool {
+        DecaString::from_centi_string(*self).eq(other)
+    }
+}
+impl ::core::cmp::PartialEq<CentiString> for DecaString {
+    fn eq(&self, other: &CentiString) -> bool {
+        self.eq(&DecaString::from_centi_string(*other))
+    }
+}
+impl ::core::cmp::PartialOrd<DecaString> for CentiString {
+    fn partial_cmp(&self, other: &DecaString) -> Option<::core::cmp::Ordering> {
+        DecaString::from_centi_string(*self).partial_cmp(other)
+    }
+}
+impl ::core::cmp::PartialOrd<CentiString> for DecaString {
+    fn partial_cmp(&self, other: &CentiString) -> Option<::core::cmp::Ordering> {
+        self.partial_cmp(&DecaString::from_centi_string(*other))
+    }
+}
+impl HectoString {
+    /**Converts a [`CentiString`] into a [`HectoString`], which always succeeds since <code>31 <= 255</code>.
+
+The sizes also convert the other way with [`TryFrom`], and compare with each other.
+```
+# use microstring::*;
+let narrow = CentiString::new("the quick brown fox").unwrap();
+let wide = HectoString::from(narrow);
+assert_eq!(wide, narrow);
+assert_eq!(CentiString::try_from(wide).unwrap(), narrow);
+
+let long = HectoString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills").unwrap();
+assert_eq!(CentiString::try_from(long).unwrap_err().kind(), ErrorKind::TooLong);
+assert_ne!(long, narrow);
+assert_eq!(narrow.partial_cmp(&long), Some(core::cmp::Ordering::Less));
+assert_eq!(long.partial_cmp(&narrow), Some(core::cmp::Ordering::Greater));
+```
+*/
+    pub const fn from_centi_string(s: CentiString) -> Self {
+        match Self::new(s.as_str()) {
+            Some(it) => it,
+            None => unreachable!(),
+        }
+    }
+}
+impl ::core::convert::From<CentiString> for HectoString {
+    fn from(value: CentiString) -> Self {
+        Self::from_centi_string(value)
+    }
+}
+impl ::core::convert::TryFrom<HectoString> for CentiString {
+    type Error = CentiStringError;
+    fn try_from(value: HectoString) -> Result<Self, CentiStringError> {
+        Self::try_from(value.as_str())
+    }
+}
+impl ::core::cmp::PartialEq<HectoString> for CentiString {
+    fn eq(&self, other: &HectoString) -> bool {
+        HectoString::from_centi_string(*self).eq(other)
+    }
+}
+impl ::core::cmp::PartialEq<CentiString> for HectoString {
+    fn eq(&self, other: &CentiString) -> bool {
+        self.eq(&HectoString::from_centi_string(*other))
+    }
+}
+impl ::core::cmp::PartialOrd<HectoString> for CentiString {
+    fn partial_cmp(&self, other: &HectoString) -> Option<::core::cmp::Ordering> {
+        HectoString::from_centi_string(*self).partial_cmp(other)
+    }
+}
+impl ::core::cmp::PartialOrd<CentiString> for HectoString {
+    fn partial_cmp(&self, other: &CentiString) -> Option<::core::cmp::Ordering> {
+        self.partial_cmp(&HectoString::from_centi_string(*other))
+    }
+}
+impl DecaString {
+    /**Converts a [`DeciString`] into a [`DecaString`], which always succeeds since <code>63 <= 127</code>.
+
+The sizes also convert the other way with [`TryFrom`], and compare with each other.
+```
+# use microstring::*;
+let narrow = DeciString::new("the quick brown fox jumps over the lazy dog").unwrap();
+let wide = DecaString::from(narrow);
+assert_eq!(wide, narrow);
+assert_eq!(DeciString::try_from(wide).unwrap(), narrow);
+
+let long = DecaString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home").unwrap();
+assert_eq!(DeciString::try_from(long).unwrap_err().kind(), ErrorKind::TooLong);
+assert_ne!(long, narrow);
+assert_eq!(narrow.partial_cmp(&long), Some(core::cmp::Ordering::Less));
+assert_eq!(long.partial_cmp(&narrow), Some(core::cmp::Ordering::Greater));
+```
+*/
+    pub const fn from_deci_string(s: DeciString) -> Self {
+        match Self::new(s.as_str()) {
+            Some(it) => it,
+            None => unreachable!(),
+        }
+    }
+}
+impl ::core::convert::From<DeciString> for DecaString {
+    fn from(value: DeciString) -> Self {
+        Self::from_deci_string(value)
+    }
+}
+impl ::core::convert::TryFrom<DecaString> for DeciString {
+    type Error = DeciStringError;
+    fn try_from(value: DecaString) -> Result<Self, DeciStringError> {
+        Self::try_from(value.as_str())
+    }
+}
+impl ::core::cmp::PartialEq<DecaString> for DeciString {
+    fn eq(&self, other: &DecaString) -> bool {
+        DecaString::from_deci_string(*self).eq(other)
+    }
+}
+impl ::core::cmp::PartialEq<DeciString> for DecaString {
+    fn eq(&self, other: &DeciString) -> bool {
+        self.eq(&DecaString::from_deci_string(*other))
+    }
+}
+impl ::core::cmp::PartialOrd<DecaString> for DeciString {
+    fn partial_cmp(&self, other: &DecaString) -> Option<::core::cmp::Ordering> {
+        DecaString::from_deci_string(*self).partial_cmp(other)
+    }
+}
+impl ::core::cmp::PartialOrd<DeciString> for DecaString {
+    fn partial_cmp(&self, other: &DeciString) -> Option<::core::cmp::Ordering> {
+        self.partial_cmp(&DecaString::from_deci_string(*other))
+    }
+}
+impl HectoString {
+    /**Converts a [`DeciString`] into a [`HectoString`], which always succeeds since <code>63 <= 255</code>.
+
+The sizes also convert the other way with [`TryFrom`], and compare with each other.
+```
+# use microstring::*;
+let narrow = DeciString::new("the quick brown fox jumps over the lazy dog").unwrap();
+let wide = HectoString::from(narrow);
+assert_eq!(wide, narrow);
+assert_eq!(DeciString::try_from(wide).unwrap(), narrow);
+
+let long = HectoString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills").unwrap();
+assert_eq!(DeciString::try_from(long).unwrap_err().kind(), ErrorKind::TooLong);
+assert_ne!(long, narrow);
+assert_eq!(narrow.partial_cmp(&long), Some(core::cmp::Ordering::Less));
+assert_eq!(long.partial_cmp(&narrow), Some(core::cmp::Ordering::Greater));
+```
+*/
+    pub const fn from_deci_string(s: DeciString) -> Self {
+        match Self::new(s.as_str()) {
+            Some(it) => it,
+            None => unreachable!(),
+        }
+    }
+}
+impl ::core::convert::From<DeciString> for HectoString {
+    fn from(value: DeciString) -> Self {
+        Self::from_deci_string(value)
+    }
+}
+impl ::core::convert::TryFrom<HectoString> for DeciString {
+    type Error = DeciStringError;
+    fn try_from(value: HectoString) -> Result<Self, DeciStringError> {
+        Self::try_from(value.as_str())
+    }
+}
+impl ::core::cmp::PartialEq<HectoString> for DeciString {
+    fn eq(&self, other: &HectoString) -> bool {
+        HectoString::from_deci_string(*self).eq(other)
+    }
+}
+impl ::core::cmp::PartialEq<DeciString> for HectoString {
+    fn eq(&self, other: &DeciString) -> bool {
+        self.eq(&HectoString::from_deci_string(*other))
+    }
+}
+impl ::core::cmp::PartialOrd<HectoString> for DeciString {
+    fn partial_cmp(&self, other: &HectoString) -> Option<::core::cmp::Ordering> {
+        HectoString::from_deci_string(*self).partial_cmp(other)
+    }
+}
+impl ::core::cmp::PartialOrd<DeciString> for HectoString {
+    fn partial_cmp(&self, other: &DeciString) -> Option<::core::cmp::Ordering> {
+        self.partial_cmp(&HectoString::from_deci_string(*other))
+    }
+}
+impl HectoString {
+    /**Converts a [`DecaString`] into a [`HectoString`], which always succeeds since <code>127 <= 255</code>.
+
+The sizes also convert the other way with [`TryFrom`], and compare with each other.
+```
+# use microstring::*;
+let narrow = DecaString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home").unwrap();
+let wide = HectoString::from(narrow);
+assert_eq!(wide, narrow);
+assert_eq!(DecaString::try_from(wide).unwrap(), narrow);
+
+let long = HectoString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, where they both sleep by the fire until the sun rises over the hills").unwrap();
+assert_eq!(DecaString::try_from(long).unwrap_err().kind(), ErrorKind::TooLong);
+assert_ne!(long, narrow);
+assert_eq!(narrow.partial_cmp(&long), Some(core::cmp::Ordering::Less));
+assert_eq!(long.partial_cmp(&narrow), Some(core::cmp::Ordering::Greater));
+```
+*/
+    pub const fn from_deca_string(s: DecaString) -> Self {
+        match Self::new(s.as_str()) {
+            Some(it) => it,
+            None => unreachable!(),
+        }
+    }
+}
+impl ::core::convert::From<DecaString> for HectoString {
+    fn from(value: DecaString) -> Self {
+        Self::from_deca_string(value)
+    }
+}
+impl ::core::convert::TryFrom<HectoString> for DecaString {
+    type Error = DecaStringError;
+    fn try_from(value: HectoString) -> Result<Self, DecaStringError> {
+        Self::try_from(value.as_str())
+    }
+}
+impl ::core::cmp::PartialEq<HectoString> for DecaString {
+    fn eq(&self, other: &HectoString) -> bool {
+        HectoString::from_deca_string(*self).eq(other)
+    }
+}
+impl ::core::cmp::PartialEq<DecaString> for HectoString {
+    fn eq(&self, other: &DecaString) -> bool {
+        self.eq(&HectoString::from_deca_string(*other))
+    }
+}
+impl ::core::cmp::PartialOrd<HectoString> for DecaString {
+    fn partial_cmp(&self, other: &HectoString) -> Option<::core::cmp::Ordering> {
+        HectoString::from_deca_string(*self).partial_cmp(other)
+    }
+}
+impl ::core::cmp::PartialOrd<DecaString> for HectoString {
+    fn partial_cmp(&self, other: &DecaString) -> Option<::core::cmp::Ordering> {
+        self.partial_cmp(&HectoString::from_deca_string(*other))
+    }
+}
+impl NanoString {
+    /**Concatenates this string with a [`NanoString`], which always fits in a [`MicroString`] since <code>3 + 3 <= 7</code>.
+
+This is also available as `+`.
+```
+# use microstring::*;
+let left = NanoString::new("GBP").unwrap();
+let right = NanoString::new("GBP").unwrap();
+let both: MicroString = left + right;
+assert_eq!(both, "GBPGBP");
+assert_eq!(left.concat_nano_string(right), both);
+```
+*/
+    pub const fn concat_nano_string(self, other: NanoString) -> MicroString {
+        match MicroString::try_concat(self.as_str(), other.as_str()) {
+            Ok(it) => it,
+            Err(_) => unreachable!(),
+        }
+    }
+}
+impl ::core::ops::Add<NanoString> for NanoString {
+    type Output = MicroString;
+    fn add(self, rhs: NanoString) -> MicroString {
+        self.concat_nano_string(rhs)
+    }
+}
+impl NanoString {
+    /**Concatenates this string with a [`MicroString`], which always fits in a [`MilliString`] since <code>3 + 7 <= 15</code>.
+
+This is also available as `+`.
+```
+# use microstring::*;
+let left = NanoString::new("GBP").unwrap();
+let right = MicroString::new("1234567").unwrap();
+let both: MilliString = left + right;
+assert_eq!(both, "GBP1234567");
+assert_eq!(left.concat_micro_string(right), both);
+```
+*/
+    pub const fn concat_micro_string(self, other: MicroString) -> MilliString {
+        match MilliString::try_concat(self.as_str(), other.as_str()) {
+            Ok(it) => it,
+            Err(_) => unreachable!(),
+        }
+    }
+}
+impl ::core::ops::Add<MicroString> for NanoString {
+    type Output = MilliString;
+    fn add(self, rhs: MicroString) -> MilliString {
+        self.concat_micro_string(rhs)
     }
 }
-impl ::core::convert::AsRef<[u8]> for MicroString {
-    fn as_ref(&self) -> &[u8] {
-        self.as_str().as_bytes()
+impl NanoString {
+    /**Concatenates this string with a [`MilliString`], which always fits in a [`CentiString`] since <code>3 + 15 <= 31</code>.
+
+This is also available as `+`.
+```
+# use microstring::*;
+let left = NanoString::new("GBP").unwrap();
+let right = MilliString::new("hello world :)").unwrap();
+let both: CentiString = left + right;
+assert_eq!(both, "GBPhello world :)");
+assert_eq!(left.concat_milli_string(right), both);
+```
+*/
+    pub const fn concat_milli_string(self, other: MilliString) -> CentiString {
+        match CentiString::try_concat(self.as_str(), other.as_str()) {
+            Ok(it) => it,
+            Err(_) => unreachable!(),
+        }
     }
 }
-impl TryFrom<&str> for MicroString {
-    type Error = MicroStringError;
-    fn try_from(value: &str) -> Result<Self, MicroStringError> {
-        Self::new(value).ok_or(MicroStringError)
+impl ::core::ops::Add<MilliString> for NanoString {
+    type Output = CentiString;
+    fn add(self, rhs: MilliString) -> CentiString {
+        self.concat_milli_string(rhs)
     }
 }
-#[cfg(feature = "std")]
-impl ::core::convert::AsRef<::std::ffi::OsStr> for MicroString {
-    fn as_ref(&self) -> &::std::ffi::OsStr {
-        self.as_str().as_ref()
+impl NanoString {
+    /**Concatenates this string with a [`CentiString`], which always fits in a [`DeciString`] since <code>3 + 31 <= 63</code>.
+
+This is also available as `+`.
+```
+# use microstring::*;
+let left = NanoString::new("GBP").unwrap();
+let right = CentiString::new("the quick brown fox").unwrap();
+let both: DeciString = left + right;
+assert_eq!(both, "GBPthe quick brown fox");
+assert_eq!(left.concat_centi_string(right), both);
+```
+*/
+    pub const fn concat_centi_string(self, other: CentiString) -> DeciString {
+        match DeciString::try_concat(self.as_str(), other.as_str()) {
+            Ok(it) => it,
+            Err(_) => unreachable!(),
+        }
     }
 }
-#[cfg(feature = "std")]
-impl ::core::convert::AsRef<::std::path::Path> for MicroString {
-    fn as_ref(&self) -> &::std::path::Path {
-        self.as_str().as_ref()
+impl ::core::ops::Add<CentiString> for NanoString {
+    type Output = DeciString;
+    fn add(self, rhs: CentiString) -> DeciString {
+        self.concat_centi_string(rhs)
     }
 }
-#[cfg(feature = "alloc")]
-impl ::core::convert::From<MicroString> for ::alloc::string::String {
-    fn from(val: MicroString) -> Self {
-        Self::from(val.as_str())
+impl NanoString {
+    /**Concatenates this string with a [`DeciString`], which always fits in a [`DecaString`] since <code>3 + 63 <= 127</code>.
+
+This is also available as `+`.
+```
+# use microstring::*;
+let left = NanoString::new("GBP").unwrap();
+let right = DeciString::new("the quick brown fox jumps over the lazy dog").unwrap();
+let both: DecaString = left + right;
+assert_eq!(both, "GBPthe quick brown fox jumps over the lazy dog");
+assert_eq!(left.concat_deci_string(right), both);
+```
+*/
+    pub const fn concat_deci_string(self, other: DeciString) -> DecaString {
+        match DecaString::try_concat(self.as_str(), other.as_str()) {
+            Ok(it) => it,
+            Err(_) => unreachable!(),
+        }
     }
 }
-#[cfg(feature = "alloc")]
-impl ::core::convert::TryFrom<::alloc::string::String> for MicroString {
-    type Error = MicroStringError;
-    fn try_from(value: ::alloc::string::String) -> Result<Self, MicroStringError> {
-        Self::new(&value).ok_or(MicroStringError)
+impl ::core::ops::Add<DeciString> for NanoString {
+    type Output = DecaString;
+    fn add(self, rhs: DeciString) -> DecaString {
+        self.concat_deci_string(rhs)
     }
 }
-impl ::core::borrow::Borrow<str> for MicroString {
-    fn borrow(&self) -> &str {
-        self.as_str()
+impl NanoString {
+    /**Concatenates this string with a [`DecaString`], which always fits in a [`HectoString`] since <code>3 + 127 <= 255</code>.
+
+This is also available as `+`.
+```
+# use microstring::*;
+let left = NanoString::new("GBP").unwrap();
+let right = DecaString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home").unwrap();
+let both: HectoString = left + right;
+assert_eq!(both, "GBPthe quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home");
+assert_eq!(left.concat_deca_string(right), both);
+```
+*/
+    pub const fn concat_deca_string(self, other: DecaString) -> HectoString {
+        match HectoString::try_concat(self.as_str(), other.as_str()) {
+            Ok(it) => it,
+            Err(_) => unreachable!(),
+        }
     }
 }
-impl ::core::borrow::BorrowMut<str> for MicroString {
-    fn borrow_mut(&mut self) -> &mut str {
-        self.as_mut_str()
+impl ::core::ops::Add<DecaString> for NanoString {
+    type Output = HectoString;
+    fn add(self, rhs: DecaString) -> HectoString {
+        self.concat_deca_string(rhs)
     }
 }
-impl ::core::ops::Deref for MicroString {
-    type Target = str;
-    fn deref(&self) -> &str {
-        self.as_str()
+impl MicroString {
+    /**Concatenates this string with a [`NanoString`], which always fits in a [`MilliString`] since <code>7 + 3 <= 15</code>.
+
+This is also available as `+`.
+```
+# use microstring::*;
+let left = MicroString::new("1234567").unwrap();
+let right = NanoString::new("GBP").unwrap();
+let both: MilliString = left + right;
+assert_eq!(both, "1234567GBP");
+assert_eq!(left.concat_nano_string(right), both);
+```
+*/
+    pub const fn concat_nano_string(self, other: NanoString) -> MilliString {
+        match MilliString::try_concat(self.as_str(), other.as_str()) {
+            Ok(it) => it,
+            Err(_) => unreachable!(),
+        }
     }
 }
-impl ::core::ops::DerefMut for MicroString {
-    fn deref_mut(&mut self) -> &mut str {
-        self.as_mut_str()
+impl ::core::ops::Add<NanoString> for MicroString {
+    type Output = MilliString;
+    fn add(self, rhs: NanoString) -> MilliString {
+        self.concat_nano_string(rhs)
     }
 }
-impl<T: ::core::convert::AsRef<str>> ::core::cmp::PartialEq<T> for MicroString {
-    fn eq(&self, other: &T) -> bool {
-        self.as_str().eq(other.as_ref())
+impl MicroString {
+    /**Concatenates this string with a [`MicroString`], which always fits in a [`MilliString`] since <code>7 + 7 <= 15</code>.
+
+This is also available as `+`.
+```
+# use microstring::*;
+let left = MicroString::new("1234567").unwrap();
+let right = MicroString::new("1234567").unwrap();
+let both: MilliString = left + right;
+assert_eq!(both, "12345671234567");
+assert_eq!(left.concat_micro_string(right), both);
+```
+*/
+    pub const fn concat_micro_string(self, other: MicroString) -> MilliString {
+        match MilliString::try_concat(self.as_str(), other.as_str()) {
+            Ok(it) => it,
+            Err(_) => unreachable!(),
+        }
     }
 }
-impl<T: ::core::convert::AsRef<str>> ::core::cmp::PartialOrd<T> for MicroString {
-    fn partial_cmp(&self, other: &T) -> Option<::core::cmp::Ordering> {
-        self.as_str().partial_cmp(other.as_ref())
+impl ::core::ops::Add<MicroString> for MicroString {
+    type Output = MilliString;
+    fn add(self, rhs: MicroString) -> MilliString {
+        self.concat_micro_string(rhs)
     }
 }
-impl ::core::fmt::Debug for MicroString {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        self.as_str().fmt(f)
+impl MicroString {
+    /**Concatenates this string with a [`MilliString`], which always fits in a [`CentiString`] since <code>7 + 15 <= 31</code>.
+
+This is also available as `+`.
+```
+# use microstring::*;
+let left = MicroString::new("1234567").unwrap();
+let right = MilliString::new("hello world :)").unwrap();
+let both: CentiString = left + right;
+assert_eq!(both, "1234567hello world :)");
+assert_eq!(left.concat_milli_string(right), both);
+```
+*/
+    pub const fn concat_milli_string(self, other: MilliString) -> CentiString {
+        match CentiString::try_concat(self.as_str(), other.as_str()) {
+            Ok(it) => it,
+            Err(_) => unreachable!(),
+        }
     }
 }
-impl ::core::fmt::Display for MicroString {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        self.as_str().fmt(f)
+impl ::core::ops::Add<MilliString> for MicroString {
+    type Output = CentiString;
+    fn add(self, rhs: MilliString) -> CentiString {
+        self.concat_milli_string(rhs)
     }
 }
-impl ::core::str::FromStr for MicroString {
-    type Err = MicroStringError;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Self::new(s).ok_or(MicroStringError)
+impl MicroString {
+    /**Concatenates this string with a [`CentiString`], which always fits in a [`DeciString`] since <code>7 + 31 <= 63</code>.
+
+This is also available as `+`.
+```
+# use microstring::*;
+let left = MicroString::new("1234567").unwrap();
+let right = CentiString::new("the quick brown fox").unwrap();
+let both: DeciString = left + right;
+assert_eq!(both, "1234567the quick brown fox");
+assert_eq!(left.concat_centi_string(right), both);
+```
+*/
+    pub const fn concat_centi_string(self, other: CentiString) -> DeciString {
+        match DeciString::try_concat(self.as_str(), other.as_str()) {
+            Ok(it) => it,
+            Err(_) => unreachable!(),
+        }
     }
 }
-#[cfg(feature = "serde")]
-impl serde::Serialize for MicroString {
-    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
-        s.serialize_str(self)
+impl ::core::ops::Add<CentiString> for MicroString {
+    type Output = DeciString;
+    fn add(self, rhs: CentiString) -> DeciString {
+        self.concat_centi_string(rhs)
     }
 }
-#[cfg(feature = "serde")]
-impl<'de> serde::Deserialize<'de> for MicroString {
-    fn deserialize<D: serde::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
-        struct Visitor;
-        impl<'de> serde::de::Visitor<'de> for Visitor {
-            type Value = MicroString;
-            fn expecting(
-                &self,
-                f: &mut ::core::fmt::Formatter<'_>,
-            ) -> ::core::fmt::Result {
-                f.write_str("a string of at most 7 bytes")
-            }
-            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<MicroString, E> {
-                MicroString::new(v)
-                    .ok_or_else(|| serde::de::Error::invalid_value(
-                        serde::de::Unexpected::Str(v),
-                        &self,
-                    ))
-            }
+impl MicroString {
+    /**Concatenates this string with a [`DeciString`], which always fits in a [`DecaString`] since <code>7 + 63 <= 127</code>.
+
+This is also available as `+`.
+```
+# use microstring::*;
+let left = MicroString::new("1234567").unwrap();
+let right = DeciString::new("the quick brown fox jumps over the lazy dog").unwrap();
+let both: DecaString = left + right;
+assert_eq!(both, "1234567the quick brown fox jumps over the lazy dog");
+assert_eq!(left.concat_deci_string(right), both);
+```
+*/
+    pub const fn concat_deci_string(self, other: DeciString) -> DecaString {
+        match DecaString::try_concat(self.as_str(), other.as_str()) {
+            Ok(it) => it,
+            Err(_) => unreachable!(),
         }
-        d.deserialize_str(Visitor)
     }
 }
-#[cfg(feature = "schemars")]
-impl schemars::JsonSchema for MicroString {
-    fn schema_name() -> ::std::borrow::Cow<'static, str> {
-        ::std::borrow::Cow::Borrowed(::core::stringify!(MicroString))
+impl ::core::ops::Add<DeciString> for MicroString {
+    type Output = DecaString;
+    fn add(self, rhs: DeciString) -> DecaString {
+        self.concat_deci_string(rhs)
     }
-    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
-        schemars::json_schema! {
-            { "type" : "string", "maxLength" : 7u8, }
+}
+impl MicroString {
+    /**Concatenates this string with a [`DecaString`], which always fits in a [`HectoString`] since <code>7 + 127 <= 255</code>.
+
+This is also available as `+`.
+```
+# use microstring::*;
+let left = MicroString::new("1234567").unwrap();
+let right = DecaString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home").unwrap();
+let both: HectoString = left + right;
+assert_eq!(both, "1234567the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home");
+assert_eq!(left.concat_deca_string(right), both);
+```
+*/
+    pub const fn concat_deca_string(self, other: DecaString) -> HectoString {
+        match HectoString::try_concat(self.as_str(), other.as_str()) {
+            Ok(it) => it,
+            Err(_) => unreachable!(),
         }
     }
-    fn schema_id() -> ::std::borrow::Cow<'static, str> {
-        ::std::borrow::Cow::Borrowed(
-            ::core::concat!(
-                ::core::module_path!(), "::", ::core::stringify!(MicroString)
-            ),
-        )
+}
+impl ::core::ops::Add<DecaString> for MicroString {
+    type Output = HectoString;
+    fn add(self, rhs: DecaString) -> HectoString {
+        self.concat_deca_string(rhs)
     }
 }
-#[cfg(feature = "const-default")]
-impl const_default::ConstDefault for MicroString {
-    const DEFAULT: Self = MicroString::EMPTY;
+impl MilliString {
+    /**Concatenates this string with a [`NanoString`], which always fits in a [`CentiString`] since <code>15 + 3 <= 31</code>.
+
+This is also available as `+`.
+```
+# use microstring::*;
+let left = MilliString::new("hello world :)").unwrap();
+let right = NanoString::new("GBP").unwrap();
+let both: CentiString = left + right;
+assert_eq!(both, "hello world :)GBP");
+assert_eq!(left.concat_nano_string(right), both);
+```
+*/
+    pub const fn concat_nano_string(self, other: NanoString) -> CentiString {
+        match CentiString::try_concat(self.as_str(), other.as_str()) {
+            Ok(it) => it,
+            Err(_) => unreachable!(),
+        }
+    }
 }
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-#[repr(u8)]
-#[cfg_attr(
-    feature = "zerocopy",
-    derive(zerocopy::TryFromBytes, zerocopy::IntoBytes, zerocopy::Immutable)
-)]
-enum MicroStringLen {
-    _0 = 0u8,
-    _1 = 1u8,
-    _2 = 2u8,
-    _3 = 3u8,
-    _4 = 4u8,
-    _5 = 5u8,
-    _6 = 6u8,
-    _7 = 7u8,
+impl ::core::ops::Add<NanoString> for MilliString {
+    type Output = CentiString;
+    fn add(self, rhs: NanoString) -> CentiString {
+        self.concat_nano_string(rhs)
+    }
 }
-impl MicroStringLen {
-    const fn from_usize(u: usize) -> Option<Self> {
-        const U8_MAX: usize = u8::MAX as _;
-        if u > U8_MAX {
-            return None;
+impl MilliString {
+    /**Concatenates this string with a [`MicroString`], which always fits in a [`CentiString`] since <code>15 + 7 <= 31</code>.
+
+This is also available as `+`.
+```
+# use microstring::*;
+let left = MilliString::new("hello world :)").unwrap();
+let right = MicroString::new("1234567").unwrap();
+let both: CentiString = left + right;
+assert_eq!(both, "hello world :)1234567");
+assert_eq!(left.concat_micro_string(right), both);
+```
+*/
+    pub const fn concat_micro_string(self, other: MicroString) -> CentiString {
+        match CentiString::try_concat(self.as_str(), other.as_str()) {
+            Ok(it) => it,
+            Err(_) => unreachable!(),
         }
-        Self::from_u8(u as u8)
     }
-    const fn from_u8(u: u8) -> Option<Self> {
-        match u {
-            0u8 => Some(Self::_0),
-            1u8 => Some(Self::_1),
-            2u8 => Some(Self::_2),
-            3u8 => Some(Self::_3),
-            4u8 => Some(Self::_4),
-            5u8 => Some(Self::_5),
-            6u8 => Some(Self::_6),
-            7u8 => Some(Self::_7),
-            _ => None,
-        }
+}
+impl ::core::ops::Add<MicroString> for MilliString {
+    type Output = CentiString;
+    fn add(self, rhs: MicroString) -> CentiString {
+        self.concat_micro_string(rhs)
     }
 }
-/**A stack-allocated string which can hold up to 15 UTF-8 encoded bytes.
+impl MilliString {
+    /**Concatenates this string with a [`MilliString`], which always fits in a [`CentiString`] since <code>15 + 15 <= 31</code>.
+
+This is also available as `+`.
 ```
-# use core::mem::size_of;
 # use microstring::*;
-assert_eq! {
-    size_of::<MilliString>(),
-    size_of::<u128>(),
+let left = MilliString::new("hello world :)").unwrap();
+let right = MilliString::new("hello world :)").unwrap();
+let both: CentiString = left + right;
+assert_eq!(both, "hello world :)hello world :)");
+assert_eq!(left.concat_milli_string(right), both);
+```
+*/
+    pub const fn concat_milli_string(self, other: MilliString) -> CentiString {
+        match CentiString::try_concat(self.as_str(), other.as_str()) {
+            Ok(it) => it,
+            Err(_) => unreachable!(),
+        }
+    }
 }
-assert_eq! {
-    size_of::<MilliString>(),
-    size_of::<Option<MilliString>>(),
+impl ::core::ops::Add<MilliString> for MilliString {
+    type Output = CentiString;
+    fn add(self, rhs: MilliString) -> CentiString {
+        self.concat_milli_string(rhs)
+    }
 }
+impl MilliString {
+    /**Concatenates this string with a [`CentiString`], which always fits in a [`DeciString`] since <code>15 + 31 <= 63</code>.
+
+This is also available as `+`.
+```
+# use microstring::*;
+let left = MilliString::new("hello world :)").unwrap();
+let right = CentiString::new("the quick brown fox").unwrap();
+let both: DeciString = left + right;
+assert_eq!(both, "hello world :)the quick brown fox");
+assert_eq!(left.concat_centi_string(right), both);
 ```
 */
-#[derive(Clone, Copy, Eq)]
-#[repr(C)]
-#[cfg_attr(
-    feature = "zerocopy",
-    derive(zerocopy::TryFromBytes, zerocopy::IntoBytes, zerocopy::Immutable)
-)]
-pub struct MilliString {
-    len: MilliStringLen,
-    bytes: [u8; 15u8 as _],
+    pub const fn concat_centi_string(self, other: CentiString) -> DeciString {
+        match DeciString::try_concat(self.as_str(), other.as_str()) {
+            Ok(it) => it,
+            Err(_) => unreachable!(),
+        }
+    }
+}
+impl ::core::ops::Add<CentiString> for MilliString {
+    type Output = DeciString;
+    fn add(self, rhs: CentiString) -> DeciString {
+        self.concat_centi_string(rhs)
+    }
 }
 impl MilliString {
-    pub const EMPTY: Self = Self::new("").unwrap();
-    /**Returns [`None`] if the given <code>[str::len()] > 15</code>.
+    /**Concatenates this string with a [`DeciString`], which always fits in a [`DecaString`] since <code>15 + 63 <= 127</code>.
+
+This is also available as `+`.
 ```
 # use microstring::*;
-const STRING: MilliString = MilliString::new("hello world :)").unwrap();
+let left = MilliString::new("hello world :)").unwrap();
+let right = DeciString::new("the quick brown fox jumps over the lazy dog").unwrap();
+let both: DecaString = left + right;
+assert_eq!(both, "hello world :)the quick brown fox jumps over the lazy dog");
+assert_eq!(left.concat_deci_string(right), both);
+```
+*/
+    pub const fn concat_deci_string(self, other: DeciString) -> DecaString {
+        match DecaString::try_concat(self.as_str(), other.as_str()) {
+            Ok(it) => it,
+            Err(_) => unreachable!(),
+        }
+    }
+}
+impl ::core::ops::Add<DeciString> for MilliString {
+    type Output = DecaString;
+    fn add(self, rhs: DeciString) -> DecaString {
+        self.concat_deci_string(rhs)
+    }
+}
+impl MilliString {
+    /**Concatenates this string with a [`DecaString`], which always fits in a [`HectoString`] since <code>15 + 127 <= 255</code>.
+
+This is also available as `+`.
 ```
-```compile_fail
 # use microstring::*;
-const TOO_BIG: MilliString = MilliString::new("goodbye world :(").unwrap();
+let left = MilliString::new("hello world :)").unwrap();
+let right = DecaString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home").unwrap();
+let both: HectoString = left + right;
+assert_eq!(both, "hello world :)the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home");
+assert_eq!(left.concat_deca_string(right), both);
 ```
 */
-    pub const fn new(s: &str) -> Option<Self> {
-        match MilliStringLen::from_usize(s.len()) {
-            Some(len) => {
-                let mut bytes = [0; 15u8 as _];
-                unsafe {
-                    ::core::ptr::copy_nonoverlapping(
-                        s.as_ptr(),
-                        bytes.as_mut_ptr(),
-                        s.len(),
-                    )
-                }
-                Some(Self { len, bytes })
-            }
-            None => None,
+    pub const fn concat_deca_string(self, other: DecaString) -> HectoString {
+        match HectoString::try_concat(self.as_str(), other.as_str()) {
+            Ok(it) => it,
+            Err(_) => unreachable!(),
         }
     }
-    pub const fn as_str(&self) -> &str {
-        unsafe {
-            str::from_utf8_unchecked(
-                ::core::slice::from_raw_parts(
-                    self.bytes.as_ptr(),
-                    self.len as u8 as usize,
-                ),
-            )
+}
+impl ::core::ops::Add<DecaString> for MilliString {
+    type Output = HectoString;
+    fn add(self, rhs: DecaString) -> HectoString {
+        self.concat_deca_string(rhs)
+    }
+}
+impl CentiString {
+    /**Concatenates this string with a [`NanoString`], which always fits in a [`DeciString`] since <code>31 + 3 <= 63</code>.
+
+This is also available as `+`.
+```
+# use microstring::*;
+let left = CentiString::new("the quick brown fox").unwrap();
+let right = NanoString::new("GBP").unwrap();
+let both: DeciString = left + right;
+assert_eq!(both, "the quick brown foxGBP");
+assert_eq!(left.concat_nano_string(right), both);
+```
+*/
+    pub const fn concat_nano_string(self, other: NanoString) -> DeciString {
+        match DeciString::try_concat(self.as_str(), other.as_str()) {
+            Ok(it) => it,
+            Err(_) => unreachable!(),
         }
     }
-    pub const fn as_mut_str(&mut self) -> &mut str {
-        unsafe {
-            str::from_utf8_unchecked_mut(
-                ::core::slice::from_raw_parts_mut(
-                    self.bytes.as_mut_ptr(),
-                    self.len as u8 as usize,
-                ),
-            )
-        }
+}
+impl ::core::ops::Add<NanoString> for CentiString {
+    type Output = DeciString;
+    fn add(self, rhs: NanoString) -> DeciString {
+        self.concat_nano_string(rhs)
     }
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct MilliStringError;
-impl ::core::fmt::Display for MilliStringError {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        f.write_str("expected a string of at most 15 bytes")
+impl CentiString {
+    /**Concatenates this string with a [`MicroString`], which always fits in a [`DeciString`] since <code>31 + 7 <= 63</code>.
+
+This is also available as `+`.
+```
+# use microstring::*;
+let left = CentiString::new("the quick brown fox").unwrap();
+let right = MicroString::new("1234567").unwrap();
+let both: DeciString = left + right;
+assert_eq!(both, "the quick brown fox1234567");
+assert_eq!(left.concat_micro_string(right), both);
+```
+*/
+    pub const fn concat_micro_string(self, other: MicroString) -> DeciString {
+        match DeciString::try_concat(self.as_str(), other.as_str()) {
+            Ok(it) => it,
+            Err(_) => unreachable!(),
+        }
     }
 }
-impl ::core::error::Error for MilliStringError {}
-impl ::core::default::Default for MilliString {
-    fn default() -> Self {
-        Self::EMPTY
+impl ::core::ops::Add<MicroString> for CentiString {
+    type Output = DeciString;
+    fn add(self, rhs: MicroString) -> DeciString {
+        self.concat_micro_string(rhs)
     }
 }
-impl ::core::default::Default for &MilliString {
-    fn default() -> Self {
-        &MilliString::EMPTY
+impl CentiString {
+    /**Concatenates this string with a [`MilliString`], which always fits in a [`DeciString`] since <code>31 + 15 <= 63</code>.
+
+This is also available as `+`.
+```
+# use microstring::*;
+let left = CentiString::new("the quick brown fox").unwrap();
+let right = MilliString::new("hello world :)").unwrap();
+let both: DeciString = left + right;
+assert_eq!(both, "the quick brown foxhello world :)");
+assert_eq!(left.concat_milli_string(right), both);
+```
+*/
+    pub const fn concat_milli_string(self, other: MilliString) -> DeciString {
+        match DeciString::try_concat(self.as_str(), other.as_str()) {
+            Ok(it) => it,
+            Err(_) => unreachable!(),
+        }
     }
 }
-impl ::core::hash::Hash for MilliString {
-    fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
-        self.as_str().hash(state)
+impl ::core::ops::Add<MilliString> for CentiString {
+    type Output = DeciString;
+    fn add(self, rhs: MilliString) -> DeciString {
+        self.concat_milli_string(rhs)
     }
 }
-impl ::core::convert::AsRef<Self> for MilliString {
-    fn as_ref(&self) -> &Self {
-        self
+impl CentiString {
+    /**Concatenates this string with a [`CentiString`], which always fits in a [`DeciString`] since <code>31 + 31 <= 63</code>.
+
+This is also available as `+`.
+```
+# use microstring::*;
+let left = CentiString::new("the quick brown fox").unwrap();
+let right = CentiString::new("the quick brown fox").unwrap();
+let both: DeciString = left + right;
+assert_eq!(both, "the quick brown foxthe quick brown fox");
+assert_eq!(left.concat_centi_string(right), both);
+```
+*/
+    pub const fn concat_centi_string(self, other: CentiString) -> DeciString {
+        match DeciString::try_concat(self.as_str(), other.as_str()) {
+            Ok(it) => it,
+            Err(_) => unreachable!(),
+        }
     }
 }
-impl ::core::convert::AsMut<Self> for MilliString {
-    fn as_mut(&mut self) -> &mut Self {
-        self
+impl ::core::ops::Add<CentiString> for CentiString {
+    type Output = DeciString;
+    fn add(self, rhs: CentiString) -> DeciString {
+        self.concat_centi_string(rhs)
     }
 }
-impl ::core::convert::AsRef<str> for MilliString {
-    fn as_ref(&self) -> &str {
-        self.as_str()
+impl CentiString {
+    /**Concatenates this string with a [`DeciString`], which always fits in a [`DecaString`] since <code>31 + 63 <= 127</code>.
+
+This is also available as `+`.
+```
+# use microstring::*;
+let left = CentiString::new("the quick brown fox").unwrap();
+let right = DeciString::new("the quick brown fox jumps over the lazy dog").unwrap();
+let both: DecaString = left + right;
+assert_eq!(both, "the quick brown foxthe quick brown fox jumps over the lazy dog");
+assert_eq!(left.concat_deci_string(right), both);
+```
+*/
+    pub const fn concat_deci_string(self, other: DeciString) -> DecaString {
+        match DecaString::try_concat(self.as_str(), other.as_str()) {
+            Ok(it) => it,
+            Err(_) => unreachable!(),
+        }
     }
 }
-impl ::core::convert::AsMut<str> for MilliString {
-    fn as_mut(&mut self) -> &mut str {
-        self.as_mut_str()
+impl ::core::ops::Add<DeciString> for CentiString {
+    type Output = DecaString;
+    fn add(self, rhs: DeciString) -> DecaString {
+        self.concat_deci_string(rhs)
     }
 }
-impl ::core::convert::AsRef<[u8]> for MilliString {
-    fn as_ref(&self) -> &[u8] {
-        self.as_str().as_bytes()
+impl CentiString {
+    /**Concatenates this string with a [`DecaString`], which always fits in a [`HectoString`] since <code>31 + 127 <= 255</code>.
+
+This is also available as `+`.
+```
+# use microstring::*;
+let left = CentiString::new("the quick brown fox").unwrap();
+let right = DecaString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home").unwrap();
+let both: HectoString = left + right;
+assert_eq!(both, "the quick brown foxthe quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home");
+assert_eq!(left.concat_deca_string(right), both);
+```
+*/
+    pub const fn concat_deca_string(self, other: DecaString) -> HectoString {
+        match HectoString::try_concat(self.as_str(), other.as_str()) {
+            Ok(it) => it,
+            Err(_) => unreachable!(),
+        }
     }
 }
-impl TryFrom<&str> for MilliString {
-    type Error = MilliStringError;
-    fn try_from(value: &str) -> Result<Self, MilliStringError> {
-        Self::new(value).ok_or(MilliStringError)
+impl ::core::ops::Add<DecaString> for CentiString {
+    type Output = HectoString;
+    fn add(self, rhs: DecaString) -> HectoString {
+        self.concat_deca_string(rhs)
     }
 }
-#[cfg(feature = "std")]
-impl ::core::convert::AsRef<::std::ffi::OsStr> for MilliString {
-    fn as_ref(&self) -> &::std::ffi::OsStr {
-        self.as_str().as_ref()
+impl DeciString {
+    /**Concatenates this string with a [`NanoString`], which always fits in a [`DecaString`] since <code>63 + 3 <= 127</code>.
+
+This is also available as `+`.
+```
+# use microstring::*;
+let left = DeciString::new("the quick brown fox jumps over the lazy dog").unwrap();
+let right = NanoString::new("GBP").unwrap();
+let both: DecaString = left + right;
+assert_eq!(both, "the quick brown fox jumps over the lazy dogGBP");
+assert_eq!(left.concat_nano_string(right), both);
+```
+*/
+    pub const fn concat_nano_string(self, other: NanoString) -> DecaString {
+        match DecaString::try_concat(self.as_str(), other.as_str()) {
+            Ok(it) => it,
+            Err(_) => unreachable!(),
+        }
     }
 }
-#[cfg(feature = "std")]
-impl ::core::convert::AsRef<::std::path::Path> for MilliString {
-    fn as_ref(&self) -> &::std::path::Path {
-        self.as_str().as_ref()
+impl ::core::ops::Add<NanoString> for DeciString {
+    type Output = DecaString;
+    fn add(self, rhs: NanoString) -> DecaString {
+        self.concat_nano_string(rhs)
     }
 }
-#[cfg(feature = "alloc")]
-impl ::core::convert::From<MilliString> for ::alloc::string::String {
-    fn from(val: MilliString) -> Self {
-        Self::from(val.as_str())
+impl DeciString {
+    /**Concatenates this string with a [`MicroString`], which always fits in a [`DecaString`] since <code>63 + 7 <= 127</code>.
+
+This is also available as `+`.
+```
+# use microstring::*;
+let left = DeciString::new("the quick brown fox jumps over the lazy dog").unwrap();
+let right = MicroString::new("1234567").unwrap();
+let both: DecaString = left + right;
+assert_eq!(both, "the quick brown fox jumps over the lazy dog1234567");
+assert_eq!(left.concat_micro_string(right), both);
+```
+*/
+    pub const fn concat_micro_string(self, other: MicroString) -> DecaString {
+        match DecaString::try_concat(self.as_str(), other.as_str()) {
+            Ok(it) => it,
+            Err(_) => unreachable!(),
+        }
     }
 }
-#[cfg(feature = "alloc")]
-impl ::core::convert::TryFrom<::alloc::string::String> for MilliString {
-    type Error = MilliStringError;
-    fn try_from(value: ::alloc::string::String) -> Result<Self, MilliStringError> {
-        Self::new(&value).ok_or(MilliStringError)
+impl ::core::ops::Add<MicroString> for DeciString {
+    type Output = DecaString;
+    fn add(self, rhs: MicroString) -> DecaString {
+        self.concat_micro_string(rhs)
     }
 }
-impl ::core::borrow::Borrow<str> for MilliString {
-    fn borrow(&self) -> &str {
-        self.as_str()
+impl DeciString {
+    /**Concatenates this string with a [`MilliString`], which always fits in a [`DecaString`] since <code>63 + 15 <= 127</code>.
+
+This is also available as `+`.
+```
+# use microstring::*;
+let left = DeciString::new("the quick brown fox jumps over the lazy dog").unwrap();
+let right = MilliString::new("hello world :)").unwrap();
+let both: DecaString = left + right;
+assert_eq!(both, "the quick brown fox jumps over the lazy doghello world :)");
+assert_eq!(left.concat_milli_string(right), both);
+```
+*/
+    pub const fn concat_milli_string(self, other: MilliString) -> DecaString {
+        match DecaString::try_concat(self.as_str(), other.as_str()) {
+            Ok(it) => it,
+            Err(_) => unreachable!(),
+        }
     }
 }
-impl ::core::borrow::BorrowMut<str> for MilliString {
-    fn borrow_mut(&mut self) -> &mut str {
-        self.as_mut_str()
+impl ::core::ops::Add<MilliString> for DeciString {
+    type Output = DecaString;
+    fn add(self, rhs: MilliString) -> DecaString {
+        self.concat_milli_string(rhs)
     }
 }
-impl ::core::ops::Deref for MilliString {
-    type Target = str;
-    fn deref(&self) -> &str {
-        self.as_str()
+impl DeciString {
+    /**Concatenates this string with a [`CentiString`], which always fits in a [`DecaString`] since <code>63 + 31 <= 127</code>.
+
+This is also available as `+`.
+```
+# use microstring::*;
+let left = DeciString::new("the quick brown fox jumps over the lazy dog").unwrap();
+let right = CentiString::new("the quick brown fox").unwrap();
+let both: DecaString = left + right;
+assert_eq!(both, "the quick brown fox jumps over the lazy dogthe quick brown fox");
+assert_eq!(left.concat_centi_string(right), both);
+```
+*/
+    pub const fn concat_centi_string(self, other: CentiString) -> DecaString {
+        match DecaString::try_concat(self.as_str(), other.as_str()) {
+            Ok(it) => it,
+            Err(_) => unreachable!(),
+        }
     }
 }
-impl ::core::ops::DerefMut for MilliString {
-    fn deref_mut(&mut self) -> &mut str {
-        self.as_mut_str()
+impl ::core::ops::Add<CentiString> for DeciString {
+    type Output = DecaString;
+    fn add(self, rhs: CentiString) -> DecaString {
+        self.concat_centi_string(rhs)
     }
 }
-impl<T: ::core::convert::AsRef<str>> ::core::cmp::PartialEq<T> for MilliString {
-    fn eq(&self, other: &T) -> bool {
-        self.as_str().eq(other.as_ref())
+impl DeciString {
+    /**Concatenates this string with a [`DeciString`], which always fits in a [`DecaString`] since <code>63 + 63 <= 127</code>.
+
+This is also available as `+`.
+```
+# use microstring::*;
+let left = DeciString::new("the quick brown fox jumps over the lazy dog").unwrap();
+let right = DeciString::new("the quick brown fox jumps over the lazy dog").unwrap();
+let both: DecaString = left + right;
+assert_eq!(both, "the quick brown fox jumps over the lazy dogthe quick brown fox jumps over the lazy dog");
+assert_eq!(left.concat_deci_string(right), both);
+```
+*/
+    pub const fn concat_deci_string(self, other: DeciString) -> DecaString {
+        match DecaString::try_concat(self.as_str(), other.as_str()) {
+            Ok(it) => it,
+            Err(_) => unreachable!(),
+        }
     }
 }
-impl<T: ::core::convert::AsRef<str>> ::core::cmp::PartialOrd<T> for MilliString {
-    fn partial_cmp(&self, other: &T) -> Option<::core::cmp::Ordering> {
-        self.as_str().partial_cmp(other.as_ref())
+impl ::core::ops::Add<DeciString> for DeciString {
+    type Output = DecaString;
+    fn add(self, rhs: DeciString) -> DecaString {
+        self.concat_deci_string(rhs)
     }
 }
-impl ::core::fmt::Debug for MilliString {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        self.as_str().fmt(f)
+impl DeciString {
+    /**Concatenates this string with a [`DecaString`], which always fits in a [`HectoString`] since <code>63 + 127 <= 255</code>.
+
+This is also available as `+`.
+```
+# use microstring::*;
+let left = DeciString::new("the quick brown fox jumps over the lazy dog").unwrap();
+let right = DecaString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home").unwrap();
+let both: HectoString = left + right;
+assert_eq!(both, "the quick brown fox jumps over the lazy dogthe quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home");
+assert_eq!(left.concat_deca_string(right), both);
+```
+*/
+    pub const fn concat_deca_string(self, other: DecaString) -> HectoString {
+        match HectoString::try_concat(self.as_str(), other.as_str()) {
+            Ok(it) => it,
+            Err(_) => unreachable!(),
+        }
     }
 }
-impl ::core::fmt::Display for MilliString {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        self.as_str().fmt(f)
+impl ::core::ops::Add<DecaString> for DeciString {
+    type Output = HectoString;
+    fn add(self, rhs: DecaString) -> HectoString {
+        self.concat_deca_string(rhs)
     }
 }
-impl ::core::str::FromStr for MilliString {
-    type Err = MilliStringError;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Self::new(s).ok_or(MilliStringError)
+impl DecaString {
+    /**Concatenates this string with a [`NanoString`], which always fits in a [`HectoString`] since <code>127 + 3 <= 255</code>.
+
+This is also available as `+`.
+```
+# use microstring::*;
+let left = DecaString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home").unwrap();
+let right = NanoString::new("GBP").unwrap();
+let both: HectoString = left + right;
+assert_eq!(both, "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox homeGBP");
+assert_eq!(left.concat_nano_string(right), both);
+```
+*/
+    pub const fn concat_nano_string(self, other: NanoString) -> HectoString {
+        match HectoString::try_concat(self.as_str(), other.as_str()) {
+            Ok(it) => it,
+            Err(_) => unreachable!(),
+        }
     }
 }
-#[cfg(feature = "serde")]
-impl serde::Serialize for MilliString {
-    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
-        s.serialize_str(self)
+impl ::core::ops::Add<NanoString> for DecaString {
+    type Output = HectoString;
+    fn add(self, rhs: NanoString) -> HectoString {
+        self.concat_nano_string(rhs)
     }
 }
-#[cfg(feature = "serde")]
-impl<'de> serde::Deserialize<'de> for MilliString {
-    fn deserialize<D: serde::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
-        struct Visitor;
-        impl<'de> serde::de::Visitor<'de> for Visitor {
-            type Value = MilliString;
-            fn expecting(
-                &self,
-                f: &mut ::core::fmt::Formatter<'_>,
-            ) -> ::core::fmt::Result {
-                f.write_str("a string of at most 15 bytes")
-            }
-            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<MilliString, E> {
-                MilliString::new(v)
-                    .ok_or_else(|| serde::de::Error::invalid_value(
-                        serde::de::Unexpected::Str(v),
-                        &self,
-                    ))
-            }
+impl DecaString {
+    /**Concatenates this string with a [`MicroString`], which always fits in a [`HectoString`] since <code>127 + 7 <= 255</code>.
+
+This is also available as `+`.
+```
+# use microstring::*;
+let left = DecaString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home").unwrap();
+let right = MicroString::new("1234567").unwrap();
+let both: HectoString = left + right;
+assert_eq!(both, "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home1234567");
+assert_eq!(left.concat_micro_string(right), both);
+```
+*/
+    pub const fn concat_micro_string(self, other: MicroString) -> HectoString {
+        match HectoString::try_concat(self.as_str(), other.as_str()) {
+            Ok(it) => it,
+            Err(_) => unreachable!(),
         }
-        d.deserialize_str(Visitor)
     }
 }
-#[cfg(feature = "schemars")]
-impl schemars::JsonSchema for MilliString {
-    fn schema_name() -> ::std::borrow::Cow<'static, str> {
-        ::std::borrow::Cow::Borrowed(::core::stringify!(MilliString))
+impl ::core::ops::Add<MicroString> for DecaString {
+    type Output = HectoString;
+    fn add(self, rhs: MicroString) -> HectoString {
+        self.concat_micro_string(rhs)
     }
-    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
-        schemars::json_schema! {
-            { "type" : "string", "maxLength" : 15u8, }
+}
+impl DecaString {
+    /**Concatenates this string with a [`MilliString`], which always fits in a [`HectoString`] since <code>127 + 15 <= 255</code>.
+
+This is also available as `+`.
+```
+# use microstring::*;
+let left = DecaString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home").unwrap();
+let right = MilliString::new("hello world :)").unwrap();
+let both: HectoString = left + right;
+assert_eq!(both, "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox homehello world :)");
+assert_eq!(left.concat_milli_string(right), both);
+```
+*/
+    pub const fn concat_milli_string(self, other: MilliString) -> HectoString {
+        match HectoString::try_concat(self.as_str(), other.as_str()) {
+            Ok(it) => it,
+            Err(_) => unreachable!(),
         }
     }
-    fn schema_id() -> ::std::borrow::Cow<'static, str> {
-        ::std::borrow::Cow::Borrowed(
-            ::core::concat!(
-                ::core::module_path!(), "::", ::core::stringify!(MilliString)
-            ),
-        )
+}
+impl ::core::ops::Add<MilliString> for DecaString {
+    type Output = HectoString;
+    fn add(self, rhs: MilliString) -> HectoString {
+        self.concat_milli_string(rhs)
     }
 }
-#[cfg(feature = "const-default")]
-impl const_default::ConstDefault for MilliString {
-    const DEFAULT: Self = MilliString::EMPTY;
+impl DecaString {
+    /**Concatenates this string with a [`CentiString`], which always fits in a [`HectoString`] since <code>127 + 31 <= 255</code>.
+
+This is also available as `+`.
+```
+# use microstring::*;
+let left = DecaString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home").unwrap();
+let right = CentiString::new("the quick brown fox").unwrap();
+let both: HectoString = left + right;
+assert_eq!(both, "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox homethe quick brown fox");
+assert_eq!(left.concat_centi_string(right), both);
+```
+*/
+    pub const fn concat_centi_string(self, other: CentiString) -> HectoString {
+        match HectoString::try_concat(self.as_str(), other.as_str()) {
+            Ok(it) => it,
+            Err(_) => unreachable!(),
+        }
+    }
 }
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-#[repr(u8)]
-#[cfg_attr(
-    feature = "zerocopy",
-    derive(zerocopy::TryFromBytes, zerocopy::IntoBytes, zerocopy::Immutable)
-)]
-enum MilliStringLen {
-    _0 = 0u8,
-    _1 = 1u8,
-    _2 = 2u8,
-    _3 = 3u8,
-    _4 = 4u8,
-    _5 = 5u8,
-    _6 = 6u8,
-    _7 = 7u8,
-    _8 = 8u8,
-    _9 = 9u8,
-    _10 = 10u8,
-    _11 = 11u8,
-    _12 = 12u8,
-    _13 = 13u8,
-    _14 = 14u8,
-    _15 = 15u8,
+impl ::core::ops::Add<CentiString> for DecaString {
+    type Output = HectoString;
+    fn add(self, rhs: CentiString) -> HectoString {
+        self.concat_centi_string(rhs)
+    }
 }
-impl MilliStringLen {
-    const fn from_usize(u: usize) -> Option<Self> {
-        const U8_MAX: usize = u8::MAX as _;
-        if u > U8_MAX {
-            return None;
+impl DecaString {
+    /**Concatenates this string with a [`DeciString`], which always fits in a [`HectoString`] since <code>127 + 63 <= 255</code>.
+
+This is also available as `+`.
+```
+# use microstring::*;
+let left = DecaString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home").unwrap();
+let right = DeciString::new("the quick brown fox jumps over the lazy dog").unwrap();
+let both: HectoString = left + right;
+assert_eq!(both, "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox homethe quick brown fox jumps over the lazy dog");
+assert_eq!(left.concat_deci_string(right), both);
+```
+*/
+    pub const fn concat_deci_string(self, other: DeciString) -> HectoString {
+        match HectoString::try_concat(self.as_str(), other.as_str()) {
+            Ok(it) => it,
+            Err(_) => unreachable!(),
         }
-        Self::from_u8(u as u8)
     }
-    const fn from_u8(u: u8) -> Option<Self> {
-        match u {
-            0u8 => Some(Self::_0),
-            1u8 => Some(Self::_1),
-            2u8 => Some(Self::_2),
-            3u8 => Some(Self::_3),
-            4u8 => Some(Self::_4),
-            5u8 => Some(Self::_5),
-            6u8 => Some(Self::_6),
-            7u8 => Some(Self::_7),
-            8u8 => Some(Self::_8),
-            9u8 => Some(Self::_9),
-            10u8 => Some(Self::_10),
-            11u8 => Some(Self::_11),
-            12u8 => Some(Self::_12),
-            13u8 => Some(Self::_13),
-            14u8 => Some(Self::_14),
-            15u8 => Some(Self::_15),
-            _ => None,
+}
+impl ::core::ops::Add<DeciString> for DecaString {
+    type Output = HectoString;
+    fn add(self, rhs: DeciString) -> HectoString {
+        self.concat_deci_string(rhs)
+    }
+}
+impl DecaString {
+    /**Concatenates this string with a [`DecaString`], which always fits in a [`HectoString`] since <code>127 + 127 <= 255</code>.
+
+This is also available as `+`.
+```
+# use microstring::*;
+let left = DecaString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home").unwrap();
+let right = DecaString::new("the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home").unwrap();
+let both: HectoString = left + right;
+assert_eq!(both, "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox homethe quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home");
+assert_eq!(left.concat_deca_string(right), both);
+```
+*/
+    pub const fn concat_deca_string(self, other: DecaString) -> HectoString {
+        match HectoString::try_concat(self.as_str(), other.as_str()) {
+            Ok(it) => it,
+            Err(_) => unreachable!(),
         }
     }
 }
+impl ::core::ops::Add<DecaString> for DecaString {
+    type Output = HectoString;
+    fn add(self, rhs: DecaString) -> HectoString {
+        self.concat_deca_string(rhs)
+    }
+}
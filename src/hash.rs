@@ -0,0 +1,148 @@
+use core::hash::{BuildHasher, Hasher};
+
+/// A fast [`Hasher`] for the packed integer representation of a string,
+/// as returned by e.g [`NanoString::to_bits`](crate::NanoString::to_bits).
+///
+/// This is not resistant to HashDoS.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WordHasher(u64);
+
+impl WordHasher {
+    const K: u64 = 0xf1357aea2e62a9c5;
+}
+
+impl Hasher for WordHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        let mut chunks = bytes.chunks_exact(8);
+        for chunk in &mut chunks {
+            self.write_u64(u64::from_ne_bytes(chunk.try_into().unwrap()))
+        }
+        let mut tail = [0; 8];
+        tail[..chunks.remainder().len()].copy_from_slice(chunks.remainder());
+        self.write_u64(u64::from_ne_bytes(tail))
+    }
+    fn write_u8(&mut self, i: u8) {
+        self.write_u64(i as u64)
+    }
+    fn write_u32(&mut self, i: u32) {
+        self.write_u64(i as u64)
+    }
+    fn write_u64(&mut self, i: u64) {
+        self.0 = self.0.wrapping_add(i).wrapping_mul(Self::K)
+    }
+    fn write_u128(&mut self, i: u128) {
+        self.write_u64(i as u64);
+        self.write_u64((i >> 64) as u64)
+    }
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64)
+    }
+    fn finish(&self) -> u64 {
+        // the multiply leaves the entropy in the high bits
+        self.0.rotate_left(26)
+    }
+}
+
+/// A [`BuildHasher`] for [`WordHasher`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BuildWordHasher;
+
+impl BuildHasher for BuildWordHasher {
+    type Hasher = WordHasher;
+    fn build_hasher(&self) -> WordHasher {
+        WordHasher::default()
+    }
+}
+
+/// Types which can be used to look up entries in a map keyed by `K`,
+/// such as `NanoMap`.
+///
+/// Looking up a [`str`] converts it to a `K` first,
+/// so strings which are too long are never found.
+pub trait Lookup<K> {
+    /// Returns [`None`] if there can be no entry for this key.
+    fn lookup(&self) -> Option<K>;
+}
+
+/// An entry in a map keyed by `K`, which may be vacant or occupied,
+/// as returned by e.g [`NanoMap::entry`](crate::NanoMap::entry).
+///
+/// Unlike [`hash_map::Entry`](std::collections::hash_map::Entry),
+/// the key is the string rather than its packed representation.
+/// ```
+/// # use microstring::*;
+/// let mut lens = NanoMap::new();
+/// for code in ["GBP", "US", "GBP"] {
+///     let entry = lens.entry(NanoString::new(code).unwrap());
+///     assert_eq!(*entry.key(), code);
+///     entry.and_modify(|len| *len += 10).or_insert_with_key(|key| key.len());
+/// }
+/// assert_eq!(lens.get("GBP"), Some(&13));
+/// assert_eq!(lens.get("US"), Some(&2));
+/// ```
+#[cfg(feature = "std")]
+pub struct Entry<'a, K: crate::InlineStr, V> {
+    key: K,
+    inner: std::collections::hash_map::Entry<'a, K::Bits, V>,
+}
+
+#[cfg(feature = "std")]
+impl<'a, K: crate::InlineStr, V> Entry<'a, K, V> {
+    /// Used by the map types, whose keys are packed with [`to_bits`](crate::InlineStr::to_bits).
+    #[doc(hidden)]
+    pub fn __new(
+        map: &'a mut std::collections::HashMap<K::Bits, V, BuildWordHasher>,
+        key: K,
+    ) -> Self {
+        Self {
+            key,
+            inner: map.entry(key.to_bits()),
+        }
+    }
+    /// The key this entry was created with.
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+    /// Inserts `default` if the entry is vacant, and returns a reference to the value.
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.inner.or_insert(default)
+    }
+    /// Inserts the result of `f` if the entry is vacant, and returns a reference to the value.
+    pub fn or_insert_with(self, f: impl FnOnce() -> V) -> &'a mut V {
+        self.inner.or_insert_with(f)
+    }
+    /// Inserts the result of `f` on the key if the entry is vacant,
+    /// and returns a reference to the value.
+    pub fn or_insert_with_key(self, f: impl FnOnce(&K) -> V) -> &'a mut V {
+        let key = self.key;
+        self.inner.or_insert_with(|| f(&key))
+    }
+    /// Calls `f` on the value if the entry is occupied.
+    pub fn and_modify(self, f: impl FnOnce(&mut V)) -> Self {
+        Self {
+            key: self.key,
+            inner: self.inner.and_modify(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, K: crate::InlineStr, V: Default> Entry<'a, K, V> {
+    /// Inserts [`V::default()`](Default::default) if the entry is vacant,
+    /// and returns a reference to the value.
+    pub fn or_default(self) -> &'a mut V {
+        self.inner.or_default()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: crate::InlineStr, V: core::fmt::Debug> core::fmt::Debug for Entry<'_, K, V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut f = f.debug_struct("Entry");
+        f.field("key", &self.key);
+        if let std::collections::hash_map::Entry::Occupied(it) = &self.inner {
+            f.field("value", it.get());
+        }
+        f.finish()
+    }
+}
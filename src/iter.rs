@@ -0,0 +1,155 @@
+use core::iter::FusedIterator;
+
+use crate::InlineStr;
+
+/// An owning iterator over the [`char`]s of a string,
+/// as returned by e.g <code>[NanoString](crate::NanoString)::into_iter</code>.
+///
+/// The string is stored inline, so this may outlive the string it was created from.
+/// ```
+/// # use microstring::*;
+/// fn codes() -> IntoChars<NanoString> {
+///     NanoString::new("GBP").unwrap().into_iter()
+/// }
+/// assert!(codes().eq(['G', 'B', 'P']));
+/// assert!(codes().rev().eq(['P', 'B', 'G']));
+/// ```
+#[derive(Debug, Clone)]
+pub struct IntoChars<T> {
+    s: T,
+    front: usize,
+    back: usize,
+}
+
+impl<T: InlineStr> IntoChars<T> {
+    /// Equivalent to [`IntoIterator::into_iter`].
+    pub fn new(s: T) -> Self {
+        Self {
+            front: 0,
+            back: s.len(),
+            s,
+        }
+    }
+    /// The remaining [`char`]s, as a [`str`].
+    pub fn as_str(&self) -> &str {
+        &self.s[self.front..self.back]
+    }
+}
+
+impl<T: InlineStr> Iterator for IntoChars<T> {
+    type Item = char;
+    fn next(&mut self) -> Option<char> {
+        let c = self.as_str().chars().next()?;
+        self.front += c.len_utf8();
+        Some(c)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.as_str().chars().size_hint()
+    }
+    fn count(self) -> usize {
+        self.as_str().chars().count()
+    }
+    fn last(mut self) -> Option<char> {
+        self.next_back()
+    }
+}
+
+impl<T: InlineStr> DoubleEndedIterator for IntoChars<T> {
+    fn next_back(&mut self) -> Option<char> {
+        let c = self.as_str().chars().next_back()?;
+        self.back -= c.len_utf8();
+        Some(c)
+    }
+}
+
+impl<T: InlineStr> FusedIterator for IntoChars<T> {}
+
+/// An owning iterator over the bytes of a string,
+/// as returned by e.g [`NanoString::into_bytes`](crate::NanoString::into_bytes).
+/// ```
+/// # use microstring::*;
+/// let mut bytes = NanoString::new("GBP").unwrap().into_bytes();
+/// assert_eq!(bytes.len(), 3);
+/// assert_eq!(bytes.next_back(), Some(b'P'));
+/// assert_eq!(bytes.as_slice(), b"GB");
+/// ```
+#[derive(Debug, Clone)]
+pub struct IntoBytes<T> {
+    s: T,
+    front: usize,
+    back: usize,
+}
+
+impl<T: InlineStr> IntoBytes<T> {
+    /// Equivalent to e.g [`NanoString::into_bytes`](crate::NanoString::into_bytes).
+    pub fn new(s: T) -> Self {
+        Self {
+            front: 0,
+            back: s.len(),
+            s,
+        }
+    }
+    /// The remaining bytes.
+    pub fn as_slice(&self) -> &[u8] {
+        &self.s.as_bytes()[self.front..self.back]
+    }
+}
+
+impl<T: InlineStr> Iterator for IntoBytes<T> {
+    type Item = u8;
+    fn next(&mut self) -> Option<u8> {
+        let b = *self.as_slice().first()?;
+        self.front += 1;
+        Some(b)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+    fn count(self) -> usize {
+        self.len()
+    }
+    fn last(mut self) -> Option<u8> {
+        self.next_back()
+    }
+    fn nth(&mut self, n: usize) -> Option<u8> {
+        self.front = self.back.min(self.front.saturating_add(n));
+        self.next()
+    }
+}
+
+impl<T: InlineStr> DoubleEndedIterator for IntoBytes<T> {
+    fn next_back(&mut self) -> Option<u8> {
+        let b = *self.as_slice().last()?;
+        self.back -= 1;
+        Some(b)
+    }
+}
+
+impl<T: InlineStr> ExactSizeIterator for IntoBytes<T> {}
+
+impl<T: InlineStr> FusedIterator for IntoBytes<T> {}
+
+/// Collects [`char`]s into a string, keeping the error if they do not fit.
+///
+/// [`FromIterator`] cannot fail, so strings are collected through this wrapper instead.
+/// See e.g [`NanoString::try_from_iter`](crate::NanoString::try_from_iter).
+/// ```
+/// # use microstring::*;
+/// let Collected(gbp) = "gbp"
+///     .chars()
+///     .map(|c| c.to_ascii_uppercase())
+///     .collect::<Collected<NanoString>>();
+/// assert_eq!(gbp.unwrap(), "GBP");
+///
+/// let Collected(gbpusd) = "gbpusd".chars().collect::<Collected<NanoString>>();
+/// assert_eq!(gbpusd.unwrap_err().kind(), ErrorKind::TooLong);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Collected<T: InlineStr>(pub Result<T, T::Error>);
+
+impl<T: InlineStr> FromIterator<char> for Collected<T> {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        Self(T::try_from_iter(iter))
+    }
+}
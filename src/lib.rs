@@ -18,6 +18,83 @@
 //!     size_of::<Option<NanoString>>(),
 //! }
 //! ```
+//!
+//! Codes can be dispatched with an integer switch on their packed [`to_bits`](NanoString::to_bits)
+//! representation, which is what `match_micro!` does, with the `macros` feature.
+//! ```
+//! # use microstring::*;
+//! const GBP: u32 = NanoString::new("GBP").unwrap().to_bits();
+//! const USD: u32 = NanoString::new("USD").unwrap().to_bits();
+//!
+//! fn symbol(code: NanoString) -> &'static str {
+//!     match code.to_bits() {
+//!         GBP => "£",
+//!         USD => "$",
+//!         _ => "¤",
+//!     }
+//! }
+//! assert_eq!(symbol(NanoString::new("GBP").unwrap()), "£");
+//! assert_eq!(symbol(NanoString::new("JPY").unwrap()), "¤");
+//! ```
+//!
+//! Strings compare with [`str`] in either order.
+//! ```
+//! # use microstring::*;
+//! let gbp = NanoString::new("GBP").unwrap();
+//! assert!(gbp == "GBP" && "GBP" == gbp);
+//! assert!("EUR" < gbp && gbp < *"USD");
+//! ```
+//!
+//! Strings widen into the larger sizes, and concatenate into the next size up.
+//! ```
+//! # use microstring::*;
+//! let gbp = NanoString::new("GBP").unwrap();
+//! let usd = NanoString::new("USD").unwrap();
+//! let pair: MicroString = gbp + usd;
+//! assert_eq!(pair, "GBPUSD");
+//! assert_eq!(MicroString::from(gbp), gbp);
+//! assert!(NanoString::try_from(pair).is_err());
+//! ```
+//!
+//! Most methods are `const`, so tables of codes can be checked at compile time.
+//! ```
+//! # use microstring::*;
+//! const CURRENCIES: [NanoString; 3] = [
+//!     NanoString::new("EUR").unwrap(),
+//!     NanoString::new("GBP").unwrap(),
+//!     NanoString::new("USD").unwrap(),
+//! ];
+//! const _: () = {
+//!     let mut ix = 0;
+//!     while ix < CURRENCIES.len() {
+//!         let code = CURRENCIES[ix];
+//!         assert!(code.len() == 3);
+//!         if ix > 0 {
+//!             assert!(CURRENCIES[ix - 1].const_cmp(code.as_str()).is_lt(), "not sorted");
+//!         }
+//!         ix += 1;
+//!     }
+//! };
+//! const _: () = assert!(CURRENCIES[1].const_starts_with("GB"));
+//! const _: () = assert!(CURRENCIES[2].const_ends_with("SD"));
+//! // the `str` methods are still reachable through `Deref`
+//! assert!(CURRENCIES[1].starts_with('G') && CURRENCIES[2].ends_with(char::is_uppercase));
+//! ```
+//!
+//! Strings convert to and from the standard string types, including paths and C strings.
+//! ```
+//! # use microstring::*;
+//! let gbp = NanoString::try_from(c"GBP").unwrap();
+//! # #[cfg(feature = "std")] {
+//! # use std::{path::{Path, PathBuf}, sync::Arc};
+//! assert_eq!(&*Arc::<str>::from(gbp), "GBP");
+//! assert_eq!(PathBuf::from(gbp), Path::new("GBP"));
+//! assert_eq!(
+//!     NanoString::try_from(Path::new("GEEBEEPEE")).unwrap_err().kind(),
+//!     ErrorKind::TooLong,
+//! );
+//! # }
+//! ```
 
 #![no_std]
 
@@ -26,5 +103,176 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+use core::{
+    borrow::{Borrow, BorrowMut},
+    error::Error,
+    fmt::{self, Debug, Display},
+    hash::Hash,
+    ops::{Deref, DerefMut},
+    str::FromStr,
+};
+
 mod generated;
+mod hash;
+mod iter;
 pub use generated::*;
+pub use hash::*;
+pub use iter::*;
+#[cfg(feature = "macros")]
+pub use microstring_macros::{MicroNewtype, define, match_micro, micro, milli, nano};
+
+/// A string type which is stored inline, such as [`NanoString`].
+///
+/// This trait is sealed, and implemented for every string type in this crate,
+/// and those created with `define!`, so code may be written once for all sizes.
+/// ```
+/// # use microstring::*;
+/// fn shout<T: InlineStr>(s: &str) -> Result<T, T::Error> {
+///     let mut it = s.parse::<T>()?;
+///     it.make_ascii_uppercase();
+///     Ok(it)
+/// }
+/// assert_eq!(shout::<NanoString>("gbp").unwrap(), "GBP");
+/// assert_eq!(shout::<MicroString>("gbp").unwrap(), "GBP");
+/// assert!(shout::<NanoString>("gbpusd").is_err());
+/// ```
+pub trait InlineStr:
+    sealed::Sealed
+    + Copy
+    + Eq
+    + Ord
+    + Hash
+    + Default
+    + Debug
+    + Display
+    + Send
+    + Sync
+    + 'static
+    + AsRef<str>
+    + AsMut<str>
+    + AsRef<[u8]>
+    + Borrow<str>
+    + BorrowMut<str>
+    + Deref<Target = str>
+    + DerefMut
+    + PartialEq<str>
+    + for<'a> PartialEq<&'a str>
+    + PartialOrd<str>
+    + for<'a> PartialOrd<&'a str>
+    + FromStr<Err = Self::Error>
+    + fmt::Write
+    + Extend<char>
+    + for<'a> Extend<&'a str>
+    + IntoIterator<Item = char, IntoIter = IntoChars<Self>>
+{
+    /// The maximum length, in bytes.
+    const CAPACITY: usize;
+    /// The empty string.
+    const EMPTY: Self;
+    /// The error returned when a string cannot be constructed.
+    type Error: Error + Copy + Eq + Hash + Send + Sync + 'static;
+    /// The integer with the same size as this type, as used by [`to_bits`](InlineStr::to_bits),
+    /// or a byte array if there is no such integer.
+    type Bits: Copy + Eq + Ord + Hash + Debug + Send + Sync + 'static;
+    /// Returns [`None`] if the given <code>[str::len()] > [CAPACITY](InlineStr::CAPACITY)</code>.
+    fn new(s: &str) -> Option<Self>;
+    fn as_str(&self) -> &str;
+    fn as_mut_str(&mut self) -> &mut str;
+    /// Returns an error if the given bytes are not UTF-8,
+    /// or if <code>[slice::len()] > [CAPACITY](InlineStr::CAPACITY)</code>.
+    fn from_utf8(bytes: &[u8]) -> Result<Self, Self::Error>;
+    /// Packs this string into an integer, or a byte array.
+    ///
+    /// See e.g [`NanoString::to_bits`].
+    fn to_bits(self) -> Self::Bits;
+    /// The inverse of [`to_bits`](InlineStr::to_bits).
+    fn from_bits(bits: Self::Bits) -> Option<Self>;
+    /// Appends the given string to the end of this one.
+    fn push_str(&mut self, s: &str) -> Result<(), Self::Error>;
+    /// Collects the given [`char`]s into a string,
+    /// returning an error if there are too many to fit.
+    ///
+    /// See also [`Collected`].
+    fn try_from_iter<I: IntoIterator<Item = char>>(iter: I) -> Result<Self, Self::Error>;
+    /// Used by `#[derive(MicroNewtype)]` to report validation failures.
+    #[doc(hidden)]
+    fn __error(kind: ErrorKind, len: usize) -> Self::Error;
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// The reason a string could not be constructed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The input was longer than the capacity.
+    TooLong,
+    /// The input was not valid UTF-8.
+    InvalidUtf8,
+    /// The input was rejected by a validator.
+    Invalid,
+}
+
+/// Items used by code generated by `microstring::define!`, which is not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::sealed::Sealed;
+    #[cfg(feature = "alloc")]
+    pub extern crate alloc;
+    #[cfg(feature = "arbitrary")]
+    pub use arbitrary;
+    #[cfg(feature = "const-default")]
+    pub use const_default;
+    #[cfg(feature = "schemars")]
+    pub use schemars;
+    #[cfg(feature = "serde")]
+    pub use serde;
+    #[cfg(feature = "std")]
+    pub extern crate std;
+    #[cfg(feature = "unicode-segmentation")]
+    pub use unicode_segmentation;
+    #[cfg(feature = "zerocopy")]
+    pub use zerocopy;
+
+    /// [`Ord`] for byte slices, which is not `const`.
+    pub const fn cmp_bytes(left: &[u8], right: &[u8]) -> core::cmp::Ordering {
+        let mut ix = 0;
+        while ix < left.len() && ix < right.len() {
+            if left[ix] != right[ix] {
+                return match left[ix] < right[ix] {
+                    true => core::cmp::Ordering::Less,
+                    false => core::cmp::Ordering::Greater,
+                };
+            }
+            ix += 1;
+        }
+        if left.len() < right.len() {
+            core::cmp::Ordering::Less
+        } else if left.len() > right.len() {
+            core::cmp::Ordering::Greater
+        } else {
+            core::cmp::Ordering::Equal
+        }
+    }
+
+    /// Returns a [`char`] which is `width` bytes long when UTF-8 encoded.
+    #[cfg(feature = "arbitrary")]
+    pub fn arbitrary_char(
+        u: &mut arbitrary::Unstructured<'_>,
+        width: usize,
+    ) -> arbitrary::Result<char> {
+        let c = match width {
+            1 => u.int_in_range(0..=0x7F)?,
+            2 => u.int_in_range(0x80..=0x7FF)?,
+            // skip the surrogates
+            3 => match u.int_in_range(0x800..=0xFFFF - 0x800)? {
+                c if c >= 0xD800 => c + 0x800,
+                c => c,
+            },
+            _ => u.int_in_range(0x1_0000..=0x10_FFFF)?,
+        };
+        Ok(char::from_u32(c).expect("code point is in range, and not a surrogate"))
+    }
+}
@@ -0,0 +1,92 @@
+//! Races writers against readers on the atomic strings.
+
+#![cfg(target_has_atomic = "64")]
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+use microstring::*;
+
+const WRITERS: usize = 4;
+const READERS: usize = 4;
+const ITERATIONS: usize = 100_000;
+
+/// A string of `len` copies of the same letter, which is chosen by `len`,
+/// so a string built from parts of two of them is recognisably torn.
+fn milli(len: usize) -> MilliString {
+    let letter = char::from(b'a' + len as u8);
+    MilliString::try_from_iter((0..len).map(|_| letter)).unwrap()
+}
+
+fn is_milli(s: MilliString) -> bool {
+    s.bytes().all(|b| usize::from(b - b'a') == s.len())
+}
+
+#[test]
+fn milli_loads_are_never_torn() {
+    let atomic = AtomicMilliString::new(milli(0));
+    let writing = AtomicUsize::new(WRITERS);
+    thread::scope(|scope| {
+        for writer in 0..WRITERS {
+            let (atomic, writing) = (&atomic, &writing);
+            scope.spawn(move || {
+                for i in 0..ITERATIONS {
+                    let s = milli((writer + i) % (MilliString::CAPACITY + 1));
+                    match i % 3 {
+                        0 => atomic.store(s),
+                        1 => assert!(is_milli(atomic.swap(s))),
+                        _ => {
+                            let prev = atomic.load();
+                            if let Err(it) = atomic.compare_exchange(prev, s) {
+                                assert!(is_milli(it));
+                            }
+                        }
+                    }
+                }
+                writing.fetch_sub(1, Ordering::Release);
+            });
+        }
+        for _ in 0..READERS {
+            scope.spawn(|| {
+                while writing.load(Ordering::Acquire) > 0 {
+                    let s = atomic.load();
+                    assert!(is_milli(s), "torn load: {:?}", s.as_bytes());
+                }
+            });
+        }
+    });
+    assert!(is_milli(atomic.into_inner()));
+}
+
+/// Counts in decimal, so that lost updates show up in the total.
+fn increment<T: InlineStr>(s: T) -> Option<T> {
+    let n = s.parse::<u64>().unwrap();
+    T::new(&(n + 1).to_string())
+}
+
+#[test]
+fn fetch_update_loses_no_updates() {
+    let nano = AtomicNanoString::new(NanoString::new("0").unwrap());
+    let micro = AtomicMicroString::new(MicroString::new("0").unwrap());
+    let milli = AtomicMilliString::new(MilliString::new("0").unwrap());
+    thread::scope(|scope| {
+        for _ in 0..WRITERS {
+            scope.spawn(|| {
+                for _ in 0..ITERATIONS {
+                    nano.fetch_update(Ordering::AcqRel, Ordering::Acquire, |s| {
+                        increment(s).or(NanoString::new("0"))
+                    })
+                    .unwrap();
+                    micro
+                        .fetch_update(Ordering::AcqRel, Ordering::Acquire, increment)
+                        .unwrap();
+                    milli.fetch_update(increment).unwrap();
+                }
+            });
+        }
+    });
+    let total = WRITERS * ITERATIONS;
+    assert_eq!(nano.into_inner().as_str(), (total % 1000).to_string());
+    assert_eq!(micro.into_inner().as_str(), total.to_string());
+    assert_eq!(milli.into_inner().as_str(), total.to_string());
+}
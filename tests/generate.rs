@@ -1,371 +1,185 @@
-use proc_macro2::{Ident, Literal, Span, TokenStream};
+use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
 use syn::parse_quote;
 
+#[path = "../macros/src/string.rs"]
+#[allow(dead_code, reason = "only microstring-macros expands downstream")]
+mod string;
+use string::{Host, string};
+
+const FOX: &str = "the quick brown fox";
+const FOX_DOG: &str = "the quick brown fox jumps over the lazy dog";
+const FOX_DOG_HOME: &str = "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home";
+const FOX_DOG_HILLS: &str = "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, \
+    where they both sleep by the fire until the sun rises over the hills";
+const FOX_DOG_DAWN: &str = "the quick brown fox jumps over the lazy dog, then the lazy dog chases the quick brown fox home, \
+    where they both sleep by the fire until the sun rises over the hills, \
+    and at dawn the quick brown fox wakes, stretches, and trots off into the woods to find its breakfast, \
+    while the lazy dog dreams on";
+
 #[test]
 fn test() {
-    let nano = string(&parse_quote!(NanoString), 3, "u32", "GBP", "GEEBEEPEE");
-    let micro = string(&parse_quote!(MicroString), 7, "u64", "1234567", "12345678");
-    let milli = string(
-        &parse_quote!(MilliString),
-        15,
-        "u128",
-        "hello world :)",
-        "goodbye world :(",
-    );
+    let strings = [
+        (parse_quote!(NanoString), 3, "GBP", "GEEBEEPEE"),
+        (parse_quote!(MicroString), 7, "1234567", "12345678"),
+        (
+            parse_quote!(MilliString),
+            15,
+            "hello world :)",
+            "goodbye world :(",
+        ),
+        (parse_quote!(CentiString), 31, FOX, FOX_DOG),
+        (parse_quote!(DeciString), 63, FOX_DOG, FOX_DOG_HOME),
+        (parse_quote!(DecaString), 127, FOX_DOG_HOME, FOX_DOG_HILLS),
+        (parse_quote!(HectoString), 255, FOX_DOG_HILLS, FOX_DOG_DAWN),
+    ];
+    let sizes = strings
+        .iter()
+        .map(|(ident, n, small, _)| (Ident::clone(ident), *n, *small))
+        .collect::<Vec<_>>();
+    let strings = strings.iter().map(|(ident, n, small, big)| {
+        string(&Host::Microstring { small, big }, &quote!(pub), ident, *n)
+    });
+    let conversions = sizes.iter().enumerate().flat_map(|(ix, narrow)| {
+        sizes[ix + 1..]
+            .iter()
+            .map(move |wide| conversions(narrow, wide))
+    });
+    let concats = sizes.iter().flat_map(|left| {
+        sizes.iter().filter_map(|right| {
+            let output = sizes
+                .iter()
+                .find(|output| u16::from(output.1) >= u16::from(left.1) + u16::from(right.1))?;
+            Some(concat(left, right, output))
+        })
+    });
     let pretty = prettyplease::unparse(
         &syn::parse2(quote! {
             #![cfg_attr(rustfmt, rustfmt::skip)]
-            #nano
-            #micro
-            #milli
+            #(#strings)*
+            #(#conversions)*
+            #(#concats)*
         })
         .unwrap(),
     );
     expect_test::expect_file!["../src/generated.rs"].assert_eq(&pretty);
 }
 
-fn string(ident: &Ident, n: u8, prim: &str, small: &str, big: &str) -> TokenStream {
-    let err_ident = Ident::new(&format!("{ident}Error"), Span::call_site());
-    let len_ident = Ident::new(&format!("{ident}Len"), Span::call_site());
-    let len = len(&len_ident, n);
-    let struct_doc = indoc::formatdoc! {"
-        A stack-allocated string which can hold up to {n} UTF-8 encoded bytes.
-        ```
-        # use core::mem::size_of;
-        # use microstring::*;
-        assert_eq! {{
-            size_of::<{ident}>(),
-            size_of::<{prim}>(),
-        }}
-        assert_eq! {{
-            size_of::<{ident}>(),
-            size_of::<Option<{ident}>>(),
-        }}
-        ```
-    "};
-    let new_doc = indoc::formatdoc! {"
-        Returns [`None`] if the given <code>[str::len()] > {n}</code>.
+fn conversions(
+    (narrow, narrow_n, narrow_small): &(Ident, u8, &str),
+    (wide, wide_n, wide_small): &(Ident, u8, &str),
+) -> TokenStream {
+    let from_narrow = Ident::new(&format!("from_{}", snake_case(narrow)), Span::call_site());
+    let narrow_err = Ident::new(&format!("{narrow}Error"), Span::call_site());
+    // each size's example string is too long for all of the smaller sizes
+    let ordering = narrow_small.cmp(wide_small);
+    let from_narrow_doc = indoc::formatdoc! {"
+        Converts a [`{narrow}`] into a [`{wide}`], which always succeeds since <code>{narrow_n} <= {wide_n}</code>.
+
+        The sizes also convert the other way with [`TryFrom`], and compare with each other.
         ```
         # use microstring::*;
-        const STRING: {ident} = {ident}::new(\"{small}\").unwrap();
+        let narrow = {narrow}::new(\"{narrow_small}\").unwrap();
+        let wide = {wide}::from(narrow);
+        assert_eq!(wide, narrow);
+        assert_eq!({narrow}::try_from(wide).unwrap(), narrow);
+
+        let long = {wide}::new(\"{wide_small}\").unwrap();
+        assert_eq!({narrow}::try_from(long).unwrap_err().kind(), ErrorKind::TooLong);
+        assert_ne!(long, narrow);
+        assert_eq!(narrow.partial_cmp(&long), Some(core::cmp::Ordering::{ordering:?}));
+        assert_eq!(long.partial_cmp(&narrow), Some(core::cmp::Ordering::{:?}));
         ```
-        ```compile_fail
-        # use microstring::*;
-        const TOO_BIG: {ident} = {ident}::new(\"{big}\").unwrap();
-        ```
-    "};
-    let err_msg = format!("expected a string of at most {n} bytes");
-    let deser_err_msg = format!("a string of at most {n} bytes");
+    ", ordering.reverse()};
     quote! {
-        #[doc = #struct_doc]
-        #[derive(Clone, Copy, Eq)]
-        #[repr(C)]
-        #[cfg_attr(feature = "zerocopy", derive(zerocopy::TryFromBytes, zerocopy::IntoBytes, zerocopy::Immutable))]
-        pub struct #ident {
-            len: #len_ident,
-            bytes: [u8; #n as _]
-        }
-        impl #ident {
-            pub const EMPTY: Self = Self::new("").unwrap();
-
-            #[doc = #new_doc]
-            pub const fn new(s: &str) -> Option<Self> {
-                match #len_ident::from_usize(s.len()) {
-                    Some(len) => {
-                        let mut bytes = [0; #n as _];
-                        unsafe {
-                            ::core::ptr::copy_nonoverlapping(s.as_ptr(), bytes.as_mut_ptr(), s.len())
-                        }
-                        Some(Self { len, bytes })
-                    },
-                    None => None
-                }
-            }
-            pub const fn as_str(&self) -> &str {
-                unsafe {
-                    str::from_utf8_unchecked(
-                        ::core::slice::from_raw_parts(
-                            self.bytes.as_ptr(),
-                            self.len as u8 as usize
-                        )
-                    )
-                }
-            }
-            pub const fn as_mut_str(&mut self) -> &mut str {
-                unsafe {
-                    str::from_utf8_unchecked_mut(
-                        ::core::slice::from_raw_parts_mut(
-                            self.bytes.as_mut_ptr(),
-                            self.len as u8 as usize
-                        )
-                    )
+        impl #wide {
+            #[doc = #from_narrow_doc]
+            pub const fn #from_narrow(s: #narrow) -> Self {
+                match Self::new(s.as_str()) {
+                    Some(it) => it,
+                    None => unreachable!(),
                 }
             }
         }
-
-        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-        pub struct #err_ident;
-
-        impl ::core::fmt::Display for #err_ident {
-            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                f.write_str(#err_msg)
-            }
-        }
-
-        impl ::core::error::Error for #err_ident {}
-
-        // default
-        // -------
-
-        impl ::core::default::Default for #ident {
-            fn default() -> Self {
-                Self::EMPTY
-            }
-        }
-        impl ::core::default::Default for &#ident {
-            fn default() -> Self {
-                &#ident::EMPTY
-            }
-        }
-
-        // hash
-        // ----
-
-        impl ::core::hash::Hash for #ident {
-            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
-                self.as_str().hash(state)
-            }
-        }
-
-        // convert
-        // -------
-
-        impl ::core::convert::AsRef<Self> for #ident {
-            fn as_ref(&self) -> &Self {
-                self
-            }
-        }
-        impl ::core::convert::AsMut<Self> for #ident {
-            fn as_mut(&mut self) -> &mut Self {
-                self
-            }
-        }
-
-        impl ::core::convert::AsRef<str> for #ident {
-            fn as_ref(&self) -> &str {
-                self.as_str()
-            }
-        }
-        impl ::core::convert::AsMut<str> for #ident {
-            fn as_mut(&mut self) -> &mut str {
-                self.as_mut_str()
+        impl ::core::convert::From<#narrow> for #wide {
+            fn from(value: #narrow) -> Self {
+                Self::#from_narrow(value)
             }
         }
-
-        impl ::core::convert::AsRef<[u8]> for #ident {
-            fn as_ref(&self) -> &[u8] {
-                self.as_str().as_bytes()
+        impl ::core::convert::TryFrom<#wide> for #narrow {
+            type Error = #narrow_err;
+            fn try_from(value: #wide) -> Result<Self, #narrow_err> {
+                Self::try_from(value.as_str())
             }
         }
-
-        impl TryFrom<&str> for #ident {
-            type Error = #err_ident;
-            fn try_from(value: &str) -> Result<Self, #err_ident> {
-                Self::new(value).ok_or(#err_ident)
+        impl ::core::cmp::PartialEq<#wide> for #narrow {
+            fn eq(&self, other: &#wide) -> bool {
+                #wide::#from_narrow(*self).eq(other)
             }
         }
-
-        #[cfg(feature = "std")]
-        impl ::core::convert::AsRef<::std::ffi::OsStr> for #ident {
-            fn as_ref(&self) -> &::std::ffi::OsStr {
-                self.as_str().as_ref()
+        impl ::core::cmp::PartialEq<#narrow> for #wide {
+            fn eq(&self, other: &#narrow) -> bool {
+                self.eq(&#wide::#from_narrow(*other))
             }
         }
-
-        #[cfg(feature = "std")]
-        impl ::core::convert::AsRef<::std::path::Path> for #ident {
-            fn as_ref(&self) -> &::std::path::Path {
-                self.as_str().as_ref()
+        impl ::core::cmp::PartialOrd<#wide> for #narrow {
+            fn partial_cmp(&self, other: &#wide) -> Option<::core::cmp::Ordering> {
+                #wide::#from_narrow(*self).partial_cmp(other)
             }
         }
-
-        #[cfg(feature = "alloc")]
-        impl ::core::convert::From<#ident> for ::alloc::string::String {
-            fn from(val: #ident) -> Self {
-                Self::from(val.as_str())
-            }
-        }
-
-        #[cfg(feature = "alloc")]
-        impl ::core::convert::TryFrom<::alloc::string::String> for #ident {
-            type Error = #err_ident;
-            fn try_from(value: ::alloc::string::String) -> Result<Self, #err_ident> {
-                Self::new(&value).ok_or(#err_ident)
-            }
-        }
-
-        // borrow
-        // ------
-
-        impl ::core::borrow::Borrow<str> for #ident {
-            fn borrow(&self) -> &str {
-                self.as_str()
+        impl ::core::cmp::PartialOrd<#narrow> for #wide {
+            fn partial_cmp(&self, other: &#narrow) -> Option<::core::cmp::Ordering> {
+                self.partial_cmp(&#wide::#from_narrow(*other))
             }
         }
-        impl ::core::borrow::BorrowMut<str> for #ident {
-            fn borrow_mut(&mut self) -> &mut str {
-                self.as_mut_str()
-            }
-        }
-
-        // ops
-        // ---
-
-        impl ::core::ops::Deref for #ident {
-            type Target = str;
-            fn deref(&self) -> &str {
-                self.as_str()
-            }
-        }
-        impl ::core::ops::DerefMut for #ident {
-            fn deref_mut(&mut self) -> &mut str {
-                self.as_mut_str()
-            }
-        }
-
-        // cmp
-        // ---
-
-        impl<T: ::core::convert::AsRef<str>> ::core::cmp::PartialEq<T> for #ident {
-            fn eq(&self, other: &T) -> bool {
-                self.as_str().eq(other.as_ref())
-            }
-        }
-        impl<T: ::core::convert::AsRef<str>> ::core::cmp::PartialOrd<T> for #ident {
-            fn partial_cmp(&self, other: &T) -> Option<::core::cmp::Ordering> {
-                self.as_str().partial_cmp(other.as_ref())
-            }
-        }
-
-        // fmt
-        // ---
-
-        impl ::core::fmt::Debug for #ident {
-            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                self.as_str().fmt(f)
-            }
-        }
-        impl ::core::fmt::Display for #ident {
-            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                self.as_str().fmt(f)
-            }
-        }
-
-        // str
-        // ---
-
-        impl ::core::str::FromStr for #ident {
-            type Err = #err_ident;
-            fn from_str(s: &str) -> Result<Self, Self::Err> {
-                Self::new(s).ok_or(#err_ident)
-            }
-        }
-
-        // serde
-        // -----
-
-        #[cfg(feature = "serde")]
-        impl serde::Serialize for #ident {
-            fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
-                s.serialize_str(self)
-            }
-        }
-
-        #[cfg(feature = "serde")]
-        impl<'de> serde::Deserialize<'de> for #ident {
-            fn deserialize<D: serde::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
-                struct Visitor;
-                impl<'de> serde::de::Visitor<'de> for Visitor {
-                    type Value = #ident;
-                    fn expecting(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                        f.write_str(#deser_err_msg)
-                    }
-                    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<#ident, E> {
-                        #ident::new(v).ok_or_else(||serde::de::Error::invalid_value(serde::de::Unexpected::Str(v), &self))
-                    }
-                }
-                d.deserialize_str(Visitor)
-            }
-        }
-
-        // schemars
-        // --------
-
-        #[cfg(feature = "schemars")]
-        impl schemars::JsonSchema for #ident {
-            fn schema_name() -> ::std::borrow::Cow<'static, str> {
-                ::std::borrow::Cow::Borrowed(::core::stringify!(#ident))
-            }
-            fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
-                schemars::json_schema! {{
-                    "type": "string",
-                    "maxLength": #n,
-                }}
-            }
-            fn schema_id() -> ::std::borrow::Cow<'static, str> {
-                ::std::borrow::Cow::Borrowed(::core::concat!(
-                    ::core::module_path!(),
-                    "::",
-                    ::core::stringify!(#ident)
-                ))
-            }
-        }
-
-        // const_default
+    }
+}
 
-        #[cfg(feature = "const-default")]
-        impl const_default::ConstDefault for #ident {
-            const DEFAULT: Self = #ident::EMPTY;
+fn snake_case(ident: &Ident) -> String {
+    let mut s = String::new();
+    for (ix, c) in ident.to_string().chars().enumerate() {
+        if c.is_ascii_uppercase() && ix != 0 {
+            s.push('_')
         }
-
-        // len
-        // ---
-
-        #len
+        s.push(c.to_ascii_lowercase())
     }
+    s
 }
 
-fn len(ident: &Ident, n: u8) -> TokenStream {
-    let (variants, vals) = (0..=n)
-        .map(|i| {
-            let ident = Ident::new(&format!("_{i}"), Span::call_site());
-            let val = Literal::u8_suffixed(i);
-            (ident, val)
-        })
-        .unzip::<_, _, Vec<_>, Vec<_>>();
+fn concat(
+    (left, left_n, left_small): &(Ident, u8, &str),
+    (right, right_n, right_small): &(Ident, u8, &str),
+    (output, output_n, _): &(Ident, u8, &str),
+) -> TokenStream {
+    let concat = Ident::new(&format!("concat_{}", snake_case(right)), Span::call_site());
+    let concat_doc = indoc::formatdoc! {"
+        Concatenates this string with a [`{right}`], \
+        which always fits in a [`{output}`] since <code>{left_n} + {right_n} <= {output_n}</code>.
+
+        This is also available as `+`.
+        ```
+        # use microstring::*;
+        let left = {left}::new(\"{left_small}\").unwrap();
+        let right = {right}::new(\"{right_small}\").unwrap();
+        let both: {output} = left + right;
+        assert_eq!(both, \"{left_small}{right_small}\");
+        assert_eq!(left.{concat}(right), both);
+        ```
+    "};
     quote! {
-        #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-        #[repr(u8)]
-        #[cfg_attr(feature = "zerocopy", derive(zerocopy::TryFromBytes, zerocopy::IntoBytes, zerocopy::Immutable))]
-        enum #ident {
-            #(#variants = #vals),*
-        }
-        impl #ident {
-            const fn from_usize(u: usize) -> Option<Self> {
-                const U8_MAX: usize = u8::MAX as _;
-                if u > U8_MAX {
-                    return None;
+        impl #left {
+            #[doc = #concat_doc]
+            pub const fn #concat(self, other: #right) -> #output {
+                match #output::try_concat(self.as_str(), other.as_str()) {
+                    Ok(it) => it,
+                    Err(_) => unreachable!(),
                 }
-                Self::from_u8(u as u8)
             }
-            const fn from_u8(u: u8) -> Option<Self> {
-                match u {
-                    #(
-                        #vals => Some(Self::#variants),
-                    )*
-                    _ => None
-                }
+        }
+        impl ::core::ops::Add<#right> for #left {
+            type Output = #output;
+            fn add(self, rhs: #right) -> #output {
+                self.#concat(rhs)
             }
         }
     }